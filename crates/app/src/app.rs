@@ -92,6 +92,16 @@ pub fn App() -> impl IntoView {
                         }
                     }
                 />
+                <Route
+                    path=path!("/waiting")
+                    view=|| {
+                        view! {
+                            <components::layout::AppLayout>
+                                <pages::waiting::WaitingPage/>
+                            </components::layout::AppLayout>
+                        }
+                    }
+                />
                 <Route
                     path=path!("/projects/:id")
                     view=|| {
@@ -112,6 +122,26 @@ pub fn App() -> impl IntoView {
                         }
                     }
                 />
+                <Route
+                    path=path!("/stats")
+                    view=|| {
+                        view! {
+                            <components::layout::AppLayout>
+                                <pages::stats::StatsPage/>
+                            </components::layout::AppLayout>
+                        }
+                    }
+                />
+                <Route
+                    path=path!("/trash")
+                    view=|| {
+                        view! {
+                            <components::layout::AppLayout>
+                                <pages::trash::TrashPage/>
+                            </components::layout::AppLayout>
+                        }
+                    }
+                />
                 <Route
                     path=path!("/review")
                     view=|| {
@@ -122,6 +152,36 @@ pub fn App() -> impl IntoView {
                         }
                     }
                 />
+                <Route
+                    path=path!("/review/projects")
+                    view=|| {
+                        view! {
+                            <components::layout::AppLayout>
+                                <pages::project_review::ProjectReviewPage/>
+                            </components::layout::AppLayout>
+                        }
+                    }
+                />
+                <Route
+                    path=path!("/tags")
+                    view=|| {
+                        view! {
+                            <components::layout::AppLayout>
+                                <pages::tags::TagsPage/>
+                            </components::layout::AppLayout>
+                        }
+                    }
+                />
+                <Route
+                    path=path!("/tags/:name")
+                    view=|| {
+                        view! {
+                            <components::layout::AppLayout>
+                                <pages::tag::TagPage/>
+                            </components::layout::AppLayout>
+                        }
+                    }
+                />
                 <Route
                     path=path!("/settings")
                     view=|| {
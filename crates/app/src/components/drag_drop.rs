@@ -12,6 +12,10 @@ pub struct DragDropContext {
     pub dragging_task_id: RwSignal<Option<i64>>,
     pub dragging_is_someday: RwSignal<bool>,
     pub drop_target: RwSignal<Option<(i64, DropZone)>>,
+    /// Kanban column under the cursor, for drops onto a lane rather than a card.
+    pub drop_column: RwSignal<Option<i64>>,
+    /// List section header under the cursor, for drops into a section.
+    pub drop_section: RwSignal<Option<i64>>,
 }
 
 impl Default for DragDropContext {
@@ -26,6 +30,8 @@ impl DragDropContext {
             dragging_task_id: RwSignal::new(None),
             dragging_is_someday: RwSignal::new(false),
             drop_target: RwSignal::new(None),
+            drop_column: RwSignal::new(None),
+            drop_section: RwSignal::new(None),
         }
     }
 }
@@ -29,6 +29,9 @@ pub fn FilterAutocompleteTextarea(
                 } else {
                     s.color
                 },
+                group: None,
+                value: None,
+                detail: None,
             })
             .collect::<Vec<_>>()
     });
@@ -15,7 +15,18 @@ pub fn KeybindingsModal(open: ReadSignal<bool>, set_open: WriteSignal<bool>) ->
         ("E", "Open detail"),
         ("R", "Mark as reviewed"),
         ("S", "Toggle someday"),
-        ("Delete", "Delete task"),
+        ("N", "Skip to next occurrence"),
+        ("P", "Postpone to a date"),
+        ("1 \u{2013} 4 / 0", "Set / clear priority"),
+        ("T", "Start / stop timer"),
+        ("Delete", "Delete task or selection"),
+        ("X", "Select / deselect task"),
+        ("Shift+J / Shift+K", "Extend selection down/up"),
+        ("Shift+Click", "Select range"),
+        ("Ctrl+Click", "Select / deselect task"),
+        ("Ctrl+K", "Command palette"),
+        ("Ctrl+Z", "Undo"),
+        ("Ctrl+Shift+Z", "Redo"),
         ("Escape", "Clear selection"),
         ("?", "This help"),
     ];
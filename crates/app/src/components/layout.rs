@@ -3,9 +3,12 @@ use leptos_router::hooks::use_navigate;
 use north_repositories::ErrorNotifier;
 use north_stores::status_bar_store::StatusBarVariant;
 use north_stores::AppStore;
+use wasm_bindgen::JsCast;
 
 use crate::components::connectivity_monitor::ConnectivityMonitor;
 use crate::components::status_bar::StatusBar;
+use crate::components::theme_toggle::ThemeState;
+use crate::containers::command_palette::CommandPalette;
 use crate::containers::sidebar::Sidebar;
 use crate::containers::task_detail_modal::TaskDetailModal;
 use north_server_fns::auth::check_auth;
@@ -19,6 +22,7 @@ pub fn AppLayout(children: Children) -> impl IntoView {
     provide_context(app_store);
     provide_context(app_store.modal);
     provide_context(app_store.task_detail_modal);
+    provide_context(ThemeState::new());
 
     let status_bar = app_store.status_bar;
     provide_context(ErrorNotifier(Callback::new(move |msg: String| {
@@ -35,6 +39,28 @@ pub fn AppLayout(children: Children) -> impl IntoView {
         app_store.refetch();
     });
 
+    // Ctrl+Z / Ctrl+Shift+Z undo and redo task changes. Text fields keep
+    // their native undo.
+    window_event_listener(leptos::ev::keydown, move |ev| {
+        if !(ev.ctrl_key() || ev.meta_key()) || !ev.key().eq_ignore_ascii_case("z") {
+            return;
+        }
+        if let Some(el) = document().active_element() {
+            if let Some(html_el) = el.dyn_ref::<web_sys::HtmlElement>() {
+                let tag = html_el.tag_name().to_lowercase();
+                if tag == "input" || tag == "textarea" || html_el.is_content_editable() {
+                    return;
+                }
+            }
+        }
+        ev.prevent_default();
+        if ev.shift_key() {
+            app_store.tasks.redo();
+        } else {
+            app_store.tasks.undo();
+        }
+    });
+
     view! {
         <div class="flex h-screen">
             <Sidebar/>
@@ -43,6 +69,7 @@ pub fn AppLayout(children: Children) -> impl IntoView {
             </main>
         </div>
         <TaskDetailModal/>
+        <CommandPalette/>
         <ConnectivityMonitor/>
         <StatusBar/>
     }
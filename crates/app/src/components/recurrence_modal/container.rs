@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use leptos::prelude::*;
 use north_dto::RecurrenceType;
 
@@ -8,10 +9,11 @@ use super::view::RecurrenceModalView;
 pub fn RecurrenceModal(
     recurrence_type: Option<RecurrenceType>,
     recurrence_rule: Option<String>,
-    on_save: Callback<(Option<RecurrenceType>, Option<String>)>,
+    recurrence_exdates: Vec<NaiveDate>,
+    on_save: Callback<(Option<RecurrenceType>, Option<String>, Vec<NaiveDate>)>,
     on_close: Callback<()>,
 ) -> impl IntoView {
-    let ctrl = RecurrenceModalController::new(recurrence_type, recurrence_rule);
+    let ctrl = RecurrenceModalController::new(recurrence_type, recurrence_rule, recurrence_exdates);
 
     view! {
         <RecurrenceModalView
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use north_dto::RecurrenceType;
 use north_stores::use_app_store;
 
@@ -9,18 +10,27 @@ pub struct RecurrenceModalController {
 }
 
 impl RecurrenceModalController {
-    pub fn new(existing_type: Option<RecurrenceType>, existing_rule: Option<String>) -> Self {
+    pub fn new(
+        existing_type: Option<RecurrenceType>,
+        existing_rule: Option<String>,
+        existing_exdates: Vec<NaiveDate>,
+    ) -> Self {
         let app_store = use_app_store();
         let timezone = app_store.settings.timezone();
-        let rule = ReactiveRecurrenceRule::from_str(existing_type, existing_rule, timezone);
+        let rule = ReactiveRecurrenceRule::from_str(
+            existing_type,
+            existing_rule,
+            existing_exdates,
+            timezone,
+        );
         Self { rule }
     }
 
-    pub fn save_result(&self) -> (Option<RecurrenceType>, Option<String>) {
+    pub fn save_result(&self) -> (Option<RecurrenceType>, Option<String>, Vec<NaiveDate>) {
         self.rule.to_result()
     }
 
-    pub fn remove_result() -> (Option<RecurrenceType>, Option<String>) {
-        (None, None)
+    pub fn remove_result() -> (Option<RecurrenceType>, Option<String>, Vec<NaiveDate>) {
+        (None, None, vec![])
     }
 }
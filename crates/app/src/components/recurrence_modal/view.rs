@@ -5,6 +5,7 @@ use north_ui::Modal;
 use super::components::{DayCheckbox, PresetChip, RadioChip};
 use super::controller::RecurrenceModalController;
 use crate::atoms::{Text, TextColor, TextTag, TextVariant};
+use crate::libs::WORKDAY_CODE;
 
 #[component]
 pub fn RecurrenceModalView(
@@ -34,6 +35,14 @@ pub fn RecurrenceModalView(
     let time_str = ctrl.rule.time_str();
     let monthday_str = ctrl.rule.by_month_day_str();
     let month_str = ctrl.rule.by_month_str();
+    let weekday_position = ctrl.rule.uses_weekday_position();
+    let position_str = ctrl.rule.position_str();
+    let position_day_str = ctrl.rule.position_day_str();
+    let end_mode = ctrl.rule.end_mode();
+    let until_str = ctrl.rule.until_str();
+    let count_str = ctrl.rule.count_str();
+    let exdates = ctrl.rule.exdates();
+    let new_exdate = RwSignal::new(String::new());
 
     view! {
         <Modal open=open_read set_open=open_write>
@@ -178,8 +187,75 @@ pub fn RecurrenceModalView(
                     </div>
                 </Show>
 
-                // Day-of-month select (monthly + yearly)
+                // Day-of-month vs. weekday-position toggle (monthly + yearly)
                 <Show when=move || show_monthday.get()>
+                    <div class="flex gap-1.5">
+                        <RadioChip
+                            label="Day of month"
+                            active=Signal::derive(move || !weekday_position.get())
+                            on_click=Callback::new(move |()| {
+                                ctrl.rule.set_weekday_position(false);
+                            })
+                        />
+                        <RadioChip
+                            label="Day of week"
+                            active=weekday_position
+                            on_click=Callback::new(move |()| {
+                                ctrl.rule.set_weekday_position(true);
+                            })
+                        />
+                    </div>
+                </Show>
+
+                // Weekday position: "the [last] [Friday]"
+                <Show when=move || show_monthday.get() && weekday_position.get()>
+                    <div class="flex items-center gap-2">
+                        <Text
+                            variant=TextVariant::LabelLg
+                            color=TextColor::Secondary
+                        >
+                            "The"
+                        </Text>
+                        <select
+                            class="bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                            prop:value=move || position_str.get()
+                            on:change=move |ev| {
+                                ctrl.rule.set_position(&event_target_value(&ev));
+                            }
+                        >
+                            <option value="1">"first"</option>
+                            <option value="2">"second"</option>
+                            <option value="3">"third"</option>
+                            <option value="4">"fourth"</option>
+                            <option value="-2">"second-to-last"</option>
+                            <option value="-1">"last"</option>
+                        </select>
+                        <select
+                            class="flex-1 bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                            prop:value=move || position_day_str.get()
+                            on:change=move |ev| {
+                                ctrl.rule.set_position_day(&event_target_value(&ev));
+                            }
+                        >
+                            {Weekday::ALL
+                                .into_iter()
+                                .map(|day| {
+                                    view! { <option value=day.code()>{day.name()}</option> }
+                                })
+                                .collect::<Vec<_>>()}
+                            <option value=WORKDAY_CODE>"weekday"</option>
+                        </select>
+                    </div>
+                </Show>
+
+                // Day-of-month select (monthly + yearly)
+                <Show when=move || show_monthday.get() && !weekday_position.get()>
                     <div class="space-y-1.5">
                         <Text
                             variant=TextVariant::LabelLg
@@ -283,6 +359,127 @@ pub fn RecurrenceModalView(
                     </div>
                 </div>
 
+                // End condition
+                <div class="space-y-1.5">
+                    <Text
+                        variant=TextVariant::LabelLg
+                        color=TextColor::Secondary
+                        tag=TextTag::Label
+                        class="block"
+                    >
+                        "Ends"
+                    </Text>
+                    <div class="flex items-center gap-1.5">
+                        <RadioChip
+                            label="Never"
+                            active=Signal::derive(move || end_mode.get() == "never")
+                            on_click=Callback::new(move |()| ctrl.rule.set_end_mode("never"))
+                        />
+                        <RadioChip
+                            label="On date"
+                            active=Signal::derive(move || end_mode.get() == "until")
+                            on_click=Callback::new(move |()| ctrl.rule.set_end_mode("until"))
+                        />
+                        <RadioChip
+                            label="After"
+                            active=Signal::derive(move || end_mode.get() == "count")
+                            on_click=Callback::new(move |()| ctrl.rule.set_end_mode("count"))
+                        />
+                    </div>
+                    <Show when=move || end_mode.get() == "until">
+                        <input
+                            type="date"
+                            prop:value=move || until_str.get()
+                            on:change=move |ev| {
+                                ctrl.rule.set_until(&event_target_value(&ev));
+                            }
+                            class="bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                    </Show>
+                    <Show when=move || end_mode.get() == "count">
+                        <div class="flex items-center gap-2">
+                            <input
+                                type="number"
+                                min="1"
+                                prop:value=move || count_str.get()
+                                on:input=move |ev| {
+                                    ctrl.rule.set_count(&event_target_value(&ev));
+                                }
+                                class="w-16 bg-bg-input border border-border \
+                                       rounded px-2 py-1.5 text-sm \
+                                       text-text-primary text-center \
+                                       focus:outline-none \
+                                       focus:border-accent"
+                            />
+                            <Text
+                                variant=TextVariant::BodySm
+                                color=TextColor::Tertiary
+                            >
+                                "occurrences"
+                            </Text>
+                        </div>
+                    </Show>
+                </div>
+
+                // Exceptions (EXDATE)
+                <div class="space-y-1.5">
+                    <Text
+                        variant=TextVariant::LabelLg
+                        color=TextColor::Secondary
+                        tag=TextTag::Label
+                        class="block"
+                    >
+                        "Skip dates"
+                    </Text>
+                    <div class="flex items-center gap-2">
+                        <input
+                            type="date"
+                            prop:value=move || new_exdate.get()
+                            on:input=move |ev| new_exdate.set(event_target_value(&ev))
+                            class="bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                        <button
+                            on:click=move |_| {
+                                ctrl.rule.add_exdate(&new_exdate.get_untracked());
+                                new_exdate.set(String::new());
+                            }
+                            class="px-3 py-1.5 text-sm text-text-secondary \
+                                   hover:text-text-primary transition-colors"
+                        >
+                            "Add"
+                        </button>
+                    </div>
+                    <div class="flex flex-wrap gap-1.5">
+                        {move || {
+                            exdates
+                                .get()
+                                .into_iter()
+                                .map(|date| {
+                                    view! {
+                                        <button
+                                            on:click=move |_| ctrl.rule.remove_exdate(date)
+                                            class="px-2 py-0.5 rounded-full text-xs \
+                                                   bg-bg-input text-text-secondary \
+                                                   border border-border \
+                                                   hover:border-danger hover:text-danger \
+                                                   transition-colors"
+                                        >
+                                            {date.format("%b %-d, %Y").to_string()}
+                                            " ×"
+                                        </button>
+                                    }
+                                })
+                                .collect::<Vec<_>>()
+                        }}
+                    </div>
+                </div>
+
                 // Summary
                 <Text
                     variant=TextVariant::BodySm
@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use north_stores::status_bar_store::{StatusBarStyle, StatusBarVariant};
+use north_stores::status_bar_store::{StatusBarAction, StatusBarStyle, StatusBarVariant};
 use north_stores::use_app_store;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
@@ -82,6 +82,28 @@ pub fn StatusBar() -> impl IntoView {
                 <span class="flex-1">
                     {move || message.get().map(|m| m.text)}
                 </span>
+                {move || {
+                    message
+                        .get()
+                        .and_then(|m| m.action)
+                        .map(|action| match action {
+                            StatusBarAction::Undo => {
+                                view! {
+                                    <button
+                                        data-testid="status-bar-undo"
+                                        class="ml-3 font-semibold underline \
+                                               hover:opacity-80"
+                                        on:click=move |_| {
+                                            app_store.status_bar.hide_message();
+                                            app_store.tasks.undo();
+                                        }
+                                    >
+                                        "Undo"
+                                    </button>
+                                }
+                            }
+                        })
+                }}
             </div>
         </Show>
     }
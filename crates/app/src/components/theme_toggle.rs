@@ -13,24 +13,37 @@ mod js {
     }
 }
 
-#[component]
-pub fn ThemeToggle(#[prop(optional)] collapsed: Option<Signal<bool>>) -> impl IntoView {
-    let (is_dark, set_is_dark) = signal(false);
+/// Light/dark theme, shared by the sidebar toggle and the command palette.
+#[derive(Clone, Copy)]
+pub struct ThemeState {
+    is_dark: RwSignal<bool>,
+}
 
-    #[cfg(feature = "hydrate")]
-    {
-        Effect::new(move |_| {
-            let doc = document();
-            if let Some(el) = doc.document_element() {
-                let dark = el.class_list().contains("dark");
-                set_is_dark.set(dark);
-            }
-        });
+impl ThemeState {
+    pub fn new() -> Self {
+        let is_dark = RwSignal::new(false);
+
+        #[cfg(feature = "hydrate")]
+        {
+            Effect::new(move |_| {
+                let doc = document();
+                if let Some(el) = doc.document_element() {
+                    let dark = el.class_list().contains("dark");
+                    is_dark.set(dark);
+                }
+            });
+        }
+
+        Self { is_dark }
     }
 
-    let toggle = move |_| {
-        let new_dark = !is_dark.get_untracked();
-        set_is_dark.set(new_dark);
+    pub fn is_dark(&self) -> bool {
+        self.is_dark.get()
+    }
+
+    pub fn toggle(&self) {
+        let new_dark = !self.is_dark.get_untracked();
+        self.is_dark.set(new_dark);
 
         #[cfg(feature = "hydrate")]
         {
@@ -46,7 +59,20 @@ pub fn ThemeToggle(#[prop(optional)] collapsed: Option<Signal<bool>>) -> impl In
             let theme = if new_dark { "dark" } else { "light" };
             js::set_theme(theme);
         }
-    };
+    }
+}
+
+impl Default for ThemeState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[component]
+pub fn ThemeToggle(#[prop(optional)] collapsed: Option<Signal<bool>>) -> impl IntoView {
+    let theme = use_context::<ThemeState>().unwrap_or_default();
+    let is_dark = Signal::derive(move || theme.is_dark());
+    let toggle = move |_| theme.toggle();
 
     let is_collapsed = move || collapsed.is_some_and(|s| s.get());
 
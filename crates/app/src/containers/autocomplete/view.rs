@@ -3,7 +3,7 @@ use leptos::html;
 use leptos::prelude::*;
 use leptos::wasm_bindgen::JsCast;
 
-use north_dto::{Project, ProjectStatus, Tag};
+use north_dto::{tag_namespace, Project, ProjectStatus, Tag};
 use north_ui::{AutocompleteDropdown, SuggestionItem};
 
 #[derive(Clone)]
@@ -24,7 +24,7 @@ pub fn find_trigger(value: &str, cursor: usize) -> Option<TriggerState> {
                 let query = &before[pos + 1..];
                 if query
                     .chars()
-                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+                    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.'))
                 {
                     return Some(TriggerState {
                         trigger,
@@ -46,14 +46,26 @@ pub fn get_suggestions(
 ) -> Vec<SuggestionItem> {
     let query_lower = query.to_lowercase();
     match trigger {
-        '#' => tags
-            .iter()
-            .filter(|t| query_lower.is_empty() || t.name.to_lowercase().contains(&query_lower))
-            .map(|t| SuggestionItem {
-                name: t.name.clone(),
-                color: t.color.clone(),
-            })
-            .collect(),
+        '#' => {
+            // Grouped by namespace; un-namespaced tags come first.
+            let mut matches: Vec<&Tag> = tags
+                .iter()
+                .filter(|t| query_lower.is_empty() || t.name.to_lowercase().contains(&query_lower))
+                .collect();
+            matches.sort_by(|a, b| {
+                (tag_namespace(&a.name), &a.name).cmp(&(tag_namespace(&b.name), &b.name))
+            });
+            matches
+                .into_iter()
+                .map(|t| SuggestionItem {
+                    name: t.name.clone(),
+                    color: t.color.clone(),
+                    group: tag_namespace(&t.name).map(str::to_string),
+                    value: None,
+                    detail: None,
+                })
+                .collect()
+        }
         '@' => projects
             .iter()
             .filter(|p| {
@@ -63,6 +75,9 @@ pub fn get_suggestions(
             .map(|p| SuggestionItem {
                 name: p.title.clone(),
                 color: p.color.clone(),
+                group: None,
+                value: None,
+                detail: None,
             })
             .collect(),
         _ => vec![],
@@ -207,3 +222,40 @@ pub fn AutocompleteInputView(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str) -> Tag {
+        Tag {
+            id: 0,
+            user_id: 1,
+            name: name.into(),
+            color: north_dto::DEFAULT_COLOR.into(),
+            task_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_find_trigger_keeps_namespaced_query() {
+        let ts = find_trigger("Call #ctx:ph", 12).unwrap();
+        assert_eq!(ts.trigger, '#');
+        assert_eq!(ts.query, "ctx:ph");
+    }
+
+    #[test]
+    fn test_tag_suggestions_grouped_by_namespace() {
+        let tags = [
+            tag("ctx:phone"),
+            tag("urgent"),
+            tag("area:work"),
+            tag("ctx:email"),
+        ];
+        let items = get_suggestions(&tags, &[], '#', "");
+        let names: Vec<&str> = items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["urgent", "area:work", "ctx:email", "ctx:phone"]);
+        assert_eq!(items[0].group, None);
+        assert_eq!(items[2].group.as_deref(), Some("ctx"));
+    }
+}
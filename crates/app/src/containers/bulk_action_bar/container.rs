@@ -0,0 +1,77 @@
+use leptos::prelude::*;
+use north_dto::BulkTaskAction;
+use north_stores::use_app_store;
+
+use super::view::BulkActionBarView;
+
+/// Actions for the tasks selected in a task list.
+#[component]
+pub fn BulkActionBar(
+    count: Signal<usize>,
+    on_action: Callback<BulkTaskAction>,
+    on_toggle_complete: Callback<()>,
+    on_toggle_someday: Callback<()>,
+    on_delete: Callback<()>,
+    on_clear: Callback<()>,
+) -> impl IntoView {
+    let app_store = use_app_store();
+    let projects = Memo::new(move |_| app_store.projects.get());
+    let tag_input = RwSignal::new(String::new());
+
+    let tag_names = move || -> Vec<String> {
+        tag_input
+            .get_untracked()
+            .split([',', ' '])
+            .filter_map(north_dto::normalize_tag_name)
+            .collect()
+    };
+    let on_add_tags = Callback::new(move |()| {
+        let names = tag_names();
+        if !names.is_empty() {
+            on_action.run(BulkTaskAction::AddTags(names));
+            tag_input.set(String::new());
+        }
+    });
+    let on_remove_tags = Callback::new(move |()| {
+        let names = tag_names();
+        if !names.is_empty() {
+            on_action.run(BulkTaskAction::RemoveTags(names));
+            tag_input.set(String::new());
+        }
+    });
+
+    let on_set_project = Callback::new(move |value: String| {
+        let project_id = value.parse::<i64>().ok();
+        on_action.run(BulkTaskAction::SetProject(project_id));
+    });
+    let on_set_start = Callback::new(move |value: String| {
+        // Same default time as the date picker.
+        let start_at = chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(9, 0, 0))
+            .map(|dt| dt.and_utc());
+        on_action.run(BulkTaskAction::SetStartAt(start_at));
+    });
+    let on_set_due = Callback::new(move |value: String| {
+        let due_date = chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok();
+        on_action.run(BulkTaskAction::SetDueDate(due_date));
+    });
+
+    view! {
+        <BulkActionBarView
+            count=count
+            projects=projects
+            tag_input=tag_input
+            on_toggle_complete=on_toggle_complete
+            on_set_project=on_set_project
+            on_add_tags=on_add_tags
+            on_remove_tags=on_remove_tags
+            on_set_start=on_set_start
+            on_set_due=on_set_due
+            on_review=Callback::new(move |()| on_action.run(BulkTaskAction::MarkReviewed))
+            on_toggle_someday=on_toggle_someday
+            on_delete=on_delete
+            on_clear=on_clear
+        />
+    }
+}
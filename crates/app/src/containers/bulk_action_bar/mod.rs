@@ -0,0 +1,4 @@
+mod container;
+mod view;
+
+pub use container::BulkActionBar;
@@ -0,0 +1,139 @@
+use leptos::prelude::*;
+use north_dto::Project;
+use north_ui::{Icon, IconKind};
+
+use crate::atoms::{Text, TextColor, TextTag, TextVariant};
+
+const BUTTON_CLASS: &str = "text-xs px-2 py-1 rounded bg-bg-tertiary text-text-secondary \
+                            hover:text-text-primary transition-colors";
+const INPUT_CLASS: &str = "bg-bg-input border border-border rounded px-2 py-1 text-xs \
+                           text-text-primary focus:outline-none focus:border-accent";
+
+#[component]
+pub fn BulkActionBarView(
+    count: Signal<usize>,
+    projects: Memo<Vec<Project>>,
+    tag_input: RwSignal<String>,
+    on_toggle_complete: Callback<()>,
+    on_set_project: Callback<String>,
+    on_add_tags: Callback<()>,
+    on_remove_tags: Callback<()>,
+    on_set_start: Callback<String>,
+    on_set_due: Callback<String>,
+    on_review: Callback<()>,
+    on_toggle_someday: Callback<()>,
+    on_delete: Callback<()>,
+    on_clear: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <div
+            data-testid="bulk-action-bar"
+            class="sticky top-0 z-10 flex flex-wrap items-center gap-2 mb-2 px-3 py-2 \
+                   rounded-md bg-bg-secondary border border-border"
+            on:click=|ev| ev.stop_propagation()
+        >
+            <Text variant=TextVariant::LabelMd color=TextColor::Primary tag=TextTag::Span>
+                {move || format!("{} selected", count.get())}
+            </Text>
+            <button
+                data-testid="bulk-complete-btn"
+                class=BUTTON_CLASS
+                on:click=move |_| on_toggle_complete.run(())
+            >
+                <Icon kind=IconKind::Check class="w-3.5 h-3.5 inline-block mr-1"/>
+                "Complete"
+            </button>
+            <select
+                data-testid="bulk-project-select"
+                class=INPUT_CLASS
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if !value.is_empty() {
+                        on_set_project.run(value);
+                    }
+                }
+                prop:value=""
+            >
+                <option value="">"Move to…"</option>
+                <option value="inbox">"Inbox"</option>
+                <For
+                    each=move || projects.get()
+                    key=|p| p.id
+                    children=move |p| {
+                        view! { <option value=p.id.to_string()>{p.title}</option> }
+                    }
+                />
+            </select>
+            <input
+                type="text"
+                data-testid="bulk-tag-input"
+                class=INPUT_CLASS
+                placeholder="tags"
+                bind:value=tag_input
+            />
+            <button
+                data-testid="bulk-add-tags-btn"
+                class=BUTTON_CLASS
+                on:click=move |_| on_add_tags.run(())
+            >
+                "Add"
+            </button>
+            <button
+                data-testid="bulk-remove-tags-btn"
+                class=BUTTON_CLASS
+                on:click=move |_| on_remove_tags.run(())
+            >
+                "Remove"
+            </button>
+            <label class="flex items-center gap-1 text-xs text-text-tertiary">
+                "Start"
+                <input
+                    type="date"
+                    data-testid="bulk-start-input"
+                    class=INPUT_CLASS
+                    on:change=move |ev| on_set_start.run(event_target_value(&ev))
+                />
+            </label>
+            <label class="flex items-center gap-1 text-xs text-text-tertiary">
+                "Due"
+                <input
+                    type="date"
+                    data-testid="bulk-due-input"
+                    class=INPUT_CLASS
+                    on:change=move |ev| on_set_due.run(event_target_value(&ev))
+                />
+            </label>
+            <button
+                data-testid="bulk-review-btn"
+                class=BUTTON_CLASS
+                on:click=move |_| on_review.run(())
+            >
+                "Reviewed"
+            </button>
+            <button
+                data-testid="bulk-someday-btn"
+                class=BUTTON_CLASS
+                on:click=move |_| on_toggle_someday.run(())
+            >
+                "Someday"
+            </button>
+            <button
+                data-testid="bulk-delete-btn"
+                class="text-xs px-2 py-1 rounded bg-bg-tertiary text-danger \
+                       hover:text-danger-hover transition-colors"
+                on:click=move |_| on_delete.run(())
+            >
+                "Delete"
+            </button>
+            <button
+                data-testid="bulk-clear-btn"
+                class="ml-auto p-1 rounded text-text-tertiary hover:text-text-primary \
+                       transition-colors"
+                aria-label="Clear selection"
+                on:click=move |_| on_clear.run(())
+            >
+                <Icon kind=IconKind::Close class="w-3.5 h-3.5"/>
+            </button>
+        </div>
+    }
+}
@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use north_stores::use_app_store;
+
+use super::controller::CommandPaletteController;
+use super::view::CommandPaletteView;
+use crate::components::theme_toggle::ThemeState;
+
+/// Ctrl+K palette: fuzzy search over projects, saved filters, tags and
+/// tasks, plus quick actions. Mounted once by `AppLayout`.
+#[component]
+pub fn CommandPalette() -> impl IntoView {
+    let app_store = use_app_store();
+    let theme = expect_context::<ThemeState>();
+    let navigate = use_navigate();
+    let navigate = Callback::new(move |path: String| navigate(&path, Default::default()));
+    let ctrl = CommandPaletteController::new(app_store, theme, navigate);
+
+    window_event_listener(leptos::ev::keydown, move |ev| {
+        if (ev.ctrl_key() || ev.meta_key()) && ev.key().eq_ignore_ascii_case("k") {
+            ev.prevent_default();
+            if ctrl.is_open() {
+                ctrl.close();
+            } else {
+                ctrl.open();
+            }
+        }
+    });
+
+    view! { <CommandPaletteView ctrl=ctrl/> }
+}
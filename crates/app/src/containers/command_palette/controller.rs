@@ -0,0 +1,286 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::{fuzzy_score, CreateTask, SearchResult, SearchResultKind};
+use north_repositories::SearchRepository;
+use north_stores::{AppStore, StatusBarVariant};
+use north_ui::SuggestionItem;
+
+use crate::components::theme_toggle::ThemeState;
+
+/// `ModalStore` name of the palette.
+const COMMAND_PALETTE: &str = "command-palette";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteAction {
+    CreateTask,
+    GoToInbox,
+    GoToToday,
+    GoToAllTasks,
+    StartReview,
+    ToggleTheme,
+    OpenSettings,
+}
+
+impl PaletteAction {
+    const ALL: [PaletteAction; 7] = [
+        PaletteAction::CreateTask,
+        PaletteAction::GoToInbox,
+        PaletteAction::GoToToday,
+        PaletteAction::GoToAllTasks,
+        PaletteAction::StartReview,
+        PaletteAction::ToggleTheme,
+        PaletteAction::OpenSettings,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            PaletteAction::CreateTask => "Create task",
+            PaletteAction::GoToInbox => "Go to Inbox",
+            PaletteAction::GoToToday => "Go to Today",
+            PaletteAction::GoToAllTasks => "Go to All Tasks",
+            PaletteAction::StartReview => "Start review",
+            PaletteAction::ToggleTheme => "Toggle theme",
+            PaletteAction::OpenSettings => "Open settings",
+        }
+    }
+}
+
+/// A row of the palette.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaletteEntry {
+    Action(PaletteAction),
+    /// Creates a task titled with the current query.
+    CreateTaskNamed(String),
+    Result(SearchResult),
+}
+
+#[derive(Clone, Copy)]
+pub struct CommandPaletteController {
+    pub query: RwSignal<String>,
+    /// Set after picking "Create task": the input then takes a title.
+    pub creating: RwSignal<bool>,
+    pub highlighted: RwSignal<usize>,
+    pub entries: Memo<Vec<PaletteEntry>>,
+    results: RwSignal<Vec<SearchResult>>,
+    /// Bumped per search so that slow responses for older queries are
+    /// dropped.
+    generation: RwSignal<u64>,
+    app_store: AppStore,
+    theme: ThemeState,
+    navigate: Callback<String>,
+}
+
+impl CommandPaletteController {
+    pub fn new(app_store: AppStore, theme: ThemeState, navigate: Callback<String>) -> Self {
+        let query = RwSignal::new(String::new());
+        let creating = RwSignal::new(false);
+        let results = RwSignal::new(Vec::<SearchResult>::new());
+
+        let entries = Memo::new(move |_| {
+            if creating.get() {
+                return vec![];
+            }
+            let q = query.get();
+            let q = q.trim();
+            let mut actions: Vec<(i32, PaletteAction)> = PaletteAction::ALL
+                .into_iter()
+                .filter_map(|a| fuzzy_score(q, a.label()).map(|s| (s, a)))
+                .collect();
+            actions.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+            let mut entries: Vec<PaletteEntry> = actions
+                .into_iter()
+                .map(|(_, a)| PaletteEntry::Action(a))
+                .collect();
+            if !q.is_empty() {
+                entries.push(PaletteEntry::CreateTaskNamed(q.to_string()));
+                entries.extend(results.get().into_iter().map(PaletteEntry::Result));
+            }
+            entries
+        });
+
+        Self {
+            query,
+            creating,
+            highlighted: RwSignal::new(0),
+            entries,
+            results,
+            generation: RwSignal::new(0),
+            app_store,
+            theme,
+            navigate,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.app_store.modal.is_open(COMMAND_PALETTE)
+    }
+
+    pub fn open(&self) {
+        self.query.set(String::new());
+        self.creating.set(false);
+        self.highlighted.set(0);
+        self.results.set(vec![]);
+        self.app_store.modal.open(COMMAND_PALETTE);
+    }
+
+    pub fn close(&self) {
+        self.app_store.modal.close(COMMAND_PALETTE);
+    }
+
+    pub fn set_query(&self, value: String) {
+        self.query.set(value.clone());
+        self.highlighted.set(0);
+        if self.creating.get_untracked() {
+            return;
+        }
+
+        let generation = self.generation.get_untracked() + 1;
+        self.generation.set(generation);
+        if value.trim().is_empty() {
+            self.results.set(vec![]);
+            return;
+        }
+        let ctrl = *self;
+        spawn_local(async move {
+            if let Ok(results) = SearchRepository::search(value).await {
+                if ctrl.generation.get_untracked() == generation {
+                    ctrl.results.set(results);
+                }
+            }
+        });
+    }
+
+    pub fn move_highlight(&self, delta: i32) {
+        let len = self.entries.get_untracked().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.highlighted.get_untracked() as i32;
+        let next = (current + delta).rem_euclid(len as i32);
+        self.highlighted.set(next as usize);
+    }
+
+    /// Enter: runs the highlighted entry, or creates the task being typed.
+    pub fn submit(&self) {
+        if self.creating.get_untracked() {
+            let title = self.query.get_untracked();
+            if !title.trim().is_empty() {
+                self.create_task(title);
+            }
+            return;
+        }
+        let entries = self.entries.get_untracked();
+        if let Some(entry) = entries.get(self.highlighted.get_untracked()) {
+            self.run(entry.clone());
+        }
+    }
+
+    /// Runs the entry whose `SuggestionItem::value` is `value`.
+    pub fn select(&self, value: String) {
+        let Ok(index) = value.parse::<usize>() else {
+            return;
+        };
+        if let Some(entry) = self.entries.get_untracked().get(index) {
+            self.run(entry.clone());
+        }
+    }
+
+    fn run(&self, entry: PaletteEntry) {
+        match entry {
+            PaletteEntry::Action(PaletteAction::CreateTask) => {
+                self.query.set(String::new());
+                self.creating.set(true);
+                return;
+            }
+            PaletteEntry::Action(PaletteAction::ToggleTheme) => self.theme.toggle(),
+            PaletteEntry::Action(action) => {
+                let path = match action {
+                    PaletteAction::GoToInbox => "/inbox",
+                    PaletteAction::GoToToday => "/today",
+                    PaletteAction::GoToAllTasks => "/tasks",
+                    PaletteAction::StartReview => "/review",
+                    _ => "/settings",
+                };
+                self.navigate.run(path.to_string());
+            }
+            PaletteEntry::CreateTaskNamed(title) => {
+                self.create_task(title);
+                return;
+            }
+            PaletteEntry::Result(result) => match result.kind {
+                SearchResultKind::Project => {
+                    self.navigate.run(format!("/projects/{}", result.id));
+                }
+                SearchResultKind::Filter => {
+                    self.navigate.run(format!("/filters/{}", result.id));
+                }
+                SearchResultKind::Tag => {
+                    self.navigate
+                        .run(format!("/tags/{}", urlencoding::encode(&result.title)));
+                }
+                SearchResultKind::Task => {
+                    self.close();
+                    self.app_store
+                        .task_detail_modal
+                        .open(result.id, vec![result.id]);
+                    return;
+                }
+            },
+        }
+        self.close();
+    }
+
+    /// Creates an inbox task; `#tag` and `@project` tokens in the title
+    /// are parsed by the server as usual.
+    fn create_task(&self, title: String) {
+        self.app_store.tasks.create_task(CreateTask {
+            title: title.trim().to_string(),
+            ..Default::default()
+        });
+        self.app_store
+            .status_bar
+            .notify(StatusBarVariant::Info, "Task added to Inbox");
+        self.close();
+    }
+}
+
+/// Dropdown rows for `entries`; each row's value is its index.
+pub fn suggestion_items(entries: &[PaletteEntry]) -> Vec<SuggestionItem> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let (name, color, group, detail) = match entry {
+                PaletteEntry::Action(action) => (action.label().to_string(), None, "Actions", None),
+                PaletteEntry::CreateTaskNamed(title) => (
+                    format!("Create task \u{201c}{title}\u{201d}"),
+                    None,
+                    "Actions",
+                    None,
+                ),
+                PaletteEntry::Result(r) => {
+                    let group = match r.kind {
+                        SearchResultKind::Project => "Projects",
+                        SearchResultKind::Filter => "Filters",
+                        SearchResultKind::Tag => "Tags",
+                        SearchResultKind::Task => "Tasks",
+                    };
+                    let name = if r.kind == SearchResultKind::Tag {
+                        format!("#{}", r.title)
+                    } else {
+                        r.title.clone()
+                    };
+                    (name, r.color.clone(), group, r.detail.clone())
+                }
+            };
+            SuggestionItem {
+                name,
+                color: color.unwrap_or_else(|| "transparent".into()),
+                group: Some(group.to_string()),
+                value: Some(i.to_string()),
+                detail,
+            }
+        })
+        .collect()
+}
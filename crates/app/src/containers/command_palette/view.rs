@@ -0,0 +1,81 @@
+use leptos::prelude::*;
+use north_ui::AutocompleteDropdown;
+
+use super::controller::{suggestion_items, CommandPaletteController};
+
+#[component]
+pub fn CommandPaletteView(ctrl: CommandPaletteController) -> impl IntoView {
+    let input_ref = NodeRef::<leptos::html::Input>::new();
+    let highlighted = ctrl.highlighted;
+    let creating = ctrl.creating;
+
+    Effect::new(move || {
+        // Refocus after switching to the "Create task" prompt too.
+        let _ = creating.get();
+        if let Some(el) = input_ref.get() {
+            let _ = el.focus();
+        }
+    });
+
+    let on_keydown = move |ev: web_sys::KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" => {
+            ev.prevent_default();
+            ctrl.move_highlight(1);
+        }
+        "ArrowUp" => {
+            ev.prevent_default();
+            ctrl.move_highlight(-1);
+        }
+        "Enter" => {
+            ev.prevent_default();
+            ctrl.submit();
+        }
+        "Escape" => {
+            ev.prevent_default();
+            ctrl.close();
+        }
+        _ => {}
+    };
+
+    view! {
+        <Show when=move || ctrl.is_open()>
+            <div
+                data-testid="command-palette"
+                class="fixed inset-0 z-50 flex justify-center pt-[15vh]"
+            >
+                <div class="absolute inset-0 bg-backdrop" on:click=move |_| ctrl.close()/>
+                <div class="relative z-10 w-full max-w-xl mx-4 h-fit bg-bg-secondary \
+                            border border-border/60 rounded-2xl shadow-2xl overflow-hidden">
+                    <input
+                        node_ref=input_ref
+                        type="text"
+                        data-testid="command-palette-input"
+                        class="w-full px-4 py-3 bg-transparent text-sm text-text-primary \
+                               border-b border-border/60 focus:outline-none no-focus-ring"
+                        placeholder=move || {
+                            if creating.get() {
+                                "Task title\u{2026}"
+                            } else {
+                                "Search or run a command\u{2026}"
+                            }
+                        }
+                        prop:value=move || ctrl.query.get()
+                        on:input=move |ev| ctrl.set_query(event_target_value(&ev))
+                        on:keydown=on_keydown
+                    />
+                    {move || {
+                        let items = suggestion_items(&ctrl.entries.get());
+                        view! {
+                            <AutocompleteDropdown
+                                items=items
+                                highlighted=highlighted.read_only()
+                                on_select=Callback::new(move |value| ctrl.select(value))
+                                class="p-1 max-h-[50vh] overflow-y-auto"
+                            />
+                        }
+                    }}
+                </div>
+            </div>
+        </Show>
+    }
+}
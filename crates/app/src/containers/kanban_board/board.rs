@@ -0,0 +1,145 @@
+use north_dto::ProjectColumn;
+use north_stores::TaskModel;
+
+/// One board column with its cards, top to bottom.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lane {
+    pub column: ProjectColumn,
+    pub task_ids: Vec<i64>,
+}
+
+/// Distributes tasks over columns. Tasks without a column, or whose column
+/// was deleted, land in the first one. Open cards are ordered by sort key
+/// and completed cards sink to the bottom.
+pub fn group_into_lanes(columns: &[ProjectColumn], tasks: &[TaskModel]) -> Vec<Lane> {
+    let Some(first) = columns.first() else {
+        return vec![];
+    };
+    let mut sorted: Vec<&TaskModel> = tasks.iter().collect();
+    sorted.sort_by(|a, b| {
+        (a.completed_at.is_some(), &a.sort_key).cmp(&(b.completed_at.is_some(), &b.sort_key))
+    });
+
+    columns
+        .iter()
+        .map(|column| Lane {
+            column: column.clone(),
+            task_ids: sorted
+                .iter()
+                .filter(|t| {
+                    let lane = t
+                        .column_id
+                        .filter(|id| columns.iter().any(|c| c.id == *id))
+                        .unwrap_or(first.id);
+                    lane == column.id
+                })
+                .map(|t| t.id)
+                .collect(),
+        })
+        .collect()
+}
+
+/// Sort key that puts a card at `index` among `keys`, the sort keys of the
+/// other open cards in the target lane.
+pub fn sort_key_at(keys: &[String], index: usize) -> String {
+    let index = index.min(keys.len());
+    let above = index.checked_sub(1).map(|i| keys[i].as_str());
+    let below = keys.get(index).map(String::as_str);
+    north_dto::sort_key_between(above, below)
+}
+
+/// Index of the lane containing `task_id`, and the card's position in it.
+pub fn locate(lanes: &[Lane], task_id: i64) -> Option<(usize, usize)> {
+    lanes.iter().enumerate().find_map(|(li, lane)| {
+        lane.task_ids
+            .iter()
+            .position(|&id| id == task_id)
+            .map(|pos| (li, pos))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn column(id: i64) -> ProjectColumn {
+        ProjectColumn {
+            id,
+            project_id: 1,
+            title: format!("Column {id}"),
+            position: id as i32,
+        }
+    }
+
+    fn task(id: i64, column_id: Option<i64>, sort_key: &str) -> TaskModel {
+        TaskModel {
+            id,
+            project_id: Some(1),
+            parent_id: None,
+            user_id: 1,
+            title: format!("Task {id}"),
+            body: None,
+            sort_key: sort_key.to_string(),
+            sequential_limit: 1,
+            start_at: None,
+            due_date: None,
+            completed_at: None,
+            reviewed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            recurrence: None,
+            is_url_fetching: None,
+            someday: false,
+            series_id: None,
+            column_id,
+            waiting_for: None,
+            follow_up_date: None,
+            priority: None,
+            estimate_minutes: None,
+            section_id: None,
+            project_title: None,
+            tags: vec![],
+            subtask_count: 0,
+            completed_subtask_count: 0,
+            blocked_by: vec![],
+            spent_minutes: 0,
+            tracking_since: None,
+        }
+    }
+
+    #[test]
+    fn test_group_into_lanes() {
+        let columns = vec![column(10), column(20)];
+        let mut done = task(4, Some(20), "");
+        done.completed_at = Some(Utc::now());
+        let tasks = vec![
+            task(1, Some(20), "b"),
+            task(2, None, "a"),
+            task(3, Some(99), "c"),
+            done,
+            task(5, Some(20), "a"),
+        ];
+        let lanes = group_into_lanes(&columns, &tasks);
+        assert_eq!(lanes[0].task_ids, vec![2, 3]);
+        assert_eq!(lanes[1].task_ids, vec![5, 1, 4]);
+        assert_eq!(locate(&lanes, 1), Some((1, 1)));
+        assert_eq!(locate(&lanes, 42), None);
+        assert!(group_into_lanes(&[], &tasks).is_empty());
+    }
+
+    #[test]
+    fn test_sort_key_at() {
+        let a = north_dto::sort_key_after(None);
+        let b = north_dto::sort_key_after(Some(&a));
+        let keys = vec![a.clone(), b.clone()];
+
+        let top = sort_key_at(&keys, 0);
+        assert!(top < a);
+        let middle = sort_key_at(&keys, 1);
+        assert!(a < middle && middle < b);
+        let bottom = sort_key_at(&keys, 5);
+        assert!(bottom > b);
+        assert!(!sort_key_at(&[], 0).is_empty());
+    }
+}
@@ -0,0 +1,29 @@
+use leptos::prelude::*;
+use north_stores::use_app_store;
+
+use super::controller::KanbanBoardController;
+use super::view::KanbanBoardView;
+use crate::components::drag_drop::DragDropContext;
+
+#[component]
+pub fn KanbanBoard(project_id: Signal<i64>, show_completed: Signal<bool>) -> impl IntoView {
+    let app_store = use_app_store();
+    let ctrl = KanbanBoardController::new(app_store, project_id, show_completed);
+    provide_context(DragDropContext::new());
+
+    view! {
+        <KanbanBoardView
+            lanes=ctrl.lanes
+            cursor=ctrl.cursor
+            on_open=Callback::new(move |id| ctrl.open_detail(id))
+            on_drop=Callback::new(move |(task_id, column_id, target)| {
+                ctrl.drop_card(task_id, column_id, target)
+            })
+            on_keydown=Callback::new(move |ev: web_sys::KeyboardEvent| ctrl.handle_keydown(&ev))
+            on_add_card=Callback::new(move |(column_id, title)| ctrl.add_card(column_id, title))
+            on_add_column=Callback::new(move |title| ctrl.add_column(title))
+            on_rename_column=Callback::new(move |(id, title)| ctrl.rename_column(id, title))
+            on_delete_column=Callback::new(move |id| ctrl.delete_column(id))
+        />
+    }
+}
@@ -0,0 +1,293 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::{CreateTask, ProjectColumn, UpdateProjectColumn};
+use north_repositories::ProjectRepository;
+use north_stores::{AppStore, IdFilter, TaskModel, TaskStoreFilter};
+
+use super::board::{group_into_lanes, locate, sort_key_at, Lane};
+use crate::components::drag_drop::DropZone;
+
+#[derive(Clone, Copy)]
+pub struct KanbanBoardController {
+    app_store: AppStore,
+    project_id: Signal<i64>,
+    project_tasks: Memo<Vec<TaskModel>>,
+    pub columns: RwSignal<Vec<ProjectColumn>>,
+    pub lanes: Memo<Vec<Lane>>,
+    pub cursor: RwSignal<Option<i64>>,
+}
+
+impl KanbanBoardController {
+    pub fn new(app_store: AppStore, project_id: Signal<i64>, show_completed: Signal<bool>) -> Self {
+        let columns = RwSignal::new(Vec::<ProjectColumn>::new());
+
+        Effect::new(move |_| {
+            let pid = project_id.get();
+            spawn_local(async move {
+                if let Ok(list) = ProjectRepository::list_columns(pid).await {
+                    columns.set(list);
+                }
+            });
+        });
+
+        let project_tasks = Memo::new(move |_| {
+            app_store
+                .tasks
+                .filtered(TaskStoreFilter {
+                    project_id: IdFilter::Is(project_id.get()),
+                    parent_id: IdFilter::IsNull,
+                    is_completed: None,
+                    ..Default::default()
+                })
+                .get()
+        });
+
+        let lanes = Memo::new(move |_| {
+            let show = show_completed.get();
+            let tasks: Vec<TaskModel> = project_tasks
+                .get()
+                .into_iter()
+                .filter(|t| show || t.completed_at.is_none())
+                .collect();
+            group_into_lanes(&columns.get(), &tasks)
+        });
+
+        Self {
+            app_store,
+            project_id,
+            project_tasks,
+            columns,
+            lanes,
+            cursor: RwSignal::new(None),
+        }
+    }
+
+    pub fn open_detail(&self, task_id: i64) {
+        let task_ids = self
+            .lanes
+            .get_untracked()
+            .into_iter()
+            .flat_map(|l| l.task_ids)
+            .collect();
+        self.app_store.task_detail_modal.open(task_id, task_ids);
+    }
+
+    /// Moves a card into `lane` at `index`, counted among the lane's other
+    /// open cards.
+    pub fn move_card(&self, task_id: i64, lane: usize, index: usize) {
+        let lanes = self.lanes.get_untracked();
+        let Some(target) = lanes.get(lane) else {
+            return;
+        };
+        let tasks = self.project_tasks.get_untracked();
+        let keys: Vec<String> = target
+            .task_ids
+            .iter()
+            .filter(|&&id| id != task_id)
+            .filter_map(|id| tasks.iter().find(|t| t.id == *id))
+            .filter(|t| t.completed_at.is_none())
+            .map(|t| t.sort_key.clone())
+            .collect();
+        let sort_key = sort_key_at(&keys, index);
+        self.app_store
+            .tasks
+            .move_to_column(task_id, Some(target.column.id), sort_key);
+    }
+
+    /// Drops the dragged card next to `target`, or at the end of the lane
+    /// when dropped on empty space.
+    pub fn drop_card(&self, task_id: i64, column_id: i64, target: Option<(i64, DropZone)>) {
+        let lanes = self.lanes.get_untracked();
+        let Some(lane) = lanes.iter().position(|l| l.column.id == column_id) else {
+            return;
+        };
+        let others: Vec<i64> = lanes[lane]
+            .task_ids
+            .iter()
+            .copied()
+            .filter(|&id| id != task_id)
+            .collect();
+        let index = match target {
+            Some((id, DropZone::Above)) => others.iter().position(|&o| o == id),
+            Some((id, _)) => others.iter().position(|&o| o == id).map(|p| p + 1),
+            None => None,
+        }
+        .unwrap_or(others.len());
+        self.move_card(task_id, lane, index);
+    }
+
+    /// Creates a task in this project and places it at the bottom of `column_id`.
+    pub fn add_card(&self, column_id: i64, title: String) {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return;
+        }
+        let ctrl = *self;
+        let input = CreateTask {
+            title,
+            project_id: Some(self.project_id.get_untracked()),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            if let Some(task) = ctrl.app_store.tasks.create_task_async(input).await {
+                if let Some(lane) = ctrl
+                    .lanes
+                    .get_untracked()
+                    .iter()
+                    .position(|l| l.column.id == column_id)
+                {
+                    ctrl.move_card(task.id, lane, usize::MAX);
+                }
+            }
+        });
+    }
+
+    // ── Keyboard ───────────────────────────────────────────────────
+
+    pub fn handle_keydown(&self, ev: &web_sys::KeyboardEvent) {
+        if self.app_store.modal.is_any_open() {
+            return;
+        }
+        let lanes = self.lanes.get_untracked();
+        let Some(cursor) = self.cursor.get_untracked() else {
+            if matches!(
+                ev.key().as_str(),
+                "ArrowDown" | "ArrowUp" | "ArrowLeft" | "ArrowRight"
+            ) {
+                ev.prevent_default();
+                let first = lanes.iter().find_map(|l| l.task_ids.first().copied());
+                self.cursor.set(first);
+            }
+            return;
+        };
+        let Some((lane, pos)) = locate(&lanes, cursor) else {
+            self.cursor.set(None);
+            return;
+        };
+
+        match (ev.key().as_str(), ev.shift_key()) {
+            ("ArrowUp", false) => {
+                ev.prevent_default();
+                if pos > 0 {
+                    self.cursor.set(Some(lanes[lane].task_ids[pos - 1]));
+                }
+            }
+            ("ArrowDown", false) => {
+                ev.prevent_default();
+                if let Some(&id) = lanes[lane].task_ids.get(pos + 1) {
+                    self.cursor.set(Some(id));
+                }
+            }
+            ("ArrowLeft", false) | ("ArrowRight", false) => {
+                ev.prevent_default();
+                let next = self.adjacent_lane(&lanes, lane, ev.key() == "ArrowRight", false);
+                if let Some(next) = next {
+                    let ids = &lanes[next].task_ids;
+                    self.cursor.set(Some(ids[pos.min(ids.len() - 1)]));
+                }
+            }
+            ("ArrowUp", true) => {
+                ev.prevent_default();
+                if pos > 0 {
+                    self.move_card(cursor, lane, pos - 1);
+                }
+            }
+            ("ArrowDown", true) => {
+                ev.prevent_default();
+                if pos + 1 < lanes[lane].task_ids.len() {
+                    self.move_card(cursor, lane, pos + 1);
+                }
+            }
+            ("ArrowLeft", true) | ("ArrowRight", true) => {
+                ev.prevent_default();
+                let next = self.adjacent_lane(&lanes, lane, ev.key() == "ArrowRight", true);
+                if let Some(next) = next {
+                    self.move_card(cursor, next, pos);
+                }
+            }
+            ("Enter", _) | ("e", false) => {
+                ev.prevent_default();
+                self.open_detail(cursor);
+            }
+            (" ", false) => {
+                ev.prevent_default();
+                let completed = self
+                    .project_tasks
+                    .get_untracked()
+                    .iter()
+                    .any(|t| t.id == cursor && t.completed_at.is_some());
+                self.app_store.tasks.toggle_complete(cursor, completed);
+            }
+            ("Escape", _) => {
+                ev.prevent_default();
+                self.cursor.set(None);
+            }
+            _ => {}
+        }
+    }
+
+    /// Next lane left or right; when only selecting, empty lanes are skipped.
+    fn adjacent_lane(
+        &self,
+        lanes: &[Lane],
+        lane: usize,
+        right: bool,
+        allow_empty: bool,
+    ) -> Option<usize> {
+        let candidates: Box<dyn Iterator<Item = usize>> = if right {
+            Box::new(lane + 1..lanes.len())
+        } else {
+            Box::new((0..lane).rev())
+        };
+        candidates
+            .into_iter()
+            .find(|&i| allow_empty || !lanes[i].task_ids.is_empty())
+    }
+
+    // ── Columns ────────────────────────────────────────────────────
+
+    pub fn add_column(&self, title: String) {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return;
+        }
+        let columns = self.columns;
+        let pid = self.project_id.get_untracked();
+        spawn_local(async move {
+            if let Ok(column) = ProjectRepository::create_column(pid, title).await {
+                columns.update(|list| list.push(column));
+            }
+        });
+    }
+
+    pub fn rename_column(&self, id: i64, title: String) {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return;
+        }
+        self.columns.update(|list| {
+            if let Some(c) = list.iter_mut().find(|c| c.id == id) {
+                c.title = title.clone();
+            }
+        });
+        spawn_local(async move {
+            let input = UpdateProjectColumn {
+                title: Some(title),
+                ..Default::default()
+            };
+            let _ = ProjectRepository::update_column(id, input).await;
+        });
+    }
+
+    /// Removes a column; the last remaining one can't be deleted.
+    pub fn delete_column(&self, id: i64) {
+        if self.columns.get_untracked().len() <= 1 {
+            return;
+        }
+        // Cards pointing at the removed column regroup into the first one.
+        self.columns.update(|list| list.retain(|c| c.id != id));
+        spawn_local(async move {
+            let _ = ProjectRepository::delete_column(id).await;
+        });
+    }
+}
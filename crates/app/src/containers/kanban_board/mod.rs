@@ -0,0 +1,6 @@
+pub mod board;
+mod container;
+mod controller;
+mod view;
+
+pub use container::KanbanBoard;
@@ -0,0 +1,316 @@
+use leptos::prelude::*;
+use north_ui::{Icon, IconKind};
+use wasm_bindgen::JsCast;
+
+use super::board::Lane;
+use crate::atoms::{TextColor, TextVariant};
+use crate::components::drag_drop::{DragDropContext, DropZone};
+use crate::components::rich_title::RichTitle;
+use crate::containers::task_checkbox::TaskCheckbox;
+use crate::containers::task_meta::TaskMeta;
+
+/// `(task_id, column_id, drop target card)` for a card dropped on a lane.
+pub type DropCallback = Callback<(i64, i64, Option<(i64, DropZone)>)>;
+
+#[component]
+pub fn KanbanBoardView(
+    lanes: Memo<Vec<Lane>>,
+    cursor: RwSignal<Option<i64>>,
+    on_open: Callback<i64>,
+    on_drop: DropCallback,
+    on_keydown: Callback<web_sys::KeyboardEvent>,
+    on_add_card: Callback<(i64, String)>,
+    on_add_column: Callback<String>,
+    on_rename_column: Callback<(i64, String)>,
+    on_delete_column: Callback<i64>,
+) -> impl IntoView {
+    // Global keyboard listener, like the task list: skipped while typing.
+    window_event_listener(leptos::ev::keydown, move |ev| {
+        if let Some(el) = document().active_element() {
+            if let Some(html_el) = el.dyn_ref::<web_sys::HtmlElement>() {
+                let tag = html_el.tag_name().to_lowercase();
+                if tag == "input" || tag == "textarea" || html_el.is_content_editable() {
+                    return;
+                }
+            }
+        }
+        on_keydown.run(ev);
+    });
+
+    let lane_count = Memo::new(move |_| lanes.get().len());
+
+    view! {
+        <div data-testid="kanban-board" class="flex items-start gap-3 overflow-x-auto pb-4">
+            <For
+                each=move || lanes.get()
+                key=|lane| lane.column.id
+                children=move |lane| {
+                    view! {
+                        <KanbanLane
+                            column_id=lane.column.id
+                            title=lane.column.title.clone()
+                            task_ids=Signal::derive(move || {
+                                lanes
+                                    .get()
+                                    .into_iter()
+                                    .find(|l| l.column.id == lane.column.id)
+                                    .map(|l| l.task_ids)
+                                    .unwrap_or_default()
+                            })
+                            can_delete=Signal::derive(move || lane_count.get() > 1)
+                            cursor=cursor
+                            on_open=on_open
+                            on_drop=on_drop
+                            on_add_card=on_add_card
+                            on_rename=on_rename_column
+                            on_delete=on_delete_column
+                        />
+                    }
+                }
+            />
+            <div class="w-72 shrink-0">
+                <input
+                    type="text"
+                    placeholder="+ Add column"
+                    data-testid="kanban-add-column"
+                    on:keydown=move |ev| {
+                        if ev.key() == "Enter" {
+                            let input = event_target::<web_sys::HtmlInputElement>(&ev);
+                            on_add_column.run(input.value());
+                            input.set_value("");
+                        }
+                    }
+                    class="w-full bg-transparent border border-dashed border-border \
+                           rounded px-3 py-2 text-sm text-text-primary \
+                           placeholder:text-text-tertiary focus:outline-none \
+                           focus:border-accent"
+                />
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn KanbanLane(
+    column_id: i64,
+    title: String,
+    task_ids: Signal<Vec<i64>>,
+    can_delete: Signal<bool>,
+    cursor: RwSignal<Option<i64>>,
+    on_open: Callback<i64>,
+    on_drop: DropCallback,
+    on_add_card: Callback<(i64, String)>,
+    on_rename: Callback<(i64, String)>,
+    on_delete: Callback<i64>,
+) -> impl IntoView {
+    let drag_ctx = use_context::<DragDropContext>();
+    let count = Memo::new(move |_| task_ids.get().len());
+
+    view! {
+        <div
+            data-testid="kanban-column"
+            class=move || {
+                let mut classes = "w-72 shrink-0 rounded-lg bg-bg-secondary p-2 \
+                                   flex flex-col gap-2 transition-colors"
+                    .to_string();
+                if drag_ctx.is_some_and(|ctx| ctx.drop_column.get() == Some(column_id)) {
+                    classes.push_str(" ring-1 ring-accent");
+                }
+                classes
+            }
+            on:dragover=move |ev: web_sys::DragEvent| {
+                let Some(ctx) = drag_ctx else { return };
+                if ctx.dragging_task_id.get_untracked().is_none() {
+                    return;
+                }
+                ev.prevent_default();
+                ctx.drop_column.set(Some(column_id));
+            }
+            on:dragleave=move |_: web_sys::DragEvent| {
+                if let Some(ctx) = drag_ctx {
+                    request_animation_frame(move || {
+                        if ctx.drop_column.get_untracked() == Some(column_id)
+                            && ctx.drop_target.get_untracked().is_none()
+                        {
+                            ctx.drop_column.set(None);
+                        }
+                    });
+                }
+            }
+            on:drop=move |ev: web_sys::DragEvent| {
+                ev.prevent_default();
+                let Some(ctx) = drag_ctx else { return };
+                if let Some(task_id) = ctx.dragging_task_id.get_untracked() {
+                    let target = ctx
+                        .drop_target
+                        .get_untracked()
+                        .filter(|(id, _)| task_ids.get_untracked().contains(id));
+                    on_drop.run((task_id, column_id, target));
+                }
+                ctx.dragging_task_id.set(None);
+                ctx.drop_target.set(None);
+                ctx.drop_column.set(None);
+            }
+        >
+            <div class="group flex items-center gap-2 px-1">
+                <input
+                    type="text"
+                    prop:value=title
+                    on:change=move |ev| on_rename.run((column_id, event_target_value(&ev)))
+                    class="flex-1 min-w-0 bg-transparent text-sm font-medium \
+                           text-text-primary focus:outline-none"
+                />
+                <span class="text-xs text-text-tertiary">{move || count.get()}</span>
+                <Show when=move || can_delete.get()>
+                    <button
+                        title="Delete column"
+                        on:click=move |_| on_delete.run(column_id)
+                        class="opacity-0 group-hover:opacity-100 text-text-tertiary \
+                               hover:text-text-primary transition-opacity cursor-pointer"
+                    >
+                        <Icon kind=IconKind::Close class="w-3.5 h-3.5" />
+                    </button>
+                </Show>
+            </div>
+            <For
+                each=move || task_ids.get()
+                key=|id| *id
+                children=move |task_id| {
+                    view! { <KanbanCard task_id=task_id cursor=cursor on_open=on_open /> }
+                }
+            />
+            <input
+                type="text"
+                placeholder="+ Add card"
+                on:keydown=move |ev| {
+                    if ev.key() == "Enter" {
+                        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+                        on_add_card.run((column_id, input.value()));
+                        input.set_value("");
+                    }
+                }
+                class="w-full bg-transparent px-2 py-1 text-sm text-text-primary \
+                       placeholder:text-text-tertiary focus:outline-none"
+            />
+        </div>
+    }
+}
+
+#[component]
+fn KanbanCard(
+    task_id: i64,
+    cursor: RwSignal<Option<i64>>,
+    on_open: Callback<i64>,
+) -> impl IntoView {
+    let app_store = north_stores::use_app_store();
+    let task = app_store.tasks.get_by_id(task_id);
+    let drag_ctx = use_context::<DragDropContext>();
+
+    view! {
+        {move || {
+            let t = task.get()?;
+            let completed = t.completed_at.is_some();
+            Some(view! {
+                <div
+                    data-testid="kanban-card"
+                    draggable="true"
+                    on:click=move |_| {
+                        cursor.set(Some(task_id));
+                        on_open.run(task_id);
+                    }
+                    on:dragstart=move |ev: web_sys::DragEvent| {
+                        if let Some(ctx) = drag_ctx {
+                            ctx.dragging_task_id.set(Some(task_id));
+                        }
+                        if let Some(dt) = ev.data_transfer() {
+                            let _ = dt.set_data("text/plain", &task_id.to_string());
+                            dt.set_effect_allowed("move");
+                        }
+                    }
+                    on:dragend=move |_: web_sys::DragEvent| {
+                        if let Some(ctx) = drag_ctx {
+                            ctx.dragging_task_id.set(None);
+                            ctx.drop_target.set(None);
+                            ctx.drop_column.set(None);
+                        }
+                    }
+                    on:dragover=move |ev: web_sys::DragEvent| {
+                        let Some(ctx) = drag_ctx else { return };
+                        if ctx.dragging_task_id.get_untracked() == Some(task_id) {
+                            return;
+                        }
+                        if let Some(target) = ev
+                            .current_target()
+                            .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+                        {
+                            let rect = target.get_bounding_client_rect();
+                            let y = ev.client_y() as f64 - rect.top();
+                            let zone = if y < rect.height() / 2.0 {
+                                DropZone::Above
+                            } else {
+                                DropZone::Below
+                            };
+                            ctx.drop_target.set(Some((task_id, zone)));
+                        }
+                    }
+                    on:dragleave=move |_: web_sys::DragEvent| {
+                        if let Some(ctx) = drag_ctx {
+                            request_animation_frame(move || {
+                                if ctx.drop_target.get_untracked().map(|(id, _)| id)
+                                    == Some(task_id)
+                                {
+                                    ctx.drop_target.set(None);
+                                }
+                            });
+                        }
+                    }
+                    class=move || {
+                        let mut classes = "rounded bg-bg-primary border border-border \
+                                           px-2 py-1.5 cursor-pointer \
+                                           hover:border-accent/60 transition-colors"
+                            .to_string();
+                        if cursor.get() == Some(task_id) {
+                            classes.push_str(" ring-1 ring-accent");
+                        }
+                        if let Some(ctx) = drag_ctx {
+                            if ctx.dragging_task_id.get() == Some(task_id) {
+                                classes.push_str(" opacity-30");
+                            }
+                            match ctx.drop_target.get() {
+                                Some((id, DropZone::Above)) if id == task_id => {
+                                    classes.push_str(" dnd-drop-above");
+                                }
+                                Some((id, DropZone::Below)) if id == task_id => {
+                                    classes.push_str(" dnd-drop-below");
+                                }
+                                _ => {}
+                            }
+                        }
+                        classes
+                    }
+                >
+                    <div class="flex items-start gap-2">
+                        <div class="mt-0.5" on:click=move |ev| ev.stop_propagation()>
+                            <TaskCheckbox task_id=task_id />
+                        </div>
+                        <RichTitle
+                            title=t.title.clone()
+                            variant=TextVariant::BodyMd
+                            color=if completed { TextColor::Tertiary } else { TextColor::Primary }
+                            line_through=completed
+                        />
+                    </div>
+                    <TaskMeta
+                        start_at=t.start_at
+                        due_date=t.due_date
+                        tags=t.tags.clone()
+                        subtask_count=t.subtask_count
+                        completed_subtask_count=t.completed_subtask_count
+                        recurrence=t.recurrence.clone()
+                        class="mt-1 ml-6"
+                    />
+                </div>
+            })
+        }}
+    }
+}
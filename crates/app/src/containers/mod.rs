@@ -1,11 +1,20 @@
 pub mod autocomplete;
+pub mod bulk_action_bar;
+pub mod command_palette;
 pub mod inline_task_input;
+pub mod kanban_board;
 pub mod project_picker;
+pub mod recurrence_history;
 pub mod sidebar;
 pub mod smart_textarea;
 pub mod tag_picker;
+pub mod task_attachments;
 pub mod task_checkbox;
+pub mod task_comments;
+pub mod task_dependencies;
 pub mod task_detail_modal;
+pub mod task_history;
 pub mod task_list_item;
 pub mod task_meta;
+pub mod task_reminders;
 pub mod traversable_task_list;
@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+
+use super::controller::RecurrenceHistoryController;
+use super::view::RecurrenceHistoryView;
+
+#[component]
+pub fn RecurrenceHistory(task_id: i64) -> impl IntoView {
+    let ctrl = RecurrenceHistoryController::new(task_id);
+
+    view! { <RecurrenceHistoryView series=ctrl.series/> }
+}
@@ -0,0 +1,25 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::TaskSeries;
+use north_repositories::TaskRepository;
+
+#[derive(Clone, Copy)]
+pub struct RecurrenceHistoryController {
+    pub series: RwSignal<Option<TaskSeries>>,
+}
+
+impl RecurrenceHistoryController {
+    pub fn new(task_id: i64) -> Self {
+        let series = RwSignal::new(None::<TaskSeries>);
+
+        Effect::new(move |_| {
+            spawn_local(async move {
+                if let Ok(s) = TaskRepository::get_series(task_id).await {
+                    series.set(Some(s));
+                }
+            });
+        });
+
+        Self { series }
+    }
+}
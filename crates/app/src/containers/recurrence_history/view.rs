@@ -0,0 +1,74 @@
+use leptos::prelude::*;
+use north_dto::{OccurrenceStatus, TaskSeries};
+
+use crate::atoms::{Text, TextColor, TextVariant};
+
+/// Streak summary and completion log for a recurring task's series.
+#[component]
+pub fn RecurrenceHistoryView(series: RwSignal<Option<TaskSeries>>) -> impl IntoView {
+    view! {
+        {move || {
+            let series = series.get()?;
+            let past: Vec<_> = series
+                .occurrences
+                .iter()
+                .rev()
+                .filter(|o| o.status != OccurrenceStatus::Pending)
+                .cloned()
+                .collect();
+
+            Some(view! {
+                <div data-testid="recurrence-history" class="space-y-2">
+                    <Text variant=TextVariant::LabelMd color=TextColor::Tertiary>
+                        "History"
+                    </Text>
+                    <div class="flex items-center gap-4 text-xs text-text-secondary">
+                        <span>{format!("Current streak: {}", series.current_streak)}</span>
+                        <span>{format!("Longest: {}", series.longest_streak)}</span>
+                        <span>
+                            {format!(
+                                "On time: {}/{}",
+                                series.on_time_count,
+                                series.completed_count,
+                            )}
+                        </span>
+                    </div>
+                    {if past.is_empty() {
+                        view! {
+                            <Text variant=TextVariant::BodySm color=TextColor::Tertiary>
+                                "No completed occurrences yet."
+                            </Text>
+                        }
+                        .into_any()
+                    } else {
+                        view! {
+                            <ul class="space-y-1 text-xs">
+                                {past
+                                    .into_iter()
+                                    .map(|o| {
+                                        let date = o
+                                            .completed_on
+                                            .or(o.scheduled_on)
+                                            .map(|d| d.format("%b %-d, %Y").to_string())
+                                            .unwrap_or_default();
+                                        let badge_class = match o.status {
+                                            OccurrenceStatus::OnTime => "text-accent",
+                                            _ => "text-danger",
+                                        };
+                                        view! {
+                                            <li class="flex items-center justify-between">
+                                                <span class="text-text-secondary">{date}</span>
+                                                <span class=badge_class>{o.status.label()}</span>
+                                            </li>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()}
+                            </ul>
+                        }
+                        .into_any()
+                    }}
+                </div>
+            })
+        }}
+    }
+}
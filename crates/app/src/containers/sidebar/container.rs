@@ -1,14 +1,23 @@
 use leptos::prelude::*;
 use leptos::wasm_bindgen::JsCast;
-use north_stores::use_app_store;
+use north_dto::ProjectStatus;
+use north_stores::{use_app_store, TaskStoreFilter};
 
 use super::view::SidebarView;
+use crate::libs::with_live_progress;
+
+fn area_collapsed_key(id: i64) -> String {
+    format!("north:area-collapsed:{id}")
+}
 
 #[component]
 pub fn Sidebar() -> impl IntoView {
     let app_store = use_app_store();
 
     let collapsed = app_store.settings.sidebar_collapsed();
+    let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+    let today = app_store.settings.today();
+    let tasks_loaded = app_store.tasks.loaded_signal();
 
     let on_toggle_collapsed = Callback::new(move |_: ()| {
         app_store.settings.toggle_sidebar_collapsed();
@@ -47,8 +56,20 @@ pub fn Sidebar() -> impl IntoView {
 
     view! {
         <SidebarView
-            projects=Signal::derive(move || app_store.projects.get())
+            projects=Signal::derive(move || {
+                let projects = app_store.projects.get();
+                if !tasks_loaded.get() {
+                    return projects;
+                }
+                let all = all_tasks.get();
+                let today = today.get();
+                projects
+                    .into_iter()
+                    .map(|p| with_live_progress(p, &all, today))
+                    .collect()
+            })
             saved_filters=Signal::derive(move || app_store.saved_filters.get())
+            tags=Signal::derive(move || app_store.tags.get())
             collapsed=collapsed
             on_toggle_collapsed=on_toggle_collapsed
             on_create_project=Callback::new(move |title: String| {
@@ -68,6 +89,34 @@ pub fn Sidebar() -> impl IntoView {
             on_delete_filter=Callback::new(move |id: i64| {
                 app_store.saved_filters.delete(id);
             })
+            areas=Signal::derive(move || {
+                app_store
+                    .projects
+                    .areas()
+                    .into_iter()
+                    .filter(|a| a.status == ProjectStatus::Active)
+                    .collect()
+            })
+            is_area_collapsed=Callback::new(move |id: i64| {
+                app_store.browser_storage.get_bool(&area_collapsed_key(id))
+            })
+            on_toggle_area=Callback::new(move |id: i64| {
+                app_store.browser_storage.toggle_bool(&area_collapsed_key(id));
+            })
+            on_create_area=Callback::new(move |title: String| {
+                app_store.projects.create_area(title);
+            })
+            on_rename_area=Callback::new(move |(id, title): (i64, String)| {
+                app_store.projects.rename_area(id, title);
+            })
+            on_archive_area=Callback::new(move |id: i64| {
+                app_store.projects.set_area_status(id, ProjectStatus::Archived);
+            })
+            on_move_project=Callback::new(
+                move |(id, area_id, before_id): (i64, Option<i64>, Option<i64>)| {
+                    app_store.projects.move_project(id, area_id, before_id);
+                },
+            )
         />
     }
 }
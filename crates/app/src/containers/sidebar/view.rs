@@ -4,7 +4,7 @@ use leptos_router::hooks::use_location;
 use crate::atoms::{Text, TextColor, TextVariant};
 use crate::components::drag_drop::DragDropContext;
 use crate::components::theme_toggle::ThemeToggle;
-use north_dto::{Project, SavedFilter};
+use north_dto::{Area, Project, SavedFilter, Tag, TagNode};
 use north_ui::{DropdownItem, DropdownMenu, Icon, IconKind, Popover};
 
 use crate::constants::PRESET_COLORS;
@@ -13,6 +13,7 @@ use crate::constants::PRESET_COLORS;
 pub fn SidebarView(
     projects: Signal<Vec<Project>>,
     saved_filters: Signal<Vec<SavedFilter>>,
+    tags: Signal<Vec<Tag>>,
     collapsed: Signal<bool>,
     on_toggle_collapsed: Callback<()>,
     on_create_project: Callback<String>,
@@ -20,9 +21,47 @@ pub fn SidebarView(
     on_edit_project: Callback<(i64, String, String)>,
     on_drop_task_to_project: Callback<(i64, i64)>,
     on_delete_filter: Callback<i64>,
+    areas: Signal<Vec<Area>>,
+    is_area_collapsed: Callback<i64, bool>,
+    on_toggle_area: Callback<i64>,
+    on_create_area: Callback<String>,
+    on_rename_area: Callback<(i64, String)>,
+    on_archive_area: Callback<i64>,
+    on_move_project: Callback<(i64, Option<i64>, Option<i64>)>,
 ) -> impl IntoView {
     let (creating, set_creating) = signal(false);
     let (new_title, set_new_title) = signal(String::new());
+    let (creating_area, set_creating_area) = signal(false);
+    let (new_area_title, set_new_area_title) = signal(String::new());
+    let drag = ProjectDrag {
+        dragging: RwSignal::new(None),
+        on_move: on_move_project,
+    };
+    let (ungrouped_drag_over, set_ungrouped_drag_over) = signal(false);
+
+    let project_item = move |p: Project| {
+        let pid = p.id;
+        view! {
+            <ProjectItem
+                id=pid
+                title=p.title
+                color=p.color
+                area_id=p.area_id
+                progress=(p.task_count > 0).then_some(p.progress)
+                stalled=p.stalled
+                due_date=p.due_date
+                outcome=p.outcome
+                drag=Some(drag)
+                on_archive=move || {
+                    on_archive_project.run(pid);
+                }
+                on_edit=move |id, t, c| {
+                    on_edit_project.run((id, t, c));
+                }
+                on_drop_task=on_drop_task_to_project
+            />
+        }
+    };
 
     let aside_class = move || {
         let base =
@@ -50,6 +89,7 @@ pub fn SidebarView(
                 <NavItem href="/inbox" label="Inbox" icon=IconKind::Inbox collapsed=collapsed/>
                 <NavItem href="/today" label="Today" icon=IconKind::Today collapsed=collapsed/>
                 <NavItem href="/someday" label="Someday" icon=IconKind::Someday collapsed=collapsed/>
+                <NavItem href="/waiting" label="Waiting" icon=IconKind::Waiting collapsed=collapsed/>
                 <NavItem href="/tasks" label="All Tasks" icon=IconKind::Tasks collapsed=collapsed/>
                 <NavItem href="/review" label="Review" icon=IconKind::Review collapsed=collapsed/>
 
@@ -69,22 +109,94 @@ pub fn SidebarView(
                             }
                         }
                     >
-                        <div class="flex items-center justify-between px-3">
+                        // Dropping a project on the header takes it out of its area.
+                        <div
+                            class=move || {
+                                if ungrouped_drag_over.get() {
+                                    "flex items-center justify-between px-3 rounded-lg \
+                                     ring-1 ring-accent"
+                                } else {
+                                    "flex items-center justify-between px-3"
+                                }
+                            }
+                            on:dragover=move |ev: web_sys::DragEvent| {
+                                if drag.dragging.get_untracked().is_some() {
+                                    ev.prevent_default();
+                                    set_ungrouped_drag_over.set(true);
+                                }
+                            }
+                            on:dragleave=move |_: web_sys::DragEvent| {
+                                set_ungrouped_drag_over.set(false);
+                            }
+                            on:drop=move |ev: web_sys::DragEvent| {
+                                ev.prevent_default();
+                                set_ungrouped_drag_over.set(false);
+                                drag.drop(None, None);
+                            }
+                        >
                             <Text variant=TextVariant::LabelMd color=TextColor::Secondary>
                                 "Projects"
                             </Text>
-                            <button
-                                class="p-0.5 rounded text-text-tertiary \
-                                       hover:text-text-secondary \
-                                       hover:bg-bg-tertiary transition-colors"
-                                data-testid="sidebar-create-project-btn"
-                                on:click=move |_| {
-                                    set_creating.update(|v| *v = !*v);
+                            <div class="flex items-center gap-0.5">
+                                <button
+                                    class="p-0.5 rounded text-text-tertiary \
+                                           hover:text-text-secondary \
+                                           hover:bg-bg-tertiary transition-colors"
+                                    title="New area"
+                                    data-testid="sidebar-create-area-btn"
+                                    on:click=move |_| {
+                                        set_creating_area.update(|v| *v = !*v);
+                                    }
+                                >
+                                    <Icon kind=IconKind::Folder class="w-3.5 h-3.5"/>
+                                </button>
+                                <button
+                                    class="p-0.5 rounded text-text-tertiary \
+                                           hover:text-text-secondary \
+                                           hover:bg-bg-tertiary transition-colors"
+                                    title="New project"
+                                    data-testid="sidebar-create-project-btn"
+                                    on:click=move |_| {
+                                        set_creating.update(|v| *v = !*v);
+                                    }
+                                >
+                                    <Icon kind=IconKind::Plus class="w-3.5 h-3.5"/>
+                                </button>
+                            </div>
+                        </div>
+
+                        <Show when=move || creating_area.get()>
+                            <form
+                                class="px-1 mt-1"
+                                on:submit=move |ev| {
+                                    ev.prevent_default();
+                                    let title = new_area_title.get_untracked();
+                                    if !title.trim().is_empty() {
+                                        on_create_area.run(title.trim().to_string());
+                                        set_creating_area.set(false);
+                                        set_new_area_title.set(String::new());
+                                    }
                                 }
                             >
-                                <Icon kind=IconKind::Plus class="w-3.5 h-3.5"/>
-                            </button>
-                        </div>
+                                <input
+                                    type="text"
+                                    data-testid="sidebar-create-area-input"
+                                    class="w-full bg-bg-input border border-border \
+                                           rounded px-2 py-1.5 text-sm \
+                                           text-text-primary placeholder:text-text-tertiary \
+                                           focus:outline-none focus:border-accent"
+                                    placeholder="Area name"
+                                    autofocus=true
+                                    bind:value=(new_area_title, set_new_area_title)
+                                    on:keydown=move |ev| {
+                                        if ev.key() == "Escape" {
+                                            set_creating_area.set(false);
+                                            set_new_area_title.set(String::new());
+                                        }
+                                    }
+                                />
+                            </form>
+                        </Show>
 
                         <Show when=move || creating.get()>
                             <form
@@ -126,23 +238,42 @@ pub fn SidebarView(
                                 projects
                                     .get()
                                     .into_iter()
-                                    .map(|p| {
-                                        let pid = p.id;
-                                        let title = p.title.clone();
-                                        let color = p.color.clone();
+                                    .filter(|p| p.area_id.is_none())
+                                    .map(project_item)
+                                    .collect::<Vec<_>>()
+                            }}
+                            {move || {
+                                areas
+                                    .get()
+                                    .into_iter()
+                                    .map(|area| {
+                                        let area_id = area.id;
                                         view! {
-                                            <ProjectItem
-                                                id=pid
-                                                title=title
-                                                color=color
-                                                on_archive=move || {
-                                                    on_archive_project.run(pid);
-                                                }
-                                                on_edit=move |id, t, c| {
-                                                    on_edit_project.run((id, t, c));
-                                                }
-                                                on_drop_task=on_drop_task_to_project
-                                            />
+                                            <AreaSection
+                                                area=area
+                                                collapsed=Signal::derive(move || {
+                                                    is_area_collapsed.run(area_id)
+                                                })
+                                                drag=drag
+                                                on_toggle=Callback::new(move |_| {
+                                                    on_toggle_area.run(area_id);
+                                                })
+                                                on_rename=Callback::new(move |title| {
+                                                    on_rename_area.run((area_id, title));
+                                                })
+                                                on_archive=Callback::new(move |_| {
+                                                    on_archive_area.run(area_id);
+                                                })
+                                            >
+                                                {move || {
+                                                    projects
+                                                        .get()
+                                                        .into_iter()
+                                                        .filter(|p| p.area_id == Some(area_id))
+                                                        .map(project_item)
+                                                        .collect::<Vec<_>>()
+                                                }}
+                                            </AreaSection>
                                         }
                                     })
                                     .collect::<Vec<_>>()
@@ -203,8 +334,40 @@ pub fn SidebarView(
                     </Show>
                 </div>
 
+                // Tags section
+                <Show when=move || !collapsed.get() && !tags.get().is_empty()>
+                    <div class="pt-4">
+                        <div class="flex items-center justify-between px-3">
+                            <Text variant=TextVariant::LabelMd color=TextColor::Secondary>
+                                "Tags"
+                            </Text>
+                            <a
+                                href="/tags"
+                                title="Manage tags"
+                                class="p-0.5 rounded text-text-tertiary \
+                                       hover:text-text-secondary \
+                                       hover:bg-bg-tertiary transition-colors"
+                            >
+                                <Icon kind=IconKind::Settings class="w-3.5 h-3.5"/>
+                            </a>
+                        </div>
+                        <div class="mt-1 space-y-0.5" data-testid="sidebar-tag-tree">
+                            {move || {
+                                north_dto::build_tag_tree(&tags.get())
+                                    .into_iter()
+                                    .map(|node| view! { <TagTreeItem node=node depth=0/> })
+                                    .collect::<Vec<_>>()
+                            }}
+                        </div>
+                    </div>
+                </Show>
+
                 <div class="pt-4">
+                    <Show when=move || collapsed.get() || tags.get().is_empty()>
+                        <NavItem href="/tags" label="Tags" icon=IconKind::Tag collapsed=collapsed/>
+                    </Show>
                     <NavItem href="/stats" label="Stats" icon=IconKind::Stats collapsed=collapsed/>
+                    <NavItem href="/trash" label="Trash" icon=IconKind::Trash collapsed=collapsed/>
                 </div>
             </nav>
 
@@ -338,6 +501,10 @@ fn ProjectsFlyout(
                                         id=pid
                                         title=title
                                         color=color
+                                        progress=(p.task_count > 0).then_some(p.progress)
+                                        stalled=p.stalled
+                                        due_date=p.due_date
+                                        outcome=p.outcome
                                         on_archive=move || {
                                             on_archive_project.run(pid);
                                         }
@@ -433,11 +600,173 @@ fn FiltersFlyout(saved_filters: Signal<Vec<SavedFilter>>) -> impl IntoView {
     }
 }
 
+/// Drag state for reordering sidebar projects within and between areas.
+#[derive(Clone, Copy)]
+struct ProjectDrag {
+    dragging: RwSignal<Option<i64>>,
+    /// `(project_id, area_id, before_id)`.
+    on_move: Callback<(i64, Option<i64>, Option<i64>)>,
+}
+
+impl ProjectDrag {
+    /// Drops the dragged project into `area_id` before `before_id`, or at
+    /// the end of the area.
+    fn drop(&self, area_id: Option<i64>, before_id: Option<i64>) {
+        if let Some(id) = self.dragging.get_untracked() {
+            self.dragging.set(None);
+            if before_id != Some(id) {
+                self.on_move.run((id, area_id, before_id));
+            }
+        }
+    }
+}
+
+/// A collapsible group of projects.
+#[component]
+fn AreaSection(
+    area: Area,
+    collapsed: Signal<bool>,
+    drag: ProjectDrag,
+    on_toggle: Callback<()>,
+    on_rename: Callback<String>,
+    on_archive: Callback<()>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let (hover, set_hover) = signal(false);
+    let (drag_over, set_drag_over) = signal(false);
+    let (editing, set_editing) = signal(false);
+    let (edit_title, set_edit_title) = signal(area.title.clone());
+    let area_id = area.id;
+    let title = area.title;
+
+    view! {
+        <div data-testid="sidebar-area">
+            <Show
+                when=move || editing.get()
+                fallback={
+                    let title = title.clone();
+                    move || {
+                        let title = title.clone();
+                        view! {
+                            <div
+                                class=move || {
+                                    let base = "group flex items-center gap-1.5 px-2 py-1 \
+                                                rounded-lg text-xs font-medium uppercase \
+                                                tracking-wide text-text-secondary \
+                                                hover:bg-bg-tertiary select-none \
+                                                cursor-pointer transition-colors";
+                                    if drag_over.get() {
+                                        format!("{base} bg-accent/20 ring-1 ring-accent")
+                                    } else {
+                                        base.to_string()
+                                    }
+                                }
+                                on:mouseenter=move |_| set_hover.set(true)
+                                on:mouseleave=move |_| set_hover.set(false)
+                                on:click=move |_| on_toggle.run(())
+                                on:dragover=move |ev: web_sys::DragEvent| {
+                                    if drag.dragging.get_untracked().is_some() {
+                                        ev.prevent_default();
+                                        set_drag_over.set(true);
+                                    }
+                                }
+                                on:dragleave=move |_: web_sys::DragEvent| {
+                                    set_drag_over.set(false);
+                                }
+                                on:drop=move |ev: web_sys::DragEvent| {
+                                    ev.prevent_default();
+                                    set_drag_over.set(false);
+                                    drag.drop(Some(area_id), None);
+                                }
+                            >
+                                {move || {
+                                    let kind = if collapsed.get() {
+                                        IconKind::ChevronRight
+                                    } else {
+                                        IconKind::ChevronDown
+                                    };
+                                    view! { <Icon kind=kind class="w-3 h-3 flex-shrink-0"/> }
+                                }}
+                                <span class="flex-1 truncate">{title.clone()}</span>
+                                <Show when=move || hover.get()>
+                                    <button
+                                        class="p-0.5 rounded text-text-tertiary \
+                                               hover:text-text-secondary transition-colors"
+                                        title="Rename area"
+                                        on:click=move |ev| {
+                                            ev.stop_propagation();
+                                            set_editing.set(true);
+                                        }
+                                    >
+                                        <Icon kind=IconKind::Edit class="w-3 h-3"/>
+                                    </button>
+                                    <button
+                                        class="p-0.5 rounded text-text-tertiary \
+                                               hover:text-text-secondary transition-colors"
+                                        title="Archive area"
+                                        on:click=move |ev| {
+                                            ev.stop_propagation();
+                                            on_archive.run(());
+                                        }
+                                    >
+                                        <Icon kind=IconKind::Archive class="w-3 h-3"/>
+                                    </button>
+                                </Show>
+                            </div>
+                        }
+                    }
+                }
+            >
+                <form
+                    class="px-1 py-1"
+                    on:submit=move |ev| {
+                        ev.prevent_default();
+                        let t = edit_title.get_untracked().trim().to_string();
+                        if !t.is_empty() {
+                            on_rename.run(t);
+                            set_editing.set(false);
+                        }
+                    }
+                >
+                    <input
+                        type="text"
+                        class="w-full bg-bg-input border border-border \
+                               rounded px-2 py-1 text-sm text-text-primary \
+                               focus:outline-none focus:border-accent"
+                        autofocus=true
+                        bind:value=(edit_title, set_edit_title)
+                        on:keydown=move |ev| {
+                            if ev.key() == "Escape" {
+                                set_editing.set(false);
+                            }
+                        }
+                    />
+                </form>
+            </Show>
+            <Show when=move || !collapsed.get()>
+                <div class="pl-2 space-y-0.5">{children()}</div>
+            </Show>
+        </div>
+    }
+}
+
 #[component]
 fn ProjectItem(
     id: i64,
     title: String,
     color: String,
+    /// The project's area, for reordering by drag.
+    #[prop(default = None)]
+    area_id: Option<i64>,
+    #[prop(default = None)] drag: Option<ProjectDrag>,
+    /// Percentage of tasks completed; `None` for an empty project.
+    #[prop(default = None)]
+    progress: Option<i32>,
+    #[prop(default = false)] stalled: bool,
+    #[prop(default = None)] due_date: Option<chrono::NaiveDate>,
+    /// Desired outcome, shown as the link's tooltip.
+    #[prop(default = None)]
+    outcome: Option<String>,
     on_archive: impl Fn() + Send + Sync + 'static,
     on_edit: impl Fn(i64, String, String) + Send + Sync + 'static,
     on_drop_task: Callback<(i64, i64)>,
@@ -484,13 +813,32 @@ fn ProjectItem(
                             href=href.clone()
                             data-testid="sidebar-project-item"
                             class=class
+                            title=outcome.clone()
+                            draggable=if drag.is_some() { "true" } else { "false" }
                             on:mouseenter=move |_| set_hover.set(true)
                             on:mouseleave=move |_| set_hover.set(false)
+                            on:dragstart=move |ev: web_sys::DragEvent| {
+                                // Tasks dragged onto the sidebar start elsewhere.
+                                let Some(drag) = drag else { return };
+                                drag.dragging.set(Some(id));
+                                if let Some(dt) = ev.data_transfer() {
+                                    let _ = dt.set_data("text/plain", &id.to_string());
+                                    dt.set_effect_allowed("move");
+                                }
+                            }
+                            on:dragend=move |_: web_sys::DragEvent| {
+                                if let Some(drag) = drag {
+                                    drag.dragging.set(None);
+                                }
+                            }
                             on:dragover=move |ev: web_sys::DragEvent| {
-                                if drag_ctx
+                                let dragging_task = drag_ctx
                                     .and_then(|c| c.dragging_task_id.get_untracked())
-                                    .is_some()
-                                {
+                                    .is_some();
+                                let dragging_project = drag
+                                    .and_then(|d| d.dragging.get_untracked())
+                                    .is_some_and(|pid| pid != id);
+                                if dragging_task || dragging_project {
                                     ev.prevent_default();
                                     set_drag_over.set(true);
                                 }
@@ -501,6 +849,9 @@ fn ProjectItem(
                             on:drop=move |ev: web_sys::DragEvent| {
                                 ev.prevent_default();
                                 set_drag_over.set(false);
+                                if let Some(drag) = drag {
+                                    drag.drop(area_id, Some(id));
+                                }
                                 if let Some(ctx) = drag_ctx {
                                     if let Some(task_id) =
                                         ctx.dragging_task_id.get_untracked()
@@ -520,6 +871,41 @@ fn ProjectItem(
                                 )
                             />
                             <span class="flex-1 truncate">{title.clone()}</span>
+                            {stalled
+                                .then(|| {
+                                    view! {
+                                        <span
+                                            class="text-xs text-warning flex-shrink-0"
+                                            title="No next action"
+                                            data-testid="sidebar-project-stalled"
+                                        >
+                                            "stalled"
+                                        </span>
+                                    }
+                                })}
+                            {due_date
+                                .map(|d| {
+                                    let overdue = d < chrono::Utc::now().date_naive();
+                                    view! {
+                                        <span class=if overdue {
+                                            "text-xs text-danger flex-shrink-0"
+                                        } else {
+                                            "text-xs text-text-tertiary flex-shrink-0"
+                                        }>{d.format("%b %-d").to_string()}</span>
+                                    }
+                                })}
+                            {progress
+                                .map(|pct| {
+                                    view! {
+                                        <span
+                                            class="text-xs text-text-tertiary \
+                                                   tabular-nums flex-shrink-0"
+                                            data-testid="sidebar-project-progress"
+                                        >
+                                            {format!("{pct}%")}
+                                        </span>
+                                    }
+                                })}
                             <Show when=move || hover.get()>
                                 {
                                     let on_archive = on_archive.clone();
@@ -748,6 +1134,82 @@ fn FilterNavItem(
     }
 }
 
+/// A tag or namespace in the sidebar tree. Namespaces start collapsed.
+#[component]
+fn TagTreeItem(node: TagNode, depth: usize) -> impl IntoView {
+    let location = use_location();
+    let expanded = RwSignal::new(false);
+    let href = format!("/tags/{}", urlencoding::encode(&node.path));
+    let href_cmp = href.clone();
+    let has_children = !node.children.is_empty();
+    let count = node.total_count();
+    let color = node
+        .tag
+        .as_ref()
+        .map(|t| t.color.clone())
+        .unwrap_or_else(|| north_dto::DEFAULT_COLOR.into());
+    let children = node.children;
+
+    let class = Memo::new(move |_| {
+        let base = "group flex items-center gap-1.5 pr-3 py-1 rounded-lg \
+                    text-sm text-text-primary hover:bg-bg-tertiary \
+                    transition-colors";
+        if location.pathname.get() == href_cmp {
+            format!("{base} bg-bg-tertiary font-medium")
+        } else {
+            base.to_string()
+        }
+    });
+
+    view! {
+        <a
+            href=href
+            data-testid="sidebar-tag-item"
+            class=class
+            style=format!("padding-left: {}px", 8 + depth * 14)
+        >
+            {if has_children {
+                view! {
+                    <button
+                        class="p-0.5 rounded text-text-tertiary hover:text-text-secondary"
+                        aria-label="Toggle namespace"
+                        on:click=move |ev| {
+                            ev.prevent_default();
+                            ev.stop_propagation();
+                            expanded.update(|e| *e = !*e);
+                        }
+                    >
+                        {move || {
+                            let kind = if expanded.get() {
+                                IconKind::ChevronDown
+                            } else {
+                                IconKind::ChevronRight
+                            };
+                            view! { <Icon kind=kind class="w-3 h-3"/> }
+                        }}
+                    </button>
+                }
+                .into_any()
+            } else {
+                view! { <span class="w-4 flex-shrink-0"/> }.into_any()
+            }}
+            <span
+                class="w-2 h-2 rounded-full flex-shrink-0"
+                style=format!("background-color: {color}")
+            />
+            <span class="flex-1 truncate">{node.label}</span>
+            <span class="text-xs text-text-tertiary">{count}</span>
+        </a>
+        <Show when=move || expanded.get()>
+            {children
+                .clone()
+                .into_iter()
+                .map(|child| view! { <TagTreeItem node=child depth=depth + 1/> }.into_any())
+                .collect::<Vec<_>>()}
+        </Show>
+    }
+}
+
 #[component]
 fn NavItem(
     href: &'static str,
@@ -29,6 +29,10 @@ pub fn SmartTextarea(
     #[prop(optional)] on_close: Option<Callback<()>>,
     #[prop(optional)] on_blur: Option<Callback<()>>,
     #[prop(optional)] on_input: Option<Callback<()>>,
+    /// Files pasted or dropped into the textarea, with the caret offset
+    /// (UTF-16) they landed at. Without it, files are ignored.
+    #[prop(optional)]
+    on_files: Option<Callback<(Vec<web_sys::File>, usize)>>,
     #[prop(optional)] autofocus: bool,
     #[prop(optional, default = 1)] rows: u32,
 ) -> impl IntoView {
@@ -52,6 +56,7 @@ pub fn SmartTextarea(
             on_close=on_close
             on_blur=on_blur
             on_input=on_input
+            on_files=on_files
             autofocus=autofocus
             rows=rows
             tags=tags
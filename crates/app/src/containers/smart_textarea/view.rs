@@ -29,6 +29,7 @@ pub fn SmartTextareaView(
     on_close: Option<Callback<()>>,
     on_blur: Option<Callback<()>>,
     on_input: Option<Callback<()>>,
+    on_files: Option<Callback<(Vec<web_sys::File>, usize)>>,
     autofocus: bool,
     rows: u32,
 
@@ -82,6 +83,26 @@ pub fn SmartTextareaView(
         });
     }
 
+    // Hands pasted/dropped files to `on_files`; returns false when there are none.
+    let take_files = move |dt: Option<web_sys::DataTransfer>| -> bool {
+        let Some(cb) = on_files else {
+            return false;
+        };
+        let files = dt
+            .map(|dt| crate::libs::files_from_transfer(&dt))
+            .unwrap_or_default();
+        if files.is_empty() {
+            return false;
+        }
+        let at = textarea_ref
+            .get_untracked()
+            .and_then(|el| el.selection_start().ok().flatten())
+            .map(|pos| pos as usize)
+            .unwrap_or(usize::MAX);
+        cb.run((files, at));
+        true
+    };
+
     let update_suggestions = move |val: &str, cursor: usize| {
         if !autocomplete {
             return;
@@ -244,6 +265,25 @@ pub fn SmartTextareaView(
                         }
                     }
                 }
+                on:paste=move |ev| {
+                    let data = ev
+                        .dyn_ref::<web_sys::ClipboardEvent>()
+                        .and_then(|ev| ev.clipboard_data());
+                    if take_files(data) {
+                        ev.prevent_default();
+                    }
+                }
+                on:dragover=move |ev: web_sys::DragEvent| {
+                    // Needed for the browser to allow dropping files here.
+                    if on_files.is_some() {
+                        ev.prevent_default();
+                    }
+                }
+                on:drop=move |ev: web_sys::DragEvent| {
+                    if take_files(ev.data_transfer()) {
+                        ev.prevent_default();
+                    }
+                }
                 on:blur=move |_| {
                     // Clear autocomplete suggestions.
                     // Use try_set — signals may be disposed if the parent
@@ -19,6 +19,7 @@ pub fn TagPicker(
 
     let app_store = use_app_store();
     let all_tags = Memo::new(move |_| app_store.tags.get());
+    let exclusive_namespaces = app_store.settings.exclusive_tag_namespaces();
 
     let wrapped_on_set_tags = Callback::new(move |args: (i64, Vec<String>)| {
         on_set_tags.run(args);
@@ -33,6 +34,7 @@ pub fn TagPicker(
             popover_open=popover_open
             set_popover_open=set_popover_open
             all_tags=all_tags
+            exclusive_namespaces=exclusive_namespaces
             current_tags=current_tags
             set_current_tags=set_current_tags
             on_set_tags=wrapped_on_set_tags
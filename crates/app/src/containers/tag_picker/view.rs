@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use north_dto::{Tag, TagInfo};
+use north_dto::{enforce_exclusive_namespaces, tag_namespace, Tag, TagInfo};
 
 use crate::atoms::{Text, TextColor, TextTag, TextVariant};
 use north_ui::{Icon, IconKind, Popover};
@@ -12,6 +12,7 @@ pub fn TagPickerView(
     popover_open: ReadSignal<bool>,
     set_popover_open: WriteSignal<bool>,
     all_tags: Memo<Vec<Tag>>,
+    exclusive_namespaces: Signal<Vec<String>>,
     current_tags: ReadSignal<Vec<String>>,
     set_current_tags: WriteSignal<Vec<String>>,
     on_set_tags: Callback<(i64, Vec<String>)>,
@@ -26,6 +27,7 @@ pub fn TagPickerView(
             names.remove(pos);
         } else {
             names.push(name);
+            names = enforce_exclusive_namespaces(&names, &exclusive_namespaces.get_untracked());
         }
         set_current_tags.set(names.clone());
         on_set_tags.run((task_id, names));
@@ -37,6 +39,7 @@ pub fn TagPickerView(
             let mut names = current_tags.get_untracked();
             if !names.contains(&name) {
                 names.push(name.clone());
+                names = enforce_exclusive_namespaces(&names, &exclusive_namespaces.get_untracked());
                 set_current_tags.set(names.clone());
 
                 let mut tags = display_tags.get_untracked();
@@ -223,7 +226,10 @@ pub fn TagPickerView(
                     />
                 </div>
                 {move || {
-                    let list = all_tags.get();
+                    let mut list = all_tags.get();
+                    list.sort_by(|a, b| {
+                        (tag_namespace(&a.name), &a.name).cmp(&(tag_namespace(&b.name), &b.name))
+                    });
                     if list.is_empty() {
                         view! {
                             <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P class="px-3 py-2">
@@ -235,8 +241,27 @@ pub fn TagPickerView(
                         view! {
                             <div>
                                 {list
-                                    .into_iter()
-                                    .map(|tag| {
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, tag)| {
+                                        let namespace = tag_namespace(&tag.name);
+                                        let heading = namespace
+                                            .filter(|ns| {
+                                                i == 0
+                                                    || tag_namespace(&list[i - 1].name)
+                                                        != Some(*ns)
+                                            })
+                                            .map(|ns| {
+                                                view! {
+                                                    <div
+                                                        data-testid="tag-picker-group"
+                                                        class="px-3 pt-2 pb-0.5 text-xs \
+                                                               text-text-tertiary"
+                                                    >
+                                                        {ns.to_string()}
+                                                    </div>
+                                                }
+                                            });
                                         let name =
                                             tag.name.clone();
                                         let color =
@@ -246,6 +271,7 @@ pub fn TagPickerView(
                                         let check_name =
                                             name.clone();
                                         view! {
+                                            {heading}
                                             <button
                                                 class="w-full text-left \
                                                        px-3 py-1.5 text-sm \
@@ -0,0 +1,20 @@
+use leptos::prelude::*;
+
+use super::controller::TaskAttachmentsController;
+use super::view::TaskAttachmentsView;
+
+/// Files attached to a task. `refresh` is bumped by whoever uploads
+/// outside this list (e.g. pasting into the body) to trigger a refetch.
+#[component]
+pub fn TaskAttachments(task_id: i64, refresh: Signal<u32>) -> impl IntoView {
+    let ctrl = TaskAttachmentsController::new(task_id, refresh);
+
+    view! {
+        <TaskAttachmentsView
+            attachments=ctrl.attachments
+            uploading=ctrl.uploading
+            on_upload=Callback::new(move |files| ctrl.upload(files))
+            on_remove=Callback::new(move |id| ctrl.remove(id))
+        />
+    }
+}
@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::Attachment;
+use north_repositories::AttachmentRepository;
+
+#[derive(Clone, Copy)]
+pub struct TaskAttachmentsController {
+    pub attachments: RwSignal<Vec<Attachment>>,
+    pub uploading: RwSignal<bool>,
+    task_id: i64,
+}
+
+impl TaskAttachmentsController {
+    pub fn new(task_id: i64, refresh: Signal<u32>) -> Self {
+        let attachments = RwSignal::new(Vec::<Attachment>::new());
+
+        Effect::new(move |_| {
+            refresh.track();
+            spawn_local(async move {
+                if let Ok(list) = AttachmentRepository::list(task_id).await {
+                    attachments.set(list);
+                }
+            });
+        });
+
+        Self {
+            attachments,
+            uploading: RwSignal::new(false),
+            task_id,
+        }
+    }
+
+    pub fn upload(&self, files: Vec<web_sys::File>) {
+        let ctrl = *self;
+        ctrl.uploading.set(true);
+        spawn_local(async move {
+            for file in files {
+                if let Ok(attachment) = AttachmentRepository::upload(ctrl.task_id, file).await {
+                    ctrl.attachments.update(|list| list.push(attachment));
+                }
+            }
+            ctrl.uploading.set(false);
+        });
+    }
+
+    pub fn remove(&self, id: i64) {
+        self.attachments.update(|list| list.retain(|a| a.id != id));
+        spawn_local(async move {
+            let _ = AttachmentRepository::delete(id).await;
+        });
+    }
+}
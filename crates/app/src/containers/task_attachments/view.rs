@@ -0,0 +1,99 @@
+use leptos::prelude::*;
+use north_dto::Attachment;
+use north_ui::{Icon, IconKind};
+
+use crate::atoms::{Text, TextColor, TextVariant};
+
+#[component]
+pub fn TaskAttachmentsView(
+    attachments: RwSignal<Vec<Attachment>>,
+    uploading: RwSignal<bool>,
+    on_upload: Callback<Vec<web_sys::File>>,
+    on_remove: Callback<i64>,
+) -> impl IntoView {
+    view! {
+        <div data-testid="task-attachments" class="space-y-2">
+            <div class="flex items-center justify-between">
+                <Text variant=TextVariant::LabelMd color=TextColor::Tertiary>
+                    "Attachments"
+                </Text>
+                <label class="flex items-center gap-1 text-xs text-text-secondary \
+                              hover:text-text-primary transition-colors cursor-pointer">
+                    <Icon kind=IconKind::Paperclip class="w-3.5 h-3.5"/>
+                    {move || if uploading.get() { "Uploading..." } else { "Attach file" }}
+                    <input
+                        type="file"
+                        multiple=true
+                        class="hidden"
+                        on:change=move |ev| {
+                            let input = event_target::<web_sys::HtmlInputElement>(&ev);
+                            let files: Vec<web_sys::File> = input
+                                .files()
+                                .map(|list| (0..list.length()).filter_map(|i| list.get(i)).collect())
+                                .unwrap_or_default();
+                            input.set_value("");
+                            if !files.is_empty() {
+                                on_upload.run(files);
+                            }
+                        }
+                    />
+                </label>
+            </div>
+            <ul class="space-y-1">
+                <For
+                    each=move || attachments.get()
+                    key=|a| a.id
+                    let:attachment
+                >
+                    {
+                        let id = attachment.id;
+                        let url = attachment.url();
+                        let preview = attachment.is_image().then(|| {
+                            let url = url.clone();
+                            view! {
+                                <img
+                                    src=url
+                                    alt=attachment.filename.clone()
+                                    loading="lazy"
+                                    class="w-8 h-8 rounded object-cover flex-shrink-0"
+                                />
+                            }
+                        });
+                        view! {
+                            <li class="group flex items-center gap-2 text-xs">
+                                {match preview {
+                                    Some(img) => img.into_any(),
+                                    None => view! {
+                                        <Icon
+                                            kind=IconKind::Paperclip
+                                            class="w-3.5 h-3.5 text-text-tertiary flex-shrink-0"
+                                        />
+                                    }
+                                    .into_any(),
+                                }}
+                                <a
+                                    href=url
+                                    target="_blank"
+                                    rel="noopener"
+                                    class="flex-1 min-w-0 truncate text-text-secondary \
+                                           hover:text-text-primary hover:underline"
+                                >
+                                    {attachment.filename.clone()}
+                                </a>
+                                <span class="text-text-tertiary">{attachment.size_label()}</span>
+                                <button
+                                    class="opacity-0 group-hover:opacity-100 text-text-tertiary \
+                                           hover:text-danger transition-all cursor-pointer"
+                                    on:click=move |_| on_remove.run(id)
+                                    title="Remove attachment"
+                                >
+                                    <Icon kind=IconKind::Close class="w-3 h-3"/>
+                                </button>
+                            </li>
+                        }
+                    }
+                </For>
+            </ul>
+        </div>
+    }
+}
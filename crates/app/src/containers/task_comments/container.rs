@@ -0,0 +1,23 @@
+use leptos::prelude::*;
+
+use super::controller::TaskCommentsController;
+use super::view::TaskCommentsView;
+
+#[component]
+pub fn TaskComments(task_id: i64) -> impl IntoView {
+    let ctrl = TaskCommentsController::new(task_id);
+
+    view! {
+        <TaskCommentsView
+            comments=ctrl.comments
+            draft=ctrl.draft
+            editing_id=ctrl.editing_id
+            edit_draft=ctrl.edit_draft
+            on_add=Callback::new(move |()| ctrl.add())
+            on_start_edit=Callback::new(move |id| ctrl.start_edit(id))
+            on_save_edit=Callback::new(move |()| ctrl.save_edit())
+            on_cancel_edit=Callback::new(move |()| ctrl.cancel_edit())
+            on_remove=Callback::new(move |id| ctrl.remove(id))
+        />
+    }
+}
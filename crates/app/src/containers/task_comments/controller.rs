@@ -0,0 +1,128 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::TaskComment;
+use north_repositories::CommentRepository;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// URL titles are resolved on the server after saving; the thread is
+/// reloaded once after this delay to pick them up.
+const URL_RESOLUTION_DELAY_MS: i32 = 3000;
+
+#[derive(Clone, Copy)]
+pub struct TaskCommentsController {
+    pub comments: RwSignal<Vec<TaskComment>>,
+    pub draft: RwSignal<String>,
+    pub editing_id: RwSignal<Option<i64>>,
+    pub edit_draft: RwSignal<String>,
+    task_id: i64,
+}
+
+impl TaskCommentsController {
+    pub fn new(task_id: i64) -> Self {
+        let comments = RwSignal::new(Vec::<TaskComment>::new());
+
+        Effect::new(move |_| {
+            spawn_local(async move {
+                if let Ok(list) = CommentRepository::list(task_id).await {
+                    comments.set(list);
+                }
+            });
+        });
+
+        Self {
+            comments,
+            draft: RwSignal::new(String::new()),
+            editing_id: RwSignal::new(None),
+            edit_draft: RwSignal::new(String::new()),
+            task_id,
+        }
+    }
+
+    pub fn add(&self) {
+        let body = self.draft.get_untracked();
+        if body.trim().is_empty() {
+            return;
+        }
+        let ctrl = *self;
+        self.draft.set(String::new());
+        spawn_local(async move {
+            match CommentRepository::create(ctrl.task_id, body.clone()).await {
+                Ok(comment) => {
+                    ctrl.comments.update(|list| list.push(comment));
+                    ctrl.refetch_if_has_urls(&body);
+                }
+                // Keep the text so it isn't lost.
+                Err(_) => ctrl.draft.set(body),
+            }
+        });
+    }
+
+    pub fn start_edit(&self, id: i64) {
+        let body = self
+            .comments
+            .get_untracked()
+            .into_iter()
+            .find(|c| c.id == id)
+            .map(|c| c.body);
+        if let Some(body) = body {
+            self.edit_draft.set(body);
+            self.editing_id.set(Some(id));
+        }
+    }
+
+    pub fn save_edit(&self) {
+        let Some(id) = self.editing_id.get_untracked() else {
+            return;
+        };
+        let body = self.edit_draft.get_untracked();
+        if body.trim().is_empty() {
+            return;
+        }
+        let ctrl = *self;
+        self.editing_id.set(None);
+        spawn_local(async move {
+            if let Ok(updated) = CommentRepository::update(id, body.clone()).await {
+                ctrl.comments.update(|list| {
+                    if let Some(c) = list.iter_mut().find(|c| c.id == id) {
+                        *c = updated;
+                    }
+                });
+                ctrl.refetch_if_has_urls(&body);
+            }
+        });
+    }
+
+    pub fn cancel_edit(&self) {
+        self.editing_id.set(None);
+    }
+
+    pub fn remove(&self, id: i64) {
+        self.comments.update(|list| list.retain(|c| c.id != id));
+        spawn_local(async move {
+            let _ = CommentRepository::delete(id).await;
+        });
+    }
+
+    fn refetch_if_has_urls(&self, body: &str) {
+        if !body.contains("http://") && !body.contains("https://") {
+            return;
+        }
+        let comments = self.comments;
+        let task_id = self.task_id;
+        let cb = Closure::once(Box::new(move || {
+            spawn_local(async move {
+                if let Ok(list) = CommentRepository::list(task_id).await {
+                    comments.try_set(list);
+                }
+            });
+        }) as Box<dyn FnOnce()>);
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                cb.as_ref().unchecked_ref(),
+                URL_RESOLUTION_DELAY_MS,
+            );
+        }
+        cb.forget();
+    }
+}
@@ -0,0 +1,108 @@
+use leptos::prelude::*;
+use north_dto::TaskComment;
+use north_ui::{Icon, IconKind};
+
+use crate::atoms::{Text, TextColor, TextVariant};
+use crate::components::enriched_markdown::EnrichedMarkdownView;
+use crate::containers::smart_textarea::SmartTextarea;
+
+const TEXTAREA_CLASS: &str = "w-full text-sm text-text-primary \
+                              bg-bg-input border border-border rounded \
+                              px-2 py-1 focus:outline-none \
+                              focus:border-accent resize-none \
+                              placeholder:text-text-tertiary";
+
+/// Comment thread under a task, oldest first. Enter saves, Ctrl+Enter
+/// inserts a newline, Escape cancels an edit.
+#[component]
+pub fn TaskCommentsView(
+    comments: RwSignal<Vec<TaskComment>>,
+    draft: RwSignal<String>,
+    editing_id: RwSignal<Option<i64>>,
+    edit_draft: RwSignal<String>,
+    on_add: Callback<()>,
+    on_start_edit: Callback<i64>,
+    on_save_edit: Callback<()>,
+    on_cancel_edit: Callback<()>,
+    on_remove: Callback<i64>,
+) -> impl IntoView {
+    view! {
+        <div data-testid="task-comments" class="space-y-2">
+            <Text variant=TextVariant::LabelMd color=TextColor::Tertiary>
+                "Comments"
+            </Text>
+            <For
+                each=move || comments.get()
+                key=|c| (c.id, c.body.clone(), c.edited_at)
+                let:comment
+            >
+                {
+                    let id = comment.id;
+                    let meta = format!(
+                        "{} · {}{}",
+                        comment.user_name,
+                        comment.created_at.format("%b %-d, %Y %H:%M"),
+                        if comment.edited_at.is_some() { " (edited)" } else { "" },
+                    );
+                    let body = comment.body.clone();
+                    view! {
+                        <div data-testid="task-comment" class="group space-y-0.5">
+                            <div class="flex items-center justify-between gap-2 \
+                                        text-xs text-text-tertiary">
+                                <span>{meta}</span>
+                                <span class="flex items-center gap-1 opacity-0 \
+                                             group-hover:opacity-100 transition-opacity">
+                                    <button
+                                        class="hover:text-text-primary transition-colors"
+                                        on:click=move |_| on_start_edit.run(id)
+                                        title="Edit comment"
+                                    >
+                                        <Icon kind=IconKind::Edit class="w-3 h-3"/>
+                                    </button>
+                                    <button
+                                        class="hover:text-danger transition-colors"
+                                        on:click=move |_| on_remove.run(id)
+                                        title="Delete comment"
+                                    >
+                                        <Icon kind=IconKind::Close class="w-3 h-3"/>
+                                    </button>
+                                </span>
+                            </div>
+                            <Show
+                                when=move || editing_id.get() == Some(id)
+                                fallback=move || {
+                                    view! {
+                                        <div class="text-sm">
+                                            <EnrichedMarkdownView content=body.clone()/>
+                                        </div>
+                                    }
+                                }
+                            >
+                                <SmartTextarea
+                                    value=edit_draft
+                                    autocomplete=true
+                                    auto_resize=true
+                                    multiline=true
+                                    autofocus=true
+                                    on_submit=on_save_edit
+                                    on_close=on_cancel_edit
+                                    class=TEXTAREA_CLASS
+                                />
+                            </Show>
+                        </div>
+                    }
+                }
+            </For>
+            <SmartTextarea
+                value=draft
+                data_testid="task-comment-input"
+                autocomplete=true
+                auto_resize=true
+                multiline=true
+                placeholder="Add a comment..."
+                on_submit=on_add
+                class=TEXTAREA_CLASS
+            />
+        </div>
+    }
+}
@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+use north_stores::use_app_store;
+
+use super::controller::TaskDependenciesController;
+use super::view::TaskDependenciesView;
+
+#[component]
+pub fn TaskDependencies(task_id: i64) -> impl IntoView {
+    let app_store = use_app_store();
+    let ctrl = TaskDependenciesController::new(app_store, task_id);
+
+    view! {
+        <TaskDependenciesView
+            blockers=ctrl.blockers
+            dependents=ctrl.dependents
+            query=ctrl.query
+            highlighted=ctrl.highlighted
+            suggestions=ctrl.suggestions
+            on_input=Callback::new(move |q| ctrl.set_query(q))
+            on_move=Callback::new(move |delta| ctrl.move_highlight(delta))
+            on_submit=Callback::new(move |()| ctrl.submit())
+            on_add=Callback::new(move |value: String| {
+                if let Ok(id) = value.parse() {
+                    ctrl.add(id);
+                }
+            })
+            on_remove=Callback::new(move |id| ctrl.remove(id))
+            on_open=Callback::new(move |id| ctrl.open(id))
+        />
+    }
+}
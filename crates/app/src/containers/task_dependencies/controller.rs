@@ -0,0 +1,113 @@
+use leptos::prelude::*;
+use north_dto::fuzzy_score;
+use north_stores::{AppStore, TaskModel};
+use north_ui::SuggestionItem;
+
+/// How many matching tasks the "Add blocker" input offers.
+const MAX_SUGGESTIONS: usize = 8;
+
+#[derive(Clone, Copy)]
+pub struct TaskDependenciesController {
+    app_store: AppStore,
+    task_id: i64,
+    pub blockers: Memo<Vec<TaskModel>>,
+    pub dependents: Memo<Vec<TaskModel>>,
+    pub query: RwSignal<String>,
+    pub highlighted: RwSignal<usize>,
+    pub suggestions: Memo<Vec<SuggestionItem>>,
+}
+
+impl TaskDependenciesController {
+    pub fn new(app_store: AppStore, task_id: i64) -> Self {
+        let blockers = app_store.tasks.blockers(task_id);
+        let dependents = app_store.tasks.dependents(task_id);
+        let query = RwSignal::new(String::new());
+        let highlighted = RwSignal::new(0usize);
+        let all_tasks = app_store.tasks.filtered(Default::default());
+
+        let suggestions = Memo::new(move |_| {
+            let q = query.get();
+            let q = q.trim();
+            if q.is_empty() {
+                return vec![];
+            }
+            let blocker_ids: Vec<i64> = blockers.get().iter().map(|t| t.id).collect();
+            let dependent_ids: Vec<i64> = dependents.get().iter().map(|t| t.id).collect();
+            let mut scored: Vec<(i32, TaskModel)> = all_tasks
+                .get()
+                .into_iter()
+                .filter(|t| {
+                    t.id != task_id
+                        && t.completed_at.is_none()
+                        && !blocker_ids.contains(&t.id)
+                        && !dependent_ids.contains(&t.id)
+                })
+                .filter_map(|t| fuzzy_score(q, &t.title).map(|s| (s, t)))
+                .collect();
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            scored
+                .into_iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|(_, t)| SuggestionItem {
+                    name: t.title,
+                    color: "transparent".into(),
+                    group: None,
+                    value: Some(t.id.to_string()),
+                    detail: Some(t.project_title.unwrap_or_else(|| "Inbox".into())),
+                })
+                .collect()
+        });
+
+        Self {
+            app_store,
+            task_id,
+            blockers,
+            dependents,
+            query,
+            highlighted,
+            suggestions,
+        }
+    }
+
+    pub fn set_query(&self, q: String) {
+        self.query.set(q);
+        self.highlighted.set(0);
+    }
+
+    pub fn move_highlight(&self, delta: i32) {
+        let len = self.suggestions.get_untracked().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.highlighted.get_untracked() as i32;
+        let next = (current + delta).rem_euclid(len as i32);
+        self.highlighted.set(next as usize);
+    }
+
+    /// Enter: adds the highlighted suggestion as a blocker.
+    pub fn submit(&self) {
+        let items = self.suggestions.get_untracked();
+        let Some(item) = items.get(self.highlighted.get_untracked()) else {
+            return;
+        };
+        if let Some(id) = item.value.as_deref().and_then(|v| v.parse().ok()) {
+            self.add(id);
+        }
+    }
+
+    pub fn add(&self, blocker_id: i64) {
+        self.app_store.tasks.add_blocker(self.task_id, blocker_id);
+        self.set_query(String::new());
+    }
+
+    pub fn remove(&self, blocker_id: i64) {
+        self.app_store
+            .tasks
+            .remove_blocker(self.task_id, blocker_id);
+    }
+
+    /// Shows a linked task in the detail modal.
+    pub fn open(&self, id: i64) {
+        self.app_store.task_detail_modal.open(id, vec![id]);
+    }
+}
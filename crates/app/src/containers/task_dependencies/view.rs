@@ -0,0 +1,137 @@
+use leptos::prelude::*;
+use north_stores::TaskModel;
+use north_ui::{AutocompleteDropdown, Icon, IconKind, SuggestionItem};
+
+#[component]
+pub fn TaskDependenciesView(
+    blockers: Memo<Vec<TaskModel>>,
+    dependents: Memo<Vec<TaskModel>>,
+    query: RwSignal<String>,
+    highlighted: RwSignal<usize>,
+    suggestions: Memo<Vec<SuggestionItem>>,
+    on_input: Callback<String>,
+    on_move: Callback<i32>,
+    on_submit: Callback<()>,
+    on_add: Callback<String>,
+    on_remove: Callback<i64>,
+    on_open: Callback<i64>,
+) -> impl IntoView {
+    let (focused, set_focused) = signal(false);
+
+    let on_keydown = move |ev: web_sys::KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" => {
+            ev.prevent_default();
+            on_move.run(1);
+        }
+        "ArrowUp" => {
+            ev.prevent_default();
+            on_move.run(-1);
+        }
+        "Enter" => {
+            ev.prevent_default();
+            on_submit.run(());
+        }
+        "Escape" if !query.get_untracked().is_empty() => {
+            ev.prevent_default();
+            ev.stop_propagation();
+            on_input.run(String::new());
+        }
+        _ => {}
+    };
+
+    view! {
+        <div data-testid="task-dependencies" class="space-y-1">
+            <For
+                each=move || blockers.get()
+                key=|t| (t.id, t.completed_at.is_some())
+                let:blocker
+            >
+                {
+                    let id = blocker.id;
+                    let done = blocker.completed_at.is_some();
+                    view! {
+                        <div class="flex items-center justify-between gap-1 \
+                                    text-xs text-text-secondary">
+                            <button
+                                class=if done {
+                                    "inline-flex items-center gap-1 min-w-0 \
+                                     line-through text-text-tertiary hover:text-accent"
+                                } else {
+                                    "inline-flex items-center gap-1 min-w-0 hover:text-accent"
+                                }
+                                on:click=move |_| on_open.run(id)
+                                title="Open task"
+                            >
+                                <Icon
+                                    kind=if done { IconKind::Check } else { IconKind::Lock }
+                                    class="w-3 h-3 flex-shrink-0"
+                                />
+                                <span class="truncate">{blocker.title.clone()}</span>
+                            </button>
+                            <button
+                                class="text-text-tertiary hover:text-danger \
+                                       transition-colors"
+                                on:click=move |_| on_remove.run(id)
+                                title="Remove blocker"
+                            >
+                                <Icon kind=IconKind::Close class="w-3 h-3"/>
+                            </button>
+                        </div>
+                    }
+                }
+            </For>
+            <div class="relative">
+                <input
+                    type="text"
+                    data-testid="task-blocker-input"
+                    class="w-full text-xs bg-transparent text-text-secondary \
+                           placeholder:text-text-tertiary border-none \
+                           focus:outline-none no-focus-ring"
+                    placeholder="+ Add blocker"
+                    prop:value=move || query.get()
+                    on:input=move |ev| on_input.run(event_target_value(&ev))
+                    on:keydown=on_keydown
+                    on:focus=move |_| set_focused.set(true)
+                    on:blur=move |_| set_focused.set(false)
+                />
+                {move || {
+                    focused.get().then(|| {
+                        view! {
+                            <AutocompleteDropdown
+                                items=suggestions.get()
+                                highlighted=highlighted.read_only()
+                                on_select=on_add
+                            />
+                        }
+                    })
+                }}
+            </div>
+            <Show when=move || !dependents.get().is_empty()>
+                <div class="pt-1 text-xs text-text-tertiary">"Blocks"</div>
+                <For
+                    each=move || dependents.get()
+                    key=|t| t.id
+                    let:dependent
+                >
+                    {
+                        let id = dependent.id;
+                        view! {
+                            <button
+                                class="flex items-center gap-1 w-full min-w-0 text-xs \
+                                       text-text-secondary hover:text-accent"
+                                on:click=move |_| on_open.run(id)
+                                title="Open task"
+                            >
+                                <Icon
+                                    kind=IconKind::ChevronRight
+                                    class="w-3 h-3 flex-shrink-0"
+                                />
+                                <span class="truncate">{dependent.title.clone()}</span>
+                            </button>
+                        }
+                    }
+                </For>
+            </Show>
+        </div>
+    }
+}
@@ -1,11 +1,13 @@
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use north_dto::RecurrenceType;
+use north_repositories::AttachmentRepository;
 use north_stores::{
     AppStore, IdFilter, ModalStore, TaskDetailModalStore, TaskModel, TaskStoreFilter,
 };
 
 use crate::containers::traversable_task_list::ExtraVisibleIds;
-use crate::libs::KeepCompletedVisible;
+use crate::libs::{insert_at_utf16, KeepCompletedVisible};
 
 #[derive(Clone, Copy)]
 pub struct TaskDetailModalController {
@@ -19,6 +21,8 @@ pub struct TaskDetailModalController {
     pub focused_task_id: RwSignal<Option<i64>>,
     pub subtask_show_completed: RwSignal<bool>,
     pub subtask_filter: Signal<Callback<TaskModel, bool>>,
+    /// Bumped after body uploads so the attachment list refetches.
+    pub attachments_version: RwSignal<u32>,
 }
 
 impl TaskDetailModalController {
@@ -45,6 +49,7 @@ impl TaskDetailModalController {
             focused_task_id: RwSignal::new(None),
             subtask_show_completed,
             subtask_filter,
+            attachments_version: RwSignal::new(0),
         }
     }
 
@@ -132,6 +137,31 @@ impl TaskDetailModalController {
         self.store.update(t, b);
     }
 
+    /// Uploads files pasted or dropped into the body and inserts a Markdown
+    /// link for each at `at` (a UTF-16 caret offset), then saves.
+    pub fn attach_files(&self, task_id: i64, files: Vec<web_sys::File>, at: usize) {
+        let ctrl = *self;
+        spawn_local(async move {
+            let mut links = Vec::new();
+            for file in files {
+                if let Ok(attachment) = AttachmentRepository::upload(task_id, file).await {
+                    links.push(attachment.markdown());
+                }
+            }
+            if links.is_empty() {
+                return;
+            }
+            ctrl.attachments_version.update(|v| *v += 1);
+            let Some(body) = ctrl.body_draft.try_get_untracked() else {
+                return;
+            };
+            let _ = ctrl
+                .body_draft
+                .try_set(insert_at_utf16(&body, at, &links.join("\n")));
+            ctrl.save();
+        });
+    }
+
     pub fn delete(&self) {
         self.store.delete();
     }
@@ -164,14 +194,51 @@ impl TaskDetailModalController {
         self.store.clear_due_date();
     }
 
-    pub fn set_recurrence(&self, rt: Option<RecurrenceType>, rr: Option<String>) {
-        self.store.set_recurrence(rt, rr);
+    pub fn set_priority(&self, priority: Option<i16>) {
+        self.store.set_priority(priority);
+    }
+
+    pub fn set_estimate(&self, estimate_minutes: Option<i32>) {
+        self.store.set_estimate(estimate_minutes);
+    }
+
+    pub fn toggle_timer(&self) {
+        self.store.toggle_timer();
+    }
+
+    pub fn set_waiting_for(&self, who: String) {
+        self.store.set_waiting_for(who);
+    }
+
+    pub fn clear_waiting(&self) {
+        self.store.clear_waiting();
+    }
+
+    pub fn set_follow_up_date(&self, val: String) {
+        self.store.set_follow_up_date(val);
+    }
+
+    pub fn clear_follow_up_date(&self) {
+        self.store.clear_follow_up_date();
+    }
+
+    pub fn set_recurrence(
+        &self,
+        rt: Option<RecurrenceType>,
+        rr: Option<String>,
+        exdates: Vec<chrono::NaiveDate>,
+    ) {
+        self.store.set_recurrence(rt, rr, exdates);
     }
 
     pub fn set_sequential_limit(&self, n: i16) {
         self.store.set_sequential_limit(n);
     }
 
+    pub fn skip_occurrence(&self) {
+        self.store.skip_occurrence();
+    }
+
     pub fn open_recurrence_modal(&self) {
         self.modal.open("recurrence");
     }
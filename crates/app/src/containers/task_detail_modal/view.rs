@@ -9,10 +9,16 @@ use crate::components::enriched_markdown::EnrichedMarkdownView;
 use crate::components::recurrence_modal::RecurrenceModal;
 use crate::containers::inline_task_input::InlineTaskInput;
 use crate::containers::project_picker::ProjectPicker;
+use crate::containers::recurrence_history::RecurrenceHistory;
 use crate::containers::smart_textarea::SmartTextarea;
 use crate::containers::tag_picker::TagPicker;
+use crate::containers::task_attachments::TaskAttachments;
 use crate::containers::task_checkbox::TaskCheckbox;
+use crate::containers::task_comments::TaskComments;
+use crate::containers::task_dependencies::TaskDependencies;
+use crate::containers::task_history::TaskHistory;
 use crate::containers::task_list_item::ItemConfig;
+use crate::containers::task_reminders::TaskReminders;
 use crate::containers::traversable_task_list::TraversableTaskList;
 use north_ui::{Icon, IconKind};
 
@@ -55,8 +61,15 @@ pub fn TaskDetailModalView(
                     let tags = task.tags.clone();
                     let start_at = task.start_at;
                     let due_date = task.due_date;
+                    let priority = task.priority;
+                    let estimate_minutes = task.estimate_minutes;
+                    let spent_minutes = task.spent_minutes;
+                    let tracking_since = task.tracking_since;
+                    let waiting_for = task.waiting_for.clone();
+                    let follow_up_date = task.follow_up_date;
                     let sequential_limit = task.sequential_limit;
                     let recurrence = task.recurrence.clone();
+                    let show_history = recurrence.is_some() || task.series_id.is_some();
 
                     ctrl.sync_drafts(title.clone(), body.clone());
 
@@ -323,6 +336,23 @@ pub fn TaskDetailModalView(
                                         </div>
                                     </Show>
                                 </div>
+
+                                // Attachments
+                                <TaskAttachments
+                                    task_id=task_id
+                                    refresh=ctrl.attachments_version.into()
+                                />
+
+                                // Recurring series history
+                                {show_history.then(|| {
+                                    view! { <RecurrenceHistory task_id=task_id/> }
+                                })}
+
+                                // Comment thread
+                                <TaskComments task_id=task_id/>
+
+                                // Activity log
+                                <TaskHistory task_id=task_id/>
                             </div>
 
                             // Right sidebar
@@ -391,6 +421,26 @@ pub fn TaskDetailModalView(
                                     />
                                 </SidebarRow>
 
+                                // Priority
+                                <SidebarRow label="Priority">
+                                    <PrioritySelect
+                                        priority=priority
+                                        on_change=Callback::new(
+                                            move |p: Option<i16>| ctrl.set_priority(p),
+                                        )
+                                    />
+                                </SidebarRow>
+
+                                // Time tracking
+                                <SidebarRow label="Time">
+                                    <TimeTracking
+                                        estimate_minutes=estimate_minutes
+                                        spent_minutes=spent_minutes
+                                        tracking_since=tracking_since
+                                        ctrl=ctrl
+                                    />
+                                </SidebarRow>
+
                                 // Recurrence
                                 <SidebarRow label="Recurrence">
                                     <RecurrenceSidebarButton
@@ -399,17 +449,35 @@ pub fn TaskDetailModalView(
                                             ctrl.open_recurrence_modal();
                                         })
                                     />
+                                    {recurrence.is_some().then(|| {
+                                        view! {
+                                            <button
+                                                data-testid="task-detail-skip-occurrence"
+                                                class="mt-1 text-xs text-accent \
+                                                       hover:text-accent-hover \
+                                                       hover:underline cursor-pointer \
+                                                       transition-colors"
+                                                on:click=move |_| ctrl.skip_occurrence()
+                                            >
+                                                "Skip this occurrence"
+                                            </button>
+                                        }
+                                    })}
                                     <Show when=move || ctrl.show_recurrence_modal()>
                                         {
                                             let (rec_type, rec_rule) = recurrence.as_ref()
                                                 .map(|r| (r.recurrence_type, r.rule_string()))
                                                 .unwrap_or_else(Recurrence::default_rule);
+                                            let rec_exdates = recurrence.as_ref()
+                                                .map(|r| r.exdates.clone())
+                                                .unwrap_or_default();
                                             view! {
                                         <RecurrenceModal
                                             recurrence_type=Some(rec_type)
                                             recurrence_rule=Some(rec_rule)
-                                            on_save=Callback::new(move |(rt, rr)| {
-                                                ctrl.set_recurrence(rt, rr);
+                                            recurrence_exdates=rec_exdates
+                                            on_save=Callback::new(move |(rt, rr, ex)| {
+                                                ctrl.set_recurrence(rt, rr, ex);
                                                 ctrl.close_recurrence_modal();
                                             })
                                             on_close=Callback::new(move |()| {
@@ -421,6 +489,25 @@ pub fn TaskDetailModalView(
                                     </Show>
                                 </SidebarRow>
 
+                                // Reminders
+                                <SidebarRow label="Reminders">
+                                    <TaskReminders task_id=task_id/>
+                                </SidebarRow>
+
+                                // Dependencies
+                                <SidebarRow label="Blocked by">
+                                    <TaskDependencies task_id=task_id/>
+                                </SidebarRow>
+
+                                // Waiting for
+                                <SidebarRow label="Waiting for">
+                                    <WaitingForInput
+                                        waiting_for=waiting_for
+                                        follow_up_date=follow_up_date
+                                        ctrl=ctrl
+                                    />
+                                </SidebarRow>
+
                                 // Sequential limit
                                 <SidebarRow label="Seq. limit">
                                     <SequentialLimitInput
@@ -491,6 +578,81 @@ fn DueDatePicker(
     }
 }
 
+#[component]
+fn WaitingForInput(
+    waiting_for: Option<String>,
+    follow_up_date: Option<chrono::NaiveDate>,
+    ctrl: TaskDetailModalController,
+) -> impl IntoView {
+    let is_waiting = waiting_for.is_some();
+    let follow_up = follow_up_date.map(|d| d.format("%Y-%m-%d").to_string());
+
+    view! {
+        <div class="space-y-1">
+            <div class="flex items-center gap-1">
+                <input
+                    type="text"
+                    data-testid="task-waiting-for-input"
+                    class="w-full text-xs bg-transparent text-text-secondary \
+                           placeholder:text-text-tertiary border-none \
+                           focus:outline-none no-focus-ring"
+                    placeholder="Who are you waiting on?"
+                    prop:value=waiting_for.clone().unwrap_or_default()
+                    on:change=move |ev| ctrl.set_waiting_for(event_target_value(&ev))
+                />
+                {is_waiting.then(|| {
+                    view! {
+                        <button
+                            class="p-0.5 text-text-tertiary hover:text-text-primary \
+                                   transition-colors flex-shrink-0"
+                            on:click=move |_| ctrl.clear_waiting()
+                            title="Stop waiting"
+                        >
+                            <Icon kind=IconKind::Close class="w-3 h-3"/>
+                        </button>
+                    }
+                })}
+            </div>
+            {is_waiting.then(|| {
+                view! {
+                    <div class="flex items-center gap-1">
+                        <span class="text-xs text-text-tertiary flex-shrink-0">
+                            "Follow up"
+                        </span>
+                        <input
+                            type="date"
+                            class="text-xs bg-transparent text-text-secondary \
+                                   border-none focus:outline-none cursor-pointer \
+                                   w-full"
+                            data-testid="follow-up-date-input"
+                            prop:value=follow_up.clone().unwrap_or_default()
+                            on:change=move |ev| {
+                                let val = event_target_value(&ev);
+                                if !val.is_empty() {
+                                    ctrl.set_follow_up_date(val);
+                                }
+                            }
+                        />
+                        {follow_up_date.map(|_| {
+                            view! {
+                                <button
+                                    class="p-0.5 text-text-tertiary \
+                                           hover:text-text-primary \
+                                           transition-colors flex-shrink-0"
+                                    on:click=move |_| ctrl.clear_follow_up_date()
+                                    title="Clear follow-up date"
+                                >
+                                    <Icon kind=IconKind::Close class="w-3 h-3"/>
+                                </button>
+                            }
+                        })}
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
+
 #[component]
 fn RecurrenceSidebarButton(
     recurrence: Option<north_stores::Recurrence>,
@@ -564,6 +726,11 @@ fn BodySection(ctrl: TaskDetailModalController) -> impl IntoView {
                         ctrl.save();
                         editing.set(false);
                     })
+                    on_files=Callback::new(move |(files, at)| {
+                        if let Some(task) = untrack(|| ctrl.task()) {
+                            ctrl.attach_files(task.id, files, at);
+                        }
+                    })
                     class="w-full text-sm \
                            text-text-primary \
                            bg-transparent \
@@ -581,6 +748,83 @@ fn BodySection(ctrl: TaskDetailModalController) -> impl IntoView {
     }
 }
 
+#[component]
+fn PrioritySelect(priority: Option<i16>, on_change: Callback<Option<i16>>) -> impl IntoView {
+    view! {
+        <select
+            data-testid="task-priority-select"
+            class="text-xs bg-transparent text-text-secondary border-none \
+                   focus:outline-none cursor-pointer"
+            on:change=move |ev| on_change.run(event_target_value(&ev).parse().ok())
+        >
+            <option value="" selected=priority.is_none()>"None"</option>
+            {north_dto::PRIORITY_LEVELS
+                .map(|p| {
+                    view! {
+                        <option value=p.to_string() selected=priority == Some(p)>
+                            {format!("P{p}")}
+                        </option>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </select>
+    }
+}
+
+#[component]
+fn TimeTracking(
+    estimate_minutes: Option<i32>,
+    spent_minutes: i64,
+    tracking_since: Option<chrono::DateTime<chrono::Utc>>,
+    ctrl: TaskDetailModalController,
+) -> impl IntoView {
+    let estimate = estimate_minutes.map(|m| north_dto::format_duration(m.into()));
+    let spent = (spent_minutes > 0).then(|| north_dto::format_duration(spent_minutes));
+    let is_tracking = tracking_since.is_some();
+
+    view! {
+        <div class="space-y-1">
+            <input
+                type="text"
+                data-testid="task-estimate-input"
+                class="w-full text-xs bg-transparent text-text-secondary \
+                       placeholder:text-text-tertiary border-none \
+                       focus:outline-none no-focus-ring"
+                placeholder="Estimate, e.g. 1h30m"
+                prop:value=estimate.unwrap_or_default()
+                on:change=move |ev| {
+                    let val = event_target_value(&ev);
+                    if val.trim().is_empty() {
+                        ctrl.set_estimate(None);
+                    } else if let Some(minutes) = north_dto::parse_duration(&val) {
+                        ctrl.set_estimate(Some(minutes));
+                    }
+                }
+            />
+            <div class="flex items-center gap-2">
+                <span class="text-xs text-text-tertiary">
+                    {match spent {
+                        Some(spent) => format!("{spent} tracked"),
+                        None => "Nothing tracked".to_string(),
+                    }}
+                </span>
+                <button
+                    data-testid="task-timer-toggle"
+                    class=if is_tracking {
+                        "text-xs text-danger hover:underline cursor-pointer"
+                    } else {
+                        "text-xs text-accent hover:text-accent-hover \
+                         hover:underline cursor-pointer"
+                    }
+                    on:click=move |_| ctrl.toggle_timer()
+                >
+                    {if is_tracking { "Stop timer" } else { "Start timer" }}
+                </button>
+            </div>
+        </div>
+    }
+}
+
 #[component]
 fn SequentialLimitInput(sequential_limit: i16, on_change: Callback<i16>) -> impl IntoView {
     let (value, set_value) = signal(sequential_limit.to_string());
@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+
+use super::controller::TaskHistoryController;
+use super::view::TaskHistoryView;
+
+#[component]
+pub fn TaskHistory(task_id: i64) -> impl IntoView {
+    let ctrl = TaskHistoryController::new(task_id);
+
+    view! { <TaskHistoryView events=ctrl.events/> }
+}
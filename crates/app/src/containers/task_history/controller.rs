@@ -0,0 +1,30 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::TaskEvent;
+use north_repositories::TaskRepository;
+use north_stores::use_app_store;
+
+#[derive(Clone, Copy)]
+pub struct TaskHistoryController {
+    pub events: RwSignal<Vec<TaskEvent>>,
+}
+
+impl TaskHistoryController {
+    pub fn new(task_id: i64) -> Self {
+        let app_store = use_app_store();
+        let events = RwSignal::new(Vec::<TaskEvent>::new());
+        let task = app_store.tasks.get_by_id(task_id);
+
+        // Refetch whenever the task changes so edits made in the modal show up.
+        Effect::new(move |_| {
+            task.track();
+            spawn_local(async move {
+                if let Ok(list) = TaskRepository::history(task_id).await {
+                    events.set(list);
+                }
+            });
+        });
+
+        Self { events }
+    }
+}
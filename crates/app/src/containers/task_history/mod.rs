@@ -0,0 +1,5 @@
+mod container;
+mod controller;
+mod view;
+
+pub use container::TaskHistory;
@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+use north_dto::TaskEvent;
+
+use crate::atoms::{Text, TextColor, TextVariant};
+
+/// Activity log for a task, newest first.
+#[component]
+pub fn TaskHistoryView(events: RwSignal<Vec<TaskEvent>>) -> impl IntoView {
+    view! {
+        <div data-testid="task-history" class="space-y-2">
+            <Text variant=TextVariant::LabelMd color=TextColor::Tertiary>
+                "Activity"
+            </Text>
+            {move || {
+                let list = events.get();
+                if list.is_empty() {
+                    view! {
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary>
+                            "No activity yet."
+                        </Text>
+                    }
+                    .into_any()
+                } else {
+                    view! {
+                        <ul class="space-y-1 text-xs max-h-48 overflow-y-auto">
+                            {list
+                                .into_iter()
+                                .map(|event| {
+                                    let when = event
+                                        .created_at
+                                        .format("%b %-d, %Y %H:%M")
+                                        .to_string();
+                                    view! {
+                                        <li class="flex items-baseline justify-between gap-3">
+                                            <span class="text-text-primary">
+                                                {event.describe()}
+                                            </span>
+                                            <span class="text-text-tertiary \
+                                                         whitespace-nowrap">
+                                                {format!("{} · {when}", event.user_name)}
+                                            </span>
+                                        </li>
+                                    }
+                                })
+                                .collect::<Vec<_>>()}
+                        </ul>
+                    }
+                    .into_any()
+                }
+            }}
+        </div>
+    }
+}
@@ -1,5 +1,7 @@
+mod priority_marker;
 mod project_prefix;
 mod someday_prefix;
 
+pub use priority_marker::PriorityMarker;
 pub use project_prefix::ProjectPrefix;
 pub use someday_prefix::SomedayPrefix;
@@ -0,0 +1,21 @@
+use leptos::prelude::*;
+
+#[component]
+pub fn PriorityMarker(priority: i16) -> impl IntoView {
+    let color = match priority {
+        1 => "text-danger",
+        2 => "text-warning",
+        3 => "text-accent",
+        _ => "text-text-tertiary",
+    };
+
+    view! {
+        <span
+            data-testid="task-priority"
+            class=format!("text-xs font-semibold mr-1.5 {color}")
+            title=format!("Priority {priority}")
+        >
+            {format!("P{priority}")}
+        </span>
+    }
+}
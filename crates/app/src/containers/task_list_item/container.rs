@@ -38,6 +38,7 @@ pub fn TaskListItem(
     view! {
         <TaskListItemView
             task=ctrl.task
+            open_blocker_count=ctrl.open_blocker_count
             projects=ctrl.projects
             show_review=config.show_review
             show_project=config.show_project
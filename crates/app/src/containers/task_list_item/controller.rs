@@ -7,17 +7,27 @@ pub struct TaskListItemController {
     app_store: AppStore,
     task_id: i64,
     pub task: Memo<Option<TaskModel>>,
+    pub open_blocker_count: Memo<usize>,
     pub projects: Signal<Vec<Project>>,
 }
 
 impl TaskListItemController {
     pub fn new(app_store: AppStore, task_id: i64) -> Self {
         let task = app_store.tasks.get_by_id(task_id);
+        let blockers = app_store.tasks.blockers(task_id);
+        let open_blocker_count = Memo::new(move |_| {
+            blockers
+                .get()
+                .iter()
+                .filter(|t| t.completed_at.is_none())
+                .count()
+        });
         let projects = Signal::derive(move || app_store.projects.get());
         Self {
             app_store,
             task_id,
             task,
+            open_blocker_count,
             projects,
         }
     }
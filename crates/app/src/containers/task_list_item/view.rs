@@ -13,11 +13,12 @@ use north_dto::Project;
 use north_stores::TaskModel;
 use north_ui::{DropdownItem, DropdownMenu, Icon, IconKind};
 
-use super::components::{ProjectPrefix, SomedayPrefix};
+use super::components::{PriorityMarker, ProjectPrefix, SomedayPrefix};
 
 #[component]
 pub fn TaskListItemView(
     task: Memo<Option<TaskModel>>,
+    open_blocker_count: Memo<usize>,
     projects: Signal<Vec<Project>>,
     #[prop(default = false)] show_review: bool,
     #[prop(default = true)] show_project: bool,
@@ -58,12 +59,19 @@ pub fn TaskListItemView(
             let project_id = t.project_id;
             let project_title = t.project_title.clone();
             let due_date = t.due_date;
+            let waiting_for = t.waiting_for.clone();
+            let follow_up_date = t.follow_up_date;
             let start_at = t.start_at;
             let reviewed_at = t.reviewed_at;
             let tags = t.tags.clone();
             let recurrence = t.recurrence.clone();
             let completed = t.completed_at.is_some();
             let someday = t.someday;
+            let priority = t.priority;
+            let estimate_minutes = t.estimate_minutes;
+            let spent_minutes = t.spent_minutes;
+            let tracking = t.tracking_since.is_some();
+            let blocked_count = if completed { 0 } else { open_blocker_count.get() };
 
             view! {
                 <div
@@ -117,6 +125,7 @@ pub fn TaskListItemView(
                         if let Some(ctx) = drag_ctx {
                             ctx.dragging_task_id.set(None);
                             ctx.drop_target.set(None);
+                            ctx.drop_section.set(None);
                         }
                     }
                     on:dragover=move |ev: web_sys::DragEvent| {
@@ -266,6 +275,9 @@ pub fn TaskListItemView(
 
                             view! {
                                 <span class="flex-1 pt-0.5 flex items-baseline flex-wrap">
+                                    {priority.filter(|_| !completed).map(|p| {
+                                        view! { <PriorityMarker priority=p /> }
+                                    })}
                                     {someday_prefix}
                                     {project_prefix}
                                     <RichTitle
@@ -377,6 +389,12 @@ pub fn TaskListItemView(
                         show_tags=!show_inline_tags
                         on_review=on_review
                         recurrence=recurrence
+                        blocked_count=blocked_count
+                        waiting_for=waiting_for
+                        follow_up_date=follow_up_date
+                        estimate_minutes=estimate_minutes
+                        spent_minutes=spent_minutes
+                        tracking=tracking
                         class="pl-6"
                     />
                 </div>
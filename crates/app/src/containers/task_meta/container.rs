@@ -14,6 +14,20 @@ pub fn TaskMeta(
     #[prop(default = false)] show_review: bool,
     #[prop(default = 0)] subtask_count: i64,
     #[prop(default = 0)] completed_subtask_count: i64,
+    /// Open tasks this one waits on.
+    #[prop(default = 0)]
+    blocked_count: usize,
+    /// Who the task is delegated to.
+    #[prop(default = None)]
+    waiting_for: Option<String>,
+    #[prop(default = None)] follow_up_date: Option<chrono::NaiveDate>,
+    #[prop(default = None)] estimate_minutes: Option<i32>,
+    /// Finished tracked time, subtasks included.
+    #[prop(default = 0)]
+    spent_minutes: i64,
+    /// Whether a timer is running on the task.
+    #[prop(default = false)]
+    tracking: bool,
     #[prop(optional)] on_toggle_subtasks: Option<Callback<()>>,
     #[prop(default = Callback::new(|_| {}))] on_review: Callback<()>,
     #[prop(default = true)] show_tags: bool,
@@ -26,6 +40,11 @@ pub fn TaskMeta(
         || (show_tags && !tags.is_empty())
         || show_review
         || subtask_count > 0
+        || blocked_count > 0
+        || waiting_for.is_some()
+        || estimate_minutes.is_some()
+        || spent_minutes > 0
+        || tracking
         || recurrence.is_some();
 
     has_meta.then(|| {
@@ -55,6 +74,45 @@ pub fn TaskMeta(
             None => (None, TaskMetaItemVariant::Info),
         };
 
+        // Past the follow-up date the task needs chasing.
+        let waiting_display = waiting_for.map(|who| match follow_up_date {
+            Some(d) => {
+                let variant = if d <= chrono::Utc::now().date_naive() {
+                    TaskMetaItemVariant::Danger
+                } else {
+                    TaskMetaItemVariant::Info
+                };
+                (format!("Waiting on {who}, follow up {d}"), variant)
+            }
+            None => (format!("Waiting on {who}"), TaskMetaItemVariant::Info),
+        });
+
+        // Tracked time turns red once it runs over the estimate.
+        let time_display = {
+            let spent = north_dto::format_duration(spent_minutes);
+            let text = match (estimate_minutes, spent_minutes > 0 || tracking) {
+                (Some(e), true) => Some(format!(
+                    "{spent} / {}",
+                    north_dto::format_duration(e.into())
+                )),
+                (Some(e), false) => Some(north_dto::format_duration(e.into())),
+                (None, true) => Some(spent),
+                (None, false) => None,
+            };
+            text.map(|text| {
+                let text = if tracking {
+                    format!("{text} \u{b7} tracking")
+                } else {
+                    text
+                };
+                let variant = match estimate_minutes {
+                    Some(e) if spent_minutes > i64::from(e) => TaskMetaItemVariant::Danger,
+                    _ => TaskMetaItemVariant::Info,
+                };
+                (text, variant)
+            })
+        };
+
         let reviewed_at_display = show_review.then(|| match reviewed_at {
             Some(d) => format!("Reviewed {d}"),
             None => "Never reviewed".to_string(),
@@ -68,6 +126,10 @@ pub fn TaskMeta(
                 start_at_variant=start_at_variant
                 subtask_count=subtask_count
                 completed_subtask_count=completed_subtask_count
+                blocked_count=blocked_count
+                waiting_display=waiting_display
+                time_display=time_display
+                tracking=tracking
                 on_toggle_subtasks=on_toggle_subtasks
                 due_date_display=due_date_display
                 due_date_variant=due_date_variant
@@ -12,6 +12,10 @@ pub fn TaskMetaView(
     #[prop(default = TaskMetaItemVariant::Info)] start_at_variant: TaskMetaItemVariant,
     #[prop(default = 0)] subtask_count: i64,
     #[prop(default = 0)] completed_subtask_count: i64,
+    #[prop(default = 0)] blocked_count: usize,
+    #[prop(default = None)] waiting_display: Option<(String, TaskMetaItemVariant)>,
+    #[prop(default = None)] time_display: Option<(String, TaskMetaItemVariant)>,
+    #[prop(default = false)] tracking: bool,
     #[prop(default = None)] on_toggle_subtasks: Option<Callback<()>>,
     #[prop(default = None)] due_date_display: Option<String>,
     #[prop(default = TaskMetaItemVariant::Info)] due_date_variant: TaskMetaItemVariant,
@@ -84,6 +88,37 @@ pub fn TaskMetaView(
                     </TaskMetaItem>
                 }
             })}
+            {(blocked_count > 0).then(|| {
+                view! {
+                    <TaskMetaItem
+                        icon=IconKind::Lock
+                        variant=TaskMetaItemVariant::Danger
+                    >
+                        {format!(
+                            "Blocked by {blocked_count} task{}",
+                            if blocked_count == 1 { "" } else { "s" },
+                        )}
+                    </TaskMetaItem>
+                }
+            })}
+            {waiting_display.map(|(display, variant)| {
+                view! {
+                    <TaskMetaItem icon=IconKind::Waiting variant=variant>
+                        {display}
+                    </TaskMetaItem>
+                }
+            })}
+            {time_display.map(|(display, variant)| {
+                view! {
+                    <TaskMetaItem
+                        icon=IconKind::Clock
+                        variant=variant
+                        class=if tracking { "text-accent" } else { "" }
+                    >
+                        {display}
+                    </TaskMetaItem>
+                }
+            })}
             {due_date_display.map(|display| {
                 view! {
                     <TaskMetaItem
@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+
+use super::controller::TaskRemindersController;
+use super::view::TaskRemindersView;
+
+#[component]
+pub fn TaskReminders(task_id: i64) -> impl IntoView {
+    let ctrl = TaskRemindersController::new(task_id);
+
+    view! {
+        <TaskRemindersView
+            reminders=ctrl.reminders
+            on_add=Callback::new(move |(anchor, offset)| ctrl.add(anchor, offset))
+            on_remove=Callback::new(move |id| ctrl.remove(id))
+        />
+    }
+}
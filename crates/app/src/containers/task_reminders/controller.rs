@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::{CreateReminder, Reminder, ReminderAnchor};
+use north_repositories::ReminderRepository;
+
+#[derive(Clone, Copy)]
+pub struct TaskRemindersController {
+    pub reminders: RwSignal<Vec<Reminder>>,
+    task_id: i64,
+}
+
+impl TaskRemindersController {
+    pub fn new(task_id: i64) -> Self {
+        let reminders = RwSignal::new(Vec::<Reminder>::new());
+
+        Effect::new(move |_| {
+            spawn_local(async move {
+                if let Ok(list) = ReminderRepository::list(task_id).await {
+                    reminders.set(list);
+                }
+            });
+        });
+
+        Self { reminders, task_id }
+    }
+
+    pub fn add(&self, anchor: ReminderAnchor, offset_minutes: i32) {
+        let reminders = self.reminders;
+        let task_id = self.task_id;
+        spawn_local(async move {
+            let input = CreateReminder {
+                anchor,
+                offset_minutes,
+            };
+            if let Ok(reminder) = ReminderRepository::create(task_id, input).await {
+                reminders.update(|list| list.push(reminder));
+            }
+        });
+    }
+
+    pub fn remove(&self, id: i64) {
+        let reminders = self.reminders;
+        reminders.update(|list| list.retain(|r| r.id != id));
+        spawn_local(async move {
+            let _ = ReminderRepository::delete(id).await;
+        });
+    }
+}
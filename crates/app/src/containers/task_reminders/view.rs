@@ -0,0 +1,77 @@
+use leptos::prelude::*;
+use north_dto::{describe_offset, Reminder, ReminderAnchor};
+use north_ui::{Icon, IconKind};
+
+/// Offsets offered in the "Add reminder" picker.
+const PRESETS: &[(ReminderAnchor, i32)] = &[
+    (ReminderAnchor::Start, 0),
+    (ReminderAnchor::Start, 15),
+    (ReminderAnchor::Start, 60),
+    (ReminderAnchor::Due, 0),
+    (ReminderAnchor::Due, 1440),
+];
+
+#[component]
+pub fn TaskRemindersView(
+    reminders: RwSignal<Vec<Reminder>>,
+    on_add: Callback<(ReminderAnchor, i32)>,
+    on_remove: Callback<i64>,
+) -> impl IntoView {
+    view! {
+        <div data-testid="task-reminders" class="space-y-1">
+            <For
+                each=move || reminders.get()
+                key=|r| r.id
+                let:reminder
+            >
+                {
+                    let id = reminder.id;
+                    view! {
+                        <div class="flex items-center justify-between gap-1 \
+                                    text-xs text-text-secondary">
+                            <span class="inline-flex items-center gap-1">
+                                <Icon kind=IconKind::Clock class="w-3 h-3"/>
+                                {reminder.describe()}
+                            </span>
+                            <button
+                                class="text-text-tertiary hover:text-danger \
+                                       transition-colors"
+                                on:click=move |_| on_remove.run(id)
+                                title="Remove reminder"
+                            >
+                                <Icon kind=IconKind::Close class="w-3 h-3"/>
+                            </button>
+                        </div>
+                    }
+                }
+            </For>
+            <select
+                data-testid="task-reminder-add"
+                class="w-full text-xs bg-transparent text-text-secondary \
+                       border-none focus:outline-none cursor-pointer"
+                prop:value=""
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Ok(idx) = value.parse::<usize>() {
+                        if let Some(&(anchor, offset)) = PRESETS.get(idx) {
+                            on_add.run((anchor, offset));
+                        }
+                    }
+                }
+            >
+                <option value="" selected=true>"+ Add reminder"</option>
+                {PRESETS
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &(anchor, offset))| {
+                        view! {
+                            <option value=idx.to_string()>
+                                {describe_offset(anchor, offset)}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
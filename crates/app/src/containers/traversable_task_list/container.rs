@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use north_dto::ProjectSection;
 use north_stores::use_app_store;
 
 use super::controller::TraversableTaskListController;
@@ -9,6 +10,14 @@ use crate::containers::task_list_item::ItemConfig;
 #[derive(Clone, Copy)]
 pub struct ExtraVisibleIds(pub RwSignal<Vec<i64>>);
 
+/// List sections to group the roots under, with their header actions.
+#[derive(Clone, Copy)]
+pub struct SectionConfig {
+    pub sections: Signal<Vec<ProjectSection>>,
+    pub on_rename: Callback<(i64, String)>,
+    pub on_delete: Callback<i64>,
+}
+
 #[derive(Clone, Copy)]
 pub struct TtlHandle(TraversableTaskListController);
 
@@ -39,6 +48,7 @@ pub fn TraversableTaskList(
     #[prop(optional)] cursor_task_id: Option<RwSignal<Option<i64>>>,
     #[prop(optional)] handle: Option<RwSignal<Option<TtlHandle>>>,
     #[prop(optional)] node_filter: Option<Signal<Callback<north_stores::TaskModel, bool>>>,
+    #[prop(optional)] sections: Option<SectionConfig>,
 ) -> impl IntoView {
     let app_store = use_app_store();
     if item_config.draggable {
@@ -63,6 +73,7 @@ pub fn TraversableTaskList(
         scoped,
         cursor_task_id,
         node_filter,
+        sections.filter(|_| !flat),
     );
 
     if let Some(handle) = handle {
@@ -1,9 +1,10 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos::wasm_bindgen::JsCast;
-use north_dto::CreateTask;
+use north_dto::{BulkTaskAction, CreateTask};
 use north_stores::{AppStore, ModalStore, StatusBarVariant, TaskModel, TaskStoreFilter};
 
+use super::container::SectionConfig;
 use super::tree::*;
 use crate::containers::task_list_item::ItemConfig;
 use crate::libs::{KeepCompletedVisible, KeepTaskVisible};
@@ -27,6 +28,12 @@ pub struct TraversableTaskListController {
     pub inline_mode: RwSignal<InlineMode>,
     pub create_input_value: RwSignal<String>,
     pub pending_delete: RwSignal<bool>,
+    /// Task whose postpone date is being picked.
+    pub pending_postpone: RwSignal<Option<i64>>,
+    /// Tasks picked for a bulk action, in the order they were selected.
+    pub selected_ids: RwSignal<Vec<i64>>,
+    /// Where a Shift range selection starts.
+    selection_anchor: RwSignal<Option<i64>>,
     pub show_keybindings_help: RwSignal<bool>,
     pub item_config: ItemConfig,
     app_store: AppStore,
@@ -39,6 +46,11 @@ pub struct TraversableTaskListController {
     keep_completed: Option<KeepCompletedVisible>,
     on_task_click: Option<Callback<i64>>,
     on_reorder: Callback<(i64, String, Option<Option<i64>>)>,
+    pub section_config: Option<SectionConfig>,
+}
+
+fn section_collapsed_key(id: i64) -> String {
+    format!("north:section-collapsed:{id}")
 }
 
 impl TraversableTaskListController {
@@ -58,8 +70,10 @@ impl TraversableTaskListController {
         scoped: bool,
         cursor_task_id: Option<RwSignal<Option<i64>>>,
         node_filter: Option<Signal<Callback<TaskModel, bool>>>,
+        section_config: Option<SectionConfig>,
     ) -> Self {
         let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let storage = app_store.browser_storage;
 
         let flat_nodes = Memo::new(move |_| {
             let filter = node_filter.map(|s| s.get());
@@ -68,6 +82,14 @@ impl TraversableTaskListController {
             let include = |t: &TaskModel| filter.as_ref().map(|f| f.run(t.clone())).unwrap_or(true);
             if flat {
                 flatten_flat(&roots, &tasks, &include)
+            } else if let Some(config) = section_config {
+                let sections: Vec<(i64, bool)> = config
+                    .sections
+                    .get()
+                    .iter()
+                    .map(|s| (s.id, storage.get_bool(&section_collapsed_key(s.id))))
+                    .collect();
+                flatten_sections(&roots, &tasks, &sections, &include)
             } else {
                 flatten_tree(&roots, &tasks, &include)
             }
@@ -94,6 +116,9 @@ impl TraversableTaskListController {
             inline_mode,
             create_input_value,
             pending_delete,
+            pending_postpone: RwSignal::new(None),
+            selected_ids: RwSignal::new(vec![]),
+            selection_anchor: RwSignal::new(None),
             show_keybindings_help,
             item_config,
             app_store,
@@ -106,6 +131,7 @@ impl TraversableTaskListController {
             keep_completed,
             on_task_click,
             on_reorder,
+            section_config,
         }
     }
 
@@ -161,6 +187,114 @@ impl TraversableTaskListController {
         }
     }
 
+    // ── Selection ──────────────────────────────────────────────
+
+    /// Selected tasks that are still in the list.
+    pub fn selection(&self) -> Vec<i64> {
+        let nodes = self.flat_nodes.get_untracked();
+        self.selected_ids
+            .get_untracked()
+            .into_iter()
+            .filter(|id| nodes.iter().any(|n| n.task_id == *id))
+            .collect()
+    }
+
+    pub fn has_selection(&self) -> bool {
+        !self.selection().is_empty()
+    }
+
+    pub fn toggle_selected(&self, task_id: i64) {
+        self.selected_ids.update(|ids| {
+            if let Some(pos) = ids.iter().position(|&id| id == task_id) {
+                ids.remove(pos);
+            } else {
+                ids.push(task_id);
+            }
+        });
+        self.selection_anchor.set(Some(task_id));
+    }
+
+    /// Selects every row between the anchor (or the cursor) and `task_id`.
+    pub fn select_range_to(&self, task_id: i64) {
+        let nodes = self.flat_nodes.get_untracked();
+        let anchor = self
+            .selection_anchor
+            .get_untracked()
+            .or_else(|| self.cursor_task_id.get_untracked())
+            .unwrap_or(task_id);
+        let position = |id: i64| nodes.iter().position(|n| n.task_id == id);
+        let (Some(from), Some(to)) = (position(anchor), position(task_id)) else {
+            return;
+        };
+        let (from, to) = (from.min(to), from.max(to));
+        self.selected_ids
+            .set(nodes[from..=to].iter().map(|n| n.task_id).collect());
+        self.selection_anchor.set(Some(anchor));
+    }
+
+    /// Shift+J / Shift+K: moves the cursor and grows the range with it.
+    fn extend_selection(&self, down: bool) {
+        if self.selection_anchor.get_untracked().is_none() {
+            self.selection_anchor
+                .set(self.cursor_task_id.get_untracked());
+        }
+        if down {
+            self.move_down();
+        } else {
+            self.move_up();
+        }
+        if let Some(id) = self.cursor_task_id.get_untracked() {
+            self.select_range_to(id);
+        }
+    }
+
+    pub fn clear_selection(&self) {
+        self.selected_ids.set(vec![]);
+        self.selection_anchor.set(None);
+    }
+
+    /// Runs `action` on the selection; deleting also clears it.
+    pub fn bulk(&self, action: BulkTaskAction) {
+        let ids = self.selection();
+        if ids.is_empty() {
+            return;
+        }
+        if action == BulkTaskAction::Delete {
+            self.clear_selection();
+        }
+        let label = bulk_label(&action);
+        self.app_store.tasks.bulk_update(ids, action, label);
+    }
+
+    /// Completes the selection, or reopens it when it is all completed.
+    pub fn bulk_toggle_complete(&self) {
+        let all_completed = self.selection_all(|t| t.completed_at.is_some());
+        if all_completed {
+            self.bulk(BulkTaskAction::Uncomplete);
+        } else {
+            if let Some(kc) = self.keep_completed {
+                for id in self.selection() {
+                    kc.keep(id);
+                }
+            }
+            self.bulk(BulkTaskAction::Complete);
+        }
+    }
+
+    /// Moves the selection to someday, or back when it is all there.
+    pub fn bulk_toggle_someday(&self) {
+        let all_someday = self.selection_all(|t| t.someday);
+        self.bulk(BulkTaskAction::SetSomeday(!all_someday));
+    }
+
+    fn selection_all(&self, f: impl Fn(&TaskModel) -> bool) -> bool {
+        let ids = self.selection();
+        self.all_tasks()
+            .iter()
+            .filter(|t| ids.contains(&t.id))
+            .all(f)
+    }
+
     // ── Inline edit ────────────────────────────────────────────
 
     pub fn start_edit(&self) {
@@ -212,6 +346,16 @@ impl TraversableTaskListController {
         self.inline_mode.set(InlineMode::CreateTop);
     }
 
+    /// Opens the create input at the start of a section, expanding it.
+    pub fn start_create_in_section(&self, section_id: i64) {
+        if self.is_section_collapsed(section_id) {
+            self.toggle_section(section_id);
+        }
+        self.create_input_value.set(String::new());
+        self.inline_mode
+            .set(InlineMode::CreateInSection { section_id });
+    }
+
     pub fn start_create_inside(&self) {
         if let Some(anchor_id) = self.cursor_task_id.get_untracked() {
             let nodes = self.flat_nodes.get_untracked();
@@ -240,7 +384,10 @@ impl TraversableTaskListController {
                 self.create_task_anchored(anchor_task_id, placement, parent_id, depth);
             }
             InlineMode::CreateTop => {
-                self.create_task_top();
+                self.create_task_top(None);
+            }
+            InlineMode::CreateInSection { section_id } => {
+                self.create_task_top(Some(section_id));
             }
             _ => {}
         }
@@ -274,12 +421,19 @@ impl TraversableTaskListController {
             })
             .or_else(|| self.default_project_id.and_then(|s| s.get_untracked()));
 
+        // Top-level tasks join the anchor's section.
+        let section_id = parent_id
+            .is_none()
+            .then(|| section_of(&nodes, anchor_task_id))
+            .flatten();
+
         let input = CreateTask {
             title,
             body,
             parent_id,
             project_id,
             sort_key: Some(sort_key),
+            section_id,
             ..Default::default()
         };
 
@@ -311,7 +465,9 @@ impl TraversableTaskListController {
         });
     }
 
-    fn create_task_top(&self) {
+    /// Creates a top-level task at the start of the list, or of the given
+    /// section.
+    fn create_task_top(&self, section_id: Option<i64>) {
         let (title, body) = Self::parse_title_body(&self.create_input_value.get_untracked());
         if title.is_empty() {
             self.close_inline();
@@ -322,10 +478,10 @@ impl TraversableTaskListController {
         let all_tasks = self.app_store.tasks.filtered(TaskStoreFilter::default());
         let tasks = all_tasks.get_untracked();
 
-        // Sort key: before the first root task.
+        // Sort key: before the first root task of the section.
         let first_root_key = nodes
             .iter()
-            .find(|n| n.parent_id.is_none())
+            .find(|n| n.parent_id.is_none() && n.section_id == section_id)
             .and_then(|n| task_sort_key(&tasks, n.task_id));
         let sort_key = north_dto::sort_key_between(None, first_root_key.as_deref());
 
@@ -337,6 +493,7 @@ impl TraversableTaskListController {
             parent_id: None,
             project_id,
             sort_key: Some(sort_key),
+            section_id,
             ..Default::default()
         };
 
@@ -423,9 +580,42 @@ impl TraversableTaskListController {
         self.app_store.tasks.toggle_complete(task_id, is_completed);
     }
 
+    // ── Postpone to a picked date ────────────────────────────
+
+    pub fn request_postpone(&self) {
+        if let Some(task_id) = self.cursor_task_id.get_untracked() {
+            self.pending_postpone.set(Some(task_id));
+        }
+    }
+
+    /// The date the postpone picker starts on.
+    pub fn postpone_default_date(&self) -> Option<chrono::NaiveDate> {
+        let task_id = self.pending_postpone.get_untracked()?;
+        let timezone = self.app_store.settings.timezone().get_untracked();
+        self.app_store
+            .tasks
+            .default_postpone_date(task_id, &timezone)
+    }
+
+    pub fn confirm_postpone(&self, date: chrono::NaiveDate) {
+        if let Some(task_id) = self.pending_postpone.get_untracked() {
+            self.pending_postpone.set(None);
+            self.app_store.tasks.postpone(task_id, date);
+        }
+    }
+
+    pub fn cancel_postpone(&self) {
+        self.pending_postpone.set(None);
+    }
+
     // ── Delete with confirmation ─────────────────────────────
 
+    /// Asks to delete the selection, or the task under the cursor.
     pub fn request_delete(&self) {
+        if self.has_selection() {
+            self.request_bulk_delete();
+            return;
+        }
         let Some(task_id) = self.cursor_task_id.get_untracked() else {
             return;
         };
@@ -447,7 +637,27 @@ impl TraversableTaskListController {
         );
     }
 
+    fn request_bulk_delete(&self) {
+        let count = self.selection().len();
+        self.pending_delete.set(true);
+        self.app_store.status_bar.show_message(
+            format!("Delete {count} selected tasks?  Enter to confirm \u{00b7} Esc to cancel"),
+            StatusBarVariant::Danger,
+        );
+    }
+
     pub fn confirm_delete(&self) {
+        if self.has_selection() {
+            self.pending_delete.set(false);
+            self.app_store.status_bar.hide_message();
+            if let Some(cursor) = self.cursor_task_id.get_untracked() {
+                let nodes = self.flat_nodes.get_untracked();
+                self.cursor_task_id
+                    .set(cursor_after_removing(&nodes, &self.selection(), cursor));
+            }
+            self.bulk(BulkTaskAction::Delete);
+            return;
+        }
         let Some(task_id) = self.cursor_task_id.get_untracked() else {
             return;
         };
@@ -487,6 +697,114 @@ impl TraversableTaskListController {
         self.on_reorder.run((task_id, sort_key, parent_id));
     }
 
+    /// Reorders a task that ends up under `section_id`, moving it into that
+    /// section when it lands at the top level of another one.
+    pub fn place_task(
+        &self,
+        task_id: i64,
+        sort_key: String,
+        parent_id: Option<Option<i64>>,
+        section_id: Option<i64>,
+    ) {
+        let task = self.app_store.tasks.get_by_id(task_id).get_untracked();
+        let top_level = match parent_id {
+            Some(pid) => pid.is_none(),
+            None => task.as_ref().is_some_and(|t| t.parent_id.is_none()),
+        };
+        let current_section = task.and_then(|t| t.section_id);
+        if self.section_config.is_some() && top_level && current_section != section_id {
+            self.app_store
+                .tasks
+                .move_to_section(task_id, section_id, sort_key);
+        } else {
+            self.reorder_task(task_id, sort_key, parent_id);
+        }
+    }
+
+    // ── Sections ───────────────────────────────────────────────
+
+    pub fn is_section_collapsed(&self, section_id: i64) -> bool {
+        self.app_store
+            .browser_storage
+            .get_bool(&section_collapsed_key(section_id))
+    }
+
+    pub fn toggle_section(&self, section_id: i64) {
+        self.app_store
+            .browser_storage
+            .toggle_bool(&section_collapsed_key(section_id));
+    }
+
+    /// Moves a dragged task to the end of a section's open tasks.
+    pub fn drop_into_section(&self, task_id: i64, section_id: i64) {
+        let last_key = self
+            .all_tasks()
+            .iter()
+            .filter(|t| {
+                t.section_id == Some(section_id)
+                    && t.parent_id.is_none()
+                    && t.completed_at.is_none()
+                    && t.id != task_id
+            })
+            .map(|t| t.sort_key.clone())
+            .max();
+        let sort_key = north_dto::sort_key_after(last_key.as_deref());
+        self.place_task(task_id, sort_key, Some(None), Some(section_id));
+    }
+
+    /// Visible section groups in order; `None` is the unsectioned group.
+    fn section_groups(&self) -> Vec<Option<i64>> {
+        let Some(config) = self.section_config else {
+            return vec![];
+        };
+        std::iter::once(None)
+            .chain(
+                config
+                    .sections
+                    .get_untracked()
+                    .iter()
+                    .filter(|s| !self.is_section_collapsed(s.id))
+                    .map(|s| Some(s.id)),
+            )
+            .collect()
+    }
+
+    /// Shift+Up on the first task of a section / Shift+Down on the last:
+    /// moves a top-level task to the end of the previous or the start of
+    /// the next section.
+    fn reorder_across_sections(&self, task_id: i64, down: bool) {
+        let nodes = self.flat_nodes.get_untracked();
+        let Some(node) = nodes.iter().find(|n| n.task_id == task_id) else {
+            return;
+        };
+        if node.parent_id.is_some() {
+            return;
+        }
+        let groups = self.section_groups();
+        let Some(pos) = groups.iter().position(|g| *g == node.section_id) else {
+            return;
+        };
+        let target = if down {
+            groups.get(pos + 1)
+        } else {
+            pos.checked_sub(1).and_then(|p| groups.get(p))
+        };
+        let Some(&target) = target else {
+            return;
+        };
+        let tasks = self.all_tasks();
+        let mut roots = nodes
+            .iter()
+            .filter(|n| n.parent_id.is_none() && n.section_id == target && !n.is_completed)
+            .filter_map(|n| task_sort_key(&tasks, n.task_id));
+        let sort_key = if down {
+            north_dto::sort_key_between(None, roots.next().as_deref())
+        } else {
+            north_dto::sort_key_after(roots.next_back().as_deref())
+        };
+        self.place_task(task_id, sort_key, None, target);
+    }
+
     // ── Task reorder (Shift+Arrow) ──────────────────────────────
 
     fn all_tasks(&self) -> Vec<TaskModel> {
@@ -498,16 +816,12 @@ impl TraversableTaskListController {
 
     fn siblings(&self, task_id: i64) -> Vec<i64> {
         let nodes = self.flat_nodes.get_untracked();
-        let parent_id = nodes
-            .iter()
-            .find(|n| n.task_id == task_id)
-            .map(|n| n.parent_id);
-        let Some(parent_id) = parent_id else {
+        let Some(node) = nodes.iter().find(|n| n.task_id == task_id) else {
             return vec![];
         };
         nodes
             .iter()
-            .filter(|n| n.parent_id == parent_id)
+            .filter(|n| is_sibling(n, node))
             .map(|n| n.task_id)
             .collect()
     }
@@ -522,6 +836,7 @@ impl TraversableTaskListController {
             return;
         };
         if pos == 0 {
+            self.reorder_across_sections(task_id, false);
             return;
         }
 
@@ -545,6 +860,7 @@ impl TraversableTaskListController {
             return;
         };
         if pos + 1 >= siblings.len() {
+            self.reorder_across_sections(task_id, true);
             return;
         }
 
@@ -586,6 +902,7 @@ impl TraversableTaskListController {
             return;
         };
 
+        let section_id = node.and_then(|n| n.section_id);
         let parent_node = nodes.iter().find(|n| n.task_id == parent_id);
         let grandparent_id = parent_node.and_then(|n| n.parent_id);
         let tasks = self.all_tasks();
@@ -593,7 +910,7 @@ impl TraversableTaskListController {
         // Place after parent among grandparent's children.
         let parent_siblings: Vec<i64> = nodes
             .iter()
-            .filter(|n| n.parent_id == grandparent_id)
+            .filter(|n| n.parent_id == grandparent_id && n.section_id == section_id)
             .map(|n| n.task_id)
             .collect();
         let parent_pos = parent_siblings
@@ -606,7 +923,7 @@ impl TraversableTaskListController {
             .get(parent_pos + 1)
             .and_then(|&id| task_sort_key(&tasks, id));
         let new_key = north_dto::sort_key_between(above_key.as_deref(), below_key.as_deref());
-        self.reorder_task(task_id, new_key, Some(grandparent_id));
+        self.place_task(task_id, new_key, Some(grandparent_id), section_id);
     }
 
     // ── Keyboard handler ───────────────────────────────────────
@@ -638,6 +955,15 @@ impl TraversableTaskListController {
             return;
         }
 
+        // The picker's input handles its own keys while it has focus.
+        if self.pending_postpone.get_untracked().is_some() {
+            if ev.key() == "Escape" {
+                ev.prevent_default();
+                self.cancel_postpone();
+            }
+            return;
+        }
+
         let mode = self.inline_mode.get_untracked();
 
         match mode {
@@ -645,7 +971,9 @@ impl TraversableTaskListController {
             InlineMode::Edit { .. } => {
                 // Edit input handles its own keys; nothing here.
             }
-            InlineMode::Create { .. } | InlineMode::CreateTop => {
+            InlineMode::Create { .. }
+            | InlineMode::CreateTop
+            | InlineMode::CreateInSection { .. } => {
                 // Create input handles its own keys; nothing here.
             }
         }
@@ -653,6 +981,8 @@ impl TraversableTaskListController {
 
     fn handle_keydown_normal(&self, ev: &web_sys::KeyboardEvent) {
         let key = ev.key();
+        // Leaves Ctrl+P, Cmd+N and the like to the browser.
+        let plain = !(ev.ctrl_key() || ev.meta_key() || ev.alt_key());
 
         match key.as_str() {
             "ArrowUp" => {
@@ -712,6 +1042,36 @@ impl TraversableTaskListController {
                 ev.prevent_default();
                 self.open_detail();
             }
+            "x" | "X" if plain => {
+                ev.prevent_default();
+                if let Some(task_id) = self.cursor_task_id.get_untracked() {
+                    self.toggle_selected(task_id);
+                }
+            }
+            "J" | "K" if plain && ev.shift_key() => {
+                ev.prevent_default();
+                self.extend_selection(key == "J");
+            }
+            "r" | "R" if self.has_selection() && self.item_config.show_review => {
+                ev.prevent_default();
+                self.bulk(BulkTaskAction::MarkReviewed);
+            }
+            "s" | "S" if self.has_selection() => {
+                ev.prevent_default();
+                self.bulk_toggle_someday();
+            }
+            "0" | "1" | "2" | "3" | "4" if plain && self.has_selection() => {
+                ev.prevent_default();
+                self.bulk(BulkTaskAction::SetPriority(priority_for_key(&key)));
+            }
+            " " if self.has_selection() => {
+                ev.prevent_default();
+                self.bulk_toggle_complete();
+            }
+            "Delete" if self.has_selection() => {
+                ev.prevent_default();
+                self.request_bulk_delete();
+            }
             "r" | "R" => {
                 if self.item_config.show_review {
                     ev.prevent_default();
@@ -726,6 +1086,30 @@ impl TraversableTaskListController {
                     self.app_store.tasks.toggle_someday(task_id);
                 }
             }
+            "n" | "N" if plain => {
+                ev.prevent_default();
+                if let Some(task_id) = self.cursor_task_id.get_untracked() {
+                    self.app_store.tasks.skip_occurrence(task_id);
+                }
+            }
+            "p" | "P" if plain => {
+                ev.prevent_default();
+                self.request_postpone();
+            }
+            "t" | "T" if plain => {
+                ev.prevent_default();
+                if let Some(task_id) = self.cursor_task_id.get_untracked() {
+                    self.app_store.tasks.toggle_timer(task_id);
+                }
+            }
+            "0" | "1" | "2" | "3" | "4" if plain => {
+                ev.prevent_default();
+                if let Some(task_id) = self.cursor_task_id.get_untracked() {
+                    self.app_store
+                        .tasks
+                        .set_priority(task_id, priority_for_key(&key));
+                }
+            }
             " " => {
                 ev.prevent_default();
                 self.toggle_complete();
@@ -735,7 +1119,11 @@ impl TraversableTaskListController {
                 self.request_delete();
             }
             "Escape" => {
-                self.cursor_task_id.set(None);
+                if self.has_selection() {
+                    self.clear_selection();
+                } else {
+                    self.cursor_task_id.set(None);
+                }
             }
             "?" => {
                 ev.prevent_default();
@@ -745,3 +1133,27 @@ impl TraversableTaskListController {
         }
     }
 }
+
+/// `1`–`4` set that priority; `0` clears it.
+fn priority_for_key(key: &str) -> Option<i16> {
+    key.parse().ok().filter(|&p| p != 0)
+}
+
+/// Status bar and undo history label for a bulk action.
+fn bulk_label(action: &BulkTaskAction) -> &'static str {
+    match action {
+        BulkTaskAction::Complete => "Tasks completed",
+        BulkTaskAction::Uncomplete => "Tasks reopened",
+        BulkTaskAction::SetProject(_) => "Tasks moved",
+        BulkTaskAction::AddTags(_) => "Tags added",
+        BulkTaskAction::RemoveTags(_) => "Tags removed",
+        BulkTaskAction::SetStartAt(_) => "Start dates changed",
+        BulkTaskAction::SetDueDate(_) => "Due dates changed",
+        BulkTaskAction::MarkReviewed => "Tasks reviewed",
+        BulkTaskAction::SetSomeday(true) => "Tasks moved to someday",
+        BulkTaskAction::SetSomeday(false) => "Tasks moved out of someday",
+        BulkTaskAction::SetPriority(Some(_)) => "Priorities changed",
+        BulkTaskAction::SetPriority(None) => "Priorities cleared",
+        BulkTaskAction::Delete => "Tasks deleted",
+    }
+}
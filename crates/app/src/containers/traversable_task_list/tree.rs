@@ -7,6 +7,8 @@ pub struct FlatNode {
     pub depth: u8,
     pub is_completed: bool,
     pub is_someday: bool,
+    /// List section the row is shown under; subtasks inherit their root's.
+    pub section_id: Option<i64>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -28,6 +30,10 @@ pub enum InlineMode {
         depth: u8,
     },
     CreateTop,
+    /// New top-level task at the start of a list section.
+    CreateInSection {
+        section_id: i64,
+    },
 }
 
 /// Build a flat traversal list from a set of root task IDs and all tasks.
@@ -45,35 +51,83 @@ pub fn flatten_tree(
         .filter_map(|id| all_tasks.iter().find(|t| t.id == *id))
         .filter(|t| include(t))
         .collect();
+    flatten_group(roots, all_tasks, 0, None, include, &mut nodes);
+
+    nodes
+}
+
+/// Like [`flatten_tree`], but groups the roots by list section: tasks
+/// outside any section first, then each section in the given order. Roots
+/// of collapsed sections are left out; so are the sections of tasks whose
+/// section isn't listed, which count as outside any section.
+pub fn flatten_sections(
+    root_ids: &[i64],
+    all_tasks: &[TaskModel],
+    sections: &[(i64, bool)],
+    include: &dyn Fn(&TaskModel) -> bool,
+) -> Vec<FlatNode> {
+    let mut nodes = Vec::new();
+
+    let roots: Vec<&TaskModel> = root_ids
+        .iter()
+        .filter_map(|id| all_tasks.iter().find(|t| t.id == *id))
+        .filter(|t| include(t))
+        .collect();
+    let section_of = |t: &TaskModel| {
+        t.section_id
+            .filter(|sid| sections.iter().any(|(id, _)| id == sid))
+    };
+
+    let unsectioned = roots
+        .iter()
+        .copied()
+        .filter(|t| section_of(t).is_none())
+        .collect();
+    flatten_group(unsectioned, all_tasks, 0, None, include, &mut nodes);
+
+    for &(section_id, collapsed) in sections {
+        if collapsed {
+            continue;
+        }
+        let group = roots
+            .iter()
+            .copied()
+            .filter(|t| section_of(t) == Some(section_id))
+            .collect();
+        flatten_group(group, all_tasks, 0, Some(section_id), include, &mut nodes);
+    }
+
+    nodes
+}
 
-    let (completed, non_completed): (Vec<&TaskModel>, Vec<&TaskModel>) =
-        roots.into_iter().partition(|t| t.completed_at.is_some());
+/// Flattens sibling tasks: active ones sorted by sort_key, then someday,
+/// then completed, each followed by its subtree.
+fn flatten_group(
+    tasks: Vec<&TaskModel>,
+    all_tasks: &[TaskModel],
+    depth: u8,
+    section_id: Option<i64>,
+    include: &dyn Fn(&TaskModel) -> bool,
+    nodes: &mut Vec<FlatNode>,
+) {
+    let (mut completed, non_completed): (Vec<&TaskModel>, Vec<&TaskModel>) =
+        tasks.into_iter().partition(|t| t.completed_at.is_some());
     let (mut someday, mut active): (Vec<&TaskModel>, Vec<&TaskModel>) =
         non_completed.into_iter().partition(|t| t.someday);
 
     active.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
-    for task in &active {
-        flatten_subtree(task, all_tasks, 0, include, &mut nodes);
-    }
-
     someday.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
-    for task in &someday {
-        flatten_subtree(task, all_tasks, 0, include, &mut nodes);
-    }
-
-    let mut completed: Vec<&TaskModel> = completed;
     completed.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
-    for task in &completed {
-        flatten_subtree(task, all_tasks, 0, include, &mut nodes);
+    for task in active.into_iter().chain(someday).chain(completed) {
+        flatten_subtree(task, all_tasks, depth, section_id, include, nodes);
     }
-
-    nodes
 }
 
 fn flatten_subtree(
     task: &TaskModel,
     all_tasks: &[TaskModel],
     depth: u8,
+    section_id: Option<i64>,
     include: &dyn Fn(&TaskModel) -> bool,
     nodes: &mut Vec<FlatNode>,
 ) {
@@ -83,6 +137,7 @@ fn flatten_subtree(
         depth,
         is_completed: task.completed_at.is_some(),
         is_someday: task.someday,
+        section_id,
     });
 
     let children: Vec<&TaskModel> = all_tasks
@@ -90,27 +145,7 @@ fn flatten_subtree(
         .filter(|t| t.parent_id == Some(task.id))
         .filter(|t| include(t))
         .collect();
-
-    let (completed, non_completed): (Vec<&TaskModel>, Vec<&TaskModel>) =
-        children.into_iter().partition(|t| t.completed_at.is_some());
-    let (mut someday, mut active): (Vec<&TaskModel>, Vec<&TaskModel>) =
-        non_completed.into_iter().partition(|t| t.someday);
-
-    active.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
-    for child in &active {
-        flatten_subtree(child, all_tasks, depth + 1, include, nodes);
-    }
-
-    someday.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
-    for child in &someday {
-        flatten_subtree(child, all_tasks, depth + 1, include, nodes);
-    }
-
-    let mut completed: Vec<&TaskModel> = completed;
-    completed.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
-    for child in &completed {
-        flatten_subtree(child, all_tasks, depth + 1, include, nodes);
-    }
+    flatten_group(children, all_tasks, depth + 1, section_id, include, nodes);
 }
 
 /// Build a flat list preserving input order — no child expansion.
@@ -130,28 +165,32 @@ pub fn flatten_flat(
             depth: 0,
             is_completed: t.completed_at.is_some(),
             is_someday: t.someday,
+            section_id: None,
         })
         .collect()
 }
 
 // ── Navigation helpers ─────────────────────────────────────────
 
-/// Previous sibling (same parent_id) in flat order.
+/// Whether two rows share a parent and a list section.
+pub fn is_sibling(a: &FlatNode, b: &FlatNode) -> bool {
+    a.parent_id == b.parent_id && a.section_id == b.section_id
+}
+
+/// Previous sibling (same parent_id and section) in flat order.
 pub fn prev_sibling(flat: &[FlatNode], task_id: i64) -> Option<i64> {
     let idx = flat.iter().position(|n| n.task_id == task_id)?;
-    let parent_id = flat[idx].parent_id;
     (0..idx)
         .rev()
-        .find(|&i| flat[i].parent_id == parent_id)
+        .find(|&i| is_sibling(&flat[i], &flat[idx]))
         .map(|i| flat[i].task_id)
 }
 
-/// Next sibling (same parent_id) in flat order.
+/// Next sibling (same parent_id and section) in flat order.
 pub fn next_sibling(flat: &[FlatNode], task_id: i64) -> Option<i64> {
     let idx = flat.iter().position(|n| n.task_id == task_id)?;
-    let parent_id = flat[idx].parent_id;
     ((idx + 1)..flat.len())
-        .find(|&i| flat[i].parent_id == parent_id)
+        .find(|&i| is_sibling(&flat[i], &flat[idx]))
         .map(|i| flat[i].task_id)
 }
 
@@ -169,11 +208,36 @@ pub fn parent_of(flat: &[FlatNode], task_id: i64) -> Option<i64> {
         .and_then(|n| n.parent_id)
 }
 
+/// Where the cursor goes once `removed` and their subtasks are gone:
+/// it stays if its row survives, else the next surviving row, else the
+/// previous one.
+pub fn cursor_after_removing(flat: &[FlatNode], removed: &[i64], cursor: i64) -> Option<i64> {
+    let mut removed_depth: Option<u8> = None;
+    let survives: Vec<bool> = flat
+        .iter()
+        .map(|n| {
+            if removed_depth.is_some_and(|d| n.depth > d) {
+                return false;
+            }
+            removed_depth = removed.contains(&n.task_id).then_some(n.depth);
+            removed_depth.is_none()
+        })
+        .collect();
+    let idx = flat.iter().position(|n| n.task_id == cursor)?;
+    if survives[idx] {
+        return Some(cursor);
+    }
+    ((idx + 1)..flat.len())
+        .chain((0..idx).rev())
+        .find(|&i| survives[i])
+        .map(|i| flat[i].task_id)
+}
+
 // ── Sort key computation ───────────────────────────────────────
 
 /// Compute a sort_key for a new task being inserted relative to an anchor.
 /// `parent_id` is the intended parent for the new task (may differ from
-/// anchor's parent when indented/outdented).
+/// anchor's parent when indented/outdented); it lands in the anchor's section.
 pub fn compute_sort_key(
     flat: &[FlatNode],
     all_tasks: &[TaskModel],
@@ -181,9 +245,10 @@ pub fn compute_sort_key(
     placement: Placement,
     parent_id: Option<i64>,
 ) -> String {
+    let section_id = section_of(flat, anchor_task_id);
     let siblings: Vec<i64> = flat
         .iter()
-        .filter(|n| n.parent_id == parent_id)
+        .filter(|n| n.parent_id == parent_id && n.section_id == section_id)
         .map(|n| n.task_id)
         .collect();
 
@@ -216,6 +281,13 @@ pub fn compute_sort_key(
     }
 }
 
+/// List section the given row is shown under.
+pub fn section_of(flat: &[FlatNode], task_id: i64) -> Option<i64> {
+    flat.iter()
+        .find(|n| n.task_id == task_id)
+        .and_then(|n| n.section_id)
+}
+
 pub fn task_sort_key(all_tasks: &[TaskModel], task_id: i64) -> Option<String> {
     all_tasks
         .iter()
@@ -307,10 +379,20 @@ mod tests {
             recurrence: None,
             is_url_fetching: None,
             someday: false,
+            series_id: None,
+            column_id: None,
+            waiting_for: None,
+            follow_up_date: None,
+            priority: None,
+            estimate_minutes: None,
+            section_id: None,
             project_title: None,
             tags: vec![],
             subtask_count: 0,
             completed_subtask_count: 0,
+            blocked_by: vec![],
+            spent_minutes: 0,
+            tracking_since: None,
         }
     }
 
@@ -355,6 +437,58 @@ mod tests {
         assert_eq!(flat.len(), 2);
     }
 
+    fn make_sectioned_task(id: i64, section_id: Option<i64>, sort_key: &str) -> TaskModel {
+        let mut t = make_task(id, None, sort_key);
+        t.section_id = section_id;
+        t
+    }
+
+    #[test]
+    fn flatten_sections_groups_roots() {
+        let tasks = vec![
+            make_sectioned_task(1, Some(10), "a"),
+            make_sectioned_task(2, None, "b"),
+            make_sectioned_task(3, Some(20), "c"),
+            make_sectioned_task(4, Some(10), "d"),
+            make_task(5, Some(1), "a"),
+            // Section that isn't listed (e.g. deleted) counts as none.
+            make_sectioned_task(6, Some(99), "e"),
+        ];
+        let roots = vec![1, 2, 3, 4, 6];
+        let flat = flatten_sections(&roots, &tasks, &[(20, false), (10, false)], &|_| true);
+
+        let order: Vec<(i64, Option<i64>)> =
+            flat.iter().map(|n| (n.task_id, n.section_id)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (2, None),
+                (6, None),
+                (3, Some(20)),
+                (1, Some(10)),
+                (5, Some(10)),
+                (4, Some(10)),
+            ]
+        );
+
+        // Siblings don't cross section boundaries.
+        assert_eq!(next_sibling(&flat, 6), None);
+        assert_eq!(prev_sibling(&flat, 1), None);
+        assert_eq!(next_sibling(&flat, 1), Some(4));
+    }
+
+    #[test]
+    fn flatten_sections_skips_collapsed() {
+        let tasks = vec![
+            make_sectioned_task(1, Some(10), "a"),
+            make_sectioned_task(2, None, "b"),
+            make_task(3, Some(1), "a"),
+        ];
+        let flat = flatten_sections(&[1, 2], &tasks, &[(10, true)], &|_| true);
+        let ids: Vec<i64> = flat.iter().map(|n| n.task_id).collect();
+        assert_eq!(ids, vec![2]);
+    }
+
     #[test]
     fn navigation_siblings() {
         let flat = vec![
@@ -364,6 +498,7 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 3,
@@ -371,6 +506,7 @@ mod tests {
                 depth: 1,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 4,
@@ -378,6 +514,7 @@ mod tests {
                 depth: 1,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 2,
@@ -385,6 +522,7 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
         ];
 
@@ -406,6 +544,7 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 3,
@@ -413,6 +552,7 @@ mod tests {
                 depth: 1,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
         ];
 
@@ -431,6 +571,7 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 2,
@@ -438,6 +579,7 @@ mod tests {
                 depth: 1,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
         ];
         assert!(is_descendant_of(&flat, 1, 2));
@@ -453,6 +595,7 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 2,
@@ -460,6 +603,7 @@ mod tests {
                 depth: 1,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 3,
@@ -467,6 +611,7 @@ mod tests {
                 depth: 2,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
         ];
         assert!(is_descendant_of(&flat, 1, 3));
@@ -483,6 +628,7 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
             FlatNode {
                 task_id: 2,
@@ -490,9 +636,26 @@ mod tests {
                 depth: 0,
                 is_completed: false,
                 is_someday: false,
+                section_id: None,
             },
         ];
         assert!(!is_descendant_of(&flat, 1, 2));
         assert!(!is_descendant_of(&flat, 2, 1));
     }
+
+    #[test]
+    fn cursor_after_removing_skips_subtasks() {
+        let tasks = vec![
+            make_task(1, None, "a"),
+            make_task(2, Some(1), "a"),
+            make_task(3, None, "b"),
+            make_task(4, None, "c"),
+        ];
+        let flat = flatten_tree(&[1, 3, 4], &tasks, &|_| true);
+
+        assert_eq!(cursor_after_removing(&flat, &[1], 4), Some(4));
+        assert_eq!(cursor_after_removing(&flat, &[1], 2), Some(3));
+        assert_eq!(cursor_after_removing(&flat, &[3, 4], 4), Some(2));
+        assert_eq!(cursor_after_removing(&flat, &[1, 3, 4], 1), None);
+    }
 }
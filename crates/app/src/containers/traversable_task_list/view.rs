@@ -1,15 +1,23 @@
 use leptos::prelude::*;
 use north_stores::{TaskModel, TaskStoreFilter};
-use north_ui::Spinner;
+use north_ui::{Icon, IconKind, Spinner};
 use wasm_bindgen::JsCast;
 
 use super::controller::TraversableTaskListController;
 use super::tree::*;
 use crate::atoms::{Text, TextColor, TextTag, TextVariant};
 use crate::components::drag_drop::{DragDropContext, DropZone};
+use crate::containers::bulk_action_bar::BulkActionBar;
 use crate::containers::smart_textarea::SmartTextarea;
 use crate::containers::task_list_item::{ItemConfig, TaskListItem};
 
+/// A row of the list: a section header or a task.
+#[derive(Clone, PartialEq)]
+enum ListRow {
+    Section(i64),
+    Task(FlatNode),
+}
+
 #[component]
 pub fn TraversableTaskListView(
     ctrl: TraversableTaskListController,
@@ -22,6 +30,37 @@ pub fn TraversableTaskListView(
     let cursor_task_id = ctrl.cursor_task_id;
     let inline_mode = ctrl.inline_mode;
     let create_input_value = ctrl.create_input_value;
+    let selected_ids = ctrl.selected_ids;
+    let selected_count = Signal::derive(move || {
+        let nodes = flat_nodes.get();
+        selected_ids
+            .get()
+            .iter()
+            .filter(|id| nodes.iter().any(|n| n.task_id == **id))
+            .count()
+    });
+    // Headers go before their section's tasks; tasks outside any section
+    // come first.
+    let section_config = ctrl.section_config;
+    let rows = Memo::new(move |_| {
+        let nodes = flat_nodes.get();
+        let Some(config) = section_config else {
+            return nodes.into_iter().map(ListRow::Task).collect::<Vec<_>>();
+        };
+        let in_section = |section_id: Option<i64>| {
+            nodes
+                .iter()
+                .filter(move |n| n.section_id == section_id)
+                .cloned()
+                .map(ListRow::Task)
+        };
+        let mut rows: Vec<ListRow> = in_section(None).collect();
+        for section in config.sections.get() {
+            rows.push(ListRow::Section(section.id));
+            rows.extend(in_section(Some(section.id)));
+        }
+        rows
+    });
     let container_ref = NodeRef::<leptos::html::Div>::new();
     let drag_ctx = use_context::<DragDropContext>();
     let app_store = north_stores::use_app_store();
@@ -94,6 +133,21 @@ pub fn TraversableTaskListView(
                 }
             }
         >
+            <Show when=move || { selected_count.get() > 0 }>
+                <BulkActionBar
+                    count=selected_count
+                    on_action=Callback::new(move |action| ctrl.bulk(action))
+                    on_toggle_complete=Callback::new(move |()| ctrl.bulk_toggle_complete())
+                    on_toggle_someday=Callback::new(move |()| ctrl.bulk_toggle_someday())
+                    on_delete=Callback::new(move |()| ctrl.request_delete())
+                    on_clear=Callback::new(move |()| ctrl.clear_selection())
+                />
+            </Show>
+
+            <Show when=move || ctrl.pending_postpone.get().is_some()>
+                <PostponePrompt ctrl=ctrl/>
+            </Show>
+
             // Loading spinner
             <Show when=move || !is_loaded.get()>
                 <Spinner/>
@@ -113,7 +167,7 @@ pub fn TraversableTaskListView(
             // Empty state
             <Show when=move || {
                 is_loaded.get()
-                    && flat_nodes.get().is_empty()
+                    && rows.get().is_empty()
                     && !matches!(inline_mode.get(), InlineMode::CreateTop)
             }>
                 <div data-testid="empty-task-list">
@@ -132,7 +186,7 @@ pub fn TraversableTaskListView(
             <div
                 data-testid="task-list"
                 style:display=move || {
-                    if is_loaded.get() && !flat_nodes.get().is_empty() {
+                    if is_loaded.get() && !rows.get().is_empty() {
                         ""
                     } else {
                         "none"
@@ -140,9 +194,21 @@ pub fn TraversableTaskListView(
                 }
             >
             <For
-                each=move || flat_nodes.get()
-                key=|node| node.task_id
-                children=move |node| {
+                each=move || rows.get()
+                key=|row| match row {
+                    ListRow::Section(id) => (true, *id),
+                    ListRow::Task(node) => (false, node.task_id),
+                }
+                children=move |row| {
+                    let node = match row {
+                        ListRow::Section(section_id) => {
+                            return view! {
+                                <SectionHeader section_id=section_id ctrl=ctrl/>
+                            }
+                            .into_any();
+                        }
+                        ListRow::Task(node) => node,
+                    };
                     let task_id = node.task_id;
                     let initial_depth = node.depth;
 
@@ -159,6 +225,10 @@ pub fn TraversableTaskListView(
                         cursor_task_id.get() == Some(task_id)
                     });
 
+                    let is_checked = Memo::new(move |_| {
+                        selected_ids.get().contains(&task_id)
+                    });
+
                     let is_editing = Memo::new(move |_| {
                         matches!(
                             inline_mode.get(),
@@ -210,6 +280,7 @@ pub fn TraversableTaskListView(
                                 data-testid="task-row"
                                 data-task-id=task_id
                                 data-focused=move || is_selected.get().to_string()
+                                data-selected=move || is_checked.get().to_string()
                                 style=move || {
                                     format!(
                                         "padding-left: {}rem",
@@ -217,13 +288,25 @@ pub fn TraversableTaskListView(
                                     )
                                 }
                                 class=move || {
-                                    if is_selected.get() {
-                                        "trash-polka-focus"
-                                    } else {
-                                        ""
+                                    match (is_selected.get(), is_checked.get()) {
+                                        (true, true) => "trash-polka-focus task-row-selected",
+                                        (true, false) => "trash-polka-focus",
+                                        (false, true) => "task-row-selected",
+                                        (false, false) => "",
                                     }
                                 }
-                                on:click=move |_| {
+                                on:click=move |ev: web_sys::MouseEvent| {
+                                    if ev.shift_key() {
+                                        ctrl.select_range_to(task_id);
+                                        cursor_task_id.set(Some(task_id));
+                                        return;
+                                    }
+                                    if ev.ctrl_key() || ev.meta_key() {
+                                        ctrl.toggle_selected(task_id);
+                                        cursor_task_id.set(Some(task_id));
+                                        return;
+                                    }
+                                    ctrl.clear_selection();
                                     cursor_task_id.set(Some(task_id));
                                     ctrl.open_detail_for(task_id);
                                 }
@@ -251,6 +334,7 @@ pub fn TraversableTaskListView(
                             />
                         </Show>
                     }
+                    .into_any()
                 }
             />
             </div>
@@ -298,10 +382,12 @@ fn handle_drop(
     match zone {
         DropZone::Above => {
             let parent_id = target_node.parent_id;
+            let section_id = target_node.section_id;
             let siblings: Vec<i64> = flat_nodes
                 .iter()
                 .filter(|n| {
                     n.parent_id == parent_id
+                        && n.section_id == section_id
                         && !n.is_completed
                         && n.is_someday == dragging_is_someday
                 })
@@ -313,14 +399,16 @@ fn handle_drop(
                 .and_then(|p| task_sort_key(all_tasks, siblings[p - 1]));
             let below_key = task_sort_key(all_tasks, target_id);
             let new_key = north_dto::sort_key_between(above_key.as_deref(), below_key.as_deref());
-            ctrl.reorder_task(dragging_id, new_key, Some(parent_id));
+            ctrl.place_task(dragging_id, new_key, Some(parent_id), section_id);
         }
         DropZone::Below => {
             let parent_id = target_node.parent_id;
+            let section_id = target_node.section_id;
             let siblings: Vec<i64> = flat_nodes
                 .iter()
                 .filter(|n| {
                     n.parent_id == parent_id
+                        && n.section_id == section_id
                         && !n.is_completed
                         && n.is_someday == dragging_is_someday
                 })
@@ -334,7 +422,7 @@ fn handle_drop(
                     .and_then(|&id| task_sort_key(all_tasks, id))
             });
             let new_key = north_dto::sort_key_between(above_key.as_deref(), below_key.as_deref());
-            ctrl.reorder_task(dragging_id, new_key, Some(parent_id));
+            ctrl.place_task(dragging_id, new_key, Some(parent_id), section_id);
         }
         DropZone::Nest => {
             // Become last child of target.
@@ -356,6 +444,139 @@ fn handle_drop(
     ctx.drop_target.set(None);
 }
 
+/// Collapsible, renameable heading of a list section. Dropping a task on
+/// it moves the task to the end of the section.
+#[component]
+fn SectionHeader(section_id: i64, ctrl: TraversableTaskListController) -> impl IntoView {
+    let app_store = north_stores::use_app_store();
+    let drag_ctx = use_context::<DragDropContext>();
+    let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+    let config = ctrl.section_config;
+    let title = Memo::new(move |_| {
+        config
+            .and_then(|c| {
+                c.sections
+                    .get()
+                    .into_iter()
+                    .find(|s| s.id == section_id)
+                    .map(|s| s.title)
+            })
+            .unwrap_or_default()
+    });
+    let collapsed = Signal::derive(move || ctrl.is_section_collapsed(section_id));
+    let open_count = Memo::new(move |_| {
+        all_tasks
+            .get()
+            .iter()
+            .filter(|t| {
+                t.section_id == Some(section_id)
+                    && t.parent_id.is_none()
+                    && t.completed_at.is_none()
+            })
+            .count()
+    });
+    let is_creating =
+        Memo::new(move |_| ctrl.inline_mode.get() == InlineMode::CreateInSection { section_id });
+
+    view! {
+        <div
+            data-testid="section-header"
+            data-section-id=section_id
+            class=move || {
+                let mut classes = "group flex items-center gap-1.5 mt-4 mb-1 pr-4 pb-1 \
+                                   border-b border-border transition-colors"
+                    .to_string();
+                if drag_ctx.is_some_and(|ctx| ctx.drop_section.get() == Some(section_id)) {
+                    classes.push_str(" bg-accent/20");
+                }
+                classes
+            }
+            on:dragover=move |ev: web_sys::DragEvent| {
+                let Some(ctx) = drag_ctx else { return };
+                if ctx.dragging_task_id.get_untracked().is_none() {
+                    return;
+                }
+                ev.prevent_default();
+                ctx.drop_target.set(None);
+                ctx.drop_section.set(Some(section_id));
+            }
+            on:dragleave=move |_: web_sys::DragEvent| {
+                if let Some(ctx) = drag_ctx {
+                    if ctx.drop_section.get_untracked() == Some(section_id) {
+                        ctx.drop_section.set(None);
+                    }
+                }
+            }
+            on:drop=move |ev: web_sys::DragEvent| {
+                ev.prevent_default();
+                ev.stop_propagation();
+                let Some(ctx) = drag_ctx else { return };
+                if let Some(task_id) = ctx.dragging_task_id.get_untracked() {
+                    ctrl.drop_into_section(task_id, section_id);
+                }
+                ctx.dragging_task_id.set(None);
+                ctx.drop_target.set(None);
+                ctx.drop_section.set(None);
+            }
+        >
+            <button
+                title=move || if collapsed.get() { "Expand section" } else { "Collapse section" }
+                on:click=move |_| ctrl.toggle_section(section_id)
+                class="p-0.5 rounded text-text-tertiary hover:text-text-secondary \
+                       transition-colors cursor-pointer"
+            >
+                {move || {
+                    let kind = if collapsed.get() {
+                        IconKind::ChevronRight
+                    } else {
+                        IconKind::ChevronDown
+                    };
+                    view! { <Icon kind=kind class="w-3.5 h-3.5"/> }
+                }}
+            </button>
+            <input
+                type="text"
+                prop:value=move || title.get()
+                on:change=move |ev| {
+                    if let Some(c) = config {
+                        c.on_rename.run((section_id, event_target_value(&ev)));
+                    }
+                }
+                class="flex-1 min-w-0 bg-transparent text-sm font-semibold \
+                       text-text-primary focus:outline-none"
+            />
+            <span class="text-xs text-text-tertiary">{move || open_count.get()}</span>
+            <button
+                title="Add task to section"
+                on:click=move |_| ctrl.start_create_in_section(section_id)
+                class="opacity-0 group-hover:opacity-100 p-0.5 text-text-tertiary \
+                       hover:text-text-primary transition-opacity cursor-pointer"
+            >
+                <Icon kind=IconKind::Plus class="w-3.5 h-3.5"/>
+            </button>
+            <button
+                title="Delete section"
+                on:click=move |_| {
+                    if let Some(c) = config {
+                        c.on_delete.run(section_id);
+                    }
+                }
+                class="opacity-0 group-hover:opacity-100 p-0.5 text-text-tertiary \
+                       hover:text-text-primary transition-opacity cursor-pointer"
+            >
+                <Icon kind=IconKind::Close class="w-3.5 h-3.5"/>
+            </button>
+        </div>
+        <Show when=move || is_creating.get()>
+            <InlineCreateInput
+                depth=Memo::new(|_| 0u8)
+                value=ctrl.create_input_value
+                ctrl=ctrl
+            />
+        </Show>
+    }
+}
+
 /// Borderless inline textarea for editing an existing task title + body.
 /// First line = title, subsequent lines = body (same format as inline create).
 /// Ctrl+Enter inserts a newline; plain Enter saves.
@@ -439,6 +660,62 @@ fn InlineEditInput(
     }
 }
 
+/// Date prompt opened by `P`: Enter postpones the task to the picked
+/// date, Escape cancels.
+#[component]
+fn PostponePrompt(ctrl: TraversableTaskListController) -> impl IntoView {
+    let input_ref = NodeRef::<leptos::html::Input>::new();
+    let initial = ctrl
+        .postpone_default_date()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_default();
+
+    Effect::new(move || {
+        if let Some(el) = input_ref.get() {
+            let _ = el.focus();
+        }
+    });
+
+    let on_keydown = move |ev: web_sys::KeyboardEvent| match ev.key().as_str() {
+        "Enter" => {
+            ev.prevent_default();
+            let value = event_target_value(&ev);
+            if let Ok(date) = chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+                ctrl.confirm_postpone(date);
+            }
+        }
+        "Escape" => {
+            ev.prevent_default();
+            ctrl.cancel_postpone();
+        }
+        _ => {}
+    };
+
+    view! {
+        <div
+            data-testid="postpone-prompt"
+            class="sticky top-0 z-10 flex items-center gap-2 mb-2 px-3 py-2 \
+                   rounded-md bg-bg-secondary border border-border"
+        >
+            <Text variant=TextVariant::LabelMd color=TextColor::Primary tag=TextTag::Span>
+                "Postpone to"
+            </Text>
+            <input
+                type="date"
+                data-testid="postpone-date-input"
+                node_ref=input_ref
+                value=initial
+                class="bg-bg-input border border-border rounded px-2 py-1 text-xs \
+                       text-text-primary focus:outline-none focus:border-accent"
+                on:keydown=on_keydown
+            />
+            <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::Span>
+                "Enter to confirm \u{00b7} Esc to cancel"
+            </Text>
+        </div>
+    }
+}
+
 /// Borderless inline textarea for creating a new task.
 /// Supports multiline: first line becomes title, remaining lines become body.
 /// Ctrl+Enter inserts a newline; plain Enter submits.
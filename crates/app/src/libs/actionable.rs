@@ -1,11 +1,14 @@
+use chrono::NaiveDate;
 use north_stores::TaskModel;
 
 /// Compute whether a task is actionable based on its parent's sequential_limit.
 /// Root tasks are always actionable. Subtasks are actionable only if they are
 /// within the first N incomplete siblings (sorted by sort_key) where N is the
-/// parent's sequential_limit. A limit of 0 means unlimited.
-pub fn is_actionable(task: &TaskModel, all_tasks: &[TaskModel]) -> bool {
-    if task.someday {
+/// parent's sequential_limit. A limit of 0 means unlimited. Tasks waiting on
+/// an open blocker, or on someone before their follow-up date, are never
+/// actionable. `today` is the user's local date.
+pub fn is_actionable(task: &TaskModel, all_tasks: &[TaskModel], today: NaiveDate) -> bool {
+    if task.someday || is_blocked(task, all_tasks) || is_waiting(task, today) {
         return false;
     }
     let Some(parent_id) = task.parent_id else {
@@ -27,3 +30,21 @@ pub fn is_actionable(task: &TaskModel, all_tasks: &[TaskModel]) -> bool {
         .count();
     (siblings_before as i16) < limit
 }
+
+/// Whether any of the task's blockers is still open. A blocker that isn't
+/// loaded counts as open.
+pub fn is_blocked(task: &TaskModel, all_tasks: &[TaskModel]) -> bool {
+    task.blocked_by.iter().any(|&blocker_id| {
+        all_tasks
+            .iter()
+            .find(|t| t.id == blocker_id)
+            .is_none_or(|t| t.completed_at.is_none())
+    })
+}
+
+/// Whether the task is delegated and not yet due for a follow-up. Once the
+/// follow-up date arrives, in the user's timezone, the task needs chasing
+/// and counts as actionable.
+pub fn is_waiting(task: &TaskModel, today: NaiveDate) -> bool {
+    task.waiting_for.is_some() && task.follow_up_date.is_none_or(|d| d > today)
+}
@@ -0,0 +1,18 @@
+use north_stores::TaskModel;
+
+/// Minutes of planned work left in a task: its own estimate, or else the sum
+/// over its open subtasks, so a breakdown into estimated subtasks counts
+/// without estimating the parent twice.
+pub fn open_estimate_minutes(task: &TaskModel, all_tasks: &[TaskModel]) -> i64 {
+    if task.completed_at.is_some() {
+        return 0;
+    }
+    if let Some(minutes) = task.estimate_minutes {
+        return minutes.into();
+    }
+    all_tasks
+        .iter()
+        .filter(|t| t.parent_id == Some(task.id))
+        .map(|t| open_estimate_minutes(t, all_tasks))
+        .sum()
+}
@@ -1,11 +1,17 @@
 mod actionable;
+mod estimate;
 mod keep_completed_visible;
 mod keep_task_visible;
+mod project_progress;
 mod reactive_recurrence_rule;
 mod textarea;
+mod web_push;
 
-pub use actionable::is_actionable;
+pub use actionable::{is_actionable, is_blocked, is_waiting};
+pub use estimate::open_estimate_minutes;
 pub use keep_completed_visible::KeepCompletedVisible;
 pub use keep_task_visible::KeepTaskVisible;
-pub use reactive_recurrence_rule::ReactiveRecurrenceRule;
-pub use textarea::insert_newline_at_cursor;
+pub use project_progress::with_live_progress;
+pub use reactive_recurrence_rule::{ReactiveRecurrenceRule, WORKDAY_CODE};
+pub use textarea::{files_from_transfer, insert_at_utf16, insert_newline_at_cursor};
+pub use web_push::subscribe_to_push;
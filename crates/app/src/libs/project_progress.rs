@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use north_dto::{Project, ProjectStatus};
+use north_stores::TaskModel;
+
+use super::is_actionable;
+
+/// Recomputes the project's task counts, progress and stalled flag from the
+/// task store, so they follow task edits without refetching projects.
+pub fn with_live_progress(
+    mut project: Project,
+    all_tasks: &[TaskModel],
+    today: NaiveDate,
+) -> Project {
+    let tasks: Vec<&TaskModel> = all_tasks
+        .iter()
+        .filter(|t| t.project_id == Some(project.id))
+        .collect();
+    let total = tasks.len() as i64;
+    let completed = tasks.iter().filter(|t| t.completed_at.is_some()).count() as i64;
+    let has_next_action = tasks
+        .iter()
+        .any(|t| t.completed_at.is_none() && is_actionable(t, all_tasks, today));
+    project.task_count = total;
+    project.completed_task_count = completed;
+    project.progress = Project::progress_of(completed, total);
+    project.stalled = project.status == ProjectStatus::Active && !has_next_action;
+    project
+}
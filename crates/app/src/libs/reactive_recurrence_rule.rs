@@ -1,10 +1,17 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
 use leptos::prelude::*;
 use north_dto::{Frequency, RecurrenceRule, RecurrenceType, Weekday};
 
+/// `<select>` value standing for "any weekday" (MO–FR + BYSETPOS).
+pub const WORKDAY_CODE: &str = "WEEKDAY";
+
 #[derive(Clone, Copy)]
 pub struct ReactiveRecurrenceRule {
     pub rule: RwSignal<RecurrenceRule>,
     pub recurrence_type: RwSignal<RecurrenceType>,
+    exdates: RwSignal<BTreeSet<NaiveDate>>,
     pub timezone: Signal<String>,
     is_custom: RwSignal<bool>,
 }
@@ -13,6 +20,7 @@ impl ReactiveRecurrenceRule {
     pub fn from_str(
         existing_type: Option<RecurrenceType>,
         existing_rule: Option<String>,
+        existing_exdates: Vec<NaiveDate>,
         timezone: Signal<String>,
     ) -> Self {
         let parsed = existing_rule
@@ -27,20 +35,26 @@ impl ReactiveRecurrenceRule {
         Self {
             rule,
             recurrence_type,
+            exdates: RwSignal::new(existing_exdates.into_iter().collect()),
             timezone,
             is_custom,
         }
     }
 
-    pub fn to_result(&self) -> (Option<RecurrenceType>, Option<String>) {
+    pub fn to_result(&self) -> (Option<RecurrenceType>, Option<String>, Vec<NaiveDate>) {
         let rt = self.recurrence_type.get_untracked();
         let rule = self.rule.get_untracked();
-        (Some(rt), Some(rule.to_rrule_string()))
+        let exdates = self.exdates.get_untracked().into_iter().collect();
+        (Some(rt), Some(rule.to_rrule_string()), exdates)
     }
 
     pub fn summary(&self) -> Signal<String> {
         let rule = self.rule;
-        Signal::derive(move || rule.get().summarize())
+        let exdates = self.exdates;
+        Signal::derive(move || {
+            rule.get()
+                .summarize_with_exceptions(exdates.with(|d| d.len()))
+        })
     }
 
     pub fn freq(&self) -> Signal<Frequency> {
@@ -83,6 +97,72 @@ impl ReactiveRecurrenceRule {
         })
     }
 
+    pub fn uses_weekday_position(&self) -> Signal<bool> {
+        let rule = self.rule;
+        Signal::derive(move || rule.get().has_weekday_position())
+    }
+
+    pub fn position_str(&self) -> Signal<String> {
+        let rule = self.rule;
+        Signal::derive(move || {
+            let r = rule.get();
+            r.by_set_pos
+                .or(r.by_day_ordinal)
+                .map(|n| n.to_string())
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn position_day_str(&self) -> Signal<String> {
+        let rule = self.rule;
+        Signal::derive(move || {
+            let r = rule.get();
+            if r.by_set_pos.is_some() && r.by_day.len() > 1 {
+                WORKDAY_CODE.to_string()
+            } else {
+                r.by_day
+                    .iter()
+                    .next()
+                    .map(|d| d.code().to_string())
+                    .unwrap_or_default()
+            }
+        })
+    }
+
+    pub fn end_mode(&self) -> Signal<&'static str> {
+        let rule = self.rule;
+        Signal::derive(move || {
+            let r = rule.get();
+            if r.until.is_some() {
+                "until"
+            } else if r.count.is_some() {
+                "count"
+            } else {
+                "never"
+            }
+        })
+    }
+
+    pub fn until_str(&self) -> Signal<String> {
+        let rule = self.rule;
+        Signal::derive(move || {
+            rule.get()
+                .until
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn count_str(&self) -> Signal<String> {
+        let rule = self.rule;
+        Signal::derive(move || rule.get().count.map(|c| c.to_string()).unwrap_or_default())
+    }
+
+    pub fn exdates(&self) -> Signal<Vec<NaiveDate>> {
+        let exdates = self.exdates;
+        Signal::derive(move || exdates.get().into_iter().collect())
+    }
+
     pub fn is_custom(&self) -> Signal<bool> {
         let is_custom = self.is_custom;
         Signal::derive(move || is_custom.get())
@@ -119,6 +199,98 @@ impl ReactiveRecurrenceRule {
         self.rule.update(|r| r.by_month = m);
     }
 
+    /// Switch a monthly/yearly rule between "day N of the month" and
+    /// "Nth weekday of the month" (defaults to the first Monday).
+    pub fn set_weekday_position(&self, enabled: bool) {
+        self.rule.update(|r| {
+            r.by_day.clear();
+            r.by_set_pos = None;
+            if enabled {
+                r.by_month_day = None;
+                r.by_day.insert(Weekday::Mo);
+                r.by_day_ordinal = Some(1);
+            } else {
+                r.by_day_ordinal = None;
+            }
+        });
+    }
+
+    pub fn set_position(&self, val: &str) {
+        let Ok(pos) = val.parse::<i32>() else {
+            return;
+        };
+        self.rule.update(|r| {
+            if r.by_set_pos.is_some() {
+                r.by_set_pos = Some(pos);
+            } else {
+                r.by_day_ordinal = Some(pos);
+            }
+        });
+    }
+
+    pub fn set_position_day(&self, code: &str) {
+        let code = code.to_string();
+        self.rule.update(|r| {
+            let pos = r.by_set_pos.or(r.by_day_ordinal).unwrap_or(1);
+            if code == WORKDAY_CODE {
+                r.by_day = Weekday::WORKDAYS.into_iter().collect();
+                r.by_set_pos = Some(pos);
+                r.by_day_ordinal = None;
+            } else if let Some(day) = Weekday::from_code(&code) {
+                r.by_day.clear();
+                r.by_day.insert(day);
+                r.by_day_ordinal = Some(pos);
+                r.by_set_pos = None;
+            }
+        });
+    }
+
+    pub fn set_end_mode(&self, mode: &str) {
+        let today = chrono::Utc::now().date_naive();
+        self.rule.update(|r| match mode {
+            "until" => {
+                r.count = None;
+                r.until = Some(r.until.unwrap_or(today + chrono::Duration::days(30)));
+            }
+            "count" => {
+                r.until = None;
+                r.count = Some(r.count.unwrap_or(10));
+            }
+            _ => {
+                r.count = None;
+                r.until = None;
+            }
+        });
+    }
+
+    pub fn set_until(&self, val: &str) {
+        if let Ok(d) = NaiveDate::parse_from_str(val, "%Y-%m-%d") {
+            self.rule.update(|r| r.until = Some(d));
+        }
+    }
+
+    pub fn set_count(&self, val: &str) {
+        if let Ok(c) = val.parse::<u32>() {
+            if c > 0 {
+                self.rule.update(|r| r.count = Some(c));
+            }
+        }
+    }
+
+    pub fn add_exdate(&self, val: &str) {
+        if let Ok(d) = NaiveDate::parse_from_str(val, "%Y-%m-%d") {
+            self.exdates.update(|dates| {
+                dates.insert(d);
+            });
+        }
+    }
+
+    pub fn remove_exdate(&self, date: NaiveDate) {
+        self.exdates.update(|dates| {
+            dates.remove(&date);
+        });
+    }
+
     pub fn select_preset(&self, preset: &str) {
         match preset {
             "daily" => {
@@ -30,3 +30,42 @@ pub fn insert_newline_at_cursor(ta: &web_sys::HtmlTextAreaElement) {
             .map(|t| t.dispatch_event(&event));
     }
 }
+
+/// Files carried by a paste or drop, in order.
+pub fn files_from_transfer(dt: &web_sys::DataTransfer) -> Vec<web_sys::File> {
+    let Some(list) = dt.files() else {
+        return vec![];
+    };
+    (0..list.length()).filter_map(|i| list.get(i)).collect()
+}
+
+/// Inserts `insert` at `at`, a textarea selection offset (UTF-16 code
+/// units), clamped to the end of `text`.
+pub fn insert_at_utf16(text: &str, at: usize, insert: &str) -> String {
+    let mut units = 0;
+    let byte = text
+        .char_indices()
+        .find(|(_, c)| {
+            let found = units >= at;
+            units += c.len_utf16();
+            found
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    format!("{}{insert}{}", &text[..byte], &text[byte..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_at_utf16() {
+        assert_eq!(insert_at_utf16("ab", 1, "X"), "aXb");
+        assert_eq!(insert_at_utf16("ab", 9, "X"), "abX");
+        assert_eq!(insert_at_utf16("", 0, "X"), "X");
+        // "é" is one UTF-16 unit but two bytes; "😀" is two units.
+        assert_eq!(insert_at_utf16("é😀z", 1, "X"), "éX😀z");
+        assert_eq!(insert_at_utf16("é😀z", 3, "X"), "é😀Xz");
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "hydrate")]
+mod js {
+    use leptos::wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen(inline_js = "
+        function toBytes(base64url) {
+            const pad = '='.repeat((4 - (base64url.length % 4)) % 4);
+            const raw = atob((base64url + pad).replace(/-/g, '+').replace(/_/g, '/'));
+            return Uint8Array.from(raw, (c) => c.charCodeAt(0));
+        }
+        export async function enable_push(publicKey) {
+            if (!('serviceWorker' in navigator) || !('PushManager' in window)) return;
+            if ((await Notification.requestPermission()) !== 'granted') return;
+            await navigator.serviceWorker.register('/sw.js');
+            const reg = await navigator.serviceWorker.ready;
+            const sub = await reg.pushManager.subscribe({
+                userVisibleOnly: true,
+                applicationServerKey: toBytes(publicKey),
+            });
+            const json = sub.toJSON();
+            await fetch('/api/push/subscriptions', {
+                method: 'POST',
+                credentials: 'same-origin',
+                headers: { 'Content-Type': 'application/json' },
+                body: JSON.stringify({
+                    endpoint: json.endpoint,
+                    p256dh: json.keys.p256dh,
+                    auth: json.keys.auth,
+                }),
+            });
+        }
+    ")]
+    extern "C" {
+        pub fn enable_push(public_key: &str);
+    }
+}
+
+/// Asks for notification permission, registers the service worker and
+/// stores this browser's push subscription on the server.
+pub fn subscribe_to_push(public_key: &str) {
+    #[cfg(feature = "hydrate")]
+    js::enable_push(public_key);
+    #[cfg(not(feature = "hydrate"))]
+    let _ = public_key;
+}
@@ -12,9 +12,11 @@ pub fn ArchivePage() -> impl IntoView {
     view! {
         <ArchiveView
             archived_projects=ctrl.archived_projects
+            archived_areas=ctrl.archived_areas
             is_loaded=ctrl.is_loaded
             on_unarchive=Callback::new(move |id| ctrl.unarchive(id))
             on_delete=Callback::new(move |id| ctrl.delete(id))
+            on_unarchive_area=Callback::new(move |id| ctrl.unarchive_area(id))
         />
     }
 }
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use north_dto::{Project, ProjectFilter, ProjectStatus, UpdateProject};
+use north_dto::{Area, Project, ProjectFilter, ProjectStatus, UpdateArea, UpdateProject};
 use north_repositories::ProjectRepository;
 use north_stores::AppStore;
 
@@ -8,8 +8,10 @@ use north_stores::AppStore;
 pub struct ArchiveController {
     app_store: AppStore,
     pub archived_projects: Memo<Vec<Project>>,
+    pub archived_areas: Memo<Vec<Area>>,
     pub is_loaded: Signal<bool>,
     projects: RwSignal<Vec<Project>>,
+    loaded: RwSignal<bool>,
 }
 
 impl ArchiveController {
@@ -17,27 +19,58 @@ impl ArchiveController {
         let projects = RwSignal::new(Vec::<Project>::new());
         let loaded = RwSignal::new(false);
 
-        Effect::new(move |_| {
-            spawn_local(async move {
-                let filter = ProjectFilter {
-                    status: Some(ProjectStatus::Archived),
-                };
-                if let Ok(list) = ProjectRepository::list(filter).await {
-                    projects.set(list);
-                }
-                loaded.set(true);
-            });
-        });
-
         let archived_projects = Memo::new(move |_| projects.get());
+        let archived_areas = Memo::new(move |_| {
+            app_store
+                .projects
+                .areas()
+                .into_iter()
+                .filter(|a| a.status == ProjectStatus::Archived)
+                .collect()
+        });
         let is_loaded = Signal::derive(move || loaded.get());
 
-        Self {
+        let ctrl = Self {
             app_store,
             archived_projects,
+            archived_areas,
             is_loaded,
             projects,
-        }
+            loaded,
+        };
+        Effect::new(move |_| ctrl.reload());
+        ctrl
+    }
+
+    fn reload(&self) {
+        let projects = self.projects;
+        let loaded = self.loaded;
+        spawn_local(async move {
+            let filter = ProjectFilter {
+                status: Some(ProjectStatus::Archived),
+                ..Default::default()
+            };
+            if let Ok(list) = ProjectRepository::list(filter).await {
+                projects.set(list);
+            }
+            loaded.set(true);
+        });
+    }
+
+    /// Restores an area; its projects leave the archive with it unless they
+    /// were archived on their own.
+    pub fn unarchive_area(&self, id: i64) {
+        let ctrl = *self;
+        spawn_local(async move {
+            let input = UpdateArea {
+                status: Some(ProjectStatus::Active),
+                ..Default::default()
+            };
+            if ProjectRepository::update_area(id, input).await.is_ok() {
+                ctrl.app_store.projects.refetch();
+                ctrl.reload();
+            }
+        });
     }
 
     pub fn unarchive(&self, id: i64) {
@@ -1,19 +1,55 @@
 use leptos::prelude::*;
-use north_dto::Project;
+use north_dto::{Area, Project};
 
 use crate::atoms::{Text, TextColor, TextTag, TextVariant};
 
 #[component]
 pub fn ArchiveView(
     archived_projects: Memo<Vec<Project>>,
+    archived_areas: Memo<Vec<Area>>,
     is_loaded: Signal<bool>,
     on_unarchive: Callback<i64>,
     on_delete: Callback<i64>,
+    on_unarchive_area: Callback<i64>,
 ) -> impl IntoView {
     view! {
         <div class="space-y-4">
             <Text variant=TextVariant::HeadingLg>"Archive"</Text>
 
+            <Show when=move || !archived_areas.get().is_empty()>
+                <div class="space-y-1">
+                    <Text variant=TextVariant::LabelMd color=TextColor::Secondary tag=TextTag::H2>
+                        "Areas"
+                    </Text>
+                    <For each=move || archived_areas.get() key=|a| a.id let:area>
+                        {
+                            let area_id = area.id;
+                            view! {
+                                <div
+                                    data-testid="archive-area"
+                                    class="flex items-center justify-between \
+                                            px-3 py-2 rounded-md bg-bg-secondary">
+                                    <span class="text-sm text-text-primary">
+                                        {area.title.clone()}
+                                    </span>
+                                    <button
+                                        data-testid="archive-unarchive-area-btn"
+                                        class="text-xs px-2 py-1 rounded \
+                                               bg-bg-tertiary \
+                                               text-text-secondary \
+                                               hover:text-text-primary \
+                                               transition-colors"
+                                        on:click=move |_| on_unarchive_area.run(area_id)
+                                    >
+                                        "Unarchive"
+                                    </button>
+                                </div>
+                            }
+                        }
+                    </For>
+                </div>
+            </Show>
+
             <Show
                 when=move || is_loaded.get()
                 fallback=|| {
@@ -19,10 +19,42 @@ pub fn FilterHelpPage() -> impl IntoView {
                     <tbody class="text-text-primary">
                         <FieldRow field="title" desc="Task title"/>
                         <FieldRow field="body" desc="Task body/description"/>
+                        <FieldRow
+                            field="comment (or comments)"
+                            desc="Text of any comment, including on subtasks"
+                        />
+                        <FieldRow
+                            field="blocked (or blocked_by)"
+                            desc="true if waiting on an open task, or that task's title"
+                        />
+                        <FieldRow
+                            field="blocks"
+                            desc="true if an open task waits on it, or that task's title"
+                        />
+                        <FieldRow
+                            field="waiting (or waiting_for)"
+                            desc="true if delegated, or who it's waiting on"
+                        />
+                        <FieldRow
+                            field="priority"
+                            desc="1 (highest) to 4, or null; sorts unprioritized last"
+                        />
+                        <FieldRow
+                            field="estimate"
+                            desc="Estimate in minutes or as '1h30m', or null"
+                        />
+                        <FieldRow
+                            field="spent"
+                            desc="Tracked time including subtasks, in minutes or as '2h'"
+                        />
                         <FieldRow
                             field="project"
                             desc="Project name (resolves by title)"
                         />
+                        <FieldRow
+                            field="area"
+                            desc="Area of the task's project (resolves by title)"
+                        />
                         <FieldRow
                             field="tags (or tag)"
                             desc="Tag name"
@@ -148,6 +180,22 @@ pub fn FilterHelpPage() -> impl IntoView {
                         label="Untagged tasks"
                         query="tags is null AND status = 'ACTIVE'"
                     />
+                    <Example
+                        label="Ready to start, across projects"
+                        query="status = 'ACTIVE' AND blocked = false"
+                    />
+                    <Example
+                        label="Everything waiting on Sam"
+                        query="waiting = 'Sam' AND status = 'ACTIVE'"
+                    />
+                    <Example
+                        label="Urgent work first"
+                        query="status = 'ACTIVE' AND priority <= 2 ORDER BY priority"
+                    />
+                    <Example
+                        label="Quick wins"
+                        query="status = 'ACTIVE' AND estimate <= '15m' ORDER BY estimate"
+                    />
                 </div>
             </Section>
         </div>
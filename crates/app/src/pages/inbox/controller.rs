@@ -96,6 +96,7 @@ impl InboxController {
             Signal::derive(move || app_store.browser_storage.get_bool(HIDE_NON_ACTIONABLE_KEY));
 
         let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let today = app_store.settings.today();
 
         let keep_completed_signal = keep_completed.signal();
         let node_filter = Signal::derive(move || {
@@ -109,7 +110,7 @@ impl InboxController {
                 if !hide {
                     return true;
                 }
-                is_actionable(&task, &all_tasks.get_untracked())
+                is_actionable(&task, &all_tasks.get_untracked(), today.get_untracked())
             })
         });
 
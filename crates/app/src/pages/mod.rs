@@ -5,7 +5,13 @@ pub mod filter_help;
 pub mod inbox;
 pub mod login;
 pub mod project;
+pub mod project_review;
 pub mod review;
 pub mod settings;
 pub mod someday;
+pub mod stats;
+pub mod tag;
+pub mod tags;
 pub mod today;
+pub mod trash;
+pub mod waiting;
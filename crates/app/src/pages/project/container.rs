@@ -30,7 +30,10 @@ pub fn ProjectPage() -> impl IntoView {
             completed_count=ctrl.completed_count
             is_loaded=ctrl.is_loaded
             hide_non_actionable=ctrl.hide_non_actionable
+            is_board=ctrl.is_board
+            project_id=project_id
             node_filter=ctrl.node_filter
+            sections=ctrl.sections.into()
             default_project_id=default_project_id
             on_task_click=Callback::new(move |id| ctrl.open_detail(id))
             on_reorder=Callback::new(move |(id, key, parent)| {
@@ -39,6 +42,12 @@ pub fn ProjectPage() -> impl IntoView {
             on_toggle_visibility=Callback::new(move |()| {
                 ctrl.toggle_actionable_visibility()
             })
+            on_set_view_type=Callback::new(move |vt| ctrl.set_view_type(vt))
+            on_set_due_date=Callback::new(move |value| ctrl.set_due_date(value))
+            on_set_outcome=Callback::new(move |outcome| ctrl.set_outcome(outcome))
+            on_add_section=Callback::new(move |title| ctrl.add_section(title))
+            on_rename_section=Callback::new(move |(id, title)| ctrl.rename_section(id, title))
+            on_delete_section=Callback::new(move |id| ctrl.delete_section(id))
         />
     }
 }
@@ -1,8 +1,10 @@
 use leptos::prelude::*;
-use north_dto::Project;
+use leptos::task::spawn_local;
+use north_dto::{Project, ProjectSection, ProjectViewType, UpdateProjectSection};
+use north_repositories::ProjectRepository;
 use north_stores::{AppStore, IdFilter, TaskDetailModalStore, TaskModel, TaskStoreFilter};
 
-use crate::libs::{is_actionable, KeepCompletedVisible, KeepTaskVisible};
+use crate::libs::{is_actionable, with_live_progress, KeepCompletedVisible, KeepTaskVisible};
 
 const HIDE_NON_ACTIONABLE_KEY: &str = "north:hide-non-actionable:project";
 
@@ -15,7 +17,10 @@ pub struct ProjectController {
     pub completed_count: Memo<usize>,
     pub is_loaded: Signal<bool>,
     pub hide_non_actionable: Signal<bool>,
+    pub is_board: Signal<bool>,
     pub node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    pub sections: RwSignal<Vec<ProjectSection>>,
+    project_id: Signal<i64>,
     app_store: AppStore,
 }
 
@@ -27,9 +32,27 @@ impl ProjectController {
             app_store.tasks.refetch();
         });
 
+        let sections = RwSignal::new(Vec::<ProjectSection>::new());
+        Effect::new(move |_| {
+            let pid = project_id.get();
+            spawn_local(async move {
+                if let Ok(list) = ProjectRepository::list_sections(pid).await {
+                    sections.set(list);
+                }
+            });
+        });
+
+        let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let today = app_store.settings.today();
+        let tasks_loaded = app_store.tasks.loaded_signal();
         let project = Memo::new(move |_| {
             let pid = project_id.get();
-            app_store.projects.get().into_iter().find(|p| p.id == pid)
+            let project = app_store.projects.get().into_iter().find(|p| p.id == pid)?;
+            if tasks_loaded.get() {
+                Some(with_live_progress(project, &all_tasks.get(), today.get()))
+            } else {
+                Some(project)
+            }
         });
 
         let root_tasks = Memo::new(move |_| {
@@ -123,6 +146,12 @@ impl ProjectController {
         let hide_non_actionable =
             Signal::derive(move || app_store.browser_storage.get_bool(HIDE_NON_ACTIONABLE_KEY));
 
+        let is_board = Signal::derive(move || {
+            project
+                .get()
+                .is_some_and(|p| p.view_type == ProjectViewType::Kanban)
+        });
+
         let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
 
         let keep_completed_signal = keep_completed.signal();
@@ -137,7 +166,7 @@ impl ProjectController {
                 if !hide {
                     return true;
                 }
-                is_actionable(&task, &all_tasks.get_untracked())
+                is_actionable(&task, &all_tasks.get_untracked(), today.get_untracked())
             })
         });
 
@@ -149,7 +178,10 @@ impl ProjectController {
             completed_count,
             is_loaded,
             hide_non_actionable,
+            is_board,
             node_filter,
+            sections,
+            project_id,
             app_store,
         }
     }
@@ -170,4 +202,68 @@ impl ProjectController {
             .browser_storage
             .toggle_bool(HIDE_NON_ACTIONABLE_KEY);
     }
+
+    pub fn add_section(&self, title: String) {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return;
+        }
+        let sections = self.sections;
+        let pid = self.project_id.get_untracked();
+        spawn_local(async move {
+            if let Ok(section) = ProjectRepository::create_section(pid, title).await {
+                sections.update(|list| list.push(section));
+            }
+        });
+    }
+
+    pub fn rename_section(&self, id: i64, title: String) {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return;
+        }
+        self.sections.update(|list| {
+            if let Some(s) = list.iter_mut().find(|s| s.id == id) {
+                s.title = title.clone();
+            }
+        });
+        spawn_local(async move {
+            let input = UpdateProjectSection {
+                title: Some(title),
+                ..Default::default()
+            };
+            let _ = ProjectRepository::update_section(id, input).await;
+        });
+    }
+
+    /// Removes a section; its tasks stay in the project, outside any section.
+    pub fn delete_section(&self, id: i64) {
+        self.sections.update(|list| list.retain(|s| s.id != id));
+        let tasks = self.app_store.tasks;
+        spawn_local(async move {
+            if ProjectRepository::delete_section(id).await.is_ok() {
+                tasks.refetch();
+            }
+        });
+    }
+
+    /// Sets the due date from a date input's value; empty clears it.
+    pub fn set_due_date(&self, value: String) {
+        if let Some(project) = self.project.get_untracked() {
+            let due_date = chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok();
+            self.app_store.projects.set_due_date(project.id, due_date);
+        }
+    }
+
+    pub fn set_outcome(&self, outcome: String) {
+        if let Some(project) = self.project.get_untracked() {
+            self.app_store.projects.set_outcome(project.id, outcome);
+        }
+    }
+
+    pub fn set_view_type(&self, view_type: ProjectViewType) {
+        if let Some(project) = self.project.get_untracked() {
+            self.app_store.projects.set_view_type(project.id, view_type);
+        }
+    }
 }
@@ -1,11 +1,12 @@
 use leptos::prelude::*;
-use north_dto::Project;
+use north_dto::{Project, ProjectSection, ProjectViewType};
 use north_ui::{Icon, IconKind};
 
 use crate::atoms::{Text, TextVariant};
 use crate::components::keybindings_modal::KeybindingsModal;
+use crate::containers::kanban_board::KanbanBoard;
 use crate::containers::task_list_item::ItemConfig;
-use crate::containers::traversable_task_list::{TraversableTaskList, TtlHandle};
+use crate::containers::traversable_task_list::{SectionConfig, TraversableTaskList, TtlHandle};
 
 #[component]
 pub fn ProjectView(
@@ -15,11 +16,20 @@ pub fn ProjectView(
     completed_count: Memo<usize>,
     is_loaded: Signal<bool>,
     hide_non_actionable: Signal<bool>,
+    is_board: Signal<bool>,
+    project_id: Signal<i64>,
     node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    sections: Signal<Vec<ProjectSection>>,
     default_project_id: Signal<Option<i64>>,
     on_task_click: Callback<i64>,
     on_reorder: Callback<(i64, String, Option<Option<i64>>)>,
     on_toggle_visibility: Callback<()>,
+    on_set_view_type: Callback<ProjectViewType>,
+    on_set_due_date: Callback<String>,
+    on_set_outcome: Callback<String>,
+    on_add_section: Callback<String>,
+    on_rename_section: Callback<(i64, String)>,
+    on_delete_section: Callback<i64>,
 ) -> impl IntoView {
     let show_keybindings_help = RwSignal::new(false);
     let (help_read, help_write) = show_keybindings_help.split();
@@ -29,6 +39,13 @@ pub fn ProjectView(
         draggable: true,
         ..Default::default()
     };
+    let section_config = SectionConfig {
+        sections,
+        on_rename: on_rename_section,
+        on_delete: on_delete_section,
+    };
+    let (adding_section, set_adding_section) = signal(false);
+    let (section_title, set_section_title) = signal(String::new());
 
     view! {
         <div class="space-y-4">
@@ -44,6 +61,24 @@ pub fn ProjectView(
                             }}
                         </Text>
                     </div>
+                    <div class="flex items-center gap-4">
+                    <div
+                        data-testid="project-view-toggle"
+                        class="flex items-center rounded border border-border text-xs"
+                    >
+                        <button
+                            on:click=move |_| on_set_view_type.run(ProjectViewType::List)
+                            class=move || view_toggle_class(!is_board.get())
+                        >
+                            "List"
+                        </button>
+                        <button
+                            on:click=move |_| on_set_view_type.run(ProjectViewType::Kanban)
+                            class=move || view_toggle_class(is_board.get())
+                        >
+                            "Board"
+                        </button>
+                    </div>
                     <button
                         on:click=move |_| show_keybindings_help.set(true)
                         class="flex items-center gap-1.5 text-xs \
@@ -55,8 +90,11 @@ pub fn ProjectView(
                         <span class="font-mono">"?"</span>
                         " for help"
                     </button>
+                    </div>
                 </div>
+                <ProjectMeta project=project on_set_due_date=on_set_due_date on_set_outcome=on_set_outcome />
                 <div class="flex items-center gap-3 mt-2">
+                    <Show when=move || !is_board.get()>
                     <button
                         data-testid="project-add-task"
                         on:click=move |_| {
@@ -69,6 +107,43 @@ pub fn ProjectView(
                     >
                         "+" " Add task"
                     </button>
+                    <Show
+                        when=move || adding_section.get()
+                        fallback=move || view! {
+                            <button
+                                data-testid="project-add-section"
+                                on:click=move |_| set_adding_section.set(true)
+                                class="text-xs text-text-secondary hover:text-text-primary \
+                                       transition-colors cursor-pointer"
+                            >
+                                "+" " Add section"
+                            </button>
+                        }
+                    >
+                        <form on:submit=move |ev| {
+                            ev.prevent_default();
+                            on_add_section.run(section_title.get_untracked());
+                            set_section_title.set(String::new());
+                            set_adding_section.set(false);
+                        }>
+                            <input
+                                type="text"
+                                placeholder="Section title"
+                                autofocus=true
+                                bind:value=(section_title, set_section_title)
+                                on:keydown=move |ev| {
+                                    if ev.key() == "Escape" {
+                                        set_adding_section.set(false);
+                                    }
+                                }
+                                on:blur=move |_| set_adding_section.set(false)
+                                class="bg-bg-input border border-border rounded px-2 py-0.5 \
+                                       text-xs text-text-primary focus:outline-none \
+                                       focus:border-accent"
+                            />
+                        </form>
+                    </Show>
+                    </Show>
                     {move || {
                         let count = completed_count.get();
                         if count > 0 {
@@ -100,6 +175,7 @@ pub fn ProjectView(
                             None
                         }
                     }}
+                    <Show when=move || !is_board.get()>
                     <button
                         on:click=move |_| on_toggle_visibility.run(())
                         class="text-xs text-text-secondary \
@@ -114,23 +190,172 @@ pub fn ProjectView(
                             }
                         }}
                     </button>
+                    </Show>
                 </div>
             </div>
 
-            <TraversableTaskList
-                root_task_ids=root_task_ids
-                node_filter=node_filter
-                item_config=item_config
-                is_loaded=is_loaded
-                on_reorder=on_reorder
-                on_task_click=on_task_click
-                show_keybindings_help=show_keybindings_help
-                default_project_id=default_project_id
-                handle=ttl_handle
-                empty_message="No tasks in this project. Add one above."
-            />
+            <Show
+                when=move || is_board.get()
+                fallback=move || {
+                    view! {
+                        <TraversableTaskList
+                            root_task_ids=root_task_ids
+                            node_filter=node_filter
+                            item_config=item_config
+                            is_loaded=is_loaded
+                            on_reorder=on_reorder
+                            on_task_click=on_task_click
+                            show_keybindings_help=show_keybindings_help
+                            default_project_id=default_project_id
+                            handle=ttl_handle
+                            sections=section_config
+                            empty_message="No tasks in this project. Add one above."
+                        />
+                    }
+                }
+            >
+                <KanbanBoard project_id=project_id show_completed=show_completed.into() />
+            </Show>
 
             <KeybindingsModal open=help_read set_open=help_write />
         </div>
     }
 }
+
+/// Outcome, due date, progress and stalled state under the project title.
+#[component]
+fn ProjectMeta(
+    project: Memo<Option<Project>>,
+    on_set_due_date: Callback<String>,
+    on_set_outcome: Callback<String>,
+) -> impl IntoView {
+    let (editing_outcome, set_editing_outcome) = signal(false);
+    let (outcome_draft, set_outcome_draft) = signal(String::new());
+    let outcome = Memo::new(move |_| project.get().and_then(|p| p.outcome));
+    let save_outcome = move || {
+        if editing_outcome.get_untracked() {
+            set_editing_outcome.set(false);
+            let draft = outcome_draft.get_untracked();
+            if Some(draft.trim()) != outcome.get_untracked().as_deref() {
+                on_set_outcome.run(draft);
+            }
+        }
+    };
+
+    view! {
+        <div class="mt-1 space-y-2" data-testid="project-meta">
+            <Show
+                when=move || editing_outcome.get()
+                fallback=move || {
+                    view! {
+                        <button
+                            data-testid="project-outcome"
+                            on:click=move |_| {
+                                set_outcome_draft.set(outcome.get_untracked().unwrap_or_default());
+                                set_editing_outcome.set(true);
+                            }
+                            class="text-sm text-left text-text-secondary \
+                                   hover:text-text-primary transition-colors cursor-pointer"
+                        >
+                            {move || {
+                                outcome
+                                    .get()
+                                    .unwrap_or_else(|| "+ Desired outcome".to_string())
+                            }}
+                        </button>
+                    }
+                }
+            >
+                <input
+                    type="text"
+                    placeholder="What does done look like?"
+                    autofocus=true
+                    bind:value=(outcome_draft, set_outcome_draft)
+                    on:keydown=move |ev| match ev.key().as_str() {
+                        "Enter" => save_outcome(),
+                        "Escape" => set_editing_outcome.set(false),
+                        _ => {}
+                    }
+                    on:blur=move |_| save_outcome()
+                    class="w-full bg-bg-input border border-border rounded px-2 py-0.5 \
+                           text-sm text-text-primary focus:outline-none focus:border-accent"
+                />
+            </Show>
+            <div class="flex items-center gap-3 text-xs text-text-secondary">
+                <label class="flex items-center gap-1.5">
+                    "Due"
+                    <input
+                        type="date"
+                        data-testid="project-due-date"
+                        prop:value=move || {
+                            project
+                                .get()
+                                .and_then(|p| p.due_date)
+                                .map(|d| d.to_string())
+                                .unwrap_or_default()
+                        }
+                        on:change=move |ev| on_set_due_date.run(event_target_value(&ev))
+                        class=move || {
+                            let overdue = project
+                                .get()
+                                .and_then(|p| p.due_date)
+                                .is_some_and(|d| d < chrono::Utc::now().date_naive());
+                            if overdue {
+                                "bg-transparent text-danger focus:outline-none"
+                            } else {
+                                "bg-transparent text-text-secondary focus:outline-none"
+                            }
+                        }
+                    />
+                </label>
+                {move || {
+                    project
+                        .get()
+                        .filter(|p| p.task_count > 0)
+                        .map(|p| {
+                            view! {
+                                <div
+                                    class="flex items-center gap-1.5"
+                                    data-testid="project-progress"
+                                >
+                                    <div class="w-24 h-1.5 rounded-full bg-bg-tertiary \
+                                                overflow-hidden">
+                                        <div
+                                            class="h-full bg-accent"
+                                            style=format!("width: {}%", p.progress)
+                                        />
+                                    </div>
+                                    <span class="tabular-nums">
+                                        {format!(
+                                            "{}% · {} of {} done",
+                                            p.progress,
+                                            p.completed_task_count,
+                                            p.task_count,
+                                        )}
+                                    </span>
+                                </div>
+                            }
+                        })
+                }}
+                <Show when=move || project.get().is_some_and(|p| p.stalled)>
+                    <span
+                        class="text-warning"
+                        data-testid="project-stalled"
+                        title="No task in this project can be done next"
+                    >
+                        "Stalled — no next action"
+                    </span>
+                </Show>
+            </div>
+        </div>
+    }
+}
+
+fn view_toggle_class(active: bool) -> &'static str {
+    if active {
+        "px-2 py-0.5 bg-bg-tertiary text-text-primary cursor-pointer"
+    } else {
+        "px-2 py-0.5 text-text-secondary hover:text-text-primary \
+         transition-colors cursor-pointer"
+    }
+}
@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+use north_stores::use_app_store;
+
+use super::controller::ProjectReviewController;
+use super::view::ProjectReviewView;
+
+#[component]
+pub fn ProjectReviewPage() -> impl IntoView {
+    let app_store = use_app_store();
+    let ctrl = ProjectReviewController::new(app_store);
+    let default_project_id = Signal::derive(move || ctrl.project.get().map(|p| p.id));
+
+    view! {
+        <ProjectReviewView
+            project=ctrl.project
+            index=ctrl.index.into()
+            step_count=ctrl.step_count
+            reviewed_count=ctrl.reviewed_count.into()
+            is_finished=ctrl.is_finished
+            root_task_ids=ctrl.root_task_ids
+            is_loaded=ctrl.is_loaded
+            node_filter=ctrl.node_filter
+            default_project_id=default_project_id
+            on_task_click=Callback::new(move |id| ctrl.open_detail(id))
+            on_reorder=Callback::new(move |(id, key, parent)| {
+                ctrl.reorder_task(id, key, parent)
+            })
+            on_mark_reviewed=Callback::new(move |()| ctrl.mark_reviewed())
+            on_archive=Callback::new(move |()| ctrl.archive())
+            on_next=Callback::new(move |()| ctrl.next())
+            on_back=Callback::new(move |()| ctrl.back())
+            on_review_all=Callback::new(move |()| ctrl.review_all())
+            on_set_review_interval=Callback::new(move |value| ctrl.set_review_interval(value))
+        />
+    }
+}
@@ -0,0 +1,188 @@
+use chrono::Utc;
+use leptos::prelude::*;
+use north_dto::{Project, ProjectStatus};
+use north_stores::{AppStore, IdFilter, TaskDetailModalStore, TaskModel, TaskStoreFilter};
+
+use crate::libs::{with_live_progress, KeepCompletedVisible};
+
+/// Walks through active projects one at a time. The queue is fixed when the
+/// review starts, so marking a project reviewed doesn't reshuffle it.
+#[derive(Clone, Copy)]
+pub struct ProjectReviewController {
+    app_store: AppStore,
+    task_detail_modal_store: TaskDetailModalStore,
+    queue: RwSignal<Vec<i64>>,
+    pub index: RwSignal<usize>,
+    pub reviewed_count: RwSignal<usize>,
+    pub step_count: Signal<usize>,
+    pub is_finished: Signal<bool>,
+    pub project: Memo<Option<Project>>,
+    pub root_task_ids: Memo<Vec<i64>>,
+    pub is_loaded: Signal<bool>,
+    pub node_filter: Signal<Callback<TaskModel, bool>>,
+}
+
+impl ProjectReviewController {
+    pub fn new(app_store: AppStore) -> Self {
+        let task_detail_modal_store = app_store.task_detail_modal;
+
+        Effect::new(move |_| {
+            app_store.tasks.refetch();
+        });
+
+        let queue = RwSignal::new(Vec::<i64>::new());
+        let started = RwSignal::new(false);
+        let index = RwSignal::new(0usize);
+        let reviewed_count = RwSignal::new(0usize);
+
+        // Start with the projects due for review once projects are loaded.
+        let projects_loaded = app_store.projects.loaded_signal();
+        let review_interval = app_store.settings.review_interval_days();
+        Effect::new(move |_| {
+            if started.get_untracked() || !projects_loaded.get() {
+                return;
+            }
+            let interval = review_interval.get_untracked();
+            let today = Utc::now().date_naive();
+            queue.set(
+                app_store
+                    .projects
+                    .get_untracked()
+                    .iter()
+                    .filter(|p| p.status == ProjectStatus::Active)
+                    .filter(|p| p.is_review_due(interval, today))
+                    .map(|p| p.id)
+                    .collect(),
+            );
+            started.set(true);
+        });
+
+        let step_count = Signal::derive(move || queue.get().len());
+        let is_finished = Signal::derive(move || started.get() && index.get() >= queue.get().len());
+
+        let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let today = app_store.settings.today();
+        let project = Memo::new(move |_| {
+            let id = *queue.get().get(index.get())?;
+            let project = app_store.projects.get().into_iter().find(|p| p.id == id)?;
+            Some(with_live_progress(project, &all_tasks.get(), today.get()))
+        });
+
+        let root_task_ids = Memo::new(move |_| {
+            let Some(pid) = project.get().map(|p| p.id) else {
+                return vec![];
+            };
+            app_store
+                .tasks
+                .filtered(TaskStoreFilter {
+                    project_id: IdFilter::Is(pid),
+                    parent_id: IdFilter::IsNull,
+                    ..Default::default()
+                })
+                .get()
+                .iter()
+                .map(|t| t.id)
+                .collect()
+        });
+
+        let is_loaded = app_store.tasks.loaded_signal();
+
+        let keep_completed = KeepCompletedVisible::new();
+        provide_context(keep_completed);
+
+        let keep_completed_signal = keep_completed.signal();
+        let node_filter = Signal::derive(move || {
+            let pinned = keep_completed_signal.get();
+            Callback::new(move |task: TaskModel| {
+                task.completed_at.is_none() || pinned.contains(&task.id)
+            })
+        });
+
+        Self {
+            app_store,
+            task_detail_modal_store,
+            queue,
+            index,
+            reviewed_count,
+            step_count,
+            is_finished,
+            project,
+            root_task_ids,
+            is_loaded,
+            node_filter,
+        }
+    }
+
+    pub fn open_detail(&self, task_id: i64) {
+        let task_ids = self.root_task_ids.get_untracked();
+        self.task_detail_modal_store.open(task_id, task_ids);
+    }
+
+    pub fn reorder_task(&self, task_id: i64, sort_key: String, parent_id: Option<Option<i64>>) {
+        self.app_store
+            .tasks
+            .reorder_task(task_id, sort_key, parent_id);
+    }
+
+    pub fn mark_reviewed(&self) {
+        if let Some(project) = self.project.get_untracked() {
+            self.app_store.projects.mark_reviewed(project.id);
+            self.reviewed_count.update(|n| *n += 1);
+            self.next();
+        }
+    }
+
+    /// Archives the current project and moves on to the next one.
+    pub fn archive(&self) {
+        if let Some(project) = self.project.get_untracked() {
+            self.app_store.projects.archive(project.id);
+            let at = self.index.get_untracked();
+            self.queue.update(|q| {
+                q.remove(at);
+            });
+        }
+    }
+
+    pub fn next(&self) {
+        self.index.update(|i| *i += 1);
+    }
+
+    pub fn back(&self) {
+        self.index.update(|i| *i = i.saturating_sub(1));
+    }
+
+    /// Restarts the walk with every active project, due or not.
+    pub fn review_all(&self) {
+        self.queue.set(
+            self.app_store
+                .projects
+                .get_untracked()
+                .iter()
+                .filter(|p| p.status == ProjectStatus::Active)
+                .map(|p| p.id)
+                .collect(),
+        );
+        self.index.set(0);
+        self.reviewed_count.set(0);
+    }
+
+    /// Sets the current project's review interval from an input's value;
+    /// blank follows the user's setting.
+    pub fn set_review_interval(&self, value: String) {
+        let Some(project) = self.project.get_untracked() else {
+            return;
+        };
+        let value = value.trim();
+        let days = if value.is_empty() {
+            None
+        } else {
+            match value.parse::<i16>() {
+                Ok(days) if days > 0 => Some(days),
+                _ => return,
+            }
+        };
+        self.app_store
+            .projects
+            .set_review_interval(project.id, days);
+    }
+}
@@ -0,0 +1,222 @@
+use leptos::prelude::*;
+use north_dto::Project;
+
+use crate::atoms::{Text, TextVariant};
+use crate::containers::task_list_item::ItemConfig;
+use crate::containers::traversable_task_list::TraversableTaskList;
+
+#[component]
+pub fn ProjectReviewView(
+    project: Memo<Option<Project>>,
+    index: Signal<usize>,
+    step_count: Signal<usize>,
+    reviewed_count: Signal<usize>,
+    is_finished: Signal<bool>,
+    root_task_ids: Memo<Vec<i64>>,
+    is_loaded: Signal<bool>,
+    node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    default_project_id: Signal<Option<i64>>,
+    on_task_click: Callback<i64>,
+    on_reorder: Callback<(i64, String, Option<Option<i64>>)>,
+    on_mark_reviewed: Callback<()>,
+    on_archive: Callback<()>,
+    on_next: Callback<()>,
+    on_back: Callback<()>,
+    on_review_all: Callback<()>,
+    on_set_review_interval: Callback<String>,
+) -> impl IntoView {
+    let item_config = ItemConfig {
+        show_project: false,
+        ..Default::default()
+    };
+
+    view! {
+        <div class="space-y-4">
+            <div class="flex items-center justify-between">
+                <Text variant=TextVariant::HeadingLg>"Project review"</Text>
+                <a
+                    href="/review"
+                    class="text-xs text-text-secondary hover:text-text-primary transition-colors"
+                >
+                    "Back to review"
+                </a>
+            </div>
+
+            <Show
+                when=move || !is_finished.get()
+                fallback=move || {
+                    view! {
+                        <div data-testid="project-review-done" class="space-y-3">
+                            <p class="text-sm text-text-secondary">
+                                {move || match (step_count.get(), reviewed_count.get()) {
+                                    (0, _) => "No projects are due for review.".to_string(),
+                                    (_, 1) => "Done — 1 project reviewed.".to_string(),
+                                    (_, n) => format!("Done — {n} projects reviewed."),
+                                }}
+                            </p>
+                            <button
+                                data-testid="project-review-all"
+                                on:click=move |_| on_review_all.run(())
+                                class="text-xs text-text-secondary hover:text-text-primary \
+                                       transition-colors cursor-pointer"
+                            >
+                                "Review all active projects"
+                            </button>
+                        </div>
+                    }
+                }
+            >
+                {move || {
+                    project
+                        .get()
+                        .map(|p| {
+                            view! {
+                                <ProjectStep
+                                    project=p
+                                    index=index
+                                    step_count=step_count
+                                    on_mark_reviewed=on_mark_reviewed
+                                    on_archive=on_archive
+                                    on_next=on_next
+                                    on_back=on_back
+                                    on_set_review_interval=on_set_review_interval
+                                />
+                            }
+                        })
+                }}
+                <TraversableTaskList
+                    root_task_ids=root_task_ids
+                    node_filter=node_filter
+                    item_config=item_config
+                    is_loaded=is_loaded
+                    on_reorder=on_reorder
+                    on_task_click=on_task_click
+                    default_project_id=default_project_id
+                    empty_message="No open tasks. Add a next action or archive the project."
+                />
+            </Show>
+        </div>
+    }
+}
+
+/// Header of one review step: where the project stands and what to do
+/// with it.
+#[component]
+fn ProjectStep(
+    project: Project,
+    index: Signal<usize>,
+    step_count: Signal<usize>,
+    on_mark_reviewed: Callback<()>,
+    on_archive: Callback<()>,
+    on_next: Callback<()>,
+    on_back: Callback<()>,
+    on_set_review_interval: Callback<String>,
+) -> impl IntoView {
+    let reviewed = project.reviewed_at.map_or_else(
+        || "Never reviewed".to_string(),
+        |d| format!("Last reviewed {}", d.format("%b %-d, %Y")),
+    );
+    let interval = project
+        .review_interval_days
+        .map(|d| d.to_string())
+        .unwrap_or_default();
+
+    view! {
+        <div data-testid="project-review-step" class="space-y-2">
+            <div class="flex items-center justify-between text-xs text-text-tertiary">
+                <span>
+                    {move || format!("Project {} of {}", index.get() + 1, step_count.get())}
+                </span>
+                <span>{reviewed}</span>
+            </div>
+            <div class="flex items-center gap-2">
+                <span
+                    class="w-3 h-3 rounded-full flex-shrink-0"
+                    style=format!("background-color: {}", project.color)
+                />
+                <a
+                    href=format!("/projects/{}", project.id)
+                    class="hover:underline"
+                >
+                    <Text variant=TextVariant::HeadingMd>{project.title.clone()}</Text>
+                </a>
+            </div>
+            {project
+                .outcome
+                .clone()
+                .map(|o| view! { <p class="text-sm text-text-secondary">{o}</p> })}
+            <div class="flex items-center gap-3 text-xs text-text-secondary">
+                {(project.task_count > 0)
+                    .then(|| {
+                        format!(
+                            "{}% · {} of {} done",
+                            project.progress,
+                            project.completed_task_count,
+                            project.task_count,
+                        )
+                    })}
+                {project
+                    .due_date
+                    .map(|d| format!("Due {}", d.format("%b %-d, %Y")))}
+                <label class="flex items-center gap-1.5">
+                    "Review every"
+                    <input
+                        type="number"
+                        min="1"
+                        placeholder="default"
+                        data-testid="project-review-interval"
+                        value=interval
+                        on:change=move |ev| on_set_review_interval.run(event_target_value(&ev))
+                        class="w-16 bg-bg-input border border-border rounded px-1.5 py-0.5 \
+                               text-xs text-text-primary focus:outline-none \
+                               focus:border-accent"
+                    />
+                    "days"
+                </label>
+            </div>
+            {project
+                .stalled
+                .then(|| {
+                    view! {
+                        <p data-testid="project-review-stalled" class="text-sm text-warning">
+                            "No next action — add one below, or archive the project."
+                        </p>
+                    }
+                })}
+            <div class="flex items-center gap-3 pt-1">
+                <button
+                    data-testid="project-review-mark"
+                    on:click=move |_| on_mark_reviewed.run(())
+                    class="px-3 py-1.5 text-sm rounded bg-accent text-on-accent \
+                           hover:bg-accent-hover transition-colors cursor-pointer"
+                >
+                    "Mark reviewed"
+                </button>
+                <button
+                    data-testid="project-review-archive"
+                    on:click=move |_| on_archive.run(())
+                    class="text-sm text-text-secondary hover:text-danger \
+                           transition-colors cursor-pointer"
+                >
+                    "Archive project"
+                </button>
+                <span class="flex-1"/>
+                <button
+                    on:click=move |_| on_back.run(())
+                    disabled=move || index.get() == 0
+                    class="text-xs text-text-secondary hover:text-text-primary \
+                           disabled:opacity-40 transition-colors cursor-pointer"
+                >
+                    "Back"
+                </button>
+                <button
+                    on:click=move |_| on_next.run(())
+                    class="text-xs text-text-secondary hover:text-text-primary \
+                           transition-colors cursor-pointer"
+                >
+                    "Skip"
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -3,16 +3,27 @@ use north_stores::use_app_store;
 
 use super::controller::ReviewController;
 use super::view::ReviewView;
+use super::wizard::StepLists;
 
 #[component]
 pub fn ReviewPage() -> impl IntoView {
     let app_store = use_app_store();
     let ctrl = ReviewController::new(app_store);
+    let step_lists = StepLists {
+        inbox: ctrl.inbox_task_ids,
+        today: ctrl.today_task_ids,
+        someday: ctrl.someday_task_ids,
+        waiting: ctrl.waiting_task_ids,
+        due_projects: ctrl.due_project_count,
+        stalled_projects: ctrl.stalled_project_count,
+    };
 
     view! {
         <ReviewView
             review_task_ids=ctrl.review_task_ids
             reviewed_task_ids=ctrl.reviewed_task_ids
+            waiting_task_ids=ctrl.waiting_task_ids
+            due_project_count=ctrl.due_project_count
             is_loaded=ctrl.is_loaded
             hide_non_actionable=ctrl.hide_non_actionable
             pending_filter=ctrl.pending_filter
@@ -23,6 +34,16 @@ pub fn ReviewPage() -> impl IntoView {
             on_toggle_visibility=Callback::new(move |()| {
                 ctrl.toggle_actionable_visibility()
             })
+            weekly_review=ctrl.weekly_review.into()
+            review_history=ctrl.review_history.into()
+            review_loaded=ctrl.review_loaded.into()
+            step_lists=step_lists
+            on_start_review=Callback::new(move |()| ctrl.start_weekly_review())
+            on_go_to_step=Callback::new(move |i| ctrl.go_to_step(i))
+            on_toggle_step=Callback::new(move |i| ctrl.toggle_step(i))
+            on_complete_step=Callback::new(move |()| ctrl.complete_step())
+            on_finish_review=Callback::new(move |()| ctrl.finish_weekly_review())
+            on_discard_review=Callback::new(move |()| ctrl.discard_weekly_review())
         />
     }
 }
@@ -1,9 +1,11 @@
 use chrono::Utc;
 use leptos::prelude::*;
-use north_dto::ProjectStatus;
+use leptos::task::spawn_local;
+use north_dto::{ProjectStatus, ReviewStepKind, UpdateWeeklyReview, WeeklyReview};
+use north_repositories::WeeklyReviewRepository;
 use north_stores::{AppStore, IdFilter, TaskDetailModalStore, TaskModel, TaskStoreFilter};
 
-use crate::libs::{is_actionable, KeepCompletedVisible};
+use crate::libs::{is_actionable, is_waiting, with_live_progress, KeepCompletedVisible};
 
 const HIDE_NON_ACTIONABLE_KEY: &str = "north:hide-non-actionable:review";
 
@@ -13,6 +15,17 @@ pub struct ReviewController {
     task_detail_modal_store: TaskDetailModalStore,
     pub review_task_ids: Memo<Vec<i64>>,
     pub reviewed_task_ids: Memo<Vec<i64>>,
+    pub waiting_task_ids: Memo<Vec<i64>>,
+    pub due_project_count: Memo<usize>,
+    pub stalled_project_count: Memo<usize>,
+    pub inbox_task_ids: Memo<Vec<i64>>,
+    pub today_task_ids: Memo<Vec<i64>>,
+    pub someday_task_ids: Memo<Vec<i64>>,
+    /// The weekly review in progress, kept on the server between sessions.
+    pub weekly_review: RwSignal<Option<WeeklyReview>>,
+    /// Completed weekly reviews, most recent first.
+    pub review_history: RwSignal<Vec<WeeklyReview>>,
+    pub review_loaded: RwSignal<bool>,
     pub is_loaded: Signal<bool>,
     pub show_reviewed: (ReadSignal<bool>, WriteSignal<bool>),
     pub hide_non_actionable: Signal<bool>,
@@ -46,7 +59,7 @@ impl ReviewController {
             all_active
                 .get()
                 .into_iter()
-                .filter(|t| !t.someday)
+                .filter(|t| !t.someday && t.waiting_for.is_none())
                 .filter(|t| {
                     // Only include tasks in active projects or no project
                     if let Some(pid) = t.project_id {
@@ -97,6 +110,89 @@ impl ReviewController {
                 .collect()
         });
 
+        // Everything delegated gets checked on each review, soonest
+        // follow-up first.
+        let waiting_task_ids = Memo::new(move |_| {
+            let mut waiting: Vec<TaskModel> = all_active
+                .get()
+                .into_iter()
+                .filter(|t| t.waiting_for.is_some())
+                .collect();
+            waiting.sort_by_key(|t| (t.follow_up_date.is_none(), t.follow_up_date));
+            waiting.into_iter().map(|t| t.id).collect()
+        });
+
+        let due_project_count = Memo::new(move |_| {
+            let interval = review_interval.get();
+            let today = Utc::now().date_naive();
+            active_projects
+                .get()
+                .iter()
+                .filter(|p| p.status == ProjectStatus::Active)
+                .filter(|p| p.is_review_due(interval, today))
+                .count()
+        });
+
+        let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let today = app_store.settings.today();
+        let stalled_project_count = Memo::new(move |_| {
+            let all = all_tasks.get();
+            let today = today.get();
+            active_projects
+                .get()
+                .into_iter()
+                .filter(|p| with_live_progress(p.clone(), &all, today).stalled)
+                .count()
+        });
+
+        // Step lists of the weekly review.
+        let inbox_task_ids = Memo::new(move |_| {
+            all_active
+                .get()
+                .into_iter()
+                .filter(|t| t.project_id.is_none())
+                .map(|t| t.id)
+                .collect()
+        });
+        let today_task_ids = Memo::new(move |_| {
+            let now = Utc::now();
+            let today = today.get();
+            all_active
+                .get()
+                .into_iter()
+                .filter(|t| !t.someday && !is_waiting(t, today))
+                .filter(|t| {
+                    t.start_at.is_some_and(|dt| dt <= now) || t.due_date.is_some_and(|d| d <= today)
+                })
+                .map(|t| t.id)
+                .collect()
+        });
+        let someday_task_ids = Memo::new(move |_| {
+            all_active
+                .get()
+                .into_iter()
+                .filter(|t| t.someday)
+                .map(|t| t.id)
+                .collect()
+        });
+
+        let weekly_review = RwSignal::new(None::<WeeklyReview>);
+        let review_history = RwSignal::new(Vec::<WeeklyReview>::new());
+        let review_loaded = RwSignal::new(false);
+        Effect::new(move |_| {
+            spawn_local(async move {
+                if let Ok(review) = WeeklyReviewRepository::current().await {
+                    weekly_review.set(review);
+                }
+                review_loaded.set(true);
+            });
+            spawn_local(async move {
+                if let Ok(list) = WeeklyReviewRepository::history().await {
+                    review_history.set(list);
+                }
+            });
+        });
+
         let is_loaded = app_store.tasks.loaded_signal();
 
         let hide_non_actionable =
@@ -108,8 +204,6 @@ impl ReviewController {
         let show_completed = RwSignal::new(false);
         let show_completed_reviewed = RwSignal::new(false);
 
-        let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
-
         let keep_completed_signal = keep_completed.signal();
         let pending_filter = Signal::derive(move || {
             let hide = hide_non_actionable.get();
@@ -122,7 +216,7 @@ impl ReviewController {
                 if !hide {
                     return true;
                 }
-                is_actionable(&task, &all_tasks.get_untracked())
+                is_actionable(&task, &all_tasks.get_untracked(), today.get_untracked())
             })
         });
 
@@ -139,6 +233,15 @@ impl ReviewController {
             task_detail_modal_store,
             review_task_ids,
             reviewed_task_ids,
+            waiting_task_ids,
+            due_project_count,
+            stalled_project_count,
+            inbox_task_ids,
+            today_task_ids,
+            someday_task_ids,
+            weekly_review,
+            review_history,
+            review_loaded,
             is_loaded,
             show_reviewed,
             hide_non_actionable,
@@ -148,13 +251,110 @@ impl ReviewController {
     }
 
     pub fn open_detail(&self, task_id: i64) {
-        let task_ids = self.review_task_ids.get_untracked();
+        let task_ids = match self.current_step_kind() {
+            Some(ReviewStepKind::Inbox) => self.inbox_task_ids.get_untracked(),
+            Some(ReviewStepKind::Today) => self.today_task_ids.get_untracked(),
+            Some(ReviewStepKind::Someday) => self.someday_task_ids.get_untracked(),
+            Some(ReviewStepKind::Waiting) => self.waiting_task_ids.get_untracked(),
+            _ => self.review_task_ids.get_untracked(),
+        };
         self.task_detail_modal_store.open(task_id, task_ids);
     }
 
+    fn current_step_kind(&self) -> Option<ReviewStepKind> {
+        self.weekly_review.with_untracked(|review| {
+            review.as_ref().and_then(|r| {
+                r.steps
+                    .get(r.current_step.max(0) as usize)
+                    .map(|step| step.kind)
+            })
+        })
+    }
+
     pub fn toggle_actionable_visibility(&self) {
         self.app_store
             .browser_storage
             .toggle_bool(HIDE_NON_ACTIONABLE_KEY);
     }
+
+    // ── Weekly review ───────────────────────────────────────────────
+
+    pub fn start_weekly_review(&self) {
+        let weekly_review = self.weekly_review;
+        spawn_local(async move {
+            if let Ok(review) = WeeklyReviewRepository::start().await {
+                weekly_review.set(Some(review));
+            }
+        });
+    }
+
+    pub fn go_to_step(&self, index: usize) {
+        self.update_review(|review| review.current_step = index as i32);
+    }
+
+    pub fn toggle_step(&self, index: usize) {
+        self.update_review(|review| {
+            if let Some(step) = review.steps.get_mut(index) {
+                step.done = !step.done;
+            }
+        });
+    }
+
+    /// Checks off the current step and moves to the next one.
+    pub fn complete_step(&self) {
+        self.update_review(|review| {
+            let current = review.current_step as usize;
+            if let Some(step) = review.steps.get_mut(current) {
+                step.done = true;
+            }
+            if current + 1 < review.steps.len() {
+                review.current_step += 1;
+            }
+        });
+    }
+
+    pub fn finish_weekly_review(&self) {
+        let Some(id) = self.weekly_review.get_untracked().map(|r| r.id) else {
+            return;
+        };
+        let weekly_review = self.weekly_review;
+        let review_history = self.review_history;
+        spawn_local(async move {
+            if let Ok(review) = WeeklyReviewRepository::complete(id).await {
+                weekly_review.set(None);
+                review_history.update(|list| list.insert(0, review));
+            }
+        });
+    }
+
+    pub fn discard_weekly_review(&self) {
+        let Some(id) = self.weekly_review.get_untracked().map(|r| r.id) else {
+            return;
+        };
+        self.weekly_review.set(None);
+        spawn_local(async move {
+            let _ = WeeklyReviewRepository::discard(id).await;
+        });
+    }
+
+    /// Applies `f` to the review in progress right away, then saves the
+    /// step position and done flags.
+    fn update_review(&self, f: impl FnOnce(&mut WeeklyReview)) {
+        let Some(mut review) = self.weekly_review.get_untracked() else {
+            return;
+        };
+        f(&mut review);
+        let id = review.id;
+        let input = UpdateWeeklyReview {
+            current_step: Some(review.current_step),
+            steps_done: Some(review.steps.iter().map(|s| s.done).collect()),
+        };
+        self.weekly_review.set(Some(review));
+        let weekly_review = self.weekly_review;
+        spawn_local(async move {
+            if let Ok(review) = WeeklyReviewRepository::update(id, input).await {
+                weekly_review.set(Some(review));
+            }
+        });
+    }
 }
@@ -1,5 +1,6 @@
 mod container;
 mod controller;
 mod view;
+mod wizard;
 
 pub use container::ReviewPage;
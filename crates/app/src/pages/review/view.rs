@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use north_dto::WeeklyReview;
 use north_ui::{Icon, IconKind};
 
 use crate::atoms::{Text, TextVariant};
@@ -6,10 +7,14 @@ use crate::components::keybindings_modal::KeybindingsModal;
 use crate::containers::task_list_item::ItemConfig;
 use crate::containers::traversable_task_list::TraversableTaskList;
 
+use super::wizard::{StepLists, WeeklyReviewWizard};
+
 #[component]
 pub fn ReviewView(
     review_task_ids: Memo<Vec<i64>>,
     reviewed_task_ids: Memo<Vec<i64>>,
+    waiting_task_ids: Memo<Vec<i64>>,
+    due_project_count: Memo<usize>,
     is_loaded: Signal<bool>,
     hide_non_actionable: Signal<bool>,
     pending_filter: Signal<Callback<north_stores::TaskModel, bool>>,
@@ -18,6 +23,16 @@ pub fn ReviewView(
     set_show_reviewed: WriteSignal<bool>,
     on_task_click: Callback<i64>,
     on_toggle_visibility: Callback<()>,
+    weekly_review: Signal<Option<WeeklyReview>>,
+    review_history: Signal<Vec<WeeklyReview>>,
+    review_loaded: Signal<bool>,
+    step_lists: StepLists,
+    on_start_review: Callback<()>,
+    on_go_to_step: Callback<usize>,
+    on_toggle_step: Callback<usize>,
+    on_complete_step: Callback<()>,
+    on_finish_review: Callback<()>,
+    on_discard_review: Callback<()>,
 ) -> impl IntoView {
     let review_config = ItemConfig {
         show_review: true,
@@ -45,6 +60,16 @@ pub fn ReviewView(
                     </button>
                 </div>
                 <div class="flex items-center gap-3 mt-2">
+                    <a
+                        href="/review/projects"
+                        data-testid="review-projects-link"
+                        class="text-xs text-accent hover:text-accent-hover transition-colors"
+                    >
+                        {move || match due_project_count.get() {
+                            0 => "Review projects".to_string(),
+                            n => format!("Review projects ({n} due)"),
+                        }}
+                    </a>
                     <button
                         on:click=move |_| on_toggle_visibility.run(())
                         class="text-xs text-text-secondary \
@@ -62,6 +87,32 @@ pub fn ReviewView(
                 </div>
             </div>
 
+            <Show
+                when=move || weekly_review.with(Option::is_some)
+                fallback=move || {
+                    view! {
+                        <WeeklyReviewStart
+                            history=review_history
+                            review_loaded=review_loaded
+                            on_start=on_start_review
+                        />
+                    }
+                }
+            >
+                <WeeklyReviewWizard
+                    review=weekly_review
+                    lists=step_lists
+                    is_loaded=is_loaded
+                    node_filter=reviewed_filter
+                    on_task_click=on_task_click
+                    on_go_to_step=on_go_to_step
+                    on_toggle_step=on_toggle_step
+                    on_complete_step=on_complete_step
+                    on_finish=on_finish_review
+                    on_discard=on_discard_review
+                />
+            </Show>
+
             <TraversableTaskList
                 root_task_ids=review_task_ids
                 node_filter=pending_filter
@@ -74,6 +125,24 @@ pub fn ReviewView(
                 empty_message="All tasks are up to date. Nothing to review."
             />
 
+            <Show when=move || !waiting_task_ids.get().is_empty()>
+                <div data-testid="review-waiting" class="border-t border-border pt-4">
+                    <Text variant=TextVariant::HeadingSm>"Waiting for"</Text>
+                    <div class="mt-3">
+                        <TraversableTaskList
+                            root_task_ids=waiting_task_ids
+                            node_filter=reviewed_filter
+                            item_config=review_config
+                            is_loaded=is_loaded
+                            allow_create=false
+                            allow_reorder=false
+                            on_task_click=on_task_click
+                            empty_message="Nothing delegated."
+                        />
+                    </div>
+                </div>
+            </Show>
+
             <div class="border-t border-border pt-4">
                 <button
                     data-testid="review-toggle-recent"
@@ -111,3 +180,66 @@ pub fn ReviewView(
         </div>
     }
 }
+
+/// Shown when no weekly review is in progress: a way to start one and the
+/// reviews done so far.
+#[component]
+fn WeeklyReviewStart(
+    history: Signal<Vec<WeeklyReview>>,
+    review_loaded: Signal<bool>,
+    on_start: Callback<()>,
+) -> impl IntoView {
+    let last = Memo::new(move |_| history.with(|h| h.first().and_then(|r| r.completed_at)));
+
+    view! {
+        <Show when=move || review_loaded.get()>
+            <div
+                data-testid="weekly-review-start"
+                class="flex items-center gap-3 border border-border rounded px-3 py-2"
+            >
+                <button
+                    on:click=move |_| on_start.run(())
+                    class="px-3 py-1.5 text-sm rounded bg-accent text-on-accent \
+                           hover:bg-accent-hover transition-colors cursor-pointer"
+                >
+                    "Start weekly review"
+                </button>
+                <span class="text-xs text-text-secondary">
+                    {move || match last.get() {
+                        Some(at) => format!("Last review {}", at.format("%b %-d, %Y")),
+                        None => "No weekly review yet".to_string(),
+                    }}
+                </span>
+            </div>
+            <Show when=move || !history.with(Vec::is_empty)>
+                <details data-testid="weekly-review-history" class="text-xs text-text-secondary">
+                    <summary class="cursor-pointer hover:text-text-primary transition-colors">
+                        "Past reviews"
+                    </summary>
+                    <ul class="mt-2 space-y-1">
+                        {move || {
+                            history
+                                .get()
+                                .into_iter()
+                                .map(|r| {
+                                    let date = r
+                                        .completed_at
+                                        .map(|at| at.format("%b %-d, %Y").to_string())
+                                        .unwrap_or_default();
+                                    let summary =
+                                        format!("{} of {} steps", r.done_count(), r.steps.len());
+                                    view! {
+                                        <li class="flex gap-3">
+                                            <span class="w-24">{date}</span>
+                                            <span>{summary}</span>
+                                        </li>
+                                    }
+                                })
+                                .collect::<Vec<_>>()
+                        }}
+                    </ul>
+                </details>
+            </Show>
+        </Show>
+    }
+}
@@ -0,0 +1,278 @@
+use leptos::prelude::*;
+use north_dto::{ReviewStep, ReviewStepKind, WeeklyReview};
+
+use crate::atoms::{Text, TextVariant};
+use crate::containers::task_list_item::ItemConfig;
+use crate::containers::traversable_task_list::TraversableTaskList;
+
+/// Task lists and counts the wizard's built-in steps show.
+#[derive(Clone, Copy)]
+pub struct StepLists {
+    pub inbox: Memo<Vec<i64>>,
+    pub today: Memo<Vec<i64>>,
+    pub someday: Memo<Vec<i64>>,
+    pub waiting: Memo<Vec<i64>>,
+    pub due_projects: Memo<usize>,
+    pub stalled_projects: Memo<usize>,
+}
+
+#[component]
+pub fn WeeklyReviewWizard(
+    review: Signal<Option<WeeklyReview>>,
+    lists: StepLists,
+    is_loaded: Signal<bool>,
+    node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    on_task_click: Callback<i64>,
+    on_go_to_step: Callback<usize>,
+    on_toggle_step: Callback<usize>,
+    on_complete_step: Callback<()>,
+    on_finish: Callback<()>,
+    on_discard: Callback<()>,
+) -> impl IntoView {
+    let steps = Memo::new(move |_| review.get().map(|r| r.steps).unwrap_or_default());
+    let current = Memo::new(move |_| {
+        review
+            .get()
+            .map(|r| r.current_step.max(0) as usize)
+            .unwrap_or(0)
+    });
+    // Kind and title only, so checking a step off doesn't rebuild its list.
+    let current_step =
+        Memo::new(move |_| steps.with(|s| s.get(current.get()).map(|s| (s.kind, s.title.clone()))));
+    let is_last = Memo::new(move |_| current.get() + 1 >= steps.get().len());
+    let all_done = Memo::new(move |_| steps.get().iter().all(|s| s.done));
+
+    view! {
+        <div data-testid="weekly-review" class="space-y-4">
+            <ol class="flex flex-wrap gap-1.5">
+                {move || {
+                    steps
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, step)| {
+                            view! {
+                                <StepChip
+                                    index=i
+                                    step=step
+                                    active=Signal::derive(move || current.get() == i)
+                                    on_select=on_go_to_step
+                                    on_toggle=on_toggle_step
+                                />
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                }}
+            </ol>
+
+            {move || {
+                current_step
+                    .get()
+                    .map(|(kind, title)| {
+                        view! {
+                            <div class="space-y-3" data-testid="weekly-review-step">
+                                <div class="flex items-baseline justify-between">
+                                    <Text variant=TextVariant::HeadingSm>
+                                        {title}
+                                    </Text>
+                                    <span class="text-xs text-text-tertiary">
+                                        {move || {
+                                            format!(
+                                                "Step {} of {}",
+                                                current.get() + 1,
+                                                steps.get().len(),
+                                            )
+                                        }}
+                                    </span>
+                                </div>
+                                <StepBody
+                                    kind=kind
+                                    lists=lists
+                                    is_loaded=is_loaded
+                                    node_filter=node_filter
+                                    on_task_click=on_task_click
+                                />
+                            </div>
+                        }
+                    })
+            }}
+
+            <div class="flex items-center gap-3 border-t border-border pt-3">
+                <Show
+                    when=move || !is_last.get() || !all_done.get()
+                    fallback=move || {
+                        view! {
+                            <button
+                                data-testid="weekly-review-finish"
+                                on:click=move |_| on_finish.run(())
+                                class="px-3 py-1.5 text-sm rounded bg-accent text-on-accent \
+                                       hover:bg-accent-hover transition-colors cursor-pointer"
+                            >
+                                "Finish review"
+                            </button>
+                        }
+                    }
+                >
+                    <button
+                        data-testid="weekly-review-next"
+                        on:click=move |_| on_complete_step.run(())
+                        class="px-3 py-1.5 text-sm rounded bg-accent text-on-accent \
+                               hover:bg-accent-hover transition-colors cursor-pointer"
+                    >
+                        {move || if is_last.get() { "Mark done" } else { "Done, next step" }}
+                    </button>
+                </Show>
+                <button
+                    on:click=move |_| on_go_to_step.run(current.get_untracked().saturating_sub(1))
+                    disabled=move || current.get() == 0
+                    class="text-xs text-text-secondary hover:text-text-primary \
+                           disabled:opacity-40 transition-colors cursor-pointer"
+                >
+                    "Back"
+                </button>
+                <Show when=move || !is_last.get()>
+                    <button
+                        on:click=move |_| on_go_to_step.run(current.get_untracked() + 1)
+                        class="text-xs text-text-secondary hover:text-text-primary \
+                               transition-colors cursor-pointer"
+                    >
+                        "Skip"
+                    </button>
+                </Show>
+                <span class="flex-1"/>
+                <Show when=move || !all_done.get()>
+                    <button
+                        on:click=move |_| on_finish.run(())
+                        class="text-xs text-text-secondary hover:text-text-primary \
+                               transition-colors cursor-pointer"
+                    >
+                        "Finish early"
+                    </button>
+                </Show>
+                <button
+                    data-testid="weekly-review-discard"
+                    on:click=move |_| on_discard.run(())
+                    class="text-xs text-text-secondary hover:text-danger \
+                           transition-colors cursor-pointer"
+                >
+                    "Discard"
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// A step in the wizard's progress row: click to jump there, tick to check
+/// it off without visiting.
+#[component]
+fn StepChip(
+    index: usize,
+    step: ReviewStep,
+    active: Signal<bool>,
+    on_select: Callback<usize>,
+    on_toggle: Callback<usize>,
+) -> impl IntoView {
+    let class = move || {
+        let base = "flex items-center gap-1.5 px-2 py-1 rounded text-xs \
+                    border transition-colors cursor-pointer";
+        if active.get() {
+            format!("{base} border-accent text-text-primary")
+        } else {
+            format!("{base} border-border text-text-secondary hover:text-text-primary")
+        }
+    };
+    view! {
+        <li class=class on:click=move |_| on_select.run(index)>
+            <input
+                type="checkbox"
+                checked=step.done
+                on:click=move |ev| ev.stop_propagation()
+                on:change=move |_| on_toggle.run(index)
+            />
+            <span class=if step.done { "line-through" } else { "" }>{step.title}</span>
+        </li>
+    }
+}
+
+#[component]
+fn StepBody(
+    kind: ReviewStepKind,
+    lists: StepLists,
+    is_loaded: Signal<bool>,
+    node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    on_task_click: Callback<i64>,
+) -> impl IntoView {
+    let item_config = ItemConfig {
+        show_inline_project: true,
+        ..Default::default()
+    };
+    let task_list = move |ids: Memo<Vec<i64>>, hint: &'static str, empty: &'static str| {
+        view! {
+            <p class="text-sm text-text-secondary">{hint}</p>
+            <TraversableTaskList
+                root_task_ids=ids
+                node_filter=node_filter
+                item_config=item_config
+                is_loaded=is_loaded
+                allow_create=false
+                allow_reorder=false
+                on_task_click=on_task_click
+                empty_message=empty
+            />
+        }
+        .into_any()
+    };
+
+    match kind {
+        ReviewStepKind::Inbox => task_list(
+            lists.inbox,
+            "Give every inbox task a project, a date, or delete it.",
+            "Inbox is empty.",
+        ),
+        ReviewStepKind::Today => task_list(
+            lists.today,
+            "Finish, reschedule or drop what is due or overdue.",
+            "Nothing due or overdue.",
+        ),
+        ReviewStepKind::Someday => task_list(
+            lists.someday,
+            "Activate anything that is ready to start; delete what no longer matters.",
+            "Nothing parked for someday.",
+        ),
+        ReviewStepKind::Waiting => task_list(
+            lists.waiting,
+            "Chase what is overdue and update follow-up dates.",
+            "Nothing delegated.",
+        ),
+        ReviewStepKind::Projects => view! {
+            <div class="space-y-2 text-sm text-text-secondary">
+                <p>
+                    {move || match lists.due_projects.get() {
+                        0 => "No projects are due for review.".to_string(),
+                        1 => "1 project is due for review.".to_string(),
+                        n => format!("{n} projects are due for review."),
+                    }}
+                    {move || match lists.stalled_projects.get() {
+                        0 => String::new(),
+                        1 => " 1 has no next action.".to_string(),
+                        n => format!(" {n} have no next action."),
+                    }}
+                </p>
+                <a
+                    href="/review/projects"
+                    data-testid="weekly-review-projects"
+                    class="text-accent hover:text-accent-hover transition-colors"
+                >
+                    "Open project review"
+                </a>
+            </div>
+        }
+        .into_any(),
+        ReviewStepKind::Checklist => view! {
+            <p class="text-sm text-text-secondary">
+                "Check this off when it's done."
+            </p>
+        }
+        .into_any(),
+    }
+}
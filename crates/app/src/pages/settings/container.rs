@@ -13,6 +13,29 @@ pub fn SettingsPage() -> impl IntoView {
             set_interval=ctrl.interval.1
             timezone=ctrl.timezone.0
             set_timezone=ctrl.timezone.1
+            reminder_email=ctrl.reminder_email.0
+            set_reminder_email=ctrl.reminder_email.1
+            reminder_push=ctrl.reminder_push.0
+            set_reminder_push=ctrl.reminder_push.1
+            webhook_url=ctrl.webhook_url.0
+            set_webhook_url=ctrl.webhook_url.1
+            digest_frequency=ctrl.digest_frequency.0
+            set_digest_frequency=ctrl.digest_frequency.1
+            digest_hour=ctrl.digest_hour.0
+            set_digest_hour=ctrl.digest_hour.1
+            digest_filter_id=ctrl.digest_filter_id.0
+            set_digest_filter_id=ctrl.digest_filter_id.1
+            exclusive_namespaces=ctrl.exclusive_namespaces.0
+            set_exclusive_namespaces=ctrl.exclusive_namespaces.1
+            trash_retention=ctrl.trash_retention.0
+            set_trash_retention=ctrl.trash_retention.1
+            available_hours=ctrl.available_hours.0
+            set_available_hours=ctrl.available_hours.1
+            review_steps=ctrl.review_steps.0
+            set_review_steps=ctrl.review_steps.1
+            review_checklist=ctrl.review_checklist.0
+            set_review_checklist=ctrl.review_checklist.1
+            saved_filters=ctrl.saved_filters
             is_loaded=ctrl.is_loaded
             on_save=Callback::new(move |()| ctrl.save())
         />
@@ -1,13 +1,36 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use north_dto::UpdateSettings;
+use north_dto::{DigestFrequency, ReviewStepKind, SavedFilter, UpdateSettings};
+use north_repositories::ReminderRepository;
 use north_stores::status_bar_store::StatusBarVariant;
 use north_stores::use_app_store;
 
+use crate::libs::subscribe_to_push;
+
 #[derive(Clone, Copy)]
 pub struct SettingsController {
     pub interval: (ReadSignal<String>, WriteSignal<String>),
     pub timezone: (ReadSignal<String>, WriteSignal<String>),
+    pub reminder_email: (ReadSignal<bool>, WriteSignal<bool>),
+    pub reminder_push: (ReadSignal<bool>, WriteSignal<bool>),
+    pub webhook_url: (ReadSignal<String>, WriteSignal<String>),
+    pub digest_frequency: (ReadSignal<DigestFrequency>, WriteSignal<DigestFrequency>),
+    pub digest_hour: (ReadSignal<u8>, WriteSignal<u8>),
+    pub digest_filter_id: (ReadSignal<Option<i64>>, WriteSignal<Option<i64>>),
+    /// Comma-separated list of exclusive tag namespaces.
+    pub exclusive_namespaces: (ReadSignal<String>, WriteSignal<String>),
+    /// Days before trashed items are purged; 0 keeps them forever.
+    pub trash_retention: (ReadSignal<String>, WriteSignal<String>),
+    /// Hours a day available for tasks.
+    pub available_hours: (ReadSignal<String>, WriteSignal<String>),
+    /// Built-in steps of the weekly review.
+    pub review_steps: (
+        ReadSignal<Vec<ReviewStepKind>>,
+        WriteSignal<Vec<ReviewStepKind>>,
+    ),
+    /// Custom weekly review checklist, one item per line.
+    pub review_checklist: (ReadSignal<String>, WriteSignal<String>),
+    pub saved_filters: Signal<Vec<SavedFilter>>,
     pub is_loaded: Signal<bool>,
     app_store: north_stores::AppStore,
 }
@@ -19,22 +42,77 @@ impl SettingsController {
 
         let interval = signal(current.review_interval_days.to_string());
         let timezone = signal(current.timezone);
+        let reminder_email = signal(current.reminder_email);
+        let reminder_push = signal(current.reminder_push);
+        let webhook_url = signal(current.reminder_webhook_url.unwrap_or_default());
+        let digest_frequency = signal(current.digest_frequency);
+        let digest_hour = signal(current.digest_hour);
+        let digest_filter_id = signal(current.digest_filter_id);
+        let exclusive_namespaces = signal(current.exclusive_tag_namespaces.join(", "));
+        let trash_retention = signal(current.trash_retention_days.to_string());
+        let available_hours = signal(current.available_hours.to_string());
+        let review_steps = signal(current.review_steps);
+        let review_checklist = signal(current.review_checklist.join("\n"));
 
         // Sync local signals when the store loads (e.g. after async refetch on page load).
         let set_interval = interval.1;
         let set_timezone = timezone.1;
         let review_days = app_store.settings.review_interval_days();
         let tz = app_store.settings.timezone();
+        let (set_email, set_push, set_webhook) = (reminder_email.1, reminder_push.1, webhook_url.1);
+        let email = app_store.settings.reminder_email();
+        let push = app_store.settings.reminder_push();
+        let webhook = app_store.settings.reminder_webhook_url();
+        let (set_frequency, set_hour, set_filter_id) =
+            (digest_frequency.1, digest_hour.1, digest_filter_id.1);
+        let frequency = app_store.settings.digest_frequency();
+        let hour = app_store.settings.digest_hour();
+        let filter_id = app_store.settings.digest_filter_id();
+        let set_exclusive = exclusive_namespaces.1;
+        let exclusive = app_store.settings.exclusive_tag_namespaces();
+        let set_retention = trash_retention.1;
+        let retention = app_store.settings.trash_retention_days();
+        let set_available = available_hours.1;
+        let available = app_store.settings.available_hours();
+        let (set_steps, set_checklist) = (review_steps.1, review_checklist.1);
+        let steps = app_store.settings.review_steps();
+        let checklist = app_store.settings.review_checklist();
         Effect::new(move |_| {
             set_interval.set(review_days.get().to_string());
             set_timezone.set(tz.get());
+            set_email.set(email.get());
+            set_push.set(push.get());
+            set_webhook.set(webhook.get());
+            set_frequency.set(frequency.get());
+            set_hour.set(hour.get());
+            set_filter_id.set(filter_id.get());
+            set_exclusive.set(exclusive.get().join(", "));
+            set_retention.set(retention.get().to_string());
+            set_available.set(available.get().to_string());
+            set_steps.set(steps.get());
+            set_checklist.set(checklist.get().join("\n"));
         });
 
+        let saved_filters_store = app_store.saved_filters;
+        let saved_filters = Signal::derive(move || saved_filters_store.get());
+
         let is_loaded = Signal::derive(move || true);
 
         Self {
             interval,
             timezone,
+            reminder_email,
+            reminder_push,
+            webhook_url,
+            digest_frequency,
+            digest_hour,
+            digest_filter_id,
+            exclusive_namespaces,
+            trash_retention,
+            available_hours,
+            review_steps,
+            review_checklist,
+            saved_filters,
             is_loaded,
             app_store,
         }
@@ -43,7 +121,52 @@ impl SettingsController {
     pub fn save(&self) {
         let interval_str = self.interval.0.get_untracked();
         let tz = self.timezone.0.get_untracked();
+        let reminder_email = self.reminder_email.0.get_untracked();
+        let reminder_push = self.reminder_push.0.get_untracked();
+        let webhook_url = self.webhook_url.0.get_untracked();
+        let digest_frequency = self.digest_frequency.0.get_untracked();
+        let digest_hour = self.digest_hour.0.get_untracked();
+        let digest_filter_id = self.digest_filter_id.0.get_untracked();
+        let exclusive_tag_namespaces: Vec<String> = self
+            .exclusive_namespaces
+            .0
+            .get_untracked()
+            .split(',')
+            .map(|ns| ns.trim().to_string())
+            .filter(|ns| !ns.is_empty())
+            .collect();
+        let trash_retention_days = self
+            .trash_retention
+            .0
+            .get_untracked()
+            .trim()
+            .parse::<i16>()
+            .ok()
+            .filter(|days| *days >= 0);
+        let available_hours = self
+            .available_hours
+            .0
+            .get_untracked()
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|hours| (0.0..=24.0).contains(hours));
+        // Keep the built-in steps in their canonical order.
+        let enabled_steps = self.review_steps.0.get_untracked();
+        let review_steps: Vec<ReviewStepKind> = ReviewStepKind::BUILT_IN
+            .into_iter()
+            .filter(|kind| enabled_steps.contains(kind))
+            .collect();
+        let review_checklist: Vec<String> = self
+            .review_checklist
+            .0
+            .get_untracked()
+            .lines()
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect();
         let app_store = self.app_store;
+        let push_newly_enabled = reminder_push && !app_store.settings.get().reminder_push;
 
         if let Ok(days) = interval_str.parse::<i16>() {
             if days >= 1 {
@@ -51,8 +174,24 @@ impl SettingsController {
                     let input = UpdateSettings {
                         review_interval_days: Some(days),
                         timezone: Some(tz),
+                        reminder_email: Some(reminder_email),
+                        reminder_push: Some(reminder_push),
+                        reminder_webhook_url: Some(webhook_url),
+                        digest_frequency: Some(digest_frequency),
+                        digest_hour: Some(digest_hour),
+                        digest_filter_id: Some(digest_filter_id),
+                        exclusive_tag_namespaces: Some(exclusive_tag_namespaces),
+                        trash_retention_days,
+                        available_hours,
+                        review_steps: Some(review_steps),
+                        review_checklist: Some(review_checklist),
                         ..Default::default()
                     };
+                    if push_newly_enabled {
+                        if let Ok(Some(key)) = ReminderRepository::push_public_key().await {
+                            subscribe_to_push(&key);
+                        }
+                    }
                     if app_store.settings.update_async(input).await {
                         app_store
                             .status_bar
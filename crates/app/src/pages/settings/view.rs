@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use north_dto::{DigestFrequency, ReviewStepKind, SavedFilter};
 
 use crate::atoms::{Text, TextColor, TextTag, TextVariant};
 use crate::constants::TIMEZONE_GROUPS;
@@ -9,6 +10,29 @@ pub fn SettingsView(
     set_interval: WriteSignal<String>,
     timezone: ReadSignal<String>,
     set_timezone: WriteSignal<String>,
+    reminder_email: ReadSignal<bool>,
+    set_reminder_email: WriteSignal<bool>,
+    reminder_push: ReadSignal<bool>,
+    set_reminder_push: WriteSignal<bool>,
+    webhook_url: ReadSignal<String>,
+    set_webhook_url: WriteSignal<String>,
+    digest_frequency: ReadSignal<DigestFrequency>,
+    set_digest_frequency: WriteSignal<DigestFrequency>,
+    digest_hour: ReadSignal<u8>,
+    set_digest_hour: WriteSignal<u8>,
+    digest_filter_id: ReadSignal<Option<i64>>,
+    set_digest_filter_id: WriteSignal<Option<i64>>,
+    exclusive_namespaces: ReadSignal<String>,
+    set_exclusive_namespaces: WriteSignal<String>,
+    trash_retention: ReadSignal<String>,
+    set_trash_retention: WriteSignal<String>,
+    available_hours: ReadSignal<String>,
+    set_available_hours: WriteSignal<String>,
+    review_steps: ReadSignal<Vec<ReviewStepKind>>,
+    set_review_steps: WriteSignal<Vec<ReviewStepKind>>,
+    review_checklist: ReadSignal<String>,
+    set_review_checklist: WriteSignal<String>,
+    saved_filters: Signal<Vec<SavedFilter>>,
     is_loaded: Signal<bool>,
     on_save: Callback<()>,
 ) -> impl IntoView {
@@ -50,6 +74,57 @@ pub fn SettingsView(
                         />
                     </div>
 
+                    <div class="space-y-2">
+                        <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
+                            "Weekly review"
+                        </Text>
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P>
+                            "Steps the weekly review walks through. Checklist \
+                             items, one per line, come after them."
+                        </Text>
+                        <div class="space-y-1" data-testid="settings-review-steps">
+                            {ReviewStepKind::BUILT_IN
+                                .into_iter()
+                                .map(|kind| {
+                                    view! {
+                                        <label class="flex items-center gap-2 text-sm text-text-primary">
+                                            <input
+                                                type="checkbox"
+                                                prop:checked=move || {
+                                                    review_steps.get().contains(&kind)
+                                                }
+                                                on:change=move |ev| {
+                                                    let on = event_target_checked(&ev);
+                                                    set_review_steps
+                                                        .update(|steps| {
+                                                            steps.retain(|k| *k != kind);
+                                                            if on {
+                                                                steps.push(kind);
+                                                            }
+                                                        });
+                                                }
+                                            />
+                                            {kind.title()}
+                                        </label>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                        <textarea
+                            rows="3"
+                            placeholder="Clear the desk\nPlan next week's calendar"
+                            data-testid="settings-review-checklist"
+                            prop:value=move || review_checklist.get()
+                            on:input=move |ev| {
+                                set_review_checklist.set(event_target_value(&ev));
+                            }
+                            class="w-full bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                    </div>
+
                     <div class="space-y-2">
                         <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
                             "Timezone"
@@ -94,6 +169,226 @@ pub fn SettingsView(
                         </select>
                     </div>
 
+                    <div class="space-y-2">
+                        <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
+                            "Reminders"
+                        </Text>
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P>
+                            "Where task reminders are delivered."
+                        </Text>
+                        <label class="flex items-center gap-2 text-sm text-text-primary">
+                            <input
+                                type="checkbox"
+                                data-testid="settings-reminder-email"
+                                prop:checked=move || reminder_email.get()
+                                on:change=move |ev| {
+                                    set_reminder_email.set(event_target_checked(&ev));
+                                }
+                            />
+                            "Email"
+                        </label>
+                        <label class="flex items-center gap-2 text-sm text-text-primary">
+                            <input
+                                type="checkbox"
+                                data-testid="settings-reminder-push"
+                                prop:checked=move || reminder_push.get()
+                                on:change=move |ev| {
+                                    set_reminder_push.set(event_target_checked(&ev));
+                                }
+                            />
+                            "Browser notifications"
+                        </label>
+                        <input
+                            type="url"
+                            placeholder="Webhook URL (optional)"
+                            data-testid="settings-reminder-webhook"
+                            prop:value=move || webhook_url.get()
+                            on:input=move |ev| {
+                                set_webhook_url.set(event_target_value(&ev));
+                            }
+                            class="w-full bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                    </div>
+
+                    <div class="space-y-2">
+                        <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
+                            "Email digest"
+                        </Text>
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P>
+                            "A morning summary of today, overdue tasks and reviews, \
+                             sent in your timezone. Weekly digests go out on Mondays."
+                        </Text>
+                        <div class="flex items-center gap-2">
+                            <select
+                                data-testid="settings-digest-frequency"
+                                on:change=move |ev| {
+                                    set_digest_frequency
+                                        .set(DigestFrequency::parse(&event_target_value(&ev)));
+                                }
+                                class="bg-bg-input border border-border \
+                                       rounded px-3 py-1.5 text-sm \
+                                       text-text-primary focus:outline-none \
+                                       focus:border-accent"
+                            >
+                                {[
+                                    (DigestFrequency::Off, "Off"),
+                                    (DigestFrequency::Daily, "Daily"),
+                                    (DigestFrequency::Weekly, "Weekly"),
+                                ]
+                                    .into_iter()
+                                    .map(|(freq, label)| {
+                                        view! {
+                                            <option
+                                                value=freq.as_str()
+                                                selected=move || digest_frequency.get() == freq
+                                            >
+                                                {label}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </select>
+                            <span class="text-sm text-text-secondary">"at"</span>
+                            <select
+                                data-testid="settings-digest-hour"
+                                on:change=move |ev| {
+                                    if let Ok(hour) = event_target_value(&ev).parse() {
+                                        set_digest_hour.set(hour);
+                                    }
+                                }
+                                class="bg-bg-input border border-border \
+                                       rounded px-3 py-1.5 text-sm \
+                                       text-text-primary focus:outline-none \
+                                       focus:border-accent"
+                            >
+                                {(0u8..24)
+                                    .map(|hour| {
+                                        view! {
+                                            <option
+                                                value=hour.to_string()
+                                                selected=move || digest_hour.get() == hour
+                                            >
+                                                {format!("{hour:02}:00")}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </select>
+                        </div>
+                        <select
+                            data-testid="settings-digest-filter"
+                            on:change=move |ev| {
+                                set_digest_filter_id.set(event_target_value(&ev).parse().ok());
+                            }
+                            class="w-64 bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        >
+                            <option value="" selected=move || digest_filter_id.get().is_none()>
+                                "No saved filter"
+                            </option>
+                            {move || {
+                                saved_filters
+                                    .get()
+                                    .into_iter()
+                                    .map(|f| {
+                                        let id = f.id;
+                                        view! {
+                                            <option
+                                                value=id.to_string()
+                                                selected=move || digest_filter_id.get() == Some(id)
+                                            >
+                                                {f.title}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                        <a
+                            href="/api/digest/preview"
+                            target="_blank"
+                            data-testid="settings-digest-preview"
+                            class="block text-sm text-accent hover:underline"
+                        >
+                            "Preview digest"
+                        </a>
+                    </div>
+
+                    <div class="space-y-2">
+                        <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
+                            "Exclusive tag namespaces"
+                        </Text>
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P>
+                            "A task keeps at most one tag from each of these namespaces; \
+                             adding #energy:high replaces #energy:low. Separate with commas."
+                        </Text>
+                        <input
+                            type="text"
+                            placeholder="energy, status"
+                            data-testid="settings-exclusive-namespaces"
+                            prop:value=move || exclusive_namespaces.get()
+                            on:input=move |ev| {
+                                set_exclusive_namespaces.set(event_target_value(&ev));
+                            }
+                            class="w-full bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                    </div>
+
+                    <div class="space-y-2">
+                        <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
+                            "Keep trash for (days)"
+                        </Text>
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P>
+                            "Deleted tasks and projects are purged after this many days. \
+                             Use 0 to keep them until you empty the trash."
+                        </Text>
+                        <input
+                            type="number"
+                            min="0"
+                            data-testid="settings-trash-retention"
+                            prop:value=move || trash_retention.get()
+                            on:input=move |ev| {
+                                set_trash_retention.set(event_target_value(&ev));
+                            }
+                            class="w-24 bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                    </div>
+
+                    <div class="space-y-2">
+                        <Text variant=TextVariant::LabelLg color=TextColor::Secondary tag=TextTag::Label class="block">
+                            "Available hours per day"
+                        </Text>
+                        <Text variant=TextVariant::BodySm color=TextColor::Tertiary tag=TextTag::P>
+                            "Today compares the estimates of its tasks against this."
+                        </Text>
+                        <input
+                            type="number"
+                            min="0"
+                            max="24"
+                            step="0.5"
+                            data-testid="settings-available-hours"
+                            prop:value=move || available_hours.get()
+                            on:input=move |ev| {
+                                set_available_hours.set(event_target_value(&ev));
+                            }
+                            class="w-24 bg-bg-input border border-border \
+                                   rounded px-3 py-1.5 text-sm \
+                                   text-text-primary focus:outline-none \
+                                   focus:border-accent"
+                        />
+                    </div>
+
                     <button
                         data-testid="settings-save"
                         on:click=move |_| on_save.run(())
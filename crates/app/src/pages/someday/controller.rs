@@ -28,6 +28,7 @@ impl SomedayController {
             parent_id: IdFilter::IsNull,
             is_completed: Some(false),
             is_someday: Some(true),
+            ..Default::default()
         });
 
         let root_task_ids = Memo::new(move |_| someday_tasks.get().iter().map(|t| t.id).collect());
@@ -41,6 +42,7 @@ impl SomedayController {
             Signal::derive(move || app_store.browser_storage.get_bool(HIDE_NON_ACTIONABLE_KEY));
 
         let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let today = app_store.settings.today();
 
         let keep_completed_signal = keep_completed.signal();
         let node_filter = Signal::derive(move || {
@@ -53,7 +55,7 @@ impl SomedayController {
                 if !hide {
                     return true;
                 }
-                is_actionable(&task, &all_tasks.get_untracked())
+                is_actionable(&task, &all_tasks.get_untracked(), today.get_untracked())
             })
         });
 
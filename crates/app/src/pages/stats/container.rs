@@ -0,0 +1,18 @@
+use leptos::prelude::*;
+
+use super::controller::StatsController;
+use super::view::StatsView;
+
+#[component]
+pub fn StatsPage() -> impl IntoView {
+    let ctrl = StatsController::new();
+
+    view! {
+        <StatsView
+            range=ctrl.range.into()
+            dashboard=ctrl.dashboard.into()
+            is_loaded=ctrl.is_loaded
+            on_set_range=Callback::new(move |range| ctrl.set_range(range))
+        />
+    }
+}
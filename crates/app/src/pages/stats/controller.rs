@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::{Dashboard, StatsRange};
+use north_repositories::StatsRepository;
+
+#[derive(Clone, Copy)]
+pub struct StatsController {
+    pub range: RwSignal<StatsRange>,
+    pub dashboard: RwSignal<Option<Dashboard>>,
+    pub is_loaded: Signal<bool>,
+}
+
+impl StatsController {
+    pub fn new() -> Self {
+        let range = RwSignal::new(StatsRange::default());
+        let dashboard = RwSignal::new(None::<Dashboard>);
+
+        Effect::new(move |_| {
+            let selected = range.get();
+            spawn_local(async move {
+                if let Ok(data) = StatsRepository::dashboard(selected).await {
+                    // A slower response for a range switched away from is dropped.
+                    if data.range == range.get_untracked() {
+                        dashboard.set(Some(data));
+                    }
+                }
+            });
+        });
+
+        Self {
+            range,
+            dashboard,
+            is_loaded: Signal::derive(move || dashboard.with(Option::is_some)),
+        }
+    }
+
+    pub fn set_range(&self, range: StatsRange) {
+        self.range.set(range);
+    }
+}
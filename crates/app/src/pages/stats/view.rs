@@ -0,0 +1,317 @@
+use leptos::prelude::*;
+use north_dto::{CompletionRate, Dashboard, StatsBucket, StatsRange, TrendPoint};
+
+use crate::atoms::{Text, TextColor, TextTag, TextVariant};
+
+#[component]
+pub fn StatsView(
+    range: Signal<StatsRange>,
+    dashboard: Signal<Option<Dashboard>>,
+    is_loaded: Signal<bool>,
+    on_set_range: Callback<StatsRange>,
+) -> impl IntoView {
+    view! {
+        <div class="space-y-6">
+            <div class="flex items-center justify-between">
+                <Text variant=TextVariant::HeadingLg>"Stats"</Text>
+                <div class="flex items-center gap-1" data-testid="stats-range">
+                    {StatsRange::ALL
+                        .into_iter()
+                        .map(|r| {
+                            let class = move || {
+                                if range.get() == r {
+                                    "px-2 py-1 text-xs rounded bg-bg-tertiary text-text-primary \
+                                     cursor-pointer"
+                                } else {
+                                    "px-2 py-1 text-xs rounded text-text-secondary \
+                                     hover:text-text-primary transition-colors cursor-pointer"
+                                }
+                            };
+                            view! {
+                                <button class=class on:click=move |_| on_set_range.run(r)>
+                                    {r.label()}
+                                </button>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </div>
+
+            <Show
+                when=move || is_loaded.get()
+                fallback=|| {
+                    view! {
+                        <Text variant=TextVariant::BodyMd color=TextColor::Secondary tag=TextTag::P class="py-4">
+                            "Loading..."
+                        </Text>
+                    }
+                }
+            >
+                {move || dashboard.get().map(|d| view! { <DashboardBody dashboard=d/> })}
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn DashboardBody(dashboard: Dashboard) -> impl IntoView {
+    let Dashboard {
+        bucket,
+        totals,
+        trend,
+        projects,
+        tags,
+        overdue,
+        review,
+        habits,
+        ..
+    } = dashboard;
+    let created: i64 = trend.iter().map(|p| p.created).sum();
+    let completed: i64 = trend.iter().map(|p| p.completed).sum();
+    let overdue_max = overdue.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+    let overdue_total: i64 = overdue.iter().map(|b| b.count).sum();
+    let last_review = review.last_weekly_review.map_or_else(
+        || "No weekly review yet".to_string(),
+        |at| format!("Last weekly review {}", at.format("%b %-d, %Y")),
+    );
+
+    view! {
+        <div class="grid grid-cols-2 sm:grid-cols-4 gap-3" data-testid="stats-totals">
+            <Counter label="Completed today" value=totals.completed_today/>
+            <Counter label="Completed this week" value=totals.completed_week/>
+            <Counter label="Created this week" value=totals.created_week/>
+            <Counter label="Open" value=totals.total_open/>
+        </div>
+
+        <Section title="Created vs completed">
+            <p class="text-xs text-text-secondary">
+                {format!("{created} created, {completed} completed")}
+            </p>
+            <TrendChart points=trend bucket=bucket/>
+        </Section>
+
+        <div class="grid sm:grid-cols-2 gap-6">
+            <Section title="Completion by project">
+                <RateList rates=projects empty="No project tasks in this period."/>
+            </Section>
+            <Section title="Completion by tag">
+                <RateList rates=tags empty="No tagged tasks in this period."/>
+            </Section>
+        </div>
+
+        <div class="grid sm:grid-cols-2 gap-6">
+            <Section title="Overdue">
+                {if overdue_total == 0 {
+                    view! { <p class="text-sm text-text-secondary">"Nothing overdue."</p> }
+                        .into_any()
+                } else {
+                    view! {
+                        <div class="space-y-1.5" data-testid="stats-overdue">
+                            {overdue
+                                .into_iter()
+                                .map(|b| {
+                                    view! {
+                                        <Bar
+                                            label=b.label()
+                                            value=b.count.to_string()
+                                            percent=(b.count * 100 / overdue_max) as i32
+                                            color="var(--danger)".to_string()
+                                        />
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    }
+                        .into_any()
+                }}
+            </Section>
+            <Section title="Review backlog">
+                <div class="space-y-1 text-sm text-text-secondary" data-testid="stats-review">
+                    <p>
+                        <a href="/review" class="text-accent hover:text-accent-hover transition-colors">
+                            {plural(review.tasks_due, "task")}
+                        </a>
+                        " and "
+                        <a
+                            href="/review/projects"
+                            class="text-accent hover:text-accent-hover transition-colors"
+                        >
+                            {plural(review.projects_due, "project")}
+                        </a>
+                        " due for review."
+                    </p>
+                    <p class="text-xs text-text-tertiary">{last_review}</p>
+                </div>
+            </Section>
+        </div>
+
+        <Section title="Habits">
+            {if habits.is_empty() {
+                view! {
+                    <p class="text-sm text-text-secondary">
+                        "No recurring tasks were scheduled in this period."
+                    </p>
+                }
+                    .into_any()
+            } else {
+                view! {
+                    <div class="space-y-1.5" data-testid="stats-habits">
+                        {habits
+                            .into_iter()
+                            .map(|h| {
+                                let value = format!(
+                                    "{}% · {} on time, {} late, {} missed",
+                                    h.percent(),
+                                    h.on_time,
+                                    h.late,
+                                    h.missed,
+                                );
+                                view! {
+                                    <Bar
+                                        label=h.title.clone()
+                                        value=value
+                                        percent=h.percent()
+                                        color="var(--success)".to_string()
+                                    />
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                }
+                    .into_any()
+            }}
+        </Section>
+    }
+}
+
+#[component]
+fn Section(title: &'static str, children: Children) -> impl IntoView {
+    view! {
+        <section class="space-y-2">
+            <Text variant=TextVariant::HeadingSm>{title}</Text>
+            {children()}
+        </section>
+    }
+}
+
+#[component]
+fn Counter(label: &'static str, value: i64) -> impl IntoView {
+    view! {
+        <div class="px-3 py-2 rounded-md bg-bg-secondary">
+            <div class="text-2xl font-semibold text-text-primary">{value}</div>
+            <div class="text-xs text-text-tertiary">{label}</div>
+        </div>
+    }
+}
+
+/// One labelled horizontal bar, `percent` of the full width.
+#[component]
+fn Bar(label: String, value: String, percent: i32, color: String) -> impl IntoView {
+    let title = label.clone();
+    view! {
+        <div class="grid grid-cols-[8rem_1fr] items-center gap-2 text-xs">
+            <span class="truncate text-text-secondary" title=title>{label}</span>
+            <div class="flex items-center gap-2 min-w-0">
+                <div class="flex-1 h-2 rounded bg-bg-tertiary overflow-hidden">
+                    <div
+                        class="h-full rounded"
+                        style=format!("width: {}%; background-color: {color}", percent.clamp(0, 100))
+                    />
+                </div>
+                <span class="text-text-tertiary whitespace-nowrap">{value}</span>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn RateList(rates: Vec<CompletionRate>, empty: &'static str) -> impl IntoView {
+    if rates.is_empty() {
+        return view! { <p class="text-sm text-text-secondary">{empty}</p> }.into_any();
+    }
+    view! {
+        <div class="space-y-1.5">
+            {rates
+                .into_iter()
+                .map(|r| {
+                    let value = format!("{}% · {} of {}", r.percent(), r.completed, r.total);
+                    view! {
+                        <Bar label=r.name.clone() value=value percent=r.percent() color=r.color/>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+    .into_any()
+}
+
+/// Paired columns per bucket: created in the background, completed in
+/// front.
+#[component]
+fn TrendChart(points: Vec<TrendPoint>, bucket: StatsBucket) -> impl IntoView {
+    let max = points
+        .iter()
+        .map(|p| p.created.max(p.completed))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let format = match bucket {
+        StatsBucket::Day => "%b %-d",
+        StatsBucket::Week => "week of %b %-d",
+        StatsBucket::Month => "%b %Y",
+    };
+    let first = points.first().map(|p| p.date.format(format).to_string());
+    let last = points.last().map(|p| p.date.format(format).to_string());
+
+    view! {
+        <div data-testid="stats-trend">
+            <div class="flex items-end gap-px h-32">
+                {points
+                    .into_iter()
+                    .map(|p| {
+                        let title = format!(
+                            "{}: {} created, {} completed",
+                            p.date.format(format),
+                            p.created,
+                            p.completed,
+                        );
+                        view! {
+                            <div class="relative flex-1 h-full" title=title>
+                                <div
+                                    class="absolute bottom-0 inset-x-0 rounded-t bg-bg-tertiary"
+                                    style=format!("height: {}%", p.created * 100 / max)
+                                />
+                                <div
+                                    class="absolute bottom-0 inset-x-[20%] rounded-t bg-accent"
+                                    style=format!("height: {}%", p.completed * 100 / max)
+                                />
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <div class="flex justify-between mt-1 text-xs text-text-tertiary">
+                <span>{first}</span>
+                <span class="flex items-center gap-3">
+                    <span class="flex items-center gap-1">
+                        <span class="w-2 h-2 rounded-sm bg-bg-tertiary"/>
+                        "created"
+                    </span>
+                    <span class="flex items-center gap-1">
+                        <span class="w-2 h-2 rounded-sm bg-accent"/>
+                        "completed"
+                    </span>
+                </span>
+                <span>{last}</span>
+            </div>
+        </div>
+    }
+}
+
+fn plural(n: i64, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
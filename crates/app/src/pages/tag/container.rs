@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+use north_stores::use_app_store;
+
+use super::controller::TagController;
+use super::view::TagView;
+
+#[component]
+pub fn TagPage() -> impl IntoView {
+    let app_store = use_app_store();
+    let params = use_params_map();
+
+    let path = Signal::derive(move || {
+        let raw = params.read().get("name").unwrap_or_default();
+        urlencoding::decode(&raw)
+            .map(|s| s.into_owned())
+            .unwrap_or(raw)
+    });
+
+    let ctrl = TagController::new(app_store, path);
+
+    view! {
+        <TagView
+            path=path
+            color=ctrl.color
+            child_paths=ctrl.child_paths
+            root_task_ids=ctrl.root_task_ids
+            is_loaded=ctrl.is_loaded
+            hide_non_actionable=ctrl.hide_non_actionable
+            node_filter=ctrl.node_filter
+            on_task_click=Callback::new(move |id| ctrl.open_detail(id))
+            on_toggle_visibility=Callback::new(move |()| {
+                ctrl.toggle_actionable_visibility()
+            })
+        />
+    }
+}
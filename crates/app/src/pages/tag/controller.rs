@@ -0,0 +1,130 @@
+use leptos::prelude::*;
+use north_dto::tag_in_namespace;
+use north_stores::{AppStore, TaskDetailModalStore, TaskModel, TaskStoreFilter};
+
+use crate::libs::{is_actionable, KeepCompletedVisible};
+
+const HIDE_NON_ACTIONABLE_KEY: &str = "north:hide-non-actionable:tag";
+
+#[derive(Clone, Copy)]
+pub struct TagController {
+    task_detail_modal_store: TaskDetailModalStore,
+    /// Color of the tag itself; `None` for a namespace without its own tag.
+    pub color: Memo<Option<String>>,
+    /// Direct child namespaces/tags, for navigating down the tree.
+    pub child_paths: Memo<Vec<String>>,
+    pub root_task_ids: Memo<Vec<i64>>,
+    pub is_loaded: Signal<bool>,
+    pub hide_non_actionable: Signal<bool>,
+    pub node_filter: Signal<Callback<TaskModel, bool>>,
+    app_store: AppStore,
+}
+
+impl TagController {
+    /// `path` is a tag name or namespace; the page lists open tasks tagged
+    /// with it or with anything beneath it.
+    pub fn new(app_store: AppStore, path: Signal<String>) -> Self {
+        let task_detail_modal_store = app_store.task_detail_modal;
+
+        Effect::new(move |_| {
+            app_store.tasks.refetch();
+        });
+
+        let color = Memo::new(move |_| {
+            let path = path.get();
+            app_store
+                .tags
+                .get()
+                .into_iter()
+                .find(|t| t.name == path)
+                .map(|t| t.color)
+        });
+
+        let child_paths = Memo::new(move |_| {
+            let path = path.get();
+            north_dto::build_tag_tree(&app_store.tags.get())
+                .into_iter()
+                .flat_map(flatten)
+                .filter(|n| north_dto::tag_namespace(n) == Some(path.as_str()))
+                .collect()
+        });
+
+        let open_tasks = app_store.tasks.filtered(TaskStoreFilter {
+            is_completed: Some(false),
+            ..Default::default()
+        });
+
+        // A tagged subtask already shows under its tagged parent.
+        let root_task_ids = Memo::new(move |_| {
+            let path = path.get();
+            let tasks = open_tasks.get();
+            let matches =
+                |t: &TaskModel| t.tags.iter().any(|tag| tag_in_namespace(&tag.name, &path));
+            let matching: Vec<&TaskModel> = tasks.iter().filter(|t| matches(t)).collect();
+            matching
+                .iter()
+                .filter(|t| {
+                    !t.parent_id
+                        .is_some_and(|pid| matching.iter().any(|m| m.id == pid))
+                })
+                .map(|t| t.id)
+                .collect()
+        });
+
+        let is_loaded = app_store.tasks.loaded_signal();
+
+        let keep_completed = KeepCompletedVisible::new();
+        provide_context(keep_completed);
+
+        let hide_non_actionable =
+            Signal::derive(move || app_store.browser_storage.get_bool(HIDE_NON_ACTIONABLE_KEY));
+
+        let all_tasks = app_store.tasks.filtered(TaskStoreFilter::default());
+        let today = app_store.settings.today();
+
+        let keep_completed_signal = keep_completed.signal();
+        let node_filter = Signal::derive(move || {
+            let hide = hide_non_actionable.get();
+            let pinned = keep_completed_signal.get();
+            Callback::new(move |task: TaskModel| {
+                if task.completed_at.is_some() {
+                    return pinned.contains(&task.id);
+                }
+                if !hide {
+                    return true;
+                }
+                is_actionable(&task, &all_tasks.get_untracked(), today.get_untracked())
+            })
+        });
+
+        Self {
+            task_detail_modal_store,
+            color,
+            child_paths,
+            root_task_ids,
+            is_loaded,
+            hide_non_actionable,
+            node_filter,
+            app_store,
+        }
+    }
+
+    pub fn open_detail(&self, task_id: i64) {
+        let task_ids = self.root_task_ids.get_untracked();
+        self.task_detail_modal_store.open(task_id, task_ids);
+    }
+
+    pub fn toggle_actionable_visibility(&self) {
+        self.app_store
+            .browser_storage
+            .toggle_bool(HIDE_NON_ACTIONABLE_KEY);
+    }
+}
+
+fn flatten(node: north_dto::TagNode) -> Vec<String> {
+    let mut paths = vec![node.path];
+    for child in node.children {
+        paths.extend(flatten(child));
+    }
+    paths
+}
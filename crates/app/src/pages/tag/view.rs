@@ -0,0 +1,116 @@
+use leptos::prelude::*;
+use north_ui::{Icon, IconKind};
+
+use crate::atoms::{Text, TextVariant};
+use crate::components::keybindings_modal::KeybindingsModal;
+use crate::containers::task_list_item::ItemConfig;
+use crate::containers::traversable_task_list::TraversableTaskList;
+
+fn tag_href(path: &str) -> String {
+    format!("/tags/{}", urlencoding::encode(path))
+}
+
+#[component]
+pub fn TagView(
+    path: Signal<String>,
+    color: Memo<Option<String>>,
+    child_paths: Memo<Vec<String>>,
+    root_task_ids: Memo<Vec<i64>>,
+    is_loaded: Signal<bool>,
+    hide_non_actionable: Signal<bool>,
+    node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    on_task_click: Callback<i64>,
+    on_toggle_visibility: Callback<()>,
+) -> impl IntoView {
+    let show_keybindings_help = RwSignal::new(false);
+    let (help_read, help_write) = show_keybindings_help.split();
+    let item_config = ItemConfig {
+        show_inline_project: true,
+        ..Default::default()
+    };
+
+    view! {
+        <div class="space-y-4">
+            <div>
+                <div class="flex items-center justify-between">
+                    <div class="flex items-center gap-2">
+                        <span
+                            class="w-3 h-3 rounded-full flex-shrink-0"
+                            style=move || {
+                                format!(
+                                    "background-color: {}",
+                                    color.get().unwrap_or_else(|| north_dto::DEFAULT_COLOR.into()),
+                                )
+                            }
+                        />
+                        <Text variant=TextVariant::HeadingLg>
+                            {move || {
+                                let path = path.get();
+                                if color.get().is_some() { format!("#{path}") } else { format!("{path}:*") }
+                            }}
+                        </Text>
+                    </div>
+                    <button
+                        on:click=move |_| show_keybindings_help.set(true)
+                        class="flex items-center gap-1.5 text-xs \
+                               text-text-secondary hover:text-text-primary \
+                               transition-colors cursor-pointer"
+                        title="Keyboard shortcuts"
+                    >
+                        <Icon kind=IconKind::Keyboard class="w-3.5 h-3.5" />
+                        <span class="font-mono">"?"</span>
+                        " for help"
+                    </button>
+                </div>
+                <div class="flex items-center gap-3 mt-2 flex-wrap">
+                    <button
+                        on:click=move |_| on_toggle_visibility.run(())
+                        class="text-xs text-text-secondary \
+                               hover:text-text-primary transition-colors \
+                               cursor-pointer"
+                    >
+                        {move || {
+                            if hide_non_actionable.get() {
+                                "Show all tasks"
+                            } else {
+                                "Hide non-actionable"
+                            }
+                        }}
+                    </button>
+                    {move || {
+                        child_paths
+                            .get()
+                            .into_iter()
+                            .map(|child| {
+                                view! {
+                                    <a
+                                        href=tag_href(&child)
+                                        data-testid="tag-page-child"
+                                        class="text-xs text-text-secondary \
+                                               hover:text-text-primary hover:underline"
+                                    >
+                                        {format!("#{child}")}
+                                    </a>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+            </div>
+
+            <TraversableTaskList
+                root_task_ids=root_task_ids
+                node_filter=node_filter
+                item_config=item_config
+                is_loaded=is_loaded
+                on_task_click=on_task_click
+                show_keybindings_help=show_keybindings_help
+                empty_message="No open tasks with this tag."
+                allow_create=false
+                allow_reorder=false
+            />
+
+            <KeybindingsModal open=help_read set_open=help_write />
+        </div>
+    }
+}
@@ -0,0 +1,25 @@
+use leptos::prelude::*;
+use north_stores::use_app_store;
+
+use super::controller::TagsController;
+use super::view::TagsView;
+
+#[component]
+pub fn TagsPage() -> impl IntoView {
+    let app_store = use_app_store();
+    let ctrl = TagsController::new(app_store);
+
+    view! {
+        <TagsView
+            tags=ctrl.tags
+            is_loaded=ctrl.is_loaded
+            unused_count=ctrl.unused_count
+            on_create=Callback::new(move |(name, color)| ctrl.create(name, color))
+            on_rename=Callback::new(move |(id, name)| ctrl.rename(id, name))
+            on_recolor=Callback::new(move |(id, color)| ctrl.recolor(id, color))
+            on_merge=Callback::new(move |(source, into)| ctrl.merge(source, into))
+            on_delete=Callback::new(move |id| ctrl.delete(id))
+            on_delete_unused=Callback::new(move |()| ctrl.delete_unused())
+        />
+    }
+}
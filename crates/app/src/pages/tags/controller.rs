@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::{CreateTag, Tag, UpdateTag};
+use north_stores::AppStore;
+
+#[derive(Clone, Copy)]
+pub struct TagsController {
+    app_store: AppStore,
+    pub tags: Signal<Vec<Tag>>,
+    pub is_loaded: Signal<bool>,
+    pub unused_count: Memo<usize>,
+}
+
+impl TagsController {
+    pub fn new(app_store: AppStore) -> Self {
+        // Counts change as tasks are tagged elsewhere; reload on visit.
+        Effect::new(move |_| {
+            app_store.tags.refetch();
+        });
+
+        let tags = Signal::derive(move || app_store.tags.get());
+        let unused_count =
+            Memo::new(move |_| tags.get().iter().filter(|t| t.task_count == 0).count());
+
+        Self {
+            app_store,
+            tags,
+            is_loaded: app_store.tags.loaded_signal(),
+            unused_count,
+        }
+    }
+
+    pub fn create(&self, name: String, color: String) {
+        let tags = self.app_store.tags;
+        spawn_local(async move {
+            let input = CreateTag {
+                name,
+                color: Some(color),
+            };
+            let _ = tags.create(input).await;
+        });
+    }
+
+    pub fn rename(&self, id: i64, name: String) {
+        self.update(
+            id,
+            UpdateTag {
+                name: Some(name),
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn recolor(&self, id: i64, color: String) {
+        self.update(
+            id,
+            UpdateTag {
+                color: Some(color),
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn merge(&self, source_id: i64, into_id: i64) {
+        let app_store = self.app_store;
+        spawn_local(async move {
+            if app_store.tags.merge(source_id, into_id).await.is_some() {
+                app_store.tasks.refetch();
+            }
+        });
+    }
+
+    pub fn delete(&self, id: i64) {
+        let app_store = self.app_store;
+        spawn_local(async move {
+            if app_store.tags.delete(id).await {
+                app_store.tasks.refetch();
+            }
+        });
+    }
+
+    pub fn delete_unused(&self) {
+        let tags = self.app_store.tags;
+        spawn_local(async move {
+            let _ = tags.delete_unused().await;
+        });
+    }
+
+    fn update(&self, id: i64, input: UpdateTag) {
+        let app_store = self.app_store;
+        spawn_local(async move {
+            if app_store.tags.update(id, input).await.is_some() {
+                app_store.tasks.refetch();
+            } else {
+                // Restore the previous name/color in the inputs.
+                app_store.tags.refetch();
+            }
+        });
+    }
+}
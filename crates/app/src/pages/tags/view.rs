@@ -0,0 +1,219 @@
+use leptos::prelude::*;
+use north_dto::Tag;
+
+use crate::atoms::{Text, TextColor, TextTag, TextVariant};
+
+#[component]
+pub fn TagsView(
+    tags: Signal<Vec<Tag>>,
+    is_loaded: Signal<bool>,
+    unused_count: Memo<usize>,
+    on_create: Callback<(String, String)>,
+    on_rename: Callback<(i64, String)>,
+    on_recolor: Callback<(i64, String)>,
+    on_merge: Callback<(i64, i64)>,
+    on_delete: Callback<i64>,
+    on_delete_unused: Callback<()>,
+) -> impl IntoView {
+    let new_name = RwSignal::new(String::new());
+    let new_color = RwSignal::new(north_dto::DEFAULT_COLOR.to_string());
+    // Tags in use need a second click to delete.
+    let pending_delete = RwSignal::new(None::<i64>);
+
+    let submit = move || {
+        let name = new_name.get_untracked().trim().to_string();
+        if !name.is_empty() {
+            on_create.run((name, new_color.get_untracked()));
+            new_name.set(String::new());
+        }
+    };
+
+    view! {
+        <div class="space-y-4 max-w-2xl">
+            <Text variant=TextVariant::HeadingLg>"Tags"</Text>
+
+            <div class="flex items-center gap-2">
+                <input
+                    type="color"
+                    prop:value=move || new_color.get()
+                    on:input=move |ev| new_color.set(event_target_value(&ev))
+                    class="w-8 h-8 bg-transparent border-none cursor-pointer"
+                />
+                <input
+                    type="text"
+                    data-testid="tags-new-name"
+                    placeholder="New tag"
+                    prop:value=move || new_name.get()
+                    on:input=move |ev| new_name.set(event_target_value(&ev))
+                    on:keydown=move |ev| {
+                        if ev.key() == "Enter" {
+                            submit();
+                        }
+                    }
+                    class="flex-1 bg-bg-input border border-border rounded px-3 py-1.5 \
+                           text-sm text-text-primary focus:outline-none focus:border-accent"
+                />
+                <button
+                    on:click=move |_| submit()
+                    class="px-3 py-1.5 text-sm rounded bg-accent text-on-accent \
+                           hover:bg-accent-hover transition-colors cursor-pointer"
+                >
+                    "Add"
+                </button>
+            </div>
+
+            <Show
+                when=move || is_loaded.get()
+                fallback=|| {
+                    view! {
+                        <Text variant=TextVariant::BodyMd color=TextColor::Secondary tag=TextTag::P class="py-4">
+                            "Loading..."
+                        </Text>
+                    }
+                }
+            >
+                <Show
+                    when=move || !tags.get().is_empty()
+                    fallback=|| {
+                        view! {
+                            <Text variant=TextVariant::BodyMd color=TextColor::Secondary tag=TextTag::P class="py-8 text-center">
+                                "No tags yet. Add one above or type #tag in a task title."
+                            </Text>
+                        }
+                    }
+                >
+                    <div class="space-y-1">
+                        <For
+                            each=move || tags.get()
+                            key=|t| (t.id, t.name.clone(), t.color.clone(), t.task_count)
+                            let:tag
+                        >
+                            {
+                                let id = tag.id;
+                                let count = tag.task_count;
+                                let href = format!("/tags/{}", urlencoding::encode(&tag.name));
+                                let others: Vec<Tag> = tags
+                                    .get_untracked()
+                                    .into_iter()
+                                    .filter(|t| t.id != id)
+                                    .collect();
+                                view! {
+                                    <div
+                                        data-testid="tag-row"
+                                        class="flex items-center gap-3 px-3 py-2 rounded-md \
+                                               bg-bg-secondary"
+                                    >
+                                        <input
+                                            type="color"
+                                            title="Change color"
+                                            prop:value=tag.color.clone()
+                                            on:change=move |ev| {
+                                                on_recolor.run((id, event_target_value(&ev)))
+                                            }
+                                            class="w-6 h-6 bg-transparent border-none cursor-pointer"
+                                        />
+                                        <span class="text-sm text-text-tertiary">"#"</span>
+                                        <input
+                                            type="text"
+                                            data-testid="tag-name"
+                                            prop:value=tag.name.clone()
+                                            on:change=move |ev| {
+                                                on_rename.run((id, event_target_value(&ev)))
+                                            }
+                                            class="flex-1 min-w-0 bg-transparent text-sm \
+                                                   text-text-primary focus:outline-none"
+                                        />
+                                        {if count > 0 {
+                                            view! {
+                                                <a
+                                                    href=href
+                                                    class="text-xs text-text-secondary \
+                                                           hover:text-text-primary hover:underline"
+                                                >
+                                                    {format!(
+                                                        "{count} task{}",
+                                                        if count == 1 { "" } else { "s" },
+                                                    )}
+                                                </a>
+                                            }
+                                            .into_any()
+                                        } else {
+                                            view! {
+                                                <span class="text-xs text-text-tertiary italic">
+                                                    "Unused"
+                                                </span>
+                                            }
+                                            .into_any()
+                                        }}
+                                        <select
+                                            title="Merge into another tag"
+                                            prop:value=""
+                                            on:change=move |ev| {
+                                                if let Ok(into) = event_target_value(&ev)
+                                                    .parse::<i64>()
+                                                {
+                                                    on_merge.run((id, into));
+                                                }
+                                            }
+                                            class="text-xs bg-transparent text-text-secondary \
+                                                   border-none focus:outline-none cursor-pointer"
+                                        >
+                                            <option value="" selected=true>"Merge into…"</option>
+                                            {others
+                                                .into_iter()
+                                                .map(|t| {
+                                                    view! {
+                                                        <option value=t.id.to_string()>
+                                                            {format!("#{}", t.name)}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </select>
+                                        <button
+                                            data-testid="tag-delete"
+                                            class="text-xs px-2 py-1 rounded bg-bg-tertiary \
+                                                   text-danger hover:text-danger-hover \
+                                                   transition-colors cursor-pointer"
+                                            on:click=move |_| {
+                                                if count == 0
+                                                    || pending_delete.get_untracked() == Some(id)
+                                                {
+                                                    pending_delete.set(None);
+                                                    on_delete.run(id);
+                                                } else {
+                                                    pending_delete.set(Some(id));
+                                                }
+                                            }
+                                        >
+                                            {move || {
+                                                if pending_delete.get() == Some(id) {
+                                                    format!("Remove from {count} tasks?")
+                                                } else {
+                                                    "Delete".to_string()
+                                                }
+                                            }}
+                                        </button>
+                                    </div>
+                                }
+                            }
+                        </For>
+                    </div>
+                    <Show when=move || { unused_count.get() > 0 }>
+                        <button
+                            data-testid="tags-delete-unused"
+                            on:click=move |_| on_delete_unused.run(())
+                            class="text-xs text-text-secondary hover:text-text-primary \
+                                   transition-colors cursor-pointer"
+                        >
+                            {move || {
+                                let n = unused_count.get();
+                                format!("Delete {n} unused tag{}", if n == 1 { "" } else { "s" })
+                            }}
+                        </button>
+                    </Show>
+                </Show>
+            </Show>
+        </div>
+    }
+}
@@ -17,6 +17,8 @@ pub fn TodayPage() -> impl IntoView {
             is_loaded=ctrl.is_loaded
             hide_non_actionable=ctrl.hide_non_actionable
             node_filter=ctrl.node_filter
+            estimated_minutes=ctrl.estimated_minutes
+            available_hours=ctrl.available_hours
             on_task_click=Callback::new(move |id| ctrl.open_detail(id))
             on_toggle_visibility=Callback::new(move |()| {
                 ctrl.toggle_actionable_visibility()
@@ -1,8 +1,9 @@
 use chrono::Utc;
 use leptos::prelude::*;
+use north_dto::is_on_today;
 use north_stores::{AppStore, IdFilter, TaskDetailModalStore, TaskModel, TaskStoreFilter};
 
-use crate::libs::{is_actionable, KeepCompletedVisible};
+use crate::libs::{is_actionable, open_estimate_minutes, KeepCompletedVisible};
 
 const HIDE_NON_ACTIONABLE_KEY: &str = "north:hide-non-actionable:today";
 
@@ -15,6 +16,9 @@ pub struct TodayController {
     pub is_loaded: Signal<bool>,
     pub hide_non_actionable: Signal<bool>,
     pub node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    /// Estimated minutes of open work on Today.
+    pub estimated_minutes: Memo<i64>,
+    pub available_hours: Signal<f32>,
     app_store: AppStore,
 }
 
@@ -26,7 +30,9 @@ impl TodayController {
             app_store.tasks.refetch();
         });
 
-        // All top-level tasks — then post-filter by start_at <= now
+        // All top-level tasks — then post-filter with the selection the
+        // digest shares. Waiting tasks stay out until their follow-up date,
+        // then show up to be chased even without a start date.
         let base_all = app_store.tasks.filtered(TaskStoreFilter {
             project_id: IdFilter::Any,
             parent_id: IdFilter::IsNull,
@@ -34,12 +40,22 @@ impl TodayController {
             ..Default::default()
         });
 
+        let today = app_store.settings.today();
         let root_task_ids = Memo::new(move |_| {
             let now = Utc::now();
+            let today = today.get();
             base_all
                 .get()
                 .into_iter()
-                .filter(|t| t.start_at.map(|dt| dt <= now).unwrap_or(false))
+                .filter(|t| {
+                    is_on_today(
+                        t.start_at,
+                        t.waiting_for.is_some(),
+                        t.follow_up_date,
+                        now,
+                        today,
+                    )
+                })
                 .map(|t| t.id)
                 .collect()
         });
@@ -84,10 +100,20 @@ impl TodayController {
                 if !hide {
                     return true;
                 }
-                is_actionable(&task, &all_tasks.get_untracked())
+                is_actionable(&task, &all_tasks.get_untracked(), today.get_untracked())
             })
         });
 
+        let estimated_minutes = Memo::new(move |_| {
+            let all = all_tasks.get();
+            let roots: Vec<i64> = root_task_ids.get();
+            all.iter()
+                .filter(|t| roots.contains(&t.id))
+                .map(|t| open_estimate_minutes(t, &all))
+                .sum()
+        });
+        let available_hours = app_store.settings.available_hours();
+
         Self {
             task_detail_modal_store,
             root_task_ids,
@@ -96,6 +122,8 @@ impl TodayController {
             is_loaded,
             hide_non_actionable,
             node_filter,
+            estimated_minutes,
+            available_hours,
             app_store,
         }
     }
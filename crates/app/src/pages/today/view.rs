@@ -14,6 +14,8 @@ pub fn TodayView(
     is_loaded: Signal<bool>,
     hide_non_actionable: Signal<bool>,
     node_filter: Signal<Callback<north_stores::TaskModel, bool>>,
+    estimated_minutes: Memo<i64>,
+    available_hours: Signal<f32>,
     on_task_click: Callback<i64>,
     on_toggle_visibility: Callback<()>,
 ) -> impl IntoView {
@@ -97,6 +99,26 @@ pub fn TodayView(
                             }
                         }}
                     </button>
+                    {move || {
+                        let estimated = estimated_minutes.get();
+                        let available = (f64::from(available_hours.get()) * 60.0).round() as i64;
+                        (estimated > 0).then(|| {
+                            let class = if estimated > available {
+                                "ml-auto text-xs text-danger"
+                            } else {
+                                "ml-auto text-xs text-text-tertiary"
+                            };
+                            view! {
+                                <span data-testid="today-estimate" class=class>
+                                    {format!(
+                                        "Estimated {} of {} available",
+                                        north_dto::format_duration(estimated),
+                                        north_dto::format_duration(available),
+                                    )}
+                                </span>
+                            }
+                        })
+                    }}
                 </div>
             </div>
 
@@ -0,0 +1,23 @@
+use leptos::prelude::*;
+use north_stores::use_app_store;
+
+use super::controller::TrashController;
+use super::view::TrashView;
+
+#[component]
+pub fn TrashPage() -> impl IntoView {
+    let app_store = use_app_store();
+    let ctrl = TrashController::new(app_store);
+
+    view! {
+        <TrashView
+            items=ctrl.items
+            is_loaded=ctrl.is_loaded
+            pending_delete=ctrl.pending_delete
+            pending_empty=ctrl.pending_empty
+            on_restore=Callback::new(move |(kind, id)| ctrl.restore(kind, id))
+            on_delete=Callback::new(move |(kind, id)| ctrl.delete(kind, id))
+            on_empty=Callback::new(move |()| ctrl.empty())
+        />
+    }
+}
@@ -0,0 +1,79 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use north_dto::{TrashItem, TrashItemKind};
+use north_repositories::TrashRepository;
+use north_stores::AppStore;
+
+#[derive(Clone, Copy)]
+pub struct TrashController {
+    app_store: AppStore,
+    pub items: RwSignal<Vec<TrashItem>>,
+    pub is_loaded: Signal<bool>,
+    /// Item whose "Delete forever" was clicked once and awaits a second click.
+    pub pending_delete: RwSignal<Option<(TrashItemKind, i64)>>,
+    pub pending_empty: RwSignal<bool>,
+}
+
+impl TrashController {
+    pub fn new(app_store: AppStore) -> Self {
+        let items = RwSignal::new(Vec::<TrashItem>::new());
+        let loaded = RwSignal::new(false);
+
+        Effect::new(move |_| {
+            spawn_local(async move {
+                if let Ok(list) = TrashRepository::list().await {
+                    items.set(list);
+                }
+                loaded.set(true);
+            });
+        });
+
+        Self {
+            app_store,
+            items,
+            is_loaded: Signal::derive(move || loaded.get()),
+            pending_delete: RwSignal::new(None),
+            pending_empty: RwSignal::new(false),
+        }
+    }
+
+    pub fn restore(&self, kind: TrashItemKind, id: i64) {
+        let app_store = self.app_store;
+        self.remove(kind, id);
+        spawn_local(async move {
+            if TrashRepository::restore(kind, id).await.is_ok() {
+                app_store.refetch();
+            }
+        });
+    }
+
+    pub fn delete(&self, kind: TrashItemKind, id: i64) {
+        if self.pending_delete.get_untracked() != Some((kind, id)) {
+            self.pending_delete.set(Some((kind, id)));
+            return;
+        }
+        self.pending_delete.set(None);
+        self.remove(kind, id);
+        spawn_local(async move {
+            let _ = TrashRepository::delete(kind, id).await;
+        });
+    }
+
+    pub fn empty(&self) {
+        if !self.pending_empty.get_untracked() {
+            self.pending_empty.set(true);
+            return;
+        }
+        self.pending_empty.set(false);
+        self.pending_delete.set(None);
+        self.items.set(vec![]);
+        spawn_local(async move {
+            let _ = TrashRepository::empty().await;
+        });
+    }
+
+    fn remove(&self, kind: TrashItemKind, id: i64) {
+        self.items
+            .update(|list| list.retain(|item| (item.kind, item.id) != (kind, id)));
+    }
+}
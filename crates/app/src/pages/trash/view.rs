@@ -0,0 +1,144 @@
+use leptos::prelude::*;
+use north_dto::{TrashItem, TrashItemKind};
+use north_ui::{Icon, IconKind};
+
+use crate::atoms::{Text, TextColor, TextTag, TextVariant};
+
+#[component]
+pub fn TrashView(
+    items: RwSignal<Vec<TrashItem>>,
+    is_loaded: Signal<bool>,
+    pending_delete: RwSignal<Option<(TrashItemKind, i64)>>,
+    pending_empty: RwSignal<bool>,
+    on_restore: Callback<(TrashItemKind, i64)>,
+    on_delete: Callback<(TrashItemKind, i64)>,
+    on_empty: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <div class="space-y-4">
+            <div class="flex items-center justify-between">
+                <Text variant=TextVariant::HeadingLg>"Trash"</Text>
+                <Show when=move || !items.get().is_empty()>
+                    <button
+                        data-testid="trash-empty-btn"
+                        class="text-xs px-2 py-1 rounded bg-bg-tertiary text-danger \
+                               hover:text-danger-hover transition-colors"
+                        on:click=move |_| on_empty.run(())
+                    >
+                        {move || {
+                            if pending_empty.get() { "Click again to empty" } else { "Empty trash" }
+                        }}
+                    </button>
+                </Show>
+            </div>
+
+            <Show
+                when=move || is_loaded.get()
+                fallback=|| {
+                    view! {
+                        <Text variant=TextVariant::BodyMd color=TextColor::Secondary tag=TextTag::P class="py-4">
+                            "Loading..."
+                        </Text>
+                    }
+                }
+            >
+                {move || {
+                    if items.get().is_empty() {
+                        view! {
+                            <Text variant=TextVariant::BodyMd color=TextColor::Secondary tag=TextTag::P class="py-8 text-center">
+                                "Trash is empty."
+                            </Text>
+                        }
+                        .into_any()
+                    } else {
+                        view! {
+                            <div class="space-y-1">
+                                <For
+                                    each=move || items.get()
+                                    key=|item| (item.kind, item.id)
+                                    let:item
+                                >
+                                    {
+                                        let key = (item.kind, item.id);
+                                        let icon = match item.kind {
+                                            TrashItemKind::Task => IconKind::Tasks,
+                                            TrashItemKind::Project => IconKind::Folder,
+                                        };
+                                        let detail = trash_detail(&item);
+                                        view! {
+                                            <div
+                                                data-testid="trash-item"
+                                                class="flex items-center justify-between gap-3 \
+                                                        px-3 py-2 rounded-md bg-bg-secondary">
+                                                <div class="flex items-center gap-2 min-w-0">
+                                                    <Icon kind=icon class="w-4 h-4 flex-shrink-0 text-text-tertiary"/>
+                                                    <div class="min-w-0">
+                                                        <div class="text-sm text-text-primary truncate">
+                                                            {item.title.clone()}
+                                                        </div>
+                                                        <div class="text-xs text-text-tertiary">
+                                                            {detail}
+                                                        </div>
+                                                    </div>
+                                                </div>
+                                                <div class="flex items-center gap-2 flex-shrink-0">
+                                                    <button
+                                                        data-testid="trash-restore-btn"
+                                                        class="text-xs px-2 py-1 rounded \
+                                                               bg-bg-tertiary \
+                                                               text-text-secondary \
+                                                               hover:text-text-primary \
+                                                               transition-colors"
+                                                        on:click=move |_| on_restore.run(key)
+                                                    >
+                                                        "Restore"
+                                                    </button>
+                                                    <button
+                                                        data-testid="trash-delete-btn"
+                                                        class="text-xs px-2 py-1 rounded \
+                                                               bg-bg-tertiary text-danger \
+                                                               hover:text-danger-hover \
+                                                               transition-colors"
+                                                        on:click=move |_| on_delete.run(key)
+                                                    >
+                                                        {move || {
+                                                            if pending_delete.get() == Some(key) {
+                                                                "Click again to confirm"
+                                                            } else {
+                                                                "Delete forever"
+                                                            }
+                                                        }}
+                                                    </button>
+                                                </div>
+                                            </div>
+                                        }
+                                    }
+                                </For>
+                            </div>
+                        }
+                        .into_any()
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
+
+/// "In Work · 3 subtasks · Deleted Oct 18, 2026"
+fn trash_detail(item: &TrashItem) -> String {
+    let mut parts = Vec::new();
+    if let Some(project) = &item.project_title {
+        parts.push(format!("In {project}"));
+    }
+    let noun = match item.kind {
+        TrashItemKind::Task => "subtask",
+        TrashItemKind::Project => "task",
+    };
+    match item.contained_count {
+        0 => {}
+        1 => parts.push(format!("1 {noun}")),
+        n => parts.push(format!("{n} {noun}s")),
+    }
+    parts.push(format!("Deleted {}", item.deleted_at.format("%b %-d, %Y")));
+    parts.join(" \u{00b7} ")
+}
@@ -0,0 +1,20 @@
+use leptos::prelude::*;
+use north_stores::use_app_store;
+
+use super::controller::WaitingController;
+use super::view::WaitingView;
+
+#[component]
+pub fn WaitingPage() -> impl IntoView {
+    let app_store = use_app_store();
+    let ctrl = WaitingController::new(app_store);
+
+    view! {
+        <WaitingView
+            root_task_ids=ctrl.root_task_ids
+            is_loaded=ctrl.is_loaded
+            node_filter=ctrl.node_filter
+            on_task_click=Callback::new(move |id| ctrl.open_detail(id))
+        />
+    }
+}
@@ -0,0 +1,62 @@
+use leptos::prelude::*;
+use north_stores::{AppStore, IdFilter, TaskDetailModalStore, TaskModel, TaskStoreFilter};
+
+use crate::libs::KeepCompletedVisible;
+
+#[derive(Clone, Copy)]
+pub struct WaitingController {
+    task_detail_modal_store: TaskDetailModalStore,
+    pub root_task_ids: Memo<Vec<i64>>,
+    pub is_loaded: Signal<bool>,
+    pub node_filter: Signal<Callback<TaskModel, bool>>,
+}
+
+impl WaitingController {
+    pub fn new(app_store: AppStore) -> Self {
+        let task_detail_modal_store = app_store.task_detail_modal;
+
+        Effect::new(move |_| {
+            app_store.tasks.refetch();
+        });
+
+        let waiting_tasks = app_store.tasks.filtered(TaskStoreFilter {
+            project_id: IdFilter::Any,
+            parent_id: IdFilter::IsNull,
+            is_completed: Some(false),
+            is_waiting: Some(true),
+            ..Default::default()
+        });
+
+        // Soonest follow-up first; open-ended ones last.
+        let root_task_ids = Memo::new(move |_| {
+            let mut tasks = waiting_tasks.get();
+            tasks.sort_by_key(|t| (t.follow_up_date.is_none(), t.follow_up_date));
+            tasks.iter().map(|t| t.id).collect()
+        });
+
+        let is_loaded = app_store.tasks.loaded_signal();
+
+        let keep_completed = KeepCompletedVisible::new();
+        provide_context(keep_completed);
+
+        let keep_completed_signal = keep_completed.signal();
+        let node_filter = Signal::derive(move || {
+            let pinned = keep_completed_signal.get();
+            Callback::new(move |task: TaskModel| {
+                task.completed_at.is_none() || pinned.contains(&task.id)
+            })
+        });
+
+        Self {
+            task_detail_modal_store,
+            root_task_ids,
+            is_loaded,
+            node_filter,
+        }
+    }
+
+    pub fn open_detail(&self, task_id: i64) {
+        let task_ids = self.root_task_ids.get_untracked();
+        self.task_detail_modal_store.open(task_id, task_ids);
+    }
+}
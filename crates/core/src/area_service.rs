@@ -0,0 +1,112 @@
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use north_db::models::{AreaChangeset, AreaRow, NewArea};
+use north_db::schema::areas;
+use north_db::sql_types::ProjectStatusMapping;
+use north_db::DbPool;
+use north_dto::{Area, CreateArea, UpdateArea};
+
+use crate::{ServiceError, ServiceResult};
+
+pub struct AreaService;
+
+impl AreaService {
+    /// All of the user's areas, archived ones included.
+    pub async fn list(pool: &DbPool, user_id: i64) -> ServiceResult<Vec<Area>> {
+        let mut conn = pool.get().await?;
+        let rows = areas::table
+            .filter(areas::user_id.eq(user_id))
+            .order((areas::position.asc(), areas::id.asc()))
+            .select(AreaRow::as_select())
+            .load(&mut conn)
+            .await?;
+        Ok(rows.into_iter().map(Area::from).collect())
+    }
+
+    pub async fn create(pool: &DbPool, user_id: i64, input: &CreateArea) -> ServiceResult<Area> {
+        let title = input.title.trim();
+        if title.is_empty() {
+            return Err(ServiceError::BadRequest("Area title is required".into()));
+        }
+        let mut conn = pool.get().await?;
+        let max_pos: Option<i32> = areas::table
+            .filter(areas::user_id.eq(user_id))
+            .select(diesel::dsl::max(areas::position))
+            .first(&mut conn)
+            .await?;
+        let row = diesel::insert_into(areas::table)
+            .values(&NewArea {
+                user_id,
+                title,
+                position: max_pos.map_or(0, |p| p + 1),
+            })
+            .returning(AreaRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(Area::from(row))
+    }
+
+    /// Renames, reorders or (un)archives an area. Its projects follow the
+    /// area's archived state without being changed themselves.
+    pub async fn update(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &UpdateArea,
+    ) -> ServiceResult<Area> {
+        let title = input.title.as_deref().map(str::trim);
+        if title == Some("") {
+            return Err(ServiceError::BadRequest("Area title is required".into()));
+        }
+        let mut conn = pool.get().await?;
+        let existing = Self::owned(&mut conn, user_id, id).await?;
+        let changeset = AreaChangeset {
+            title,
+            position: input.position,
+            status: input
+                .status
+                .as_ref()
+                .map(|s| ProjectStatusMapping::from(s.clone())),
+        };
+        if changeset.title.is_none() && changeset.position.is_none() && changeset.status.is_none() {
+            return Ok(Area::from(existing));
+        }
+        let row = diesel::update(areas::table.filter(areas::id.eq(id)))
+            .set(&changeset)
+            .returning(AreaRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(Area::from(row))
+    }
+
+    /// Deletes an area; its projects stay, ungrouped.
+    pub async fn delete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        let affected = diesel::delete(
+            areas::table
+                .filter(areas::id.eq(id))
+                .filter(areas::user_id.eq(user_id)),
+        )
+        .execute(&mut conn)
+        .await?;
+        if affected == 0 {
+            return Err(ServiceError::NotFound("Area not found".into()));
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn owned(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<AreaRow> {
+        areas::table
+            .filter(areas::id.eq(id))
+            .filter(areas::user_id.eq(user_id))
+            .select(AreaRow::as_select())
+            .first(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Area not found".into()))
+    }
+}
@@ -0,0 +1,329 @@
+use std::path::Path;
+
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use north_db::models::{ImageRow, NewImage};
+use north_db::schema::{images, tasks};
+use north_db::DbPool;
+use north_dto::Attachment;
+use tokio::io::AsyncWriteExt;
+
+use crate::{ServiceError, ServiceResult};
+
+/// Largest accepted upload: 10 MB.
+pub const MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// Content types accepted for upload, with the extension files are stored under.
+/// SVG and HTML are left out since the browser would run scripts in them.
+const ALLOWED_TYPES: &[(&str, &str)] = &[
+    ("image/png", "png"),
+    ("image/jpeg", "jpg"),
+    ("image/gif", "gif"),
+    ("image/webp", "webp"),
+    ("application/pdf", "pdf"),
+    ("application/zip", "zip"),
+    ("application/json", "json"),
+    ("text/plain", "txt"),
+    ("text/markdown", "md"),
+    ("text/csv", "csv"),
+];
+
+/// Directory uploads are written to, provided to server functions as context.
+#[derive(Debug, Clone)]
+pub struct UploadDir(pub String);
+
+impl UploadDir {
+    pub fn path(&self) -> &Path {
+        Path::new(&self.0)
+    }
+}
+
+pub struct AttachmentService;
+
+impl AttachmentService {
+    /// Checks an upload against the size and content-type limits. Images must
+    /// also start with the signature of their declared format.
+    pub fn validate(content_type: &str, data: &[u8]) -> ServiceResult<()> {
+        if data.is_empty() {
+            return Err(ServiceError::BadRequest("File is empty".into()));
+        }
+        if data.len() > MAX_ATTACHMENT_BYTES {
+            return Err(ServiceError::BadRequest(format!(
+                "File is larger than {} MB",
+                MAX_ATTACHMENT_BYTES / (1024 * 1024)
+            )));
+        }
+        if extension_for(content_type).is_none() {
+            return Err(ServiceError::BadRequest(format!(
+                "Unsupported file type: {content_type}"
+            )));
+        }
+        if content_type.starts_with("image/") && !matches_image_signature(content_type, data) {
+            return Err(ServiceError::BadRequest(
+                "File content doesn't match its image type".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn upload(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        task_id: i64,
+        filename: &str,
+        content_type: &str,
+        data: &[u8],
+    ) -> ServiceResult<Attachment> {
+        Self::validate(content_type, data)?;
+
+        let mut conn = pool.get().await?;
+        let owned: i64 = tasks::table
+            .filter(tasks::id.eq(task_id))
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .count()
+            .get_result(&mut conn)
+            .await?;
+        if owned == 0 {
+            return Err(ServiceError::NotFound("Task not found".into()));
+        }
+
+        let ext = extension_for(content_type).unwrap_or("bin");
+        let stamp = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let relative = format!("{user_id}/{stamp:x}.{ext}");
+        let full = upload_dir.join(&relative);
+        if let Some(parent) = full.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&full)
+            .await?;
+        file.write_all(data).await?;
+        file.flush().await?;
+
+        let filename = sanitize_filename(filename);
+        let inserted = diesel::insert_into(images::table)
+            .values(&NewImage {
+                user_id,
+                task_id: Some(task_id),
+                path: &relative,
+                filename: &filename,
+                content_type,
+                size_bytes: data.len() as i64,
+            })
+            .returning(ImageRow::as_returning())
+            .get_result(&mut conn)
+            .await;
+        match inserted {
+            Ok(row) => Ok(Attachment::from(row)),
+            Err(e) => {
+                remove_file(&full).await;
+                Err(e.into())
+            }
+        }
+    }
+
+    pub async fn list_for_task(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+    ) -> ServiceResult<Vec<Attachment>> {
+        let mut conn = pool.get().await?;
+        let rows = images::table
+            .filter(images::task_id.eq(task_id))
+            .filter(images::user_id.eq(user_id))
+            .order(images::id.asc())
+            .select(ImageRow::as_select())
+            .load(&mut conn)
+            .await?;
+        Ok(rows.into_iter().map(Attachment::from).collect())
+    }
+
+    /// Loads an attachment and its file contents.
+    pub async fn read(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<(Attachment, Vec<u8>)> {
+        let row = Self::get_row(pool, user_id, id).await?;
+        let data = match tokio::fs::read(upload_dir.join(&row.path)).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(ServiceError::NotFound("Attachment file missing".into()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok((Attachment::from(row), data))
+    }
+
+    pub async fn delete(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<()> {
+        let row = Self::get_row(pool, user_id, id).await?;
+        let mut conn = pool.get().await?;
+        diesel::delete(images::table.filter(images::id.eq(row.id)))
+            .execute(&mut conn)
+            .await?;
+        remove_file(&upload_dir.join(&row.path)).await;
+        Ok(())
+    }
+
+    /// Removes the attachments of a task and all its subtasks, rows and files.
+    /// Called before the task itself is deleted.
+    pub async fn delete_for_task(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        task_id: i64,
+    ) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+
+        let mut task_ids = vec![task_id];
+        let mut frontier = vec![task_id];
+        while !frontier.is_empty() {
+            frontier = tasks::table
+                .filter(tasks::parent_id.eq_any(&frontier))
+                .filter(tasks::user_id.eq(user_id))
+                .select(tasks::id)
+                .load(&mut conn)
+                .await?;
+            task_ids.extend(&frontier);
+        }
+
+        let rows: Vec<ImageRow> = diesel::delete(
+            images::table
+                .filter(images::task_id.eq_any(&task_ids))
+                .filter(images::user_id.eq(user_id)),
+        )
+        .returning(ImageRow::as_returning())
+        .get_results(&mut conn)
+        .await?;
+        for row in rows {
+            remove_file(&upload_dir.join(&row.path)).await;
+        }
+        Ok(())
+    }
+
+    async fn get_row(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<ImageRow> {
+        let mut conn = pool.get().await?;
+        images::table
+            .filter(images::id.eq(id))
+            .filter(images::user_id.eq(user_id))
+            .select(ImageRow::as_select())
+            .first(&mut conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Attachment not found".into()))
+    }
+}
+
+fn extension_for(content_type: &str) -> Option<&'static str> {
+    ALLOWED_TYPES
+        .iter()
+        .find(|(ct, _)| *ct == content_type)
+        .map(|(_, ext)| *ext)
+}
+
+fn matches_image_signature(content_type: &str, data: &[u8]) -> bool {
+    match content_type {
+        "image/png" => data.starts_with(b"\x89PNG\r\n\x1a\n"),
+        "image/jpeg" => data.starts_with(&[0xFF, 0xD8, 0xFF]),
+        "image/gif" => data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a"),
+        "image/webp" => data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP",
+        _ => false,
+    }
+}
+
+/// Keeps the last path component, drops control characters and caps the length.
+fn sanitize_filename(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .take(200)
+        .collect();
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() {
+        "file".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+async fn remove_file(path: &Path) {
+    if let Err(e) = tokio::fs::remove_file(path).await {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            tracing::warn!("Failed to remove attachment {}: {e}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    #[test]
+    fn test_validate() {
+        assert!(AttachmentService::validate("image/png", PNG).is_ok());
+        assert!(AttachmentService::validate("text/plain", b"hello").is_ok());
+        assert!(AttachmentService::validate("text/plain", b"").is_err());
+        assert!(AttachmentService::validate("text/html", b"<script>").is_err());
+        assert!(AttachmentService::validate("image/svg+xml", b"<svg/>").is_err());
+        // Declared type must match the content for images.
+        assert!(AttachmentService::validate("image/jpeg", PNG).is_err());
+        let too_big = vec![b'a'; MAX_ATTACHMENT_BYTES + 1];
+        assert!(AttachmentService::validate("text/plain", &too_big).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_trashed_task_is_refused() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = crate::test_support::user(&pool, north_dto::UserSettings::default()).await;
+        let task = crate::TaskService::create(
+            &pool,
+            user_id,
+            &north_dto::CreateTask {
+                title: "Scan receipts".into(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        crate::TaskService::delete(&pool, user_id, task.id)
+            .await
+            .unwrap();
+
+        let dir = std::env::temp_dir();
+        let result = AttachmentService::upload(
+            &pool,
+            &dir,
+            user_id,
+            task.id,
+            "notes.txt",
+            "text/plain",
+            b"hello",
+        )
+        .await;
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize_filename("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_filename("C:\\Users\\me\\shot.png"), "shot.png");
+        assert_eq!(sanitize_filename("a\"b\n.txt"), "ab.txt");
+        assert_eq!(sanitize_filename("  "), "file");
+    }
+}
@@ -0,0 +1,452 @@
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use north_db::models::{TaskRow, UserRow};
+use north_db::schema::{tasks, users};
+use north_db::DbPool;
+use north_dto::{DigestFrequency, Stats, Task, TaskFilter, UserSettings};
+
+use crate::notifications::{Clock, SmtpNotifier};
+use crate::{FilterService, ServiceResult, StatsService, TaskService};
+
+/// A digest that could not go out within this many hours of its scheduled
+/// time (e.g. the server was down all morning) is skipped for the day.
+const SEND_WINDOW_HOURS: u32 = 4;
+
+/// Cap on tasks listed per section; the rest is summarised as a count.
+const MAX_SECTION_TASKS: usize = 25;
+
+#[derive(Debug)]
+pub struct DigestSection {
+    pub title: String,
+    pub tasks: Vec<Task>,
+}
+
+/// Everything rendered into one digest email.
+#[derive(Debug)]
+pub struct Digest {
+    pub date: NaiveDate,
+    pub frequency: DigestFrequency,
+    pub today: Vec<Task>,
+    pub overdue: Vec<Task>,
+    pub review_due_count: usize,
+    pub filter: Option<DigestSection>,
+    pub stats: Stats,
+}
+
+impl Digest {
+    pub fn subject(&self) -> String {
+        let kind = match self.frequency {
+            DigestFrequency::Weekly => "Weekly digest",
+            _ => "Daily digest",
+        };
+        format!("{kind} for {}", self.date.format("%A, %b %-d"))
+    }
+}
+
+pub struct DigestService;
+
+impl DigestService {
+    /// Builds the digest for `user_id` as of `now` in the user's timezone.
+    pub async fn build(pool: &DbPool, user_id: i64, now: DateTime<Utc>) -> ServiceResult<Digest> {
+        let settings = crate::UserService::get_settings(pool, user_id).await?;
+        let tz: chrono_tz::Tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+        let date = now.with_timezone(&tz).date_naive();
+        let end_of_day = date
+            .succ_opt()
+            .and_then(|d| tz.from_local_datetime(&d.and_hms_opt(0, 0, 0)?).earliest())
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or(now + Duration::days(1));
+
+        let today = TaskService::today(pool, user_id, end_of_day, date).await?;
+        let mut conn = pool.get().await?;
+        let overdue_rows: Vec<TaskRow> = tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::completed_at.is_null())
+            .filter(tasks::deleted_at.is_null())
+            .filter(tasks::due_date.lt(date))
+            .order((tasks::due_date.asc(), tasks::sort_key.asc()))
+            .select(TaskRow::as_select())
+            .load(&mut conn)
+            .await?;
+        drop(conn);
+
+        let review_due_count = TaskService::list(
+            pool,
+            user_id,
+            &TaskFilter {
+                review_due: Some(true),
+                ..Default::default()
+            },
+        )
+        .await?
+        .len();
+
+        let filter = match settings.digest_filter_id {
+            Some(id) => match FilterService::get_by_id(pool, user_id, id).await {
+                Ok(saved) => {
+                    let tasks = TaskService::execute_dsl_filter(pool, user_id, &saved.query)
+                        .await?
+                        .into_iter()
+                        .filter(|t| t.completed_at.is_none())
+                        .collect();
+                    Some(DigestSection {
+                        title: saved.title,
+                        tasks,
+                    })
+                }
+                // The filter was deleted since it was picked; leave it out.
+                Err(crate::ServiceError::NotFound(_)) => None,
+                Err(e) => return Err(e),
+            },
+            None => None,
+        };
+
+        Ok(Digest {
+            date,
+            frequency: settings.digest_frequency,
+            today,
+            overdue: TaskService::load_with_meta(pool, overdue_rows).await?,
+            review_due_count,
+            filter,
+            stats: StatsService::get_stats(pool, user_id).await?,
+        })
+    }
+
+    /// The local date a digest should be sent for at `now`, or `None` if
+    /// none is due (disabled, wrong weekday, outside the send window, or
+    /// already sent today).
+    pub fn due_on(
+        settings: &UserSettings,
+        sent_on: Option<NaiveDate>,
+        now: DateTime<Utc>,
+    ) -> Option<NaiveDate> {
+        let tz: chrono_tz::Tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+        let local = now.with_timezone(&tz);
+        let date = local.date_naive();
+        let in_window = (settings.digest_hour as u32
+            ..settings.digest_hour as u32 + SEND_WINDOW_HOURS)
+            .contains(&local.hour());
+        let on_day = match settings.digest_frequency {
+            DigestFrequency::Off => false,
+            DigestFrequency::Daily => true,
+            DigestFrequency::Weekly => date.weekday() == Weekday::Mon,
+        };
+        (on_day && in_window && sent_on != Some(date)).then_some(date)
+    }
+
+    pub fn render_text(digest: &Digest) -> String {
+        let mut out = format!("{}\n\n", digest.subject());
+        let mut section = |title: &str, tasks: &[Task]| {
+            if tasks.is_empty() {
+                return;
+            }
+            let _ = writeln!(out, "{title} ({})", tasks.len());
+            for task in tasks.iter().take(MAX_SECTION_TASKS) {
+                let _ = writeln!(out, "  - {}", task_line(task));
+            }
+            if tasks.len() > MAX_SECTION_TASKS {
+                let _ = writeln!(out, "  ... and {} more", tasks.len() - MAX_SECTION_TASKS);
+            }
+            out.push('\n');
+        };
+        section("Today", &digest.today);
+        section("Overdue", &digest.overdue);
+        if let Some(ref filter) = digest.filter {
+            section(&filter.title, &filter.tasks);
+        }
+        let _ = writeln!(out, "{} tasks due for review", digest.review_due_count);
+        let _ = writeln!(
+            out,
+            "{} open, {} completed this week",
+            digest.stats.total_open, digest.stats.completed_week
+        );
+        out
+    }
+
+    pub fn render_html(digest: &Digest) -> String {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{subject}</title></head>\
+             <body style=\"font-family:-apple-system,Segoe UI,sans-serif;color:#1f2937;\
+             max-width:560px;margin:0 auto;padding:24px\">\
+             <h1 style=\"font-size:20px;margin:0 0 16px\">{subject}</h1>",
+            subject = escape_html(&digest.subject()),
+        );
+        let mut section = |title: &str, tasks: &[Task]| {
+            if tasks.is_empty() {
+                return;
+            }
+            let _ = write!(
+                out,
+                "<h2 style=\"font-size:15px;margin:20px 0 8px\">{} ({})</h2>\
+                 <ul style=\"padding-left:20px;margin:0\">",
+                escape_html(title),
+                tasks.len()
+            );
+            for task in tasks.iter().take(MAX_SECTION_TASKS) {
+                let _ = write!(
+                    out,
+                    "<li style=\"margin:4px 0\">{}</li>",
+                    escape_html(&task_line(task))
+                );
+            }
+            if tasks.len() > MAX_SECTION_TASKS {
+                let _ = write!(
+                    out,
+                    "<li style=\"color:#6b7280\">and {} more</li>",
+                    tasks.len() - MAX_SECTION_TASKS
+                );
+            }
+            out.push_str("</ul>");
+        };
+        section("Today", &digest.today);
+        section("Overdue", &digest.overdue);
+        if let Some(ref filter) = digest.filter {
+            section(&filter.title, &filter.tasks);
+        }
+        if digest.today.is_empty() && digest.overdue.is_empty() {
+            out.push_str("<p>Nothing scheduled for today.</p>");
+        }
+        let _ = write!(
+            out,
+            "<p style=\"margin-top:24px;color:#6b7280;font-size:13px\">\
+             {} tasks due for review · {} open · {} completed this week</p></body></html>",
+            digest.review_due_count, digest.stats.total_open, digest.stats.completed_week
+        );
+        out
+    }
+
+    pub async fn mark_sent(pool: &DbPool, user_id: i64, date: NaiveDate) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        diesel::update(users::table.filter(users::id.eq(user_id)))
+            .set(users::digest_sent_on.eq(Some(date)))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Periodically sends digests to every user whose send time has come.
+pub struct DigestScheduler {
+    pool: DbPool,
+    clock: Arc<dyn Clock>,
+    smtp: SmtpNotifier,
+}
+
+impl DigestScheduler {
+    pub fn new(pool: DbPool, clock: Arc<dyn Clock>, smtp: SmtpNotifier) -> Self {
+        Self { pool, clock, smtp }
+    }
+
+    /// Sends every digest due right now; returns how many were sent.
+    pub async fn tick(&self) -> ServiceResult<usize> {
+        let now = self.clock.now();
+        let user_rows: Vec<UserRow> = {
+            let mut conn = self.pool.get().await?;
+            users::table
+                .select(UserRow::as_select())
+                .load(&mut conn)
+                .await?
+        };
+
+        let mut sent = 0;
+        for user in user_rows {
+            let settings: UserSettings = serde_json::from_value(user.settings).unwrap_or_default();
+            let Some(date) = DigestService::due_on(&settings, user.digest_sent_on, now) else {
+                continue;
+            };
+            // One user's broken data or mailbox must not hold up the rest.
+            let digest = match DigestService::build(&self.pool, user.id, now).await {
+                Ok(digest) => Some(digest),
+                Err(e) => {
+                    tracing::warn!(user_id = user.id, error = %e, "Digest build failed");
+                    None
+                }
+            };
+            if let Some(digest) = digest {
+                let result = self
+                    .smtp
+                    .send_mail(
+                        &user.email,
+                        &digest.subject(),
+                        DigestService::render_text(&digest),
+                        Some(DigestService::render_html(&digest)),
+                    )
+                    .await;
+                match result {
+                    Ok(()) => sent += 1,
+                    Err(e) => {
+                        tracing::warn!(user_id = user.id, error = %e, "Digest delivery failed")
+                    }
+                }
+            }
+            // Mark even on failure so a broken relay doesn't resend every tick.
+            if let Err(e) = DigestService::mark_sent(&self.pool, user.id, date).await {
+                tracing::warn!(user_id = user.id, error = %e, "Marking digest sent failed");
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Runs [`Self::tick`] every `period` on the current runtime.
+    pub fn spawn(self, period: StdDuration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.tick().await {
+                    tracing::error!(error = %e, "Digest scheduler tick failed");
+                }
+            }
+        })
+    }
+}
+
+fn task_line(task: &Task) -> String {
+    let mut line = task.title.clone();
+    if let Some(ref project) = task.project_title {
+        let _ = write!(line, " [{project}]");
+    }
+    if let Some(due) = task.due_date {
+        let _ = write!(line, " (due {})", due.format("%b %-d"));
+    }
+    line
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::MockClock;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn settings(frequency: DigestFrequency) -> UserSettings {
+        UserSettings {
+            timezone: "Europe/Berlin".into(),
+            digest_frequency: frequency,
+            digest_hour: 7,
+            ..Default::default()
+        }
+    }
+
+    fn task(title: &str, due: Option<NaiveDate>) -> Task {
+        Task {
+            id: 1,
+            project_id: None,
+            parent_id: None,
+            user_id: 1,
+            title: title.into(),
+            body: None,
+            sort_key: "a".into(),
+            sequential_limit: 1,
+            start_at: None,
+            due_date: due,
+            completed_at: None,
+            reviewed_at: None,
+            created_at: utc("2026-03-01T00:00:00Z"),
+            updated_at: utc("2026-03-01T00:00:00Z"),
+            recurrence_type: None,
+            recurrence_rule: None,
+            recurrence_exdates: vec![],
+            is_url_fetching: None,
+            someday: false,
+            series_id: None,
+            column_id: None,
+            waiting_for: None,
+            follow_up_date: None,
+            priority: None,
+            estimate_minutes: None,
+            section_id: None,
+            project_title: Some("Home".into()),
+            tags: vec![],
+            subtask_count: 0,
+            completed_subtask_count: 0,
+            blocked_by: vec![],
+            spent_minutes: 0,
+            tracking_since: None,
+        }
+    }
+
+    #[test]
+    fn test_due_on_follows_local_time() {
+        let daily = settings(DigestFrequency::Daily);
+        // Tuesday 05:30 UTC is 06:30 in Berlin: too early.
+        let clock = MockClock::new(utc("2026-03-10T05:30:00Z"));
+        assert_eq!(DigestService::due_on(&daily, None, clock.now()), None);
+
+        clock.advance(Duration::hours(1));
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10);
+        assert_eq!(DigestService::due_on(&daily, None, clock.now()), today);
+        // Already sent today.
+        assert_eq!(DigestService::due_on(&daily, today, clock.now()), None);
+
+        // Past the send window.
+        clock.advance(Duration::hours(5));
+        assert_eq!(DigestService::due_on(&daily, None, clock.now()), None);
+
+        let off = settings(DigestFrequency::Off);
+        assert_eq!(
+            DigestService::due_on(&off, None, utc("2026-03-10T06:30:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_weekly_digest_only_on_monday() {
+        let weekly = settings(DigestFrequency::Weekly);
+        assert_eq!(
+            DigestService::due_on(&weekly, None, utc("2026-03-10T06:30:00Z")),
+            None
+        );
+        assert_eq!(
+            DigestService::due_on(&weekly, None, utc("2026-03-09T06:30:00Z")),
+            NaiveDate::from_ymd_opt(2026, 3, 9)
+        );
+    }
+
+    #[test]
+    fn test_render_escapes_and_summarises() {
+        let digest = Digest {
+            date: NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+            frequency: DigestFrequency::Daily,
+            today: vec![task("Call <Bob> & Alice", None)],
+            overdue: vec![task("File taxes", NaiveDate::from_ymd_opt(2026, 3, 1))],
+            review_due_count: 4,
+            filter: None,
+            stats: Stats {
+                created_today: 0,
+                completed_today: 0,
+                created_week: 0,
+                completed_week: 3,
+                total_open: 12,
+                total_completed: 40,
+            },
+        };
+        assert_eq!(digest.subject(), "Daily digest for Tuesday, Mar 10");
+
+        let html = DigestService::render_html(&digest);
+        assert!(html.contains("Call &lt;Bob&gt; &amp; Alice [Home]"));
+        assert!(html.contains("File taxes [Home] (due Mar 1)"));
+        assert!(html.contains("4 tasks due for review"));
+
+        let text = DigestService::render_text(&digest);
+        assert!(text.contains("Overdue (1)\n  - File taxes [Home] (due Mar 1)"));
+        assert!(text.contains("12 open, 3 completed this week"));
+    }
+}
@@ -4,7 +4,7 @@ use north_dto::DslSuggestion;
 use crate::filter::context::{detect_completion_context, DslCompletionContext};
 use crate::filter::dsl::FilterField;
 use crate::filter::field_registry::TaskFieldRegistry;
-use crate::{ProjectService, ServiceResult, TagService};
+use crate::{AreaService, ProjectService, ServiceResult, TagService};
 
 pub async fn get_dsl_suggestions(
     pool: &DbPool,
@@ -60,6 +60,7 @@ pub async fn get_dsl_suggestions(
                 FilterField::Project => {
                     let filter = north_dto::ProjectFilter {
                         status: Some(north_dto::ProjectStatus::Active),
+                        ..Default::default()
                     };
                     let projects = ProjectService::list(pool, user_id, &filter).await?;
                     Ok(projects
@@ -76,6 +77,23 @@ pub async fn get_dsl_suggestions(
                         })
                         .collect())
                 }
+                FilterField::Area => {
+                    let areas = AreaService::list(pool, user_id).await?;
+                    Ok(areas
+                        .into_iter()
+                        .filter(|a| a.status == north_dto::ProjectStatus::Active)
+                        .filter(|a| a.title.to_lowercase().starts_with(&lower))
+                        .map(|a| {
+                            let value = format!("'{}'", a.title);
+                            DslSuggestion {
+                                label: a.title,
+                                value,
+                                color: String::new(),
+                                start,
+                            }
+                        })
+                        .collect())
+                }
                 FilterField::Status => {
                     let statuses = ["ACTIVE", "OPEN", "COMPLETED", "DONE"];
                     Ok(statuses
@@ -89,6 +107,38 @@ pub async fn get_dsl_suggestions(
                         })
                         .collect())
                 }
+                FilterField::Blocked | FilterField::Blocks | FilterField::Waiting => {
+                    Ok(["true", "false"]
+                        .iter()
+                        .filter(|v| v.starts_with(&lower))
+                        .map(|v| DslSuggestion {
+                            label: v.to_string(),
+                            value: v.to_string(),
+                            color: String::new(),
+                            start,
+                        })
+                        .collect())
+                }
+                FilterField::Priority => Ok(["1", "2", "3", "4"]
+                    .iter()
+                    .filter(|v| v.starts_with(&lower))
+                    .map(|v| DslSuggestion {
+                        label: format!("P{v}"),
+                        value: v.to_string(),
+                        color: String::new(),
+                        start,
+                    })
+                    .collect()),
+                FilterField::Estimate | FilterField::Spent => Ok(["15m", "30m", "1h", "2h", "4h"]
+                    .iter()
+                    .filter(|v| v.starts_with(&lower))
+                    .map(|v| DslSuggestion {
+                        label: v.to_string(),
+                        value: format!("'{v}'"),
+                        color: String::new(),
+                        start,
+                    })
+                    .collect()),
                 _ => Ok(vec![]),
             }
         }
@@ -25,7 +25,23 @@ pub struct Condition {
 pub enum FilterField {
     Title,
     Body,
+    /// Text of any comment on the task.
+    Comment,
+    /// Whether the task waits on an open task, or that task's title.
+    Blocked,
+    /// Whether the task holds up an open task, or that task's title.
+    Blocks,
+    /// Whether the task is waiting on someone, or who.
+    Waiting,
+    /// 1 (highest) to 4; compares numerically, so `priority <= 2` means P1/P2.
+    Priority,
+    /// Estimated duration in minutes, or a duration string such as `'1h30m'`.
+    Estimate,
+    /// Tracked time including subtasks, compared like `estimate`.
+    Spent,
     Project,
+    /// Title of the area the task's project belongs to.
+    Area,
     Tags,
     Status,
     DueDate,
@@ -25,12 +25,23 @@ impl TaskFieldRegistry {
             updated_at, // FilterField::Updated
             recurrence_type,
             recurrence_rule,
+            recurrence_exdates,
             is_url_fetching,
             someday,
+            series_id,
+            column_id,
+            waiting_for,      // FilterField::Waiting
+            follow_up_date,   // FilterField::Waiting (shown alongside)
+            priority,         // FilterField::Priority
+            estimate_minutes, // FilterField::Estimate
+            section_id,
             project_title, // FilterField::Project (enriched)
             tags,          // FilterField::Tags (enriched)
             subtask_count,
             completed_subtask_count,
+            blocked_by,    // FilterField::Blocked / FilterField::Blocks
+            spent_minutes, // FilterField::Spent (enriched)
+            tracking_since,
         } = task;
     }
 
@@ -38,7 +49,15 @@ impl TaskFieldRegistry {
         match s.to_lowercase().as_str() {
             "title" => Some(FilterField::Title),
             "body" => Some(FilterField::Body),
+            "comment" | "comments" => Some(FilterField::Comment),
+            "blocked" | "blocked_by" => Some(FilterField::Blocked),
+            "blocks" => Some(FilterField::Blocks),
+            "waiting" | "waiting_for" => Some(FilterField::Waiting),
+            "priority" => Some(FilterField::Priority),
+            "estimate" => Some(FilterField::Estimate),
+            "spent" => Some(FilterField::Spent),
             "project" => Some(FilterField::Project),
+            "area" => Some(FilterField::Area),
             "tags" | "tag" => Some(FilterField::Tags),
             "status" => Some(FilterField::Status),
             "due_date" | "due" => Some(FilterField::DueDate),
@@ -51,7 +70,8 @@ impl TaskFieldRegistry {
 
     pub fn field_names() -> &'static [&'static str] {
         &[
-            "title", "body", "project", "tags", "status", "due_date", "start_at", "created",
+            "title", "body", "comment", "blocked", "blocks", "waiting", "priority", "estimate",
+            "spent", "project", "area", "tags", "status", "due_date", "start_at", "created",
             "updated",
         ]
     }
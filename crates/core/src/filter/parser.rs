@@ -711,6 +711,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_comment_field() {
+        let result = parse_filter("comment =~ '*blocked*'").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Condition(Condition {
+                field: FilterField::Comment,
+                op: FilterOp::GlobMatch,
+                value: FilterValue::String("*blocked*".into()),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_dependency_fields() {
+        let result = parse_filter("blocked = true").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Condition(Condition {
+                field: FilterField::Blocked,
+                op: FilterOp::Eq,
+                value: FilterValue::Bool(true),
+            }))
+        );
+        let result = parse_filter("blocks =~ 'deploy*'").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Condition(Condition {
+                field: FilterField::Blocks,
+                op: FilterOp::GlobMatch,
+                value: FilterValue::String("deploy*".into()),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_waiting_field() {
+        let result = parse_filter("waiting = true").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Condition(Condition {
+                field: FilterField::Waiting,
+                op: FilterOp::Eq,
+                value: FilterValue::Bool(true),
+            }))
+        );
+        let result = parse_filter("waiting_for in ['Sam', 'Alex']").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Condition(Condition {
+                field: FilterField::Waiting,
+                op: FilterOp::In,
+                value: FilterValue::Array(vec![
+                    FilterValue::String("Sam".into()),
+                    FilterValue::String("Alex".into()),
+                ]),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_priority_field() {
+        let result = parse_filter("priority <= 2 ORDER BY priority").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Condition(Condition {
+                field: FilterField::Priority,
+                op: FilterOp::Lte,
+                value: FilterValue::Number(2.0),
+            }))
+        );
+        assert_eq!(
+            result.order_by,
+            Some(OrderBy {
+                field: FilterField::Priority,
+                direction: SortDirection::Asc,
+            })
+        );
+    }
+
+    #[test]
+    fn test_estimate_and_spent_fields() {
+        let result = parse_filter("estimate > '1h' AND spent < 30 ORDER BY estimate").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::And(
+                Box::new(FilterExpr::Condition(Condition {
+                    field: FilterField::Estimate,
+                    op: FilterOp::Gt,
+                    value: FilterValue::String("1h".into()),
+                })),
+                Box::new(FilterExpr::Condition(Condition {
+                    field: FilterField::Spent,
+                    op: FilterOp::Lt,
+                    value: FilterValue::Number(30.0),
+                })),
+            ))
+        );
+        assert_eq!(
+            result.order_by,
+            Some(OrderBy {
+                field: FilterField::Estimate,
+                direction: SortDirection::Asc,
+            })
+        );
+    }
+
+    #[test]
+    fn test_area_field() {
+        let result = parse_filter("area = 'Work' OR area is null").unwrap();
+        assert_eq!(
+            result.expression,
+            Some(FilterExpr::Or(
+                Box::new(FilterExpr::Condition(Condition {
+                    field: FilterField::Area,
+                    op: FilterOp::Eq,
+                    value: FilterValue::String("Work".into()),
+                })),
+                Box::new(FilterExpr::Condition(Condition {
+                    field: FilterField::Area,
+                    op: FilterOp::Is,
+                    value: FilterValue::Null,
+                })),
+            ))
+        );
+    }
+
     #[test]
     fn test_and_expression() {
         let result = parse_filter("status = 'ACTIVE' AND project = 'My Project'").unwrap();
@@ -5,11 +5,19 @@ pub struct ParsedText {
     pub cleaned: String,
     pub tags: Vec<String>,
     pub project: Option<String>,
+    /// From a standalone `!1`–`!4` word; the last one wins.
+    #[serde(default)]
+    pub priority: Option<i16>,
+    /// From a `~30m` / `~1h30m` word; the last one wins.
+    #[serde(default)]
+    pub estimate_minutes: Option<i32>,
 }
 
 pub fn parse_tokens(text: &str) -> ParsedText {
     let mut tags = Vec::new();
     let mut project: Option<String> = None;
+    let mut priority: Option<i16> = None;
+    let mut estimate_minutes: Option<i32> = None;
     let mut cleaned_parts = Vec::new();
     let mut chars = text.chars().peekable();
     let mut current_word = String::new();
@@ -18,7 +26,12 @@ pub fn parse_tokens(text: &str) -> ParsedText {
     while let Some(ch) = chars.next() {
         if ch.is_whitespace() {
             if !current_word.is_empty() {
-                cleaned_parts.push(current_word.clone());
+                push_word(
+                    &current_word,
+                    &mut cleaned_parts,
+                    &mut priority,
+                    &mut estimate_minutes,
+                );
                 current_word.clear();
             }
             at_word_start = true;
@@ -34,6 +47,7 @@ pub fn parse_tokens(text: &str) -> ParsedText {
                     || next == '-'
                     || next == ':'
                     || next == '.'
+                    || (next == '/' && trigger == '@')
                 {
                     token.push(chars.next().unwrap());
                 } else {
@@ -65,7 +79,12 @@ pub fn parse_tokens(text: &str) -> ParsedText {
     }
 
     if !current_word.is_empty() {
-        cleaned_parts.push(current_word);
+        push_word(
+            &current_word,
+            &mut cleaned_parts,
+            &mut priority,
+            &mut estimate_minutes,
+        );
     }
 
     let cleaned = cleaned_parts.join(" ");
@@ -74,6 +93,30 @@ pub fn parse_tokens(text: &str) -> ParsedText {
         cleaned,
         tags,
         project,
+        priority,
+        estimate_minutes,
+    }
+}
+
+/// Keeps a plain word, or takes it as the priority if it is `!1`–`!4` or as
+/// the estimate if it is `~` followed by a duration.
+fn push_word(
+    word: &str,
+    cleaned_parts: &mut Vec<String>,
+    priority: &mut Option<i16>,
+    estimate_minutes: &mut Option<i32>,
+) {
+    if let Some(minutes) = word.strip_prefix('~').and_then(north_dto::parse_duration) {
+        *estimate_minutes = Some(minutes);
+        return;
+    }
+    let level = word
+        .strip_prefix('!')
+        .and_then(|n| n.parse::<i16>().ok())
+        .filter(|n| north_dto::PRIORITY_LEVELS.contains(n));
+    match level {
+        Some(n) if word.len() == 2 => *priority = Some(n),
+        _ => cleaned_parts.push(word.to_string()),
     }
 }
 
@@ -127,6 +170,13 @@ mod tests {
         assert_eq!(result.tags, vec!["foo"]);
     }
 
+    #[test]
+    fn test_area_project_token() {
+        let result = parse_tokens("Task @Work/Launch");
+        assert_eq!(result.cleaned, "Task");
+        assert_eq!(result.project, Some("Work/Launch".to_string()));
+    }
+
     #[test]
     fn test_last_project_wins() {
         let result = parse_tokens("Task @First @Second");
@@ -176,6 +226,41 @@ mod tests {
         assert!(result.tags.is_empty());
     }
 
+    #[test]
+    fn test_priority() {
+        let result = parse_tokens("Call the bank !1 #finance");
+        assert_eq!(result.cleaned, "Call the bank");
+        assert_eq!(result.tags, vec!["finance"]);
+        assert_eq!(result.priority, Some(1));
+
+        let result = parse_tokens("!4 Water plants !2");
+        assert_eq!(result.cleaned, "Water plants");
+        assert_eq!(result.priority, Some(2));
+    }
+
+    #[test]
+    fn test_estimate() {
+        let result = parse_tokens("Write report ~1h30m !2");
+        assert_eq!(result.cleaned, "Write report");
+        assert_eq!(result.estimate_minutes, Some(90));
+        assert_eq!(result.priority, Some(2));
+
+        let result = parse_tokens("~15m Tidy desk");
+        assert_eq!(result.cleaned, "Tidy desk");
+        assert_eq!(result.estimate_minutes, Some(15));
+
+        let result = parse_tokens("Roughly ~ ~soon ~0m");
+        assert_eq!(result.cleaned, "Roughly ~ ~soon ~0m");
+        assert!(result.estimate_minutes.is_none());
+    }
+
+    #[test]
+    fn test_priority_out_of_range() {
+        let result = parse_tokens("Wow! !5 !0 !12 !");
+        assert_eq!(result.cleaned, "Wow! !5 !0 !12 !");
+        assert!(result.priority.is_none());
+    }
+
     #[test]
     fn test_only_tokens() {
         let result = parse_tokens("#shopping @Personal");
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
-use north_db::schema::{projects, tags, task_tags, tasks};
+use north_db::schema::{areas, projects, tags, task_comments, task_dependencies, task_tags, tasks};
 use north_db::DbPool;
 
 use crate::filter::dsl::{Condition, FilterExpr, FilterField, FilterOp, FilterValue};
@@ -41,6 +41,7 @@ async fn all_user_task_ids(pool: &DbPool, user_id: i64) -> ServiceResult<HashSet
     let ids: Vec<i64> = tasks::table
         .filter(tasks::user_id.eq(user_id))
         .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
         .select(tasks::id)
         .load(&mut conn)
         .await?;
@@ -55,8 +56,16 @@ async fn eval_condition(
     match cond.field {
         FilterField::Title => eval_text_field(pool, user_id, cond, TextField::Title).await,
         FilterField::Body => eval_text_field(pool, user_id, cond, TextField::Body).await,
+        FilterField::Comment => eval_comment(pool, user_id, cond).await,
+        FilterField::Blocked => eval_dependency(pool, user_id, cond, Dependency::Blocked).await,
+        FilterField::Blocks => eval_dependency(pool, user_id, cond, Dependency::Blocks).await,
+        FilterField::Waiting => eval_waiting(pool, user_id, cond).await,
+        FilterField::Priority => eval_priority(pool, user_id, cond).await,
+        FilterField::Estimate => eval_estimate(pool, user_id, cond).await,
+        FilterField::Spent => eval_spent(pool, user_id, cond).await,
         FilterField::Status => eval_status(pool, user_id, cond).await,
         FilterField::Project => eval_project(pool, user_id, cond).await,
+        FilterField::Area => eval_area(pool, user_id, cond).await,
         FilterField::Tags => eval_tags(pool, user_id, cond).await,
         FilterField::DueDate => eval_date_field(pool, user_id, cond, DateField::DueDate).await,
         FilterField::StartAt => eval_date_field(pool, user_id, cond, DateField::StartAt).await,
@@ -110,6 +119,7 @@ async fn eval_text_field(
     let mut query = tasks::table
         .filter(tasks::user_id.eq(user_id))
         .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
         .into_boxed();
 
     match (&field, &cond.op) {
@@ -157,6 +167,392 @@ async fn eval_text_field(
     Ok(ids.into_iter().collect())
 }
 
+/// Matches tasks by the text of their comments, including comments on
+/// subtasks. `=`/`~` match tasks with at least one matching comment;
+/// `!=`/`!~` match tasks with none. `is null` matches tasks without comments.
+async fn eval_comment(
+    pool: &DbPool,
+    user_id: i64,
+    cond: &Condition,
+) -> ServiceResult<HashSet<i64>> {
+    let mut conn = pool.get().await?;
+    let s = value_as_str(&cond.value).unwrap_or("");
+
+    let mut query = task_comments::table
+        .filter(task_comments::user_id.eq(user_id))
+        .into_boxed();
+    let negate = match &cond.op {
+        FilterOp::Eq => {
+            query = query.filter(task_comments::body.ilike(s));
+            false
+        }
+        FilterOp::Ne => {
+            query = query.filter(task_comments::body.ilike(s));
+            true
+        }
+        FilterOp::GlobMatch => {
+            query = query.filter(task_comments::body.ilike(glob_to_sql_like(s)));
+            false
+        }
+        FilterOp::GlobNotMatch => {
+            query = query.filter(task_comments::body.ilike(glob_to_sql_like(s)));
+            true
+        }
+        FilterOp::Is if cond.value == FilterValue::Null => true,
+        FilterOp::IsNot if cond.value == FilterValue::Null => false,
+        _ => return Ok(HashSet::new()),
+    };
+
+    let commented: Vec<(i64, Option<i64>)> = query
+        .inner_join(tasks::table)
+        .filter(tasks::deleted_at.is_null())
+        .select((tasks::id, tasks::parent_id))
+        .load(&mut conn)
+        .await?;
+    let matched = top_level_ids(&mut conn, commented).await?;
+
+    if negate {
+        let all = all_user_task_ids(pool, user_id).await?;
+        Ok(all.difference(&matched).copied().collect())
+    } else {
+        Ok(matched)
+    }
+}
+
+/// Matches tasks by who they're waiting on. `= true`/`is not null` match
+/// any waiting task; a name (`=`, `=~`, `in`) matches tasks waiting on that
+/// person. Negated forms match the remaining tasks.
+async fn eval_waiting(
+    pool: &DbPool,
+    user_id: i64,
+    cond: &Condition,
+) -> ServiceResult<HashSet<i64>> {
+    let mut conn = pool.get().await?;
+
+    let mut query = tasks::table
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
+        .filter(tasks::waiting_for.is_not_null())
+        .into_boxed();
+    let negate = match (&cond.op, &cond.value) {
+        (FilterOp::Eq, FilterValue::Bool(b)) => !b,
+        (FilterOp::Ne, FilterValue::Bool(b)) => *b,
+        (FilterOp::Is, FilterValue::Null) => true,
+        (FilterOp::IsNot, FilterValue::Null) => false,
+        (FilterOp::Eq, FilterValue::String(s)) => {
+            query = query.filter(tasks::waiting_for.ilike(s.clone()));
+            false
+        }
+        (FilterOp::Ne, FilterValue::String(s)) => {
+            query = query.filter(tasks::waiting_for.ilike(s.clone()));
+            true
+        }
+        (FilterOp::GlobMatch, FilterValue::String(s)) => {
+            query = query.filter(tasks::waiting_for.ilike(glob_to_sql_like(s)));
+            false
+        }
+        (FilterOp::GlobNotMatch, FilterValue::String(s)) => {
+            query = query.filter(tasks::waiting_for.ilike(glob_to_sql_like(s)));
+            true
+        }
+        (FilterOp::In, _) => {
+            query = query.filter(tasks::waiting_for.eq_any(value_as_strings(&cond.value)));
+            false
+        }
+        (FilterOp::NotIn, _) => {
+            query = query.filter(tasks::waiting_for.eq_any(value_as_strings(&cond.value)));
+            true
+        }
+        _ => return Ok(HashSet::new()),
+    };
+    let matched: HashSet<i64> = query
+        .select(tasks::id)
+        .load::<i64>(&mut conn)
+        .await?
+        .into_iter()
+        .collect();
+
+    if negate {
+        let all = all_user_task_ids(pool, user_id).await?;
+        Ok(all.difference(&matched).copied().collect())
+    } else {
+        Ok(matched)
+    }
+}
+
+/// A priority level from `1`, `'1'` or `'P1'`.
+fn value_as_priority(v: &FilterValue) -> Option<i16> {
+    let level = match v {
+        FilterValue::Number(n) if n.fract() == 0.0 => *n as i16,
+        FilterValue::String(s) => {
+            let s = s.trim();
+            let s = s
+                .strip_prefix('P')
+                .or_else(|| s.strip_prefix('p'))
+                .unwrap_or(s);
+            s.parse().ok()?
+        }
+        _ => return None,
+    };
+    north_dto::PRIORITY_LEVELS.contains(&level).then_some(level)
+}
+
+/// Matches tasks by priority level. Comparisons are numeric, so `< 3`
+/// means P1 and P2; tasks without a priority only match `is null` and `!=`.
+async fn eval_priority(
+    pool: &DbPool,
+    user_id: i64,
+    cond: &Condition,
+) -> ServiceResult<HashSet<i64>> {
+    let mut conn = pool.get().await?;
+
+    let mut query = tasks::table
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
+        .into_boxed();
+
+    match (&cond.op, &cond.value) {
+        (FilterOp::Is, FilterValue::Null) => query = query.filter(tasks::priority.is_null()),
+        (FilterOp::IsNot, FilterValue::Null) => query = query.filter(tasks::priority.is_not_null()),
+        (FilterOp::In | FilterOp::NotIn, FilterValue::Array(items)) => {
+            let levels: Vec<i16> = items.iter().filter_map(value_as_priority).collect();
+            if cond.op == FilterOp::In {
+                query = query.filter(tasks::priority.eq_any(levels));
+            } else {
+                query = query.filter(tasks::priority.ne_all(levels).or(tasks::priority.is_null()));
+            }
+        }
+        (op, value) => {
+            let Some(p) = value_as_priority(value) else {
+                return Ok(HashSet::new());
+            };
+            match op {
+                FilterOp::Eq => query = query.filter(tasks::priority.eq(p)),
+                FilterOp::Ne => {
+                    query = query.filter(tasks::priority.ne(p).or(tasks::priority.is_null()))
+                }
+                FilterOp::Gt => query = query.filter(tasks::priority.gt(p)),
+                FilterOp::Lt => query = query.filter(tasks::priority.lt(p)),
+                FilterOp::Gte => query = query.filter(tasks::priority.ge(p)),
+                FilterOp::Lte => query = query.filter(tasks::priority.le(p)),
+                _ => return Ok(HashSet::new()),
+            }
+        }
+    }
+
+    let ids: Vec<i64> = query.select(tasks::id).load(&mut conn).await?;
+    Ok(ids.into_iter().collect())
+}
+
+/// Minutes from a number (`30`) or a duration string (`'1h30m'`).
+fn value_as_minutes(v: &FilterValue) -> Option<i64> {
+    match v {
+        FilterValue::Number(n) if *n >= 0.0 => Some(n.round() as i64),
+        FilterValue::String(s) => north_dto::parse_duration(s).map(i64::from),
+        _ => None,
+    }
+}
+
+/// Matches tasks by their own estimate. Tasks without one only match
+/// `is null` and `!=`.
+async fn eval_estimate(
+    pool: &DbPool,
+    user_id: i64,
+    cond: &Condition,
+) -> ServiceResult<HashSet<i64>> {
+    let mut conn = pool.get().await?;
+
+    let mut query = tasks::table
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
+        .into_boxed();
+
+    match (&cond.op, &cond.value) {
+        (FilterOp::Is, FilterValue::Null) => {
+            query = query.filter(tasks::estimate_minutes.is_null())
+        }
+        (FilterOp::IsNot, FilterValue::Null) => {
+            query = query.filter(tasks::estimate_minutes.is_not_null())
+        }
+        (op, value) => {
+            let Some(m) = value_as_minutes(value).and_then(|m| i32::try_from(m).ok()) else {
+                return Ok(HashSet::new());
+            };
+            match op {
+                FilterOp::Eq => query = query.filter(tasks::estimate_minutes.eq(m)),
+                FilterOp::Ne => {
+                    query = query.filter(
+                        tasks::estimate_minutes
+                            .ne(m)
+                            .or(tasks::estimate_minutes.is_null()),
+                    )
+                }
+                FilterOp::Gt => query = query.filter(tasks::estimate_minutes.gt(m)),
+                FilterOp::Lt => query = query.filter(tasks::estimate_minutes.lt(m)),
+                FilterOp::Gte => query = query.filter(tasks::estimate_minutes.ge(m)),
+                FilterOp::Lte => query = query.filter(tasks::estimate_minutes.le(m)),
+                _ => return Ok(HashSet::new()),
+            }
+        }
+    }
+
+    let ids: Vec<i64> = query.select(tasks::id).load(&mut conn).await?;
+    Ok(ids.into_iter().collect())
+}
+
+/// Matches tasks by finished tracked time, subtasks included. Untracked
+/// tasks count as zero minutes.
+async fn eval_spent(pool: &DbPool, user_id: i64, cond: &Condition) -> ServiceResult<HashSet<i64>> {
+    let Some(m) = value_as_minutes(&cond.value) else {
+        return Ok(HashSet::new());
+    };
+    let mut conn = pool.get().await?;
+    let spent = crate::TimeEntryService::spent_minutes(&mut conn, &[user_id]).await?;
+    let ids: Vec<i64> = tasks::table
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
+        .select(tasks::id)
+        .load(&mut conn)
+        .await?;
+
+    Ok(ids
+        .into_iter()
+        .filter(|id| {
+            let s = spent.get(id).copied().unwrap_or(0);
+            match cond.op {
+                FilterOp::Eq => s == m,
+                FilterOp::Ne => s != m,
+                FilterOp::Gt => s > m,
+                FilterOp::Lt => s < m,
+                FilterOp::Gte => s >= m,
+                FilterOp::Lte => s <= m,
+                _ => false,
+            }
+        })
+        .collect())
+}
+
+enum Dependency {
+    Blocked,
+    Blocks,
+}
+
+/// Matches tasks by their open blockers (`blocked`) or by the open tasks
+/// waiting on them (`blocks`). `= true`/`is not null` match any such task;
+/// a title (`=`, `=~`, `in`) matches tasks linked to an open task with that
+/// title. Negated forms match the remaining tasks.
+async fn eval_dependency(
+    pool: &DbPool,
+    user_id: i64,
+    cond: &Condition,
+    side: Dependency,
+) -> ServiceResult<HashSet<i64>> {
+    let mut conn = pool.get().await?;
+
+    let mut others = tasks::table
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::deleted_at.is_null())
+        .filter(tasks::completed_at.is_null())
+        .into_boxed();
+    let negate = match (&cond.op, &cond.value) {
+        (FilterOp::Eq, FilterValue::Bool(b)) => !b,
+        (FilterOp::Ne, FilterValue::Bool(b)) => *b,
+        (FilterOp::Is, FilterValue::Null) => true,
+        (FilterOp::IsNot, FilterValue::Null) => false,
+        (FilterOp::Eq, FilterValue::String(s)) => {
+            others = others.filter(tasks::title.ilike(s.clone()));
+            false
+        }
+        (FilterOp::Ne, FilterValue::String(s)) => {
+            others = others.filter(tasks::title.ilike(s.clone()));
+            true
+        }
+        (FilterOp::GlobMatch, FilterValue::String(s)) => {
+            others = others.filter(tasks::title.ilike(glob_to_sql_like(s)));
+            false
+        }
+        (FilterOp::GlobNotMatch, FilterValue::String(s)) => {
+            others = others.filter(tasks::title.ilike(glob_to_sql_like(s)));
+            true
+        }
+        (FilterOp::In, _) => {
+            others = others.filter(tasks::title.eq_any(value_as_strings(&cond.value)));
+            false
+        }
+        (FilterOp::NotIn, _) => {
+            others = others.filter(tasks::title.eq_any(value_as_strings(&cond.value)));
+            true
+        }
+        _ => return Ok(HashSet::new()),
+    };
+    let other_ids: Vec<i64> = others.select(tasks::id).load(&mut conn).await?;
+
+    let linked: Vec<(i64, Option<i64>)> = match side {
+        Dependency::Blocked => {
+            task_dependencies::table
+                .inner_join(tasks::table.on(tasks::id.eq(task_dependencies::task_id)))
+                .filter(task_dependencies::blocker_id.eq_any(&other_ids))
+                .filter(tasks::deleted_at.is_null())
+                .select((tasks::id, tasks::parent_id))
+                .load(&mut conn)
+                .await?
+        }
+        Dependency::Blocks => {
+            task_dependencies::table
+                .inner_join(tasks::table.on(tasks::id.eq(task_dependencies::blocker_id)))
+                .filter(task_dependencies::task_id.eq_any(&other_ids))
+                .filter(tasks::deleted_at.is_null())
+                .select((tasks::id, tasks::parent_id))
+                .load(&mut conn)
+                .await?
+        }
+    };
+    let matched = top_level_ids(&mut conn, linked).await?;
+
+    if negate {
+        let all = all_user_task_ids(pool, user_id).await?;
+        Ok(all.difference(&matched).copied().collect())
+    } else {
+        Ok(matched)
+    }
+}
+
+/// Maps (task id, parent id) pairs to their top-level ancestors, since
+/// filters return top-level tasks only.
+async fn top_level_ids(
+    conn: &mut diesel_async::AsyncPgConnection,
+    mut pending: Vec<(i64, Option<i64>)>,
+) -> ServiceResult<HashSet<i64>> {
+    let mut result = HashSet::new();
+    while !pending.is_empty() {
+        let mut parents = Vec::new();
+        for (id, parent_id) in pending {
+            match parent_id {
+                Some(pid) => parents.push(pid),
+                None => {
+                    result.insert(id);
+                }
+            }
+        }
+        parents.sort_unstable();
+        parents.dedup();
+        pending = if parents.is_empty() {
+            Vec::new()
+        } else {
+            tasks::table
+                .filter(tasks::id.eq_any(&parents))
+                .select((tasks::id, tasks::parent_id))
+                .load(conn)
+                .await?
+        };
+    }
+    Ok(result)
+}
+
 async fn eval_status(pool: &DbPool, user_id: i64, cond: &Condition) -> ServiceResult<HashSet<i64>> {
     let mut conn = pool.get().await?;
     let s = value_as_str(&cond.value).unwrap_or("").to_uppercase();
@@ -164,6 +560,7 @@ async fn eval_status(pool: &DbPool, user_id: i64, cond: &Condition) -> ServiceRe
     let mut query = tasks::table
         .filter(tasks::user_id.eq(user_id))
         .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
         .into_boxed();
 
     let is_completed_check = matches!(s.as_str(), "COMPLETED" | "DONE");
@@ -220,6 +617,7 @@ async fn eval_project(
             let ids: Vec<i64> = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .filter(tasks::project_id.is_null())
                 .select(tasks::id)
                 .load(&mut conn)
@@ -230,6 +628,7 @@ async fn eval_project(
             let ids: Vec<i64> = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .filter(tasks::project_id.is_not_null())
                 .select(tasks::id)
                 .load(&mut conn)
@@ -261,6 +660,7 @@ async fn eval_project(
             let ids: Vec<i64> = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .filter(
                     tasks::project_id
                         .is_null()
@@ -292,6 +692,7 @@ async fn eval_project(
             let ids: Vec<i64> = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .filter(
                     tasks::project_id
                         .is_null()
@@ -317,6 +718,7 @@ async fn eval_project(
     let ids: Vec<i64> = tasks::table
         .filter(tasks::user_id.eq(user_id))
         .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
         .filter(tasks::project_id.eq_any(&project_ids))
         .select(tasks::id)
         .load(&mut conn)
@@ -324,6 +726,82 @@ async fn eval_project(
     Ok(ids.into_iter().collect())
 }
 
+/// Matches through the task's project: `area = 'Work'` finds tasks of any
+/// project in that area, `area is null` those outside every area.
+async fn eval_area(pool: &DbPool, user_id: i64, cond: &Condition) -> ServiceResult<HashSet<i64>> {
+    let mut conn = pool.get().await?;
+    let name = value_as_str(&cond.value).unwrap_or("");
+
+    let (area_ids, negate): (Vec<i64>, bool) = match cond.op {
+        FilterOp::Is | FilterOp::IsNot if cond.value == FilterValue::Null => {
+            let area_ids = areas::table
+                .filter(areas::user_id.eq(user_id))
+                .select(areas::id)
+                .load(&mut conn)
+                .await?;
+            (area_ids, cond.op == FilterOp::Is)
+        }
+        FilterOp::Eq | FilterOp::Ne => {
+            let area_ids = areas::table
+                .filter(areas::user_id.eq(user_id))
+                .filter(areas::title.ilike(name))
+                .select(areas::id)
+                .load(&mut conn)
+                .await?;
+            (area_ids, cond.op == FilterOp::Ne)
+        }
+        FilterOp::GlobMatch | FilterOp::GlobNotMatch => {
+            let area_ids = areas::table
+                .filter(areas::user_id.eq(user_id))
+                .filter(areas::title.ilike(glob_to_sql_like(name)))
+                .select(areas::id)
+                .load(&mut conn)
+                .await?;
+            (area_ids, cond.op == FilterOp::GlobNotMatch)
+        }
+        FilterOp::In | FilterOp::NotIn => {
+            let names = value_as_strings(&cond.value);
+            let area_ids = areas::table
+                .filter(areas::user_id.eq(user_id))
+                .filter(areas::title.eq_any(&names))
+                .select(areas::id)
+                .load(&mut conn)
+                .await?;
+            (area_ids, cond.op == FilterOp::NotIn)
+        }
+        _ => return Ok(HashSet::new()),
+    };
+
+    let project_ids: Vec<i64> = projects::table
+        .filter(projects::user_id.eq(user_id))
+        .filter(projects::area_id.eq_any(&area_ids))
+        .select(projects::id)
+        .load(&mut conn)
+        .await?;
+
+    let query = tasks::table
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::parent_id.is_null())
+        .filter(tasks::deleted_at.is_null())
+        .select(tasks::id);
+    let ids: Vec<i64> = if negate {
+        query
+            .filter(
+                tasks::project_id
+                    .is_null()
+                    .or(tasks::project_id.ne_all(&project_ids)),
+            )
+            .load(&mut conn)
+            .await?
+    } else {
+        query
+            .filter(tasks::project_id.eq_any(&project_ids))
+            .load(&mut conn)
+            .await?
+    };
+    Ok(ids.into_iter().collect())
+}
+
 async fn eval_tags(pool: &DbPool, user_id: i64, cond: &Condition) -> ServiceResult<HashSet<i64>> {
     let mut conn = pool.get().await?;
 
@@ -445,6 +923,7 @@ async fn eval_date_field(
         let mut query = tasks::table
             .filter(tasks::user_id.eq(user_id))
             .filter(tasks::parent_id.is_null())
+            .filter(tasks::deleted_at.is_null())
             .into_boxed();
 
         match (&field, &cond.op) {
@@ -478,6 +957,7 @@ async fn eval_date_field(
             let mut query = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .into_boxed();
 
             match cond.op {
@@ -503,6 +983,7 @@ async fn eval_date_field(
             let mut query = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .into_boxed();
 
             match cond.op {
@@ -528,6 +1009,7 @@ async fn eval_date_field(
             let mut query = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .into_boxed();
 
             match cond.op {
@@ -549,6 +1031,7 @@ async fn eval_date_field(
             let mut query = tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .into_boxed();
 
             match cond.op {
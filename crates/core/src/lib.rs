@@ -1,17 +1,43 @@
+pub mod area_service;
+pub mod attachment_service;
+pub mod digest_service;
 pub mod filter;
+pub mod notifications;
 pub mod project_service;
+pub mod reminder_service;
+pub mod search_service;
 pub mod stats_service;
 pub mod tag_service;
+pub mod task_comment_service;
+pub mod task_dependency_service;
+pub mod task_event_service;
 pub mod task_service;
+pub mod time_entry_service;
+pub mod trash_service;
 pub mod url_service;
 pub mod user_service;
+pub mod weekly_review_service;
 
+#[cfg(test)]
+mod test_support;
+
+pub use area_service::AreaService;
+pub use attachment_service::{AttachmentService, UploadDir};
+pub use digest_service::{DigestScheduler, DigestService};
 pub use filter::FilterService;
 pub use project_service::ProjectService;
+pub use reminder_service::{ReminderScheduler, ReminderService};
+pub use search_service::SearchService;
 pub use stats_service::StatsService;
 pub use tag_service::TagService;
+pub use task_comment_service::TaskCommentService;
+pub use task_dependency_service::TaskDependencyService;
+pub use task_event_service::TaskEventService;
 pub use task_service::TaskService;
+pub use time_entry_service::TimeEntryService;
+pub use trash_service::{TrashScheduler, TrashService};
 pub use user_service::UserService;
+pub use weekly_review_service::WeeklyReviewService;
 
 // Re-export DbPool so consumers only need north-core
 pub use north_db::DbPool;
@@ -32,6 +58,9 @@ pub enum ServiceError {
     #[error(transparent)]
     Diesel(#[from] diesel::result::Error),
 
+    #[error("File storage error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Connection pool error: {0}")]
     Pool(#[from] diesel_async::pooled_connection::deadpool::PoolError),
 }
@@ -0,0 +1,66 @@
+//! Outbound notification delivery. Each backend implements [`Notifier`];
+//! the reminder scheduler fans a [`Notification`] out to every backend the
+//! recipient has enabled.
+
+mod clock;
+mod smtp;
+mod web_push;
+mod webhook;
+
+pub use clock::{Clock, MockClock, SystemClock};
+pub use smtp::SmtpNotifier;
+pub use web_push::WebPushNotifier;
+pub use webhook::{WebhookNotifier, WebhookPolicy, WebhookTarget};
+
+use std::future::Future;
+use std::pin::Pin;
+
+use north_db::models::PushSubscriptionRow;
+use north_dto::UserSettings;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub task_id: Option<i64>,
+    pub title: String,
+    pub body: String,
+}
+
+/// Who a notification goes to, with everything a backend needs to decide
+/// whether and where to deliver it.
+#[derive(Debug, Clone)]
+pub struct Recipient {
+    pub user_id: i64,
+    pub email: String,
+    pub settings: UserSettings,
+    pub push_subscriptions: Vec<PushSubscriptionRow>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotifyError {
+    #[error("Invalid notifier configuration: {0}")]
+    Config(String),
+
+    #[error("Delivery failed: {0}")]
+    Delivery(String),
+
+    /// The push service reported these subscription endpoints as expired.
+    #[error("Push subscriptions gone: {}", .0.join(", "))]
+    SubscriptionsGone(Vec<String>),
+}
+
+/// What [`Notifier::send`] returns; boxed so backends can sit behind `dyn`.
+pub type NotifyFuture<'a> = Pin<Box<dyn Future<Output = Result<(), NotifyError>> + Send + 'a>>;
+
+pub trait Notifier: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Whether the recipient has opted into this backend.
+    fn enabled_for(&self, recipient: &Recipient) -> bool;
+
+    fn send<'a>(
+        &'a self,
+        recipient: &'a Recipient,
+        notification: &'a Notification,
+    ) -> NotifyFuture<'a>;
+}
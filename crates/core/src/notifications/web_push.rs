@@ -0,0 +1,121 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use web_push::{
+    ContentEncoding, PartialVapidSignatureBuilder, SubscriptionInfo, VapidSignatureBuilder,
+    WebPushMessageBuilder,
+};
+
+use super::{Notification, Notifier, NotifyError, NotifyFuture, Recipient, WebhookPolicy};
+
+/// Delivers browser push notifications signed with a VAPID key.
+///
+/// The key is the raw P-256 private key, base64url without padding — the
+/// format most VAPID generators print. Endpoints come from the browser,
+/// so they go through the same [`WebhookPolicy`] as webhook URLs.
+pub struct WebPushNotifier {
+    vapid: PartialVapidSignatureBuilder,
+    subject: String,
+    policy: WebhookPolicy,
+}
+
+/// Seconds a push service should hold an undelivered reminder.
+const PUSH_TTL: u32 = 60 * 60;
+
+impl WebPushNotifier {
+    pub fn new(
+        private_key: &str,
+        subject: &str,
+        policy: WebhookPolicy,
+    ) -> Result<Self, NotifyError> {
+        let vapid = VapidSignatureBuilder::from_base64_no_sub(private_key)
+            .map_err(|e| NotifyError::Config(format!("VAPID_PRIVATE_KEY: {e}")))?;
+        Ok(Self {
+            vapid,
+            subject: subject.to_string(),
+            policy,
+        })
+    }
+
+    /// Public key for the browser's `applicationServerKey`, base64url.
+    pub fn public_key(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.vapid.get_public_key())
+    }
+
+    async fn send_one(&self, info: &SubscriptionInfo, payload: &[u8]) -> Result<(), NotifyError> {
+        let mut signature = self.vapid.clone().add_sub_info(info);
+        signature.add_claim("sub", self.subject.as_str());
+        let signature = signature
+            .build()
+            .map_err(|e| NotifyError::Config(e.to_string()))?;
+
+        let mut builder = WebPushMessageBuilder::new(info);
+        builder.set_ttl(PUSH_TTL);
+        builder.set_vapid_signature(signature);
+        builder.set_payload(ContentEncoding::Aes128Gcm, payload);
+        let message = builder
+            .build()
+            .map_err(|e| NotifyError::Delivery(e.to_string()))?;
+
+        // web-push builds an `http` 0.2 request; replay it through reqwest.
+        let request = web_push::request_builder::build_request::<Vec<u8>>(message);
+        let target = self
+            .policy
+            .check(&request.uri().to_string())
+            .await
+            .map_err(NotifyError::Config)?;
+        let mut outbound = target.client()?.post(target.url);
+        for (name, value) in request.headers() {
+            if let Ok(value) = value.to_str() {
+                outbound = outbound.header(name.as_str(), value);
+            }
+        }
+        let resp = outbound
+            .body(request.into_body())
+            .send()
+            .await
+            .map_err(|e| NotifyError::Delivery(e.to_string()))?;
+
+        match resp.status().as_u16() {
+            404 | 410 => Err(NotifyError::SubscriptionsGone(vec![info.endpoint.clone()])),
+            s if (200..300).contains(&s) => Ok(()),
+            s => Err(NotifyError::Delivery(format!("push service returned {s}"))),
+        }
+    }
+}
+
+impl Notifier for WebPushNotifier {
+    fn name(&self) -> &'static str {
+        "push"
+    }
+
+    fn enabled_for(&self, recipient: &Recipient) -> bool {
+        recipient.settings.reminder_push && !recipient.push_subscriptions.is_empty()
+    }
+
+    fn send<'a>(
+        &'a self,
+        recipient: &'a Recipient,
+        notification: &'a Notification,
+    ) -> NotifyFuture<'a> {
+        Box::pin(async move {
+            let payload = serde_json::to_vec(notification)
+                .map_err(|e| NotifyError::Delivery(e.to_string()))?;
+
+            let mut gone = Vec::new();
+            let mut last_err = None;
+            for sub in &recipient.push_subscriptions {
+                let info = SubscriptionInfo::new(&sub.endpoint, &sub.p256dh, &sub.auth);
+                match self.send_one(&info, &payload).await {
+                    Ok(()) => {}
+                    Err(NotifyError::SubscriptionsGone(endpoints)) => gone.extend(endpoints),
+                    Err(e) => last_err = Some(e),
+                }
+            }
+
+            if !gone.is_empty() {
+                return Err(NotifyError::SubscriptionsGone(gone));
+            }
+            last_err.map_or(Ok(()), Err)
+        })
+    }
+}
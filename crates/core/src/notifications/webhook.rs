@@ -0,0 +1,254 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use chrono::Utc;
+use reqwest::Url;
+use serde::Serialize;
+
+use super::{Notification, Notifier, NotifyError, NotifyFuture, Recipient};
+
+/// Where the server may send webhooks. Hosts resolving to loopback,
+/// private, link-local or other non-public addresses are refused unless
+/// the operator allow-lists them in `WEBHOOK_ALLOWED_HOSTS` (comma
+/// separated host names).
+#[derive(Debug, Clone, Default)]
+pub struct WebhookPolicy {
+    allowed_hosts: Vec<String>,
+}
+
+/// A webhook URL that passed the policy, with the addresses it resolved to.
+#[derive(Debug)]
+pub struct WebhookTarget {
+    pub url: Url,
+    pub addrs: Vec<SocketAddr>,
+}
+
+impl WebhookPolicy {
+    pub fn new(allowed_hosts: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            allowed_hosts: allowed_hosts
+                .into_iter()
+                .map(|h| h.trim().to_ascii_lowercase())
+                .filter(|h| !h.is_empty())
+                .collect(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let hosts = std::env::var("WEBHOOK_ALLOWED_HOSTS").unwrap_or_default();
+        Self::new(hosts.split(',').map(str::to_string))
+    }
+
+    /// Parses `url` and resolves its host, refusing anything the server
+    /// should not call on a user's behalf.
+    pub async fn check(&self, url: &str) -> Result<WebhookTarget, String> {
+        let url = Url::parse(url).map_err(|_| "Webhook URL is not a valid URL".to_string())?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("Webhook URL must start with http:// or https://".into());
+        }
+        let port = url.port_or_known_default().unwrap_or(80);
+        let host = url
+            .host_str()
+            .ok_or("Webhook URL needs a host")?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_ascii_lowercase();
+        let addrs: Vec<SocketAddr> = match host.parse::<IpAddr>() {
+            Ok(ip) => vec![SocketAddr::new(ip, port)],
+            Err(_) => tokio::net::lookup_host((host.as_str(), port))
+                .await
+                .map_err(|_| format!("Could not resolve {host}"))?
+                .collect(),
+        };
+        if addrs.is_empty() {
+            return Err(format!("Could not resolve {host}"));
+        }
+        if !self.allowed_hosts.contains(&host) && !addrs.iter().all(|a| is_public(a.ip())) {
+            return Err("Webhook URL must point to a public address".into());
+        }
+        Ok(WebhookTarget { url, addrs })
+    }
+}
+
+impl WebhookTarget {
+    /// A client that only connects to the checked addresses and does not
+    /// follow redirects, so neither DNS nor the server can send the request
+    /// somewhere else.
+    pub fn client(&self) -> Result<reqwest::Client, NotifyError> {
+        let mut client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none());
+        if let Some(domain) = self.url.domain() {
+            client = client.resolve_to_addrs(domain, &self.addrs);
+        }
+        client
+            .build()
+            .map_err(|e| NotifyError::Config(e.to_string()))
+    }
+}
+
+/// Whether `ip` is a globally routable unicast address.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            !(v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_multicast()
+                || v4.is_documentation()
+                || a == 0
+                // Carrier-grade NAT, 100.64.0.0/10.
+                || (a == 100 && (64..128).contains(&b))
+                // Reserved, 240.0.0.0/4.
+                || a >= 240)
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_public(IpAddr::V4(v4));
+            }
+            let segments = v6.segments();
+            let embedded = |hi: u16, lo: u16| {
+                is_public(IpAddr::V4(Ipv4Addr::from(
+                    (u32::from(hi) << 16) | u32::from(lo),
+                )))
+            };
+            // NAT64, 64:ff9b::/96, carries the IPv4 target in its last 32 bits.
+            if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                return embedded(segments[6], segments[7]);
+            }
+            // 6to4, 2002::/16, carries it in the 32 bits after the prefix.
+            if segments[0] == 0x2002 {
+                return embedded(segments[1], segments[2]);
+            }
+            let first = segments[0];
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                // Unique local, fc00::/7.
+                || (first & 0xfe00) == 0xfc00
+                // Link-local, fe80::/10.
+                || (first & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// POSTs a JSON payload to the user's configured webhook URL.
+pub struct WebhookNotifier {
+    policy: WebhookPolicy,
+}
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    event: &'static str,
+    #[serde(flatten)]
+    notification: &'a Notification,
+    sent_at: chrono::DateTime<Utc>,
+}
+
+impl WebhookNotifier {
+    pub fn new(policy: WebhookPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn enabled_for(&self, recipient: &Recipient) -> bool {
+        recipient.settings.reminder_webhook_url.is_some()
+    }
+
+    fn send<'a>(
+        &'a self,
+        recipient: &'a Recipient,
+        notification: &'a Notification,
+    ) -> NotifyFuture<'a> {
+        Box::pin(async move {
+            let Some(url) = recipient.settings.reminder_webhook_url.as_deref() else {
+                return Ok(());
+            };
+            // Checked again on every send: DNS may have changed since the URL
+            // was saved.
+            let target = self.policy.check(url).await.map_err(NotifyError::Config)?;
+            let client = target.client()?;
+
+            let payload = WebhookPayload {
+                event: "reminder",
+                notification,
+                sent_at: Utc::now(),
+            };
+            client
+                .post(target.url)
+                .json(&payload)
+                .send()
+                .await
+                .and_then(|resp| resp.error_for_status())
+                .map(|_| ())
+                .map_err(|e| NotifyError::Delivery(e.to_string()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public(ip: &str) -> bool {
+        is_public(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn test_is_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fe80::1",
+            "fd00::1",
+            "::ffff:127.0.0.1",
+            "::ffff:10.0.0.1",
+            "64:ff9b::7f00:1",
+            "64:ff9b::a9fe:a9fe",
+            "2002:7f00:1::1",
+            "2002:c0a8:101::",
+        ] {
+            assert!(!public(ip), "{ip} should not be public");
+        }
+        for ip in [
+            "93.184.216.34",
+            "1.1.1.1",
+            "2606:4700::1111",
+            "::ffff:8.8.8.8",
+            "64:ff9b::808:808",
+            "2002:808:808::1",
+        ] {
+            assert!(public(ip), "{ip} should be public");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_policy_check() {
+        let policy = WebhookPolicy::default();
+        assert!(policy.check("ftp://example.com/hook").await.is_err());
+        assert!(policy.check("not a url").await.is_err());
+        assert!(policy.check("http://127.0.0.1:8080/hook").await.is_err());
+        assert!(policy.check("http://[::1]/hook").await.is_err());
+        assert!(policy.check("http://169.254.169.254/latest").await.is_err());
+        assert!(policy.check("https://1.1.1.1/hook").await.is_ok());
+
+        let policy = WebhookPolicy::new(["127.0.0.1".to_string()]);
+        assert!(policy.check("http://127.0.0.1:8080/hook").await.is_ok());
+    }
+}
@@ -1,13 +1,28 @@
-use diesel::dsl::max;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{NaiveDate, Utc};
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
-use north_db::models::{NewProject, ProjectChangeset, ProjectRow};
-use north_db::schema::projects;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use north_db::models::{
+    NewProject, NewProjectColumn, NewProjectSection, ProjectChangeset, ProjectColumnChangeset,
+    ProjectColumnRow, ProjectRow, ProjectSectionChangeset, ProjectSectionRow,
+};
+use north_db::schema::{
+    areas, project_columns, project_sections, projects, task_dependencies, tasks,
+};
 use north_db::sql_types::{ProjectStatusMapping, ProjectViewTypeMapping};
 use north_db::DbPool;
-use north_dto::{CreateProject, Project, ProjectFilter, ProjectViewType, UpdateProject};
+use north_dto::{
+    CreateProject, CreateProjectColumn, CreateProjectSection, MoveProject, Project, ProjectColumn,
+    ProjectFilter, ProjectSection, ProjectStatus, ProjectViewType, UpdateProject,
+    UpdateProjectColumn, UpdateProjectSection,
+};
+
+use crate::{AreaService, ServiceError, ServiceResult};
 
-use crate::{ServiceError, ServiceResult};
+/// Columns a project's board starts with when it switches to Kanban.
+const DEFAULT_COLUMNS: [&str; 3] = ["To do", "In progress", "Done"];
 
 pub struct ProjectService;
 
@@ -19,28 +34,66 @@ impl ProjectService {
     ) -> ServiceResult<Vec<Project>> {
         let mut conn = pool.get().await?;
         let mut query = projects::table
+            .left_join(areas::table)
             .filter(projects::user_id.eq(user_id))
-            .select(ProjectRow::as_select())
+            .filter(projects::deleted_at.is_null())
+            .select((ProjectRow::as_select(), areas::status.nullable()))
             .into_boxed();
-        if let Some(ref status) = filter.status {
-            query = query.filter(projects::status.eq(ProjectStatusMapping::from(status.clone())));
+        match filter.status {
+            Some(ProjectStatus::Active) => {
+                query = query
+                    .filter(projects::status.eq(ProjectStatusMapping::Active))
+                    .filter(
+                        areas::status
+                            .nullable()
+                            .is_distinct_from(ProjectStatusMapping::Archived),
+                    );
+            }
+            Some(ProjectStatus::Archived) => {
+                query = query.filter(
+                    projects::status
+                        .eq(ProjectStatusMapping::Archived)
+                        .or(areas::status
+                            .nullable()
+                            .is_not_distinct_from(ProjectStatusMapping::Archived)),
+                );
+            }
+            None => {}
+        }
+        if let Some(area_id) = filter.area_id {
+            query = query.filter(projects::area_id.eq(area_id));
         }
         query = query.order((projects::position.asc(), projects::created_at.asc()));
         let rows = query.load(&mut conn).await?;
-        Ok(rows.into_iter().map(Project::from).collect())
+        let mut projects: Vec<Project> = rows
+            .into_iter()
+            .map(|(row, area_status)| with_area_status(row, area_status))
+            .collect();
+        let (today, review_interval) = Self::user_calendar(pool, user_id).await?;
+        Self::fill_progress(&mut conn, &mut projects, today).await?;
+        if let Some(due) = filter.review_due {
+            projects.retain(|p| p.is_review_due(review_interval, today) == due);
+        }
+        Ok(projects)
     }
 
     pub async fn get_by_id(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<Project> {
         let mut conn = pool.get().await?;
-        let row = projects::table
+        let (row, area_status) = projects::table
+            .left_join(areas::table)
             .filter(projects::id.eq(id))
             .filter(projects::user_id.eq(user_id))
-            .select(ProjectRow::as_select())
+            .filter(projects::deleted_at.is_null())
+            .select((ProjectRow::as_select(), areas::status.nullable()))
             .first(&mut conn)
             .await
             .optional()?
             .ok_or_else(|| ServiceError::NotFound("Project not found".into()))?;
-        Ok(Project::from(row))
+        let mut projects = [with_area_status(row, area_status)];
+        let (today, _) = Self::user_calendar(pool, user_id).await?;
+        Self::fill_progress(&mut conn, &mut projects, today).await?;
+        let [project] = projects;
+        Ok(project)
     }
 
     pub async fn create(
@@ -49,10 +102,13 @@ impl ProjectService {
         input: &CreateProject,
     ) -> ServiceResult<Project> {
         let mut conn = pool.get().await?;
+        if let Some(area_id) = input.area_id {
+            AreaService::owned(&mut conn, user_id, area_id).await?;
+        }
 
         let max_pos: Option<i32> = projects::table
             .filter(projects::user_id.eq(user_id))
-            .select(max(projects::position))
+            .select(diesel::dsl::max(projects::position))
             .first(&mut conn)
             .await?;
         let position = max_pos.unwrap_or(-1) + 1;
@@ -66,12 +122,23 @@ impl ProjectService {
                 description: input.description.as_deref(),
                 view_type: ProjectViewTypeMapping::from(vt.clone()),
                 position,
+                area_id: input.area_id,
+                due_date: input.due_date,
+                outcome: input
+                    .outcome
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|o| !o.is_empty()),
             })
             .returning(ProjectRow::as_returning())
             .get_result(&mut conn)
             .await?;
 
-        Ok(Project::from(proj_row))
+        if *vt == ProjectViewType::Kanban {
+            Self::ensure_columns(&mut conn, proj_row.id).await?;
+        }
+
+        Self::get_by_id(pool, user_id, proj_row.id).await
     }
 
     pub async fn update(
@@ -80,18 +147,39 @@ impl ProjectService {
         id: i64,
         input: &UpdateProject,
     ) -> ServiceResult<Project> {
+        if input.review_interval_days.flatten().is_some_and(|d| d < 1) {
+            return Err(ServiceError::BadRequest(
+                "Review interval must be at least one day".into(),
+            ));
+        }
         let mut conn = pool.get().await?;
 
         // Verify ownership
         let existing = projects::table
             .filter(projects::id.eq(id))
             .filter(projects::user_id.eq(user_id))
+            .filter(projects::deleted_at.is_null())
             .select(ProjectRow::as_select())
             .first(&mut conn)
             .await
             .optional()?
             .ok_or_else(|| ServiceError::NotFound("Project not found".into()))?;
 
+        if let Some(Some(area_id)) = input.area_id {
+            AreaService::owned(&mut conn, user_id, area_id).await?;
+        }
+        // Unarchiving a project that is only archived through its area takes
+        // it out of the area, unless the caller places it somewhere itself.
+        let mut area_id = input.area_id;
+        if input.status == Some(ProjectStatus::Active) && area_id.is_none() {
+            if let Some(current) = existing.area_id {
+                let area = AreaService::owned(&mut conn, user_id, current).await?;
+                if area.status == ProjectStatusMapping::Archived {
+                    area_id = Some(None);
+                }
+            }
+        }
+
         let changeset = ProjectChangeset {
             title: input.title.as_deref(),
             description: input.description.as_ref().map(|d| Some(d.as_str())),
@@ -105,6 +193,14 @@ impl ProjectService {
                 .status
                 .as_ref()
                 .map(|s| ProjectStatusMapping::from(s.clone())),
+            area_id,
+            due_date: input.due_date,
+            outcome: input
+                .outcome
+                .as_ref()
+                .map(|o| o.as_deref().map(str::trim).filter(|o| !o.is_empty())),
+            reviewed_at: input.reviewed_at,
+            review_interval_days: input.review_interval_days,
         };
 
         // Only update if there's something to change
@@ -113,18 +209,75 @@ impl ProjectService {
             || changeset.view_type.is_some()
             || changeset.position.is_some()
             || changeset.color.is_some()
-            || changeset.status.is_some();
+            || changeset.status.is_some()
+            || changeset.area_id.is_some()
+            || changeset.due_date.is_some()
+            || changeset.outcome.is_some()
+            || changeset.reviewed_at.is_some()
+            || changeset.review_interval_days.is_some();
+
+        if input.view_type == Some(ProjectViewType::Kanban) {
+            Self::ensure_columns(&mut conn, id).await?;
+        }
 
         if has_changes {
-            let row = diesel::update(projects::table.filter(projects::id.eq(id)))
+            diesel::update(projects::table.filter(projects::id.eq(existing.id)))
                 .set(&changeset)
-                .returning(ProjectRow::as_returning())
-                .get_result(&mut conn)
+                .execute(&mut conn)
                 .await?;
-            Ok(Project::from(row))
-        } else {
-            Ok(Project::from(existing))
         }
+        Self::get_by_id(pool, user_id, id).await
+    }
+
+    /// Puts a project into an area (or none) just before another project of
+    /// that area, or at its end, renumbering positions to match.
+    pub async fn move_to(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &MoveProject,
+    ) -> ServiceResult<Project> {
+        let mut conn = pool.get().await?;
+        let MoveProject { area_id, before_id } = *input;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                if let Some(area_id) = area_id {
+                    AreaService::owned(conn, user_id, area_id).await?;
+                }
+                let rows: Vec<(i64, Option<i64>, i32)> = projects::table
+                    .filter(projects::user_id.eq(user_id))
+                    .filter(projects::deleted_at.is_null())
+                    .order((projects::position.asc(), projects::created_at.asc()))
+                    .select((projects::id, projects::area_id, projects::position))
+                    .load(conn)
+                    .await?;
+                let order: Vec<(i64, Option<i64>)> =
+                    rows.iter().map(|&(pid, area, _)| (pid, area)).collect();
+                let positions: HashMap<i64, i32> =
+                    rows.iter().map(|&(pid, _, pos)| (pid, pos)).collect();
+
+                for (position, pid) in reorder(&order, id, area_id, before_id)?
+                    .into_iter()
+                    .enumerate()
+                {
+                    let position = position as i32;
+                    if positions.get(&pid) != Some(&position) {
+                        diesel::update(projects::table.filter(projects::id.eq(pid)))
+                            .set(projects::position.eq(position))
+                            .execute(conn)
+                            .await?;
+                    }
+                }
+                diesel::update(projects::table.filter(projects::id.eq(id)))
+                    .set(projects::area_id.eq(area_id))
+                    .execute(conn)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+        Self::get_by_id(pool, user_id, id).await
     }
 
     pub async fn update_details(
@@ -138,7 +291,8 @@ impl ProjectService {
         let affected = diesel::update(
             projects::table
                 .filter(projects::id.eq(id))
-                .filter(projects::user_id.eq(user_id)),
+                .filter(projects::user_id.eq(user_id))
+                .filter(projects::deleted_at.is_null()),
         )
         .set((projects::title.eq(title), projects::color.eq(color)))
         .execute(&mut conn)
@@ -149,43 +303,677 @@ impl ProjectService {
         Ok(())
     }
 
+    /// Moves a project and its tasks to the trash, stamping them with the
+    /// same `deleted_at` so they are restored together.
     pub async fn delete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
         let mut conn = pool.get().await?;
-        let affected = diesel::delete(
-            projects::table
-                .filter(projects::id.eq(id))
-                .filter(projects::user_id.eq(user_id)),
-        )
-        .execute(&mut conn)
-        .await?;
-        if affected == 0 {
-            return Err(ServiceError::NotFound("Project not found".into()));
-        }
-        Ok(())
+        let now = Utc::now();
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let affected = diesel::update(
+                    projects::table
+                        .filter(projects::id.eq(id))
+                        .filter(projects::user_id.eq(user_id))
+                        .filter(projects::deleted_at.is_null()),
+                )
+                .set(projects::deleted_at.eq(now))
+                .execute(conn)
+                .await?;
+                if affected == 0 {
+                    return Err(ServiceError::NotFound("Project not found".into()));
+                }
+                diesel::update(
+                    tasks::table
+                        .filter(tasks::project_id.eq(id))
+                        .filter(tasks::deleted_at.is_null()),
+                )
+                .set(tasks::deleted_at.eq(now))
+                .execute(conn)
+                .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
     }
 
-    /// Find project by title (case-insensitive) for @project token parsing
+    /// Find project by title (case-insensitive) for @project token parsing.
+    /// `area/project` picks the project within that area, unless a project
+    /// is titled with the slash itself.
     pub async fn find_by_title(
         pool: &DbPool,
         user_id: i64,
         title: &str,
+    ) -> ServiceResult<Option<i64>> {
+        let mut conn = pool.get().await?;
+        let id = Self::find_active(&mut conn, user_id, None, title).await?;
+        if id.is_some() {
+            return Ok(id);
+        }
+        match title.split_once('/') {
+            Some((area, project)) => {
+                Self::find_active(&mut conn, user_id, Some(area), project).await
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn find_active(
+        conn: &mut diesel_async::AsyncPgConnection,
+        user_id: i64,
+        area: Option<&str>,
+        title: &str,
     ) -> ServiceResult<Option<i64>> {
         use diesel::dsl::sql;
-        use diesel::sql_types::Text;
+        use diesel::sql_types::{Bool, Text};
 
-        let mut conn = pool.get().await?;
-        let id: Option<i64> = projects::table
+        let mut query = projects::table
+            .left_join(areas::table)
             .filter(projects::user_id.eq(user_id))
             .filter(projects::status.eq(ProjectStatusMapping::Active))
             .filter(
-                sql::<diesel::sql_types::Bool>("lower(title) = lower(")
+                areas::status
+                    .nullable()
+                    .is_distinct_from(ProjectStatusMapping::Archived),
+            )
+            .filter(projects::deleted_at.is_null())
+            .filter(
+                sql::<Bool>("lower(projects.title) = lower(")
                     .bind::<Text, _>(title)
                     .sql(")"),
             )
             .select(projects::id)
+            .into_boxed();
+        if let Some(area) = area {
+            query = query.filter(
+                sql::<Bool>("lower(areas.title) = lower(")
+                    .bind::<Text, _>(area)
+                    .sql(")"),
+            );
+        }
+        Ok(query.first(conn).await.optional()?)
+    }
+
+    // ── Kanban columns ─────────────────────────────────────────────
+
+    pub async fn list_columns(
+        pool: &DbPool,
+        user_id: i64,
+        project_id: i64,
+    ) -> ServiceResult<Vec<ProjectColumn>> {
+        let mut conn = pool.get().await?;
+        Self::check_owner(&mut conn, user_id, project_id).await?;
+        let rows = project_columns::table
+            .filter(project_columns::project_id.eq(project_id))
+            .order((project_columns::position.asc(), project_columns::id.asc()))
+            .select(ProjectColumnRow::as_select())
+            .load(&mut conn)
+            .await?;
+        Ok(rows.into_iter().map(ProjectColumn::from).collect())
+    }
+
+    pub async fn create_column(
+        pool: &DbPool,
+        user_id: i64,
+        project_id: i64,
+        input: &CreateProjectColumn,
+    ) -> ServiceResult<ProjectColumn> {
+        let title = input.title.trim();
+        if title.is_empty() {
+            return Err(ServiceError::BadRequest("Column title is required".into()));
+        }
+        let mut conn = pool.get().await?;
+        Self::check_owner(&mut conn, user_id, project_id).await?;
+        let max_pos: Option<i32> = project_columns::table
+            .filter(project_columns::project_id.eq(project_id))
+            .select(diesel::dsl::max(project_columns::position))
             .first(&mut conn)
+            .await?;
+        let row = diesel::insert_into(project_columns::table)
+            .values(&NewProjectColumn {
+                project_id,
+                title,
+                position: max_pos.map_or(0, |p| p + 1),
+            })
+            .returning(ProjectColumnRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(ProjectColumn::from(row))
+    }
+
+    pub async fn update_column(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &UpdateProjectColumn,
+    ) -> ServiceResult<ProjectColumn> {
+        let title = input.title.as_deref().map(str::trim);
+        if title == Some("") {
+            return Err(ServiceError::BadRequest("Column title is required".into()));
+        }
+        let mut conn = pool.get().await?;
+        let existing = Self::owned_column(&mut conn, user_id, id).await?;
+        if title.is_none() && input.position.is_none() {
+            return Ok(ProjectColumn::from(existing));
+        }
+        let row = diesel::update(project_columns::table.filter(project_columns::id.eq(id)))
+            .set(&ProjectColumnChangeset {
+                title,
+                position: input.position,
+            })
+            .returning(ProjectColumnRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(ProjectColumn::from(row))
+    }
+
+    /// Deletes a column; its tasks fall back to the board's first column.
+    pub async fn delete_column(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        Self::owned_column(&mut conn, user_id, id).await?;
+        diesel::delete(project_columns::table.filter(project_columns::id.eq(id)))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    // ── List sections ───────────────────────────────────────────────
+
+    pub async fn list_sections(
+        pool: &DbPool,
+        user_id: i64,
+        project_id: i64,
+    ) -> ServiceResult<Vec<ProjectSection>> {
+        let mut conn = pool.get().await?;
+        Self::check_owner(&mut conn, user_id, project_id).await?;
+        let rows = project_sections::table
+            .filter(project_sections::project_id.eq(project_id))
+            .order((project_sections::sort_key.asc(), project_sections::id.asc()))
+            .select(ProjectSectionRow::as_select())
+            .load(&mut conn)
+            .await?;
+        Ok(rows.into_iter().map(ProjectSection::from).collect())
+    }
+
+    pub async fn create_section(
+        pool: &DbPool,
+        user_id: i64,
+        project_id: i64,
+        input: &CreateProjectSection,
+    ) -> ServiceResult<ProjectSection> {
+        let title = input.title.trim();
+        if title.is_empty() {
+            return Err(ServiceError::BadRequest("Section title is required".into()));
+        }
+        let mut conn = pool.get().await?;
+        Self::check_owner(&mut conn, user_id, project_id).await?;
+        let sort_key = match input.sort_key {
+            Some(ref sk) => sk.clone(),
+            None => {
+                let last_key: Option<String> = project_sections::table
+                    .filter(project_sections::project_id.eq(project_id))
+                    .select(diesel::dsl::max(project_sections::sort_key))
+                    .first(&mut conn)
+                    .await?;
+                north_dto::sort_key_after(last_key.as_deref())
+            }
+        };
+        let row = diesel::insert_into(project_sections::table)
+            .values(&NewProjectSection {
+                project_id,
+                title,
+                sort_key: &sort_key,
+            })
+            .returning(ProjectSectionRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(ProjectSection::from(row))
+    }
+
+    pub async fn update_section(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &UpdateProjectSection,
+    ) -> ServiceResult<ProjectSection> {
+        let title = input.title.as_deref().map(str::trim);
+        if title == Some("") {
+            return Err(ServiceError::BadRequest("Section title is required".into()));
+        }
+        let mut conn = pool.get().await?;
+        let existing = Self::owned_section(&mut conn, user_id, id).await?;
+        if title.is_none() && input.sort_key.is_none() {
+            return Ok(ProjectSection::from(existing));
+        }
+        let row = diesel::update(project_sections::table.filter(project_sections::id.eq(id)))
+            .set(&ProjectSectionChangeset {
+                title,
+                sort_key: input.sort_key.as_deref(),
+            })
+            .returning(ProjectSectionRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(ProjectSection::from(row))
+    }
+
+    /// Deletes a section; its tasks stay in the project, outside any section.
+    pub async fn delete_section(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        Self::owned_section(&mut conn, user_id, id).await?;
+        diesel::delete(project_sections::table.filter(project_sections::id.eq(id)))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn check_owner(
+        conn: &mut diesel_async::AsyncPgConnection,
+        user_id: i64,
+        project_id: i64,
+    ) -> ServiceResult<()> {
+        let count: i64 = projects::table
+            .filter(projects::id.eq(project_id))
+            .filter(projects::user_id.eq(user_id))
+            .filter(projects::deleted_at.is_null())
+            .count()
+            .get_result(conn)
+            .await?;
+        if count == 0 {
+            return Err(ServiceError::NotFound("Project not found".into()));
+        }
+        Ok(())
+    }
+
+    async fn owned_column(
+        conn: &mut diesel_async::AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<ProjectColumnRow> {
+        project_columns::table
+            .inner_join(projects::table)
+            .filter(project_columns::id.eq(id))
+            .filter(projects::user_id.eq(user_id))
+            .filter(projects::deleted_at.is_null())
+            .select(ProjectColumnRow::as_select())
+            .first(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Column not found".into()))
+    }
+
+    async fn owned_section(
+        conn: &mut diesel_async::AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<ProjectSectionRow> {
+        project_sections::table
+            .inner_join(projects::table)
+            .filter(project_sections::id.eq(id))
+            .filter(projects::user_id.eq(user_id))
+            .filter(projects::deleted_at.is_null())
+            .select(ProjectSectionRow::as_select())
+            .first(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Section not found".into()))
+    }
+
+    /// Today in the user's timezone, and their default review interval.
+    async fn user_calendar(pool: &DbPool, user_id: i64) -> ServiceResult<(NaiveDate, i64)> {
+        let settings = crate::UserService::get_settings(pool, user_id).await?;
+        let tz: chrono_tz::Tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+        Ok((
+            Utc::now().with_timezone(&tz).date_naive(),
+            i64::from(settings.review_interval_days),
+        ))
+    }
+
+    /// Fills in task counts, progress and the stalled flag from the
+    /// projects' live tasks. `today` decides whether waiting tasks are due
+    /// for a follow-up.
+    async fn fill_progress(
+        conn: &mut diesel_async::AsyncPgConnection,
+        projects: &mut [Project],
+        today: NaiveDate,
+    ) -> ServiceResult<()> {
+        let ids: Vec<i64> = projects.iter().map(|p| p.id).collect();
+        #[allow(clippy::type_complexity)]
+        let rows: Vec<(
+            i64,
+            Option<i64>,
+            Option<i64>,
+            String,
+            bool,
+            bool,
+            Option<String>,
+            Option<NaiveDate>,
+            i16,
+        )> = tasks::table
+            .filter(tasks::project_id.eq_any(&ids))
+            .filter(tasks::deleted_at.is_null())
+            .select((
+                tasks::id,
+                tasks::project_id,
+                tasks::parent_id,
+                tasks::sort_key,
+                tasks::completed_at.is_not_null(),
+                tasks::someday,
+                tasks::waiting_for,
+                tasks::follow_up_date,
+                tasks::sequential_limit,
+            ))
+            .load(conn)
+            .await?;
+        let open_ids: Vec<i64> = rows.iter().filter(|r| !r.4).map(|r| r.0).collect();
+        let blocked: HashSet<i64> = task_dependencies::table
+            .inner_join(tasks::table.on(tasks::id.eq(task_dependencies::blocker_id)))
+            .filter(task_dependencies::task_id.eq_any(&open_ids))
+            .filter(tasks::completed_at.is_null())
+            .filter(tasks::deleted_at.is_null())
+            .select(task_dependencies::task_id)
+            .load::<i64>(conn)
+            .await?
+            .into_iter()
+            .collect();
+
+        let tasks: Vec<ProgressTask> = rows
+            .into_iter()
+            .filter_map(
+                |(
+                    id,
+                    project_id,
+                    parent_id,
+                    sort_key,
+                    completed,
+                    someday,
+                    waiting_for,
+                    follow_up,
+                    limit,
+                )| {
+                    Some(ProgressTask {
+                        id,
+                        project_id: project_id?,
+                        parent_id,
+                        sort_key,
+                        completed,
+                        someday,
+                        waiting: waiting_for.is_some() && follow_up.is_none_or(|d| d > today),
+                        blocked: blocked.contains(&id),
+                        sequential_limit: limit,
+                    })
+                },
+            )
+            .collect();
+        let summaries = summarize(&tasks);
+        for project in projects.iter_mut() {
+            let summary = summaries.get(&project.id).copied().unwrap_or_default();
+            project.task_count = summary.total;
+            project.completed_task_count = summary.completed;
+            project.progress = Project::progress_of(summary.completed, summary.total);
+            project.stalled = project.status == ProjectStatus::Active && !summary.has_next_action;
+        }
+        Ok(())
+    }
+
+    /// Seeds [`DEFAULT_COLUMNS`] for a project that has none yet.
+    async fn ensure_columns(
+        conn: &mut diesel_async::AsyncPgConnection,
+        project_id: i64,
+    ) -> ServiceResult<()> {
+        let count: i64 = project_columns::table
+            .filter(project_columns::project_id.eq(project_id))
+            .count()
+            .get_result(conn)
+            .await?;
+        if count > 0 {
+            return Ok(());
+        }
+        let columns: Vec<NewProjectColumn> = DEFAULT_COLUMNS
+            .iter()
+            .enumerate()
+            .map(|(i, title)| NewProjectColumn {
+                project_id,
+                title,
+                position: i as i32,
+            })
+            .collect();
+        diesel::insert_into(project_columns::table)
+            .values(&columns)
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+}
+
+/// A project row as the client sees it: archived when its area is.
+fn with_area_status(row: ProjectRow, area_status: Option<ProjectStatusMapping>) -> Project {
+    let mut project = Project::from(row);
+    if area_status == Some(ProjectStatusMapping::Archived) {
+        project.status = ProjectStatus::Archived;
+    }
+    project
+}
+
+/// A project's task, reduced to what progress and next actions need.
+struct ProgressTask {
+    id: i64,
+    project_id: i64,
+    parent_id: Option<i64>,
+    sort_key: String,
+    completed: bool,
+    someday: bool,
+    /// Delegated and not yet due for a follow-up.
+    waiting: bool,
+    /// Has an open blocker.
+    blocked: bool,
+    sequential_limit: i16,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ProgressSummary {
+    total: i64,
+    completed: i64,
+    has_next_action: bool,
+}
+
+/// Per-project counts and whether any open task is actionable, using the
+/// same rules as the client's actionable filter: not someday, blocked or
+/// waiting, and within the parent's sequential limit.
+fn summarize(tasks: &[ProgressTask]) -> HashMap<i64, ProgressSummary> {
+    let limits: HashMap<i64, i16> = tasks.iter().map(|t| (t.id, t.sequential_limit)).collect();
+    let mut summaries: HashMap<i64, ProgressSummary> = HashMap::new();
+    for task in tasks {
+        let summary = summaries.entry(task.project_id).or_default();
+        summary.total += 1;
+        if task.completed {
+            summary.completed += 1;
+            continue;
+        }
+        if summary.has_next_action || task.someday || task.blocked || task.waiting {
+            continue;
+        }
+        let actionable = match task.parent_id {
+            None => true,
+            Some(parent_id) => {
+                let limit = limits.get(&parent_id).copied().unwrap_or(1);
+                limit == 0
+                    || tasks
+                        .iter()
+                        .filter(|t| {
+                            t.parent_id == Some(parent_id)
+                                && !t.completed
+                                && t.sort_key < task.sort_key
+                        })
+                        .count()
+                        < limit as usize
+            }
+        };
+        summary.has_next_action = actionable;
+    }
+    summaries
+}
+
+/// New order of all projects (`(id, area_id)` in position order) after
+/// moving `id` into `area_id` before `before_id`, or after the area's last
+/// project. `before_id` must be in the target area.
+fn reorder(
+    order: &[(i64, Option<i64>)],
+    id: i64,
+    area_id: Option<i64>,
+    before_id: Option<i64>,
+) -> ServiceResult<Vec<i64>> {
+    let mut order: Vec<(i64, Option<i64>)> = order.to_vec();
+    let from = order
+        .iter()
+        .position(|&(pid, _)| pid == id)
+        .ok_or_else(|| ServiceError::NotFound("Project not found".into()))?;
+    order.remove(from);
+    let at = match before_id {
+        Some(before_id) => order
+            .iter()
+            .position(|&(pid, area)| pid == before_id && area == area_id)
+            .ok_or_else(|| ServiceError::BadRequest("Target project is not in that area".into()))?,
+        None => order
+            .iter()
+            .rposition(|&(_, area)| area == area_id)
+            .map_or(order.len(), |i| i + 1),
+    };
+    order.insert(at, (id, area_id));
+    Ok(order.into_iter().map(|(pid, _)| pid).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reorder() {
+        // Area 10: 1, 2; no area: 3; area 20: 4.
+        let order = [(1, Some(10)), (2, Some(10)), (3, None), (4, Some(20))];
+
+        assert_eq!(reorder(&order, 4, Some(10), Some(1)).unwrap(), [4, 1, 2, 3]);
+        assert_eq!(reorder(&order, 1, Some(10), None).unwrap(), [2, 1, 3, 4]);
+        assert_eq!(reorder(&order, 1, None, None).unwrap(), [2, 3, 1, 4]);
+        // An empty area puts the project at the very end.
+        assert_eq!(reorder(&order, 3, Some(30), None).unwrap(), [1, 2, 4, 3]);
+        assert!(reorder(&order, 3, Some(20), Some(1)).is_err());
+        assert!(reorder(&order, 9, None, None).is_err());
+    }
+
+    fn task(id: i64, parent_id: Option<i64>, sort_key: &str) -> ProgressTask {
+        ProgressTask {
+            id,
+            project_id: 1,
+            parent_id,
+            sort_key: sort_key.into(),
+            completed: false,
+            someday: false,
+            waiting: false,
+            blocked: false,
+            sequential_limit: 1,
+        }
+    }
+
+    #[test]
+    fn test_summarize() {
+        let done = ProgressTask {
+            completed: true,
+            ..task(1, None, "a")
+        };
+        let someday = ProgressTask {
+            someday: true,
+            ..task(2, None, "b")
+        };
+        let summary = summarize(&[done, someday])[&1];
+        assert_eq!(
+            summary,
+            ProgressSummary {
+                total: 2,
+                completed: 1,
+                has_next_action: false,
+            }
+        );
+
+        // A blocked parent leaves only its first open subtask actionable.
+        let parent = ProgressTask {
+            blocked: true,
+            ..task(3, None, "a")
+        };
+        let first = ProgressTask {
+            waiting: true,
+            ..task(4, Some(3), "a")
+        };
+        let second = task(5, Some(3), "b");
+        assert!(!summarize(&[parent, first, second])[&1].has_next_action);
+
+        let parent = ProgressTask {
+            blocked: true,
+            sequential_limit: 0,
+            ..task(3, None, "a")
+        };
+        let first = ProgressTask {
+            waiting: true,
+            ..task(4, Some(3), "a")
+        };
+        let second = task(5, Some(3), "b");
+        assert!(summarize(&[parent, first, second])[&1].has_next_action);
+    }
+
+    #[tokio::test]
+    async fn test_progress_and_stalled() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = crate::test_support::user(&pool, Default::default()).await;
+        let input = CreateProject {
+            title: "Garden".into(),
+            description: None,
+            view_type: None,
+            area_id: None,
+            due_date: None,
+            outcome: None,
+        };
+        let project = ProjectService::create(&pool, user_id, &input)
+            .await
+            .unwrap();
+        let mut ids = Vec::new();
+        for title in ["Buy seeds", "Dig beds"] {
+            let input = north_dto::CreateTask {
+                title: title.into(),
+                project_id: Some(project.id),
+                ..Default::default()
+            };
+            let task = crate::TaskService::create(&pool, user_id, &input)
+                .await
+                .unwrap();
+            ids.push(task.id);
+        }
+        let input = north_dto::UpdateTask {
+            completed_at: Some(Some(Utc::now())),
+            ..Default::default()
+        };
+        crate::TaskService::update(&pool, user_id, ids[0], &input)
+            .await
+            .unwrap();
+
+        let project = ProjectService::get_by_id(&pool, user_id, project.id)
+            .await
+            .unwrap();
+        assert_eq!((project.completed_task_count, project.task_count), (1, 2));
+        assert_eq!(project.progress, 50);
+        assert!(!project.stalled);
+
+        // Waiting on someone leaves the project without a next action.
+        let input = north_dto::UpdateTask {
+            waiting_for: Some(Some("Sam".into())),
+            ..Default::default()
+        };
+        crate::TaskService::update(&pool, user_id, ids[1], &input)
+            .await
+            .unwrap();
+        let project = ProjectService::get_by_id(&pool, user_id, project.id)
             .await
-            .optional()?;
-        Ok(id)
+            .unwrap();
+        assert!(project.stalled);
     }
 }
@@ -0,0 +1,764 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use north_db::models::{
+    NewPushSubscription, NewReminder, PushSubscriptionRow, ReminderRow, TaskRow, UserRow,
+};
+use north_db::schema::{push_subscriptions, reminders, tasks, users};
+use north_db::DbPool;
+use north_dto::{CreateReminder, PushSubscriptionInput, Reminder, ReminderAnchor, UserSettings};
+
+use crate::notifications::{Clock, Notification, Notifier, NotifyError, Recipient, WebhookPolicy};
+use crate::{ServiceError, ServiceResult};
+
+/// Local time of day a due-date reminder is measured back from, since due
+/// dates carry no time.
+pub const DUE_REMINDER_TIME: NaiveTime = match NaiveTime::from_hms_opt(9, 0, 0) {
+    Some(t) => t,
+    None => unreachable!(),
+};
+
+/// Reminders whose fire time passed longer ago than this (e.g. while the
+/// server was down) are dropped instead of delivered late.
+const MAX_LATENESS: Duration = Duration::hours(12);
+
+/// Fire time of a start reminder, in SQL.
+const START_FIRE_AT: &str = "(tasks.start_at - make_interval(mins => reminders.offset_minutes))";
+
+/// Longest supported offset before the anchor: 30 days.
+const MAX_OFFSET_MINUTES: i32 = 30 * 24 * 60;
+
+/// A reminder whose fire time has come, with what is needed to deliver it.
+#[derive(Debug, Clone)]
+pub struct DueReminder {
+    pub reminder_id: i64,
+    pub fire_at: DateTime<Utc>,
+    pub recipient: Recipient,
+    pub notification: Notification,
+}
+
+pub struct ReminderService;
+
+impl ReminderService {
+    pub async fn list_for_task(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+    ) -> ServiceResult<Vec<Reminder>> {
+        let mut conn = pool.get().await?;
+        let rows = reminders::table
+            .filter(reminders::task_id.eq(task_id))
+            .filter(reminders::user_id.eq(user_id))
+            .order(reminders::id.asc())
+            .select(ReminderRow::as_select())
+            .load(&mut conn)
+            .await?;
+        Ok(rows.into_iter().map(Reminder::from).collect())
+    }
+
+    pub async fn create(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+        input: &CreateReminder,
+    ) -> ServiceResult<Reminder> {
+        if !(0..=MAX_OFFSET_MINUTES).contains(&input.offset_minutes) {
+            return Err(ServiceError::BadRequest(
+                "Reminder offset must be between 0 and 30 days".into(),
+            ));
+        }
+
+        let mut conn = pool.get().await?;
+        let owned: i64 = tasks::table
+            .filter(tasks::id.eq(task_id))
+            .filter(tasks::user_id.eq(user_id))
+            .count()
+            .get_result(&mut conn)
+            .await?;
+        if owned == 0 {
+            return Err(ServiceError::NotFound("Task not found".into()));
+        }
+
+        let row = diesel::insert_into(reminders::table)
+            .values(&NewReminder {
+                task_id,
+                user_id,
+                anchor: input.anchor.into(),
+                offset_minutes: input.offset_minutes,
+            })
+            .returning(ReminderRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        Ok(Reminder::from(row))
+    }
+
+    pub async fn delete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        let affected = diesel::delete(
+            reminders::table
+                .filter(reminders::id.eq(id))
+                .filter(reminders::user_id.eq(user_id)),
+        )
+        .execute(&mut conn)
+        .await?;
+        if affected == 0 {
+            return Err(ServiceError::NotFound("Reminder not found".into()));
+        }
+        Ok(())
+    }
+
+    /// Copies a task's reminders onto its next recurring instance.
+    pub async fn copy_to_task(
+        conn: &mut diesel_async::AsyncPgConnection,
+        from_task_id: i64,
+        to_task_id: i64,
+    ) -> ServiceResult<()> {
+        let rows = reminders::table
+            .filter(reminders::task_id.eq(from_task_id))
+            .select(ReminderRow::as_select())
+            .load(conn)
+            .await?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let copies: Vec<NewReminder> = rows
+            .into_iter()
+            .map(|r| NewReminder {
+                task_id: to_task_id,
+                user_id: r.user_id,
+                anchor: r.anchor,
+                offset_minutes: r.offset_minutes,
+            })
+            .collect();
+        diesel::insert_into(reminders::table)
+            .values(&copies)
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn save_push_subscription(
+        pool: &DbPool,
+        user_id: i64,
+        input: &PushSubscriptionInput,
+    ) -> ServiceResult<()> {
+        // `filter` on an upsert adds a WHERE to its DO UPDATE.
+        use diesel::query_dsl::methods::FilterDsl;
+
+        WebhookPolicy::from_env()
+            .check(&input.endpoint)
+            .await
+            .map_err(|_| ServiceError::BadRequest("Push endpoint must be a public URL".into()))?;
+
+        let mut conn = pool.get().await?;
+        // An endpoint already registered to another user is left alone.
+        let affected = diesel::insert_into(push_subscriptions::table)
+            .values(&NewPushSubscription {
+                user_id,
+                endpoint: &input.endpoint,
+                p256dh: &input.p256dh,
+                auth: &input.auth,
+            })
+            .on_conflict(push_subscriptions::endpoint)
+            .do_update()
+            .set((
+                push_subscriptions::p256dh.eq(&input.p256dh),
+                push_subscriptions::auth.eq(&input.auth),
+            ))
+            .filter(push_subscriptions::user_id.eq(user_id))
+            .execute(&mut conn)
+            .await?;
+        if affected == 0 {
+            return Err(ServiceError::BadRequest(
+                "Push subscription belongs to another account".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn delete_push_subscriptions(
+        pool: &DbPool,
+        endpoints: &[String],
+    ) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        diesel::delete(
+            push_subscriptions::table.filter(push_subscriptions::endpoint.eq_any(endpoints)),
+        )
+        .execute(&mut conn)
+        .await?;
+        Ok(())
+    }
+
+    /// When a reminder should fire, or `None` if its anchor isn't set.
+    pub fn fire_time(
+        anchor: ReminderAnchor,
+        offset_minutes: i32,
+        start_at: Option<DateTime<Utc>>,
+        due_date: Option<NaiveDate>,
+        tz: chrono_tz::Tz,
+    ) -> Option<DateTime<Utc>> {
+        let anchor_at = match anchor {
+            ReminderAnchor::Start => start_at?,
+            ReminderAnchor::Due => tz
+                .from_local_datetime(&due_date?.and_time(DUE_REMINDER_TIME))
+                .earliest()?
+                .with_timezone(&Utc),
+        };
+        Some(anchor_at - Duration::minutes(offset_minutes as i64))
+    }
+
+    /// Whether a reminder scheduled for `fire_at` should be delivered now.
+    /// A reminder fires once per fire time; one created after its fire time
+    /// had already passed stays silent.
+    pub fn should_fire(
+        fire_at: DateTime<Utc>,
+        fired_for: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        fire_at <= now
+            && now - fire_at <= MAX_LATENESS
+            && fire_at >= created_at - Duration::minutes(1)
+            && fired_for != Some(fire_at)
+    }
+
+    /// Reminders on open tasks whose fire time falls in `(since, now]`.
+    pub async fn due_reminders(
+        pool: &DbPool,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> ServiceResult<Vec<DueReminder>> {
+        use diesel::dsl::sql;
+        use diesel::sql_types::{Bool, Timestamp, Timestamptz};
+
+        // Start reminders are matched exactly. Due reminders fire at a local
+        // time, so SQL only narrows them down with the widest timezone
+        // offsets (UTC-12 to UTC+14); `should_fire` has the final say.
+        let due_local_fire_at = format!(
+            "(tasks.due_date + time '{DUE_REMINDER_TIME}' \
+             - make_interval(mins => reminders.offset_minutes))"
+        );
+        let mut conn = pool.get().await?;
+        let rows: Vec<(ReminderRow, TaskRow)> = reminders::table
+            .inner_join(tasks::table)
+            .filter(tasks::completed_at.is_null())
+            .filter(tasks::deleted_at.is_null())
+            .filter(
+                sql::<Bool>(&format!(
+                    "(reminders.anchor = 'start' AND {START_FIRE_AT} > "
+                ))
+                .bind::<Timestamptz, _>(since)
+                .sql(&format!(" AND {START_FIRE_AT} <= "))
+                .bind::<Timestamptz, _>(now)
+                .sql(&format!(
+                    " AND reminders.fired_for IS DISTINCT FROM {START_FIRE_AT}) \
+                         OR (reminders.anchor = 'due' AND {due_local_fire_at} > "
+                ))
+                .bind::<Timestamp, _>(since.naive_utc() - Duration::hours(12))
+                .sql(&format!(" AND {due_local_fire_at} <= "))
+                .bind::<Timestamp, _>(now.naive_utc() + Duration::hours(14))
+                .sql(" AND (reminders.fired_for IS NULL OR reminders.fired_for <= ")
+                .bind::<Timestamptz, _>(since)
+                .sql("))"),
+            )
+            .select((ReminderRow::as_select(), TaskRow::as_select()))
+            .load(&mut conn)
+            .await?;
+        if rows.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut user_ids: Vec<i64> = rows.iter().map(|(r, _)| r.user_id).collect();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+        let user_rows: Vec<UserRow> = users::table
+            .filter(users::id.eq_any(&user_ids))
+            .select(UserRow::as_select())
+            .load(&mut conn)
+            .await?;
+        let subscriptions: Vec<PushSubscriptionRow> = push_subscriptions::table
+            .filter(push_subscriptions::user_id.eq_any(&user_ids))
+            .select(PushSubscriptionRow::as_select())
+            .load(&mut conn)
+            .await?;
+
+        let mut recipients: HashMap<i64, (Recipient, chrono_tz::Tz)> = HashMap::new();
+        for user in user_rows {
+            let settings: UserSettings =
+                serde_json::from_value(user.settings.clone()).unwrap_or_default();
+            let tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+            let push_subscriptions = subscriptions
+                .iter()
+                .filter(|s| s.user_id == user.id)
+                .cloned()
+                .collect();
+            let recipient = Recipient {
+                user_id: user.id,
+                email: user.email,
+                settings,
+                push_subscriptions,
+            };
+            recipients.insert(user.id, (recipient, tz));
+        }
+
+        let due = rows
+            .into_iter()
+            .filter_map(|(reminder, task)| {
+                let (recipient, tz) = recipients.get(&reminder.user_id)?;
+                let anchor = ReminderAnchor::from(reminder.anchor);
+                let fire_at = Self::fire_time(
+                    anchor,
+                    reminder.offset_minutes,
+                    task.start_at,
+                    task.due_date,
+                    *tz,
+                )?;
+                if fire_at <= since
+                    || !Self::should_fire(fire_at, reminder.fired_for, reminder.created_at, now)
+                {
+                    return None;
+                }
+                Some(DueReminder {
+                    reminder_id: reminder.id,
+                    fire_at,
+                    recipient: recipient.clone(),
+                    notification: Self::notification_for(&task, anchor, *tz),
+                })
+            })
+            .collect();
+        Ok(due)
+    }
+
+    pub async fn mark_fired(pool: &DbPool, id: i64, fire_at: DateTime<Utc>) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        diesel::update(reminders::table.filter(reminders::id.eq(id)))
+            .set(reminders::fired_for.eq(Some(fire_at)))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    fn notification_for(task: &TaskRow, anchor: ReminderAnchor, tz: chrono_tz::Tz) -> Notification {
+        let body = match anchor {
+            ReminderAnchor::Start => task.start_at.map(|s| {
+                format!(
+                    "Starts {}",
+                    s.with_timezone(&tz).format("%b %-d, %-I:%M %p")
+                )
+            }),
+            ReminderAnchor::Due => task.due_date.map(|d| format!("Due {}", d.format("%b %-d"))),
+        };
+        Notification {
+            task_id: Some(task.id),
+            title: task.title.clone(),
+            body: body.unwrap_or_default(),
+        }
+    }
+}
+
+/// Polls for due reminders and hands them to every enabled [`Notifier`].
+pub struct ReminderScheduler {
+    pool: DbPool,
+    clock: Arc<dyn Clock>,
+    notifiers: Vec<Arc<dyn Notifier>>,
+    /// End of the last window that was fully delivered.
+    last_tick: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl ReminderScheduler {
+    pub fn new(pool: DbPool, clock: Arc<dyn Clock>, notifiers: Vec<Arc<dyn Notifier>>) -> Self {
+        Self {
+            pool,
+            clock,
+            notifiers,
+            last_tick: Mutex::new(None),
+        }
+    }
+
+    /// Delivers everything that came due since the last tick (at most
+    /// [`MAX_LATENESS`] ago); returns how many reminders fired.
+    pub async fn tick(&self) -> ServiceResult<usize> {
+        let now = self.clock.now();
+        let oldest = now - MAX_LATENESS;
+        let since = self
+            .last_tick
+            .lock()
+            .unwrap()
+            .map_or(oldest, |t| t.max(oldest));
+        let due = ReminderService::due_reminders(&self.pool, since, now).await?;
+        for reminder in &due {
+            let gone = self.dispatch(reminder).await;
+            if !gone.is_empty() {
+                ReminderService::delete_push_subscriptions(&self.pool, &gone).await?;
+            }
+            // Mark even on delivery failure so a broken backend can't cause
+            // a notification storm on every tick.
+            ReminderService::mark_fired(&self.pool, reminder.reminder_id, reminder.fire_at).await?;
+        }
+        // Only now: a failed tick retries its window next time.
+        *self.last_tick.lock().unwrap() = Some(now);
+        Ok(due.len())
+    }
+
+    /// Sends one reminder through every backend the recipient enabled.
+    /// Returns push endpoints reported as expired.
+    pub async fn dispatch(&self, reminder: &DueReminder) -> Vec<String> {
+        let mut gone = Vec::new();
+        for notifier in &self.notifiers {
+            if !notifier.enabled_for(&reminder.recipient) {
+                continue;
+            }
+            match notifier
+                .send(&reminder.recipient, &reminder.notification)
+                .await
+            {
+                Ok(()) => {}
+                Err(NotifyError::SubscriptionsGone(endpoints)) => gone.extend(endpoints),
+                Err(e) => tracing::warn!(
+                    reminder_id = reminder.reminder_id,
+                    backend = notifier.name(),
+                    error = %e,
+                    "Reminder delivery failed"
+                ),
+            }
+        }
+        gone
+    }
+
+    /// Runs [`Self::tick`] every `period` on the current runtime.
+    pub fn spawn(self, period: StdDuration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.tick().await {
+                    tracing::error!(error = %e, "Reminder scheduler tick failed");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::{MockClock, NotifyFuture, SmtpNotifier};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    /// Minimal SMTP server that accepts one message and returns its DATA.
+    async fn smtp_sink() -> (u16, tokio::sync::oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (read, mut write) = stream.into_split();
+            let mut lines = BufReader::new(read).lines();
+            write.write_all(b"220 sink ESMTP\r\n").await.unwrap();
+            let mut data = String::new();
+            let mut in_data = false;
+            while let Ok(Some(line)) = lines.next_line().await {
+                if in_data {
+                    if line == "." {
+                        in_data = false;
+                        write.write_all(b"250 OK\r\n").await.unwrap();
+                    } else {
+                        data.push_str(&line);
+                        data.push('\n');
+                    }
+                    continue;
+                }
+                let cmd = line.to_ascii_uppercase();
+                let reply: &[u8] = if cmd.starts_with("EHLO") || cmd.starts_with("HELO") {
+                    b"250 sink\r\n"
+                } else if cmd.starts_with("DATA") {
+                    in_data = true;
+                    b"354 go ahead\r\n"
+                } else if cmd.starts_with("QUIT") {
+                    write.write_all(b"221 bye\r\n").await.unwrap();
+                    break;
+                } else {
+                    b"250 OK\r\n"
+                };
+                write.write_all(reply).await.unwrap();
+            }
+            let _ = tx.send(data);
+        });
+        (port, rx)
+    }
+
+    fn recipient(email: bool) -> Recipient {
+        Recipient {
+            user_id: 1,
+            email: "user@example.com".into(),
+            settings: UserSettings {
+                reminder_email: email,
+                ..Default::default()
+            },
+            push_subscriptions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_fire_time_start_anchor() {
+        let start = utc("2026-03-10T14:00:00Z");
+        let at = ReminderService::fire_time(
+            ReminderAnchor::Start,
+            15,
+            Some(start),
+            None,
+            chrono_tz::Tz::UTC,
+        );
+        assert_eq!(at, Some(utc("2026-03-10T13:45:00Z")));
+        assert_eq!(
+            ReminderService::fire_time(ReminderAnchor::Start, 0, None, None, chrono_tz::Tz::UTC),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fire_time_due_anchor_uses_local_morning() {
+        let due = NaiveDate::from_ymd_opt(2026, 3, 10);
+        let at = ReminderService::fire_time(
+            ReminderAnchor::Due,
+            24 * 60,
+            None,
+            due,
+            chrono_tz::America::New_York,
+        );
+        // 09:00 EDT on Mar 9 (DST starts Mar 8) is 13:00 UTC.
+        assert_eq!(at, Some(utc("2026-03-09T13:00:00Z")));
+    }
+
+    #[test]
+    fn test_should_fire_with_mock_clock() {
+        let created = utc("2026-03-10T08:00:00Z");
+        let fire_at = utc("2026-03-10T09:00:00Z");
+        let clock = MockClock::new(utc("2026-03-10T08:59:00Z"));
+
+        assert!(!ReminderService::should_fire(
+            fire_at,
+            None,
+            created,
+            clock.now()
+        ));
+        clock.advance(Duration::minutes(1));
+        assert!(ReminderService::should_fire(
+            fire_at,
+            None,
+            created,
+            clock.now()
+        ));
+        // Already delivered for this fire time.
+        assert!(!ReminderService::should_fire(
+            fire_at,
+            Some(fire_at),
+            created,
+            clock.now()
+        ));
+        // Rescheduled: a new fire time fires again.
+        let later = utc("2026-03-10T10:00:00Z");
+        clock.set(later);
+        assert!(ReminderService::should_fire(
+            later,
+            Some(fire_at),
+            created,
+            clock.now()
+        ));
+        // Too stale to deliver.
+        clock.advance(Duration::hours(13));
+        assert!(!ReminderService::should_fire(
+            later,
+            None,
+            created,
+            clock.now()
+        ));
+    }
+
+    #[test]
+    fn test_reminder_created_after_fire_time_stays_silent() {
+        let fire_at = utc("2026-03-10T09:00:00Z");
+        let created = utc("2026-03-10T09:30:00Z");
+        let now = utc("2026-03-10T09:31:00Z");
+        assert!(!ReminderService::should_fire(fire_at, None, created, now));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_delivers_email_to_smtp_sink() {
+        let (port, received) = smtp_sink().await;
+        let smtp = SmtpNotifier::from_url(
+            &format!("smtp://127.0.0.1:{port}"),
+            "North <north@localhost>",
+        )
+        .unwrap();
+
+        let manager = diesel_async::pooled_connection::AsyncDieselConnectionManager::<
+            diesel_async::AsyncPgConnection,
+        >::new("postgres://unused");
+        let pool = DbPool::builder(manager).build().unwrap();
+        let clock = Arc::new(MockClock::new(utc("2026-03-10T09:00:00Z")));
+        let scheduler = ReminderScheduler::new(pool, clock, vec![Arc::new(smtp)]);
+
+        let due = DueReminder {
+            reminder_id: 1,
+            fire_at: utc("2026-03-10T09:00:00Z"),
+            recipient: recipient(true),
+            notification: Notification {
+                task_id: Some(7),
+                title: "Water the plants".into(),
+                body: "Due Mar 10".into(),
+            },
+        };
+        assert!(scheduler.dispatch(&due).await.is_empty());
+
+        let data = tokio::time::timeout(StdDuration::from_secs(5), received)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(data.contains("Subject: Reminder: Water the plants"));
+        assert!(data.contains("To: user@example.com"));
+        assert!(data.contains("Due Mar 10"));
+    }
+
+    /// Backend that records the titles it was asked to deliver.
+    #[derive(Default)]
+    struct RecordingNotifier(std::sync::Mutex<Vec<String>>);
+
+    impl Notifier for RecordingNotifier {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn enabled_for(&self, _recipient: &Recipient) -> bool {
+            true
+        }
+
+        fn send<'a>(
+            &'a self,
+            _recipient: &'a Recipient,
+            notification: &'a Notification,
+        ) -> NotifyFuture<'a> {
+            Box::pin(async move {
+                self.0.lock().unwrap().push(notification.title.clone());
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tick_fires_each_reminder_once() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = crate::test_support::user(&pool, UserSettings::default()).await;
+        let start_task = crate::TaskService::create(
+            &pool,
+            user_id,
+            &north_dto::CreateTask {
+                title: "Water the plants".into(),
+                start_at: Some(utc("2031-05-01T14:00:00Z")),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let due_task = crate::TaskService::create(
+            &pool,
+            user_id,
+            &north_dto::CreateTask {
+                title: "File taxes".into(),
+                due_date: NaiveDate::from_ymd_opt(2031, 5, 2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        for (task_id, anchor, offset_minutes) in [
+            (start_task.id, ReminderAnchor::Start, 15),
+            (due_task.id, ReminderAnchor::Due, 24 * 60),
+        ] {
+            let input = CreateReminder {
+                anchor,
+                offset_minutes,
+            };
+            ReminderService::create(&pool, user_id, task_id, &input)
+                .await
+                .unwrap();
+        }
+
+        let notifier = Arc::new(RecordingNotifier::default());
+        let clock = Arc::new(MockClock::new(utc("2031-05-01T08:59:00Z")));
+        let scheduler = ReminderScheduler::new(pool, clock.clone(), vec![notifier.clone()]);
+        let sent = || notifier.0.lock().unwrap().clone();
+
+        assert_eq!(scheduler.tick().await.unwrap(), 0);
+        // 09:00 UTC the day before the due date.
+        clock.advance(Duration::minutes(1));
+        assert_eq!(scheduler.tick().await.unwrap(), 1);
+        assert_eq!(sent(), vec!["File taxes"]);
+
+        clock.set(utc("2031-05-01T13:44:00Z"));
+        assert_eq!(scheduler.tick().await.unwrap(), 0);
+        clock.advance(Duration::minutes(1));
+        assert_eq!(scheduler.tick().await.unwrap(), 1);
+        // Nothing fires twice.
+        clock.advance(Duration::minutes(1));
+        assert_eq!(scheduler.tick().await.unwrap(), 0);
+        assert_eq!(sent(), vec!["File taxes", "Water the plants"]);
+    }
+
+    #[tokio::test]
+    async fn test_push_subscription_stays_with_its_owner() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let owner = crate::test_support::user(&pool, UserSettings::default()).await;
+        let other = crate::test_support::user(&pool, UserSettings::default()).await;
+        let endpoint = format!("https://1.1.1.1/push/{owner}");
+        let input = |endpoint: &str| PushSubscriptionInput {
+            endpoint: endpoint.to_string(),
+            p256dh: "key".into(),
+            auth: "auth".into(),
+        };
+
+        let internal = input("http://169.254.169.254/latest");
+        let err = ReminderService::save_push_subscription(&pool, owner, &internal).await;
+        assert!(matches!(err, Err(ServiceError::BadRequest(_))));
+
+        ReminderService::save_push_subscription(&pool, owner, &input(&endpoint))
+            .await
+            .unwrap();
+        // Re-registering is an update for the owner and refused for anyone else.
+        ReminderService::save_push_subscription(&pool, owner, &input(&endpoint))
+            .await
+            .unwrap();
+        let err = ReminderService::save_push_subscription(&pool, other, &input(&endpoint)).await;
+        assert!(matches!(err, Err(ServiceError::BadRequest(_))));
+
+        let mut conn = pool.get().await.unwrap();
+        let owners: Vec<i64> = push_subscriptions::table
+            .filter(push_subscriptions::endpoint.eq(&endpoint))
+            .select(push_subscriptions::user_id)
+            .load(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(owners, [owner]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_disabled_backends() {
+        let smtp = SmtpNotifier::from_url("smtp://127.0.0.1:9", "north@localhost").unwrap();
+        assert!(!smtp.enabled_for(&recipient(false)));
+        assert!(smtp.enabled_for(&recipient(true)));
+    }
+}
@@ -0,0 +1,212 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use north_db::schema::{areas, projects, saved_filters, tags, tasks};
+use north_db::sql_types::ProjectStatusMapping;
+use north_db::DbPool;
+use north_dto::{fuzzy_score, SearchResult, SearchResultKind};
+
+use crate::ServiceResult;
+
+/// How many results a search returns unless asked otherwise.
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+/// Completed tasks rank below open tasks with the same match.
+const COMPLETED_PENALTY: i32 = 40;
+/// Most tasks scored per search: open ones first, then the most recently
+/// completed.
+const MAX_TASK_CANDIDATES: i64 = 500;
+
+/// Task id, title, completion time and project title.
+type TaskHit = (i64, String, Option<DateTime<Utc>>, Option<String>);
+
+pub struct SearchService;
+
+impl SearchService {
+    /// Fuzzy-matches `query` against the titles of the user's active
+    /// projects, saved filters, tags and tasks. Trashed items are skipped.
+    pub async fn search(
+        pool: &DbPool,
+        user_id: i64,
+        query: &str,
+        limit: Option<i64>,
+    ) -> ServiceResult<Vec<SearchResult>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(vec![]);
+        }
+        let limit = limit
+            .map(|l| (l.max(1) as usize).min(MAX_LIMIT))
+            .unwrap_or(DEFAULT_LIMIT);
+        // Rows that can't match are dropped in SQL; fuzzy_score ranks the rest.
+        let pattern = subsequence_pattern(query);
+        let mut conn = pool.get().await?;
+        let mut results = Vec::new();
+
+        let project_rows: Vec<(i64, String, String)> = projects::table
+            .left_join(areas::table)
+            .filter(projects::user_id.eq(user_id))
+            .filter(projects::status.eq(ProjectStatusMapping::Active))
+            .filter(
+                areas::status
+                    .nullable()
+                    .is_distinct_from(ProjectStatusMapping::Archived),
+            )
+            .filter(projects::deleted_at.is_null())
+            .filter(projects::title.ilike(&pattern))
+            .select((projects::id, projects::title, projects::color))
+            .load(&mut conn)
+            .await?;
+        for (id, title, color) in project_rows {
+            if let Some(score) = fuzzy_score(query, &title) {
+                results.push(SearchResult {
+                    kind: SearchResultKind::Project,
+                    id,
+                    title,
+                    color: Some(color),
+                    detail: None,
+                    score,
+                });
+            }
+        }
+
+        let filter_rows: Vec<(i64, String)> = saved_filters::table
+            .filter(saved_filters::user_id.eq(user_id))
+            .filter(saved_filters::title.ilike(&pattern))
+            .select((saved_filters::id, saved_filters::title))
+            .load(&mut conn)
+            .await?;
+        for (id, title) in filter_rows {
+            if let Some(score) = fuzzy_score(query, &title) {
+                results.push(SearchResult {
+                    kind: SearchResultKind::Filter,
+                    id,
+                    title,
+                    color: None,
+                    detail: None,
+                    score,
+                });
+            }
+        }
+
+        let tag_query = query.strip_prefix('#').unwrap_or(query);
+        let tag_rows: Vec<(i64, String, String)> = tags::table
+            .filter(tags::user_id.eq(user_id))
+            .filter(tags::name.ilike(subsequence_pattern(tag_query)))
+            .select((tags::id, tags::name, tags::color))
+            .load(&mut conn)
+            .await?;
+        for (id, name, color) in tag_rows {
+            if let Some(score) = fuzzy_score(tag_query, &name) {
+                results.push(SearchResult {
+                    kind: SearchResultKind::Tag,
+                    id,
+                    title: name,
+                    color: Some(color),
+                    detail: None,
+                    score,
+                });
+            }
+        }
+
+        let task_rows: Vec<TaskHit> = tasks::table
+            .left_join(projects::table)
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .filter(tasks::title.ilike(&pattern))
+            .order((tasks::completed_at.desc().nulls_first(), tasks::id.desc()))
+            .limit(MAX_TASK_CANDIDATES)
+            .select((
+                tasks::id,
+                tasks::title,
+                tasks::completed_at,
+                projects::title.nullable(),
+            ))
+            .load(&mut conn)
+            .await?;
+        for (id, title, completed_at, project_title) in task_rows {
+            if let Some(score) = fuzzy_score(query, &title) {
+                let completed = completed_at.is_some();
+                results.push(SearchResult {
+                    kind: SearchResultKind::Task,
+                    id,
+                    title,
+                    color: None,
+                    detail: if completed {
+                        Some("Completed".into())
+                    } else {
+                        project_title
+                    },
+                    score: if completed {
+                        score - COMPLETED_PENALTY
+                    } else {
+                        score
+                    },
+                });
+            }
+        }
+
+        // Stable sort keeps projects, filters and tags ahead of tasks on ties.
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        Ok(results)
+    }
+}
+
+/// An ILIKE pattern matching titles that contain the query's characters in
+/// order, which every title `fuzzy_score` accepts does.
+fn subsequence_pattern(query: &str) -> String {
+    let mut pattern = String::from("%");
+    for c in query.chars().filter(|c| !c.is_whitespace()) {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+        pattern.push('%');
+    }
+    pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_pattern() {
+        assert_eq!(subsequence_pattern("gr den"), "%g%r%d%e%n%");
+        assert_eq!(subsequence_pattern("50%_a\\b"), "%5%0%\\%%\\_%a%\\\\%b%");
+        assert_eq!(subsequence_pattern(""), "%");
+    }
+
+    #[tokio::test]
+    async fn test_search_prefilters_in_sql() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = crate::test_support::user(&pool, Default::default()).await;
+        for title in ["Water the plants", "50% off seeds", "Buy 5 bags of soil"] {
+            let input = north_dto::CreateTask {
+                title: title.into(),
+                ..Default::default()
+            };
+            crate::TaskService::create(&pool, user_id, &input)
+                .await
+                .unwrap();
+        }
+        let titles = |query: &'static str| {
+            let pool = pool.clone();
+            async move {
+                SearchService::search(&pool, user_id, query, None)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|r| r.title)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(titles("WTR plnt").await, ["Water the plants"]);
+        assert_eq!(titles("50%").await, ["50% off seeds"]);
+        assert!(titles("plants water").await.is_empty());
+    }
+}
@@ -1,57 +1,347 @@
+// The QueryableByName derive expands to `field: field` initializers.
+#![allow(clippy::redundant_field_names)]
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel::sql_types::{BigInt, Date, Int4, Int8, Text, Timestamptz};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use north_db::schema::{tasks, weekly_reviews};
 use north_db::DbPool;
-use serde::Serialize;
+use north_dto::{
+    CompletionRate, Dashboard, HabitAdherence, OccurrenceStatus, OverdueBucket, ProjectFilter,
+    ProjectStatus, ReviewBacklog, SeriesOccurrence, Stats, StatsBucket, StatsRange, TrendPoint,
+};
 
 use crate::ServiceResult;
 
-#[derive(Debug, Serialize)]
-pub struct Stats {
-    pub created_today: i64,
-    pub completed_today: i64,
-    pub created_week: i64,
-    pub completed_week: i64,
-    pub total_open: i64,
-    pub total_completed: i64,
-}
+/// Projects and tags listed in the completion rates, busiest first.
+const COMPLETION_LIMIT: i64 = 10;
+
+/// Ranges of days overdue the distribution is grouped into.
+const OVERDUE_BUCKETS: [(i32, Option<i32>); 4] =
+    [(1, Some(1)), (2, Some(7)), (8, Some(30)), (31, None)];
 
 pub struct StatsService;
 
 impl StatsService {
     pub async fn get_stats(pool: &DbPool, user_id: i64) -> ServiceResult<Stats> {
+        let settings = crate::UserService::get_settings(pool, user_id).await?;
+        let tz: chrono_tz::Tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+        let today = Utc::now().with_timezone(&tz).date_naive();
         let mut conn = pool.get().await?;
+        Self::totals(&mut conn, user_id, tz, today).await
+    }
+
+    /// Aggregates for the stats page over `range`, ending today in the
+    /// user's timezone. Dates are bucketed by the user's local day.
+    pub async fn dashboard(
+        pool: &DbPool,
+        user_id: i64,
+        range: StatsRange,
+    ) -> ServiceResult<Dashboard> {
+        let settings = crate::UserService::get_settings(pool, user_id).await?;
+        let tz: chrono_tz::Tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        let bucket = range.bucket();
+        // Widened to a whole first bucket, so every bar covers a full period.
+        let start = bucket.truncate(range.start(today));
+        let since = start_of_day(tz, start);
+
+        let projects_due = crate::ProjectService::list(
+            pool,
+            user_id,
+            &ProjectFilter {
+                status: Some(ProjectStatus::Active),
+                review_due: Some(true),
+                ..Default::default()
+            },
+        )
+        .await?
+        .len() as i64;
+
+        let mut conn = pool.get().await?;
+        let totals = Self::totals(&mut conn, user_id, tz, today).await?;
+
+        let created =
+            Self::bucket_counts(&mut conn, user_id, "created_at", tz, bucket, since).await?;
+        let completed =
+            Self::bucket_counts(&mut conn, user_id, "completed_at", tz, bucket, since).await?;
+        let trend = fill_trend(start, today, bucket, &created, &completed);
+
+        let projects = diesel::sql_query(
+            "SELECT p.id, p.title AS name, p.color, \
+                COUNT(*) FILTER (WHERE t.completed_at IS NOT NULL) AS completed, \
+                COUNT(*) AS total \
+             FROM tasks t JOIN projects p ON p.id = t.project_id \
+             WHERE t.user_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL \
+               AND (t.completed_at IS NULL OR t.completed_at >= $2) \
+             GROUP BY p.id, p.title, p.color \
+             ORDER BY total DESC, p.title \
+             LIMIT $3",
+        )
+        .bind::<Int8, _>(user_id)
+        .bind::<Timestamptz, _>(since)
+        .bind::<BigInt, _>(COMPLETION_LIMIT)
+        .load::<CompletionRow>(&mut conn)
+        .await?;
+
+        let tags = diesel::sql_query(
+            "SELECT g.id, g.name, g.color, \
+                COUNT(*) FILTER (WHERE t.completed_at IS NOT NULL) AS completed, \
+                COUNT(*) AS total \
+             FROM tasks t \
+             JOIN task_tags tt ON tt.task_id = t.id \
+             JOIN tags g ON g.id = tt.tag_id \
+             WHERE t.user_id = $1 AND t.deleted_at IS NULL \
+               AND (t.completed_at IS NULL OR t.completed_at >= $2) \
+             GROUP BY g.id, g.name, g.color \
+             ORDER BY total DESC, g.name \
+             LIMIT $3",
+        )
+        .bind::<Int8, _>(user_id)
+        .bind::<Timestamptz, _>(since)
+        .bind::<BigInt, _>(COMPLETION_LIMIT)
+        .load::<CompletionRow>(&mut conn)
+        .await?;
+
+        let overdue = diesel::sql_query(
+            "SELECT ($2 - due_date) AS days, COUNT(*) AS n \
+             FROM tasks \
+             WHERE user_id = $1 AND deleted_at IS NULL AND completed_at IS NULL \
+               AND due_date < $2 \
+             GROUP BY 1",
+        )
+        .bind::<Int8, _>(user_id)
+        .bind::<Date, _>(today)
+        .load::<OverdueRow>(&mut conn)
+        .await?;
+        let overdue: Vec<(i32, i64)> = overdue.into_iter().map(|r| (r.days, r.n)).collect();
 
-        let row: (i64, i64, i64, i64, i64, i64) = diesel::sql_query(
+        // Same selection as the Review page.
+        let review_cutoff = today - Duration::days(i64::from(settings.review_interval_days));
+        let tasks_due = diesel::sql_query(
+            "SELECT COUNT(*) AS n \
+             FROM tasks t \
+             LEFT JOIN projects p ON p.id = t.project_id \
+             LEFT JOIN areas a ON a.id = p.area_id \
+             WHERE t.user_id = $1 AND t.deleted_at IS NULL AND t.completed_at IS NULL \
+               AND t.parent_id IS NULL AND NOT t.someday AND t.waiting_for IS NULL \
+               AND (t.project_id IS NULL OR (p.deleted_at IS NULL AND p.status = 'active' \
+                    AND (a.id IS NULL OR a.status = 'active'))) \
+               AND (t.reviewed_at IS NULL OR t.reviewed_at <= $2)",
+        )
+        .bind::<Int8, _>(user_id)
+        .bind::<Date, _>(review_cutoff)
+        .get_result::<CountRow>(&mut conn)
+        .await?
+        .n;
+        let last_weekly_review = weekly_reviews::table
+            .filter(weekly_reviews::user_id.eq(user_id))
+            .select(diesel::dsl::max(weekly_reviews::completed_at))
+            .first::<Option<DateTime<Utc>>>(&mut conn)
+            .await?;
+
+        let recurring: Vec<RecurringRow> = tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .filter(
+                tasks::series_id
+                    .is_not_null()
+                    .or(tasks::recurrence_rule.is_not_null()),
+            )
+            .filter(tasks::due_date.ge(start).or(tasks::start_at.ge(since)))
+            .select(RecurringRow::as_select())
+            .load(&mut conn)
+            .await?;
+
+        Ok(Dashboard {
+            range,
+            start,
+            today,
+            bucket,
+            totals,
+            trend,
+            projects: projects.into_iter().map(CompletionRate::from).collect(),
+            tags: tags.into_iter().map(CompletionRate::from).collect(),
+            overdue: bucket_overdue(&overdue),
+            review: ReviewBacklog {
+                tasks_due,
+                projects_due,
+                last_weekly_review,
+            },
+            habits: adherence(&recurring, tz, start, today),
+        })
+    }
+
+    async fn totals(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        tz: chrono_tz::Tz,
+        today: NaiveDate,
+    ) -> ServiceResult<Stats> {
+        let week_start = StatsBucket::Week.truncate(today);
+        let row = diesel::sql_query(
             "SELECT \
-                COUNT(*) FILTER (WHERE created_at::date = CURRENT_DATE) as created_today, \
-                COUNT(*) FILTER (WHERE completed_at::date = CURRENT_DATE) as completed_today, \
-                COUNT(*) FILTER (WHERE created_at >= date_trunc('week', CURRENT_DATE)) as created_week, \
-                COUNT(*) FILTER (WHERE completed_at >= date_trunc('week', CURRENT_DATE)) as completed_week, \
+                COUNT(*) FILTER (WHERE created_at >= $2) as created_today, \
+                COUNT(*) FILTER (WHERE completed_at >= $2) as completed_today, \
+                COUNT(*) FILTER (WHERE created_at >= $3) as created_week, \
+                COUNT(*) FILTER (WHERE completed_at >= $3) as completed_week, \
                 COUNT(*) FILTER (WHERE completed_at IS NULL) as total_open, \
                 COUNT(*) FILTER (WHERE completed_at IS NOT NULL) as total_completed \
-             FROM tasks WHERE user_id = $1",
+             FROM tasks WHERE user_id = $1 AND deleted_at IS NULL",
         )
-        .bind::<diesel::sql_types::Int8, _>(user_id)
-        .get_result::<StatsRow>(&mut conn)
-        .await
-        .map(|r| (
-            r.created_today,
-            r.completed_today,
-            r.created_week,
-            r.completed_week,
-            r.total_open,
-            r.total_completed,
-        ))?;
+        .bind::<Int8, _>(user_id)
+        .bind::<Timestamptz, _>(start_of_day(tz, today))
+        .bind::<Timestamptz, _>(start_of_day(tz, week_start))
+        .get_result::<StatsRow>(conn)
+        .await?;
 
         Ok(Stats {
-            created_today: row.0,
-            completed_today: row.1,
-            created_week: row.2,
-            completed_week: row.3,
-            total_open: row.4,
-            total_completed: row.5,
+            created_today: row.created_today,
+            completed_today: row.completed_today,
+            created_week: row.created_week,
+            completed_week: row.completed_week,
+            total_open: row.total_open,
+            total_completed: row.total_completed,
         })
     }
+
+    /// Tasks per local-date bucket of `column` since `since`.
+    async fn bucket_counts(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        column: &'static str,
+        tz: chrono_tz::Tz,
+        bucket: StatsBucket,
+        since: DateTime<Utc>,
+    ) -> ServiceResult<Vec<(NaiveDate, i64)>> {
+        let rows = diesel::sql_query(format!(
+            "SELECT date_trunc($2, {column} AT TIME ZONE $3)::date AS bucket, COUNT(*) AS n \
+             FROM tasks \
+             WHERE user_id = $1 AND deleted_at IS NULL AND {column} >= $4 \
+             GROUP BY 1"
+        ))
+        .bind::<Int8, _>(user_id)
+        .bind::<Text, _>(bucket.unit())
+        .bind::<Text, _>(tz.name())
+        .bind::<Timestamptz, _>(since)
+        .load::<BucketRow>(conn)
+        .await?;
+        Ok(rows.into_iter().map(|r| (r.bucket, r.n)).collect())
+    }
+}
+
+/// The instant local midnight starts `date` in `tz`.
+fn start_of_day(tz: chrono_tz::Tz, date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|midnight| tz.from_local_datetime(&midnight).earliest())
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| date.and_time(Default::default()).and_utc())
+}
+
+/// One point per bucket from `start` through `today`, zero where nothing
+/// happened.
+fn fill_trend(
+    start: NaiveDate,
+    today: NaiveDate,
+    bucket: StatsBucket,
+    created: &[(NaiveDate, i64)],
+    completed: &[(NaiveDate, i64)],
+) -> Vec<TrendPoint> {
+    let created: HashMap<_, _> = created.iter().copied().collect();
+    let completed: HashMap<_, _> = completed.iter().copied().collect();
+    let mut points = Vec::new();
+    let mut date = bucket.truncate(start);
+    while date <= today {
+        points.push(TrendPoint {
+            date,
+            created: created.get(&date).copied().unwrap_or(0),
+            completed: completed.get(&date).copied().unwrap_or(0),
+        });
+        date = bucket.next(date);
+    }
+    points
+}
+
+/// Groups `(days overdue, tasks)` counts into [`OVERDUE_BUCKETS`].
+fn bucket_overdue(days: &[(i32, i64)]) -> Vec<OverdueBucket> {
+    OVERDUE_BUCKETS
+        .iter()
+        .map(|&(min_days, max_days)| OverdueBucket {
+            min_days,
+            max_days,
+            count: days
+                .iter()
+                .filter(|(d, _)| *d >= min_days && max_days.is_none_or(|max| *d <= max))
+                .map(|(_, n)| n)
+                .sum(),
+        })
+        .collect()
+}
+
+/// On-time record of each recurring series over the occurrences scheduled
+/// from `start` through `today`, judged like [`SeriesOccurrence`]. Today's
+/// open occurrences aren't counted yet. Worst kept first.
+fn adherence(
+    rows: &[RecurringRow],
+    tz: chrono_tz::Tz,
+    start: NaiveDate,
+    today: NaiveDate,
+) -> Vec<HabitAdherence> {
+    let mut by_series: HashMap<i64, (i64, HabitAdherence)> = HashMap::new();
+    for row in rows {
+        let scheduled_on = row
+            .due_date
+            .or_else(|| row.start_at.map(|s| s.with_timezone(&tz).date_naive()));
+        let Some(scheduled_on) = scheduled_on.filter(|d| *d >= start && *d <= today) else {
+            continue;
+        };
+        let completed_on = row.completed_at.map(|c| c.with_timezone(&tz).date_naive());
+        let status = SeriesOccurrence::new(
+            row.id,
+            Some(scheduled_on),
+            row.completed_at,
+            completed_on,
+            today,
+        )
+        .status;
+        let series_id = row.series_id.unwrap_or(row.id);
+        let (latest_id, habit) = by_series.entry(series_id).or_insert_with(|| {
+            (
+                row.id,
+                HabitAdherence {
+                    series_id,
+                    title: row.title.clone(),
+                    on_time: 0,
+                    late: 0,
+                    missed: 0,
+                },
+            )
+        });
+        if row.id > *latest_id {
+            *latest_id = row.id;
+            habit.title = row.title.clone();
+        }
+        match status {
+            OccurrenceStatus::OnTime => habit.on_time += 1,
+            OccurrenceStatus::Late => habit.late += 1,
+            OccurrenceStatus::Missed => habit.missed += 1,
+            OccurrenceStatus::Pending => {}
+        }
+    }
+    let mut habits: Vec<HabitAdherence> = by_series
+        .into_values()
+        .map(|(_, habit)| habit)
+        .filter(|h| h.on_time + h.late + h.missed > 0)
+        .collect();
+    habits.sort_by(|a, b| {
+        a.percent()
+            .cmp(&b.percent())
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    habits
 }
 
 #[derive(Debug, QueryableByName)]
@@ -69,3 +359,146 @@ struct StatsRow {
     #[diesel(sql_type = diesel::sql_types::Int8)]
     total_completed: i64,
 }
+
+#[derive(Debug, QueryableByName)]
+struct BucketRow {
+    #[diesel(sql_type = Date)]
+    bucket: NaiveDate,
+    #[diesel(sql_type = Int8)]
+    n: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+struct OverdueRow {
+    #[diesel(sql_type = Int4)]
+    days: i32,
+    #[diesel(sql_type = Int8)]
+    n: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = Int8)]
+    n: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+struct CompletionRow {
+    #[diesel(sql_type = Int8)]
+    id: i64,
+    #[diesel(sql_type = Text)]
+    name: String,
+    #[diesel(sql_type = Text)]
+    color: String,
+    #[diesel(sql_type = Int8)]
+    completed: i64,
+    #[diesel(sql_type = Int8)]
+    total: i64,
+}
+
+impl From<CompletionRow> for CompletionRate {
+    fn from(row: CompletionRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            color: row.color,
+            completed: row.completed,
+            total: row.total,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = tasks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct RecurringRow {
+    id: i64,
+    series_id: Option<i64>,
+    title: String,
+    due_date: Option<NaiveDate>,
+    start_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_fill_trend() {
+        let today = date(2026, 10, 21);
+        let points = fill_trend(
+            date(2026, 10, 19),
+            today,
+            StatsBucket::Day,
+            &[(date(2026, 10, 19), 2)],
+            &[(date(2026, 10, 21), 1)],
+        );
+        assert_eq!(points.len(), 3);
+        assert_eq!((points[0].created, points[0].completed), (2, 0));
+        assert_eq!((points[1].created, points[1].completed), (0, 0));
+        assert_eq!((points[2].created, points[2].completed), (0, 1));
+
+        let weeks = fill_trend(date(2026, 9, 30), today, StatsBucket::Week, &[], &[]);
+        let starts: Vec<_> = weeks.iter().map(|p| p.date).collect();
+        assert_eq!(
+            starts,
+            [
+                date(2026, 9, 28),
+                date(2026, 10, 5),
+                date(2026, 10, 12),
+                date(2026, 10, 19),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bucket_overdue() {
+        let buckets = bucket_overdue(&[(1, 3), (2, 1), (7, 2), (8, 1), (45, 4)]);
+        let counts: Vec<_> = buckets.iter().map(|b| b.count).collect();
+        assert_eq!(counts, [3, 3, 1, 4]);
+    }
+
+    #[test]
+    fn test_start_of_day_uses_timezone() {
+        let tz: chrono_tz::Tz = "Europe/Berlin".parse().unwrap();
+        assert_eq!(
+            start_of_day(tz, date(2026, 10, 19)).to_rfc3339(),
+            "2026-10-18T22:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_adherence() {
+        let tz = chrono_tz::Tz::UTC;
+        let today = date(2026, 10, 19);
+        let at = |d: NaiveDate| Some(d.and_hms_opt(9, 0, 0).unwrap().and_utc());
+        let row = |id, due: NaiveDate, done: Option<NaiveDate>| RecurringRow {
+            id,
+            series_id: Some(1),
+            title: format!("Run {id}"),
+            due_date: Some(due),
+            start_at: None,
+            completed_at: done.and_then(at),
+        };
+        let rows = [
+            row(1, date(2026, 10, 5), Some(date(2026, 10, 5))),
+            row(2, date(2026, 10, 12), Some(date(2026, 10, 13))),
+            row(3, date(2026, 10, 15), None),
+            // Due today and still open: not counted yet.
+            row(4, date(2026, 10, 19), None),
+            // Before the range.
+            row(5, date(2026, 9, 1), None),
+        ];
+        let habits = adherence(&rows, tz, date(2026, 10, 1), today);
+        assert_eq!(habits.len(), 1);
+        let habit = &habits[0];
+        assert_eq!(habit.title, "Run 4");
+        assert_eq!((habit.on_time, habit.late, habit.missed), (1, 1, 1));
+        assert_eq!(habit.percent(), 33);
+    }
+}
@@ -1,29 +1,274 @@
+use std::collections::HashMap;
+
+use diesel::dsl::count_star;
 use diesel::prelude::*;
-use diesel_async::{AsyncPgConnection, RunQueryDsl};
-use north_db::models::{NewTag, NewTaskTag, TagRow};
-use north_db::schema::{tags, task_tags};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use north_db::models::{NewTag, NewTaskTag, TagChangeset, TagRow};
+use north_db::schema::{tags, task_tags, tasks, users};
 use north_db::DbPool;
-use north_dto::Tag;
+use north_dto::{
+    enforce_exclusive_namespaces, is_hex_color, normalize_tag_name, CreateTag, Tag, UpdateTag,
+    UserSettings,
+};
 
-use crate::ServiceResult;
+use crate::{ServiceError, ServiceResult};
 
 pub struct TagService;
 
 impl TagService {
+    /// All of the user's tags with their task counts, including tags no
+    /// task uses any more.
     pub async fn list(pool: &DbPool, user_id: i64) -> ServiceResult<Vec<Tag>> {
         let mut conn = pool.get().await?;
         let rows = tags::table
-            .inner_join(task_tags::table.on(task_tags::tag_id.eq(tags::id)))
             .filter(tags::user_id.eq(user_id))
             .select(TagRow::as_select())
-            .distinct()
             .order(tags::name.asc())
             .load(&mut conn)
             .await?;
-        Ok(rows.into_iter().map(Tag::from).collect())
+        let counts = Self::task_counts(&mut conn, user_id).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let task_count = counts.get(&row.id).copied().unwrap_or(0);
+                Tag {
+                    task_count,
+                    ..Tag::from(row)
+                }
+            })
+            .collect())
+    }
+
+    pub async fn get_by_id(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<Tag> {
+        let mut conn = pool.get().await?;
+        Self::get_with_count(&mut conn, user_id, id).await
+    }
+
+    pub async fn create(pool: &DbPool, user_id: i64, input: &CreateTag) -> ServiceResult<Tag> {
+        let name = validate_name(&input.name)?;
+        let color = input.color.as_deref().unwrap_or(north_dto::DEFAULT_COLOR);
+        validate_color(color)?;
+
+        let mut conn = pool.get().await?;
+        let row = diesel::insert_into(tags::table)
+            .values(&NewTag {
+                user_id,
+                name: &name,
+                color,
+            })
+            .on_conflict((tags::user_id, tags::name))
+            .do_nothing()
+            .returning(TagRow::as_returning())
+            .get_result(&mut conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::BadRequest(format!("Tag #{name} already exists")))?;
+        Ok(Tag::from(row))
+    }
+
+    /// Renames and/or recolors a tag. Tasks link to tags by id, so a rename
+    /// shows up on every task at once. Renaming onto an existing tag is
+    /// refused; merge them instead.
+    pub async fn update(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &UpdateTag,
+    ) -> ServiceResult<Tag> {
+        let name = input.name.as_deref().map(validate_name).transpose()?;
+        if let Some(ref color) = input.color {
+            validate_color(color)?;
+        }
+
+        let mut conn = pool.get().await?;
+        if let Some(ref name) = name {
+            let taken: i64 = tags::table
+                .filter(tags::user_id.eq(user_id))
+                .filter(tags::name.eq(name))
+                .filter(tags::id.ne(id))
+                .count()
+                .get_result(&mut conn)
+                .await?;
+            if taken > 0 {
+                return Err(ServiceError::BadRequest(format!(
+                    "Tag #{name} already exists; merge the tags instead"
+                )));
+            }
+        }
+
+        let changeset = TagChangeset {
+            name: name.as_deref(),
+            color: input.color.as_deref(),
+        };
+        if changeset.name.is_some() || changeset.color.is_some() {
+            let affected = diesel::update(
+                tags::table
+                    .filter(tags::id.eq(id))
+                    .filter(tags::user_id.eq(user_id)),
+            )
+            .set(&changeset)
+            .execute(&mut conn)
+            .await?;
+            if affected == 0 {
+                return Err(ServiceError::NotFound("Tag not found".into()));
+            }
+        }
+        Self::get_with_count(&mut conn, user_id, id).await
+    }
+
+    /// Deletes a tag and removes it from every task.
+    pub async fn delete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        let affected = diesel::delete(
+            tags::table
+                .filter(tags::id.eq(id))
+                .filter(tags::user_id.eq(user_id)),
+        )
+        .execute(&mut conn)
+        .await?;
+        if affected == 0 {
+            return Err(ServiceError::NotFound("Tag not found".into()));
+        }
+        Ok(())
+    }
+
+    /// Moves every task tagged `source_id` onto `into_id`, then deletes the
+    /// source tag. Returns the surviving tag.
+    pub async fn merge(
+        pool: &DbPool,
+        user_id: i64,
+        source_id: i64,
+        into_id: i64,
+    ) -> ServiceResult<Tag> {
+        if source_id == into_id {
+            return Err(ServiceError::BadRequest(
+                "Can't merge a tag into itself".into(),
+            ));
+        }
+
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                // Locked so neither tag can be deleted or handed over mid-merge.
+                let owned: Vec<i64> = tags::table
+                    .filter(tags::id.eq_any([source_id, into_id]))
+                    .filter(tags::user_id.eq(user_id))
+                    .select(tags::id)
+                    .for_update()
+                    .load(conn)
+                    .await?;
+                if owned.len() != 2 {
+                    return Err(ServiceError::NotFound("Tag not found".into()));
+                }
+
+                let task_ids: Vec<i64> = task_tags::table
+                    .filter(task_tags::tag_id.eq(source_id))
+                    .select(task_tags::task_id)
+                    .load(conn)
+                    .await?;
+                let links: Vec<NewTaskTag> = task_ids
+                    .into_iter()
+                    .map(|task_id| NewTaskTag {
+                        task_id,
+                        tag_id: into_id,
+                    })
+                    .collect();
+                if !links.is_empty() {
+                    diesel::insert_into(task_tags::table)
+                        .values(&links)
+                        .on_conflict((task_tags::task_id, task_tags::tag_id))
+                        .do_nothing()
+                        .execute(conn)
+                        .await?;
+                }
+                diesel::delete(tags::table.filter(tags::id.eq(source_id)))
+                    .execute(conn)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        Self::get_with_count(&mut conn, user_id, into_id).await
+    }
+
+    /// Deletes every tag no task uses. Tasks in the trash still count, so
+    /// restoring one brings its tags back. Returns how many were removed.
+    pub async fn delete_unused(pool: &DbPool, user_id: i64) -> ServiceResult<usize> {
+        let mut conn = pool.get().await?;
+        let used = task_tags::table.select(task_tags::tag_id);
+        let deleted = diesel::delete(
+            tags::table
+                .filter(tags::user_id.eq(user_id))
+                .filter(tags::id.ne_all(used)),
+        )
+        .execute(&mut conn)
+        .await?;
+        Ok(deleted)
+    }
+
+    async fn get_with_count(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<Tag> {
+        let row = tags::table
+            .filter(tags::id.eq(id))
+            .filter(tags::user_id.eq(user_id))
+            .select(TagRow::as_select())
+            .first(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Tag not found".into()))?;
+        let task_count: i64 = task_tags::table
+            .inner_join(tasks::table)
+            .filter(task_tags::tag_id.eq(id))
+            .filter(tasks::deleted_at.is_null())
+            .count()
+            .get_result(conn)
+            .await?;
+        Ok(Tag {
+            task_count,
+            ..Tag::from(row)
+        })
+    }
+
+    async fn task_counts(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+    ) -> ServiceResult<HashMap<i64, i64>> {
+        let counts: Vec<(i64, i64)> = task_tags::table
+            .inner_join(tags::table.on(tags::id.eq(task_tags::tag_id)))
+            .inner_join(tasks::table)
+            .filter(tags::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .group_by(task_tags::tag_id)
+            .select((task_tags::tag_id, count_star()))
+            .load(conn)
+            .await?;
+        Ok(counts.into_iter().collect())
+    }
+
+    async fn exclusive_namespaces(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+    ) -> ServiceResult<Vec<String>> {
+        let settings: Option<serde_json::Value> = users::table
+            .filter(users::id.eq(user_id))
+            .select(users::settings)
+            .first(conn)
+            .await
+            .optional()?;
+        Ok(settings
+            .and_then(|v| serde_json::from_value::<UserSettings>(v).ok())
+            .map(|s| s.exclusive_tag_namespaces)
+            .unwrap_or_default())
     }
 
     /// Sync task tags: upsert tags by name, delete old task_tags, insert new ones.
+    /// Within an exclusive namespace the last name given wins.
     /// Caller must provide an active connection (for transaction support).
     pub async fn sync_task_tags(
         conn: &mut AsyncPgConnection,
@@ -31,6 +276,9 @@ impl TagService {
         task_id: i64,
         names: &[String],
     ) -> ServiceResult<()> {
+        let exclusive = Self::exclusive_namespaces(conn, user_id).await?;
+        let names = &enforce_exclusive_namespaces(names, &exclusive)[..];
+        let before = Self::task_tag_names(conn, task_id).await?;
         for name in names {
             diesel::insert_into(tags::table)
                 .values(&NewTag {
@@ -67,7 +315,7 @@ impl TagService {
                 .await?;
         }
 
-        Ok(())
+        crate::TaskEventService::record_tags(conn, user_id, task_id, before, names.to_vec()).await
     }
 
     /// Pool-level wrapper: gets a connection and calls sync_task_tags.
@@ -92,7 +340,8 @@ impl TagService {
         Self::add_task_tags(&mut conn, user_id, task_id, names).await
     }
 
-    /// Additive tag sync: upsert tags and add links without removing existing ones.
+    /// Additive tag sync: upsert tags and add links without removing existing
+    /// ones, except where a new tag displaces one in an exclusive namespace.
     pub async fn add_task_tags(
         conn: &mut AsyncPgConnection,
         user_id: i64,
@@ -103,6 +352,34 @@ impl TagService {
             return Ok(());
         }
 
+        let exclusive = Self::exclusive_namespaces(conn, user_id).await?;
+        let names = &enforce_exclusive_namespaces(names, &exclusive)[..];
+        let current: Vec<(i64, String)> = task_tags::table
+            .inner_join(tags::table.on(tags::id.eq(task_tags::tag_id)))
+            .filter(task_tags::task_id.eq(task_id))
+            .select((tags::id, tags::name))
+            .load(conn)
+            .await?;
+        let before: Vec<String> = current.iter().map(|(_, name)| name.clone()).collect();
+        let combined: Vec<String> = before.iter().chain(names).cloned().collect();
+        let after = enforce_exclusive_namespaces(&combined, &exclusive);
+        if !exclusive.is_empty() {
+            let displaced: Vec<i64> = current
+                .into_iter()
+                .filter(|(_, name)| !after.contains(name))
+                .map(|(id, _)| id)
+                .collect();
+            if !displaced.is_empty() {
+                diesel::delete(
+                    task_tags::table
+                        .filter(task_tags::task_id.eq(task_id))
+                        .filter(task_tags::tag_id.eq_any(displaced)),
+                )
+                .execute(conn)
+                .await?;
+            }
+        }
+
         for name in names {
             diesel::insert_into(tags::table)
                 .values(&NewTag {
@@ -135,6 +412,69 @@ impl TagService {
             .execute(conn)
             .await?;
 
+        crate::TaskEventService::record_tags(conn, user_id, task_id, before, after).await
+    }
+
+    /// Unlinks the named tags from a task, keeping the rest.
+    pub async fn remove_task_tags(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        task_id: i64,
+        names: &[String],
+    ) -> ServiceResult<()> {
+        let before = Self::task_tag_names(conn, task_id).await?;
+        let after: Vec<String> = before
+            .iter()
+            .filter(|name| !names.contains(name))
+            .cloned()
+            .collect();
+        if after.len() == before.len() {
+            return Ok(());
+        }
+
+        let tag_ids: Vec<i64> = tags::table
+            .filter(tags::user_id.eq(user_id))
+            .filter(tags::name.eq_any(names))
+            .select(tags::id)
+            .load(conn)
+            .await?;
+        diesel::delete(
+            task_tags::table
+                .filter(task_tags::task_id.eq(task_id))
+                .filter(task_tags::tag_id.eq_any(tag_ids)),
+        )
+        .execute(conn)
+        .await?;
+
+        crate::TaskEventService::record_tags(conn, user_id, task_id, before, after).await
+    }
+
+    async fn task_tag_names(
+        conn: &mut AsyncPgConnection,
+        task_id: i64,
+    ) -> ServiceResult<Vec<String>> {
+        let names = task_tags::table
+            .inner_join(tags::table.on(tags::id.eq(task_tags::tag_id)))
+            .filter(task_tags::task_id.eq(task_id))
+            .select(tags::name)
+            .load(conn)
+            .await?;
+        Ok(names)
+    }
+}
+
+fn validate_name(name: &str) -> ServiceResult<String> {
+    normalize_tag_name(name).ok_or_else(|| {
+        ServiceError::BadRequest(
+            "Tag names may only contain letters, digits, '-', '_', ':' and '.'".into(),
+        )
+    })
+}
+
+fn validate_color(color: &str) -> ServiceResult<()> {
+    if is_hex_color(color) {
         Ok(())
+    } else {
+        Err(ServiceError::BadRequest(format!("Invalid color: {color}")))
     }
 }
@@ -0,0 +1,186 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use north_db::models::{NewTaskComment, TaskCommentChangeset, TaskCommentRow};
+use north_db::schema::{task_comments, tasks, users};
+use north_db::DbPool;
+use north_dto::{CreateTaskComment, TaskComment, UpdateTaskComment};
+
+use crate::{ServiceError, ServiceResult};
+
+pub struct TaskCommentService;
+
+impl TaskCommentService {
+    /// The task's comment thread, oldest first.
+    pub async fn list_for_task(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+    ) -> ServiceResult<Vec<TaskComment>> {
+        let mut conn = pool.get().await?;
+        ensure_task_owned(&mut conn, user_id, task_id).await?;
+
+        let rows: Vec<(TaskCommentRow, String)> = task_comments::table
+            .inner_join(users::table.on(users::id.eq(task_comments::user_id)))
+            .filter(task_comments::task_id.eq(task_id))
+            .order((task_comments::created_at.asc(), task_comments::id.asc()))
+            .select((TaskCommentRow::as_select(), users::name))
+            .load(&mut conn)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(row, name)| row.into_dto(name))
+            .collect())
+    }
+
+    pub async fn create(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+        input: &CreateTaskComment,
+    ) -> ServiceResult<TaskComment> {
+        let body = normalize_body(&input.body)?;
+        let mut conn = pool.get().await?;
+        ensure_task_owned(&mut conn, user_id, task_id).await?;
+
+        let row = diesel::insert_into(task_comments::table)
+            .values(&NewTaskComment {
+                task_id,
+                user_id,
+                body,
+            })
+            .returning(TaskCommentRow::as_returning())
+            .get_result(&mut conn)
+            .await?;
+        let comment = Self::with_user_name(&mut conn, row).await?;
+        Self::resolve_urls_in_background(pool, &comment);
+        Ok(comment)
+    }
+
+    pub async fn update(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &UpdateTaskComment,
+    ) -> ServiceResult<TaskComment> {
+        let body = normalize_body(&input.body)?;
+        let mut conn = pool.get().await?;
+        let row = diesel::update(
+            task_comments::table
+                .filter(task_comments::id.eq(id))
+                .filter(task_comments::user_id.eq(user_id)),
+        )
+        .set(&TaskCommentChangeset {
+            body: Some(body),
+            edited_at: Some(Utc::now()),
+        })
+        .returning(TaskCommentRow::as_returning())
+        .get_result(&mut conn)
+        .await
+        .optional()?
+        .ok_or_else(|| ServiceError::NotFound("Comment not found".into()))?;
+        let comment = Self::with_user_name(&mut conn, row).await?;
+        Self::resolve_urls_in_background(pool, &comment);
+        Ok(comment)
+    }
+
+    pub async fn delete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        let affected = diesel::delete(
+            task_comments::table
+                .filter(task_comments::id.eq(id))
+                .filter(task_comments::user_id.eq(user_id)),
+        )
+        .execute(&mut conn)
+        .await?;
+        if affected == 0 {
+            return Err(ServiceError::NotFound("Comment not found".into()));
+        }
+        Ok(())
+    }
+
+    async fn with_user_name(
+        conn: &mut AsyncPgConnection,
+        row: TaskCommentRow,
+    ) -> ServiceResult<TaskComment> {
+        let name: String = users::table
+            .filter(users::id.eq(row.user_id))
+            .select(users::name)
+            .first(conn)
+            .await?;
+        Ok(row.into_dto(name))
+    }
+
+    /// Replaces bare URLs with `[Page Title](url)` links once their titles
+    /// are fetched. Leaves `edited_at` alone.
+    fn resolve_urls_in_background(pool: &DbPool, comment: &TaskComment) {
+        if !crate::url_service::has_bare_urls(&comment.body) {
+            return;
+        }
+        let bg_pool = pool.clone();
+        let id = comment.id;
+        let body = comment.body.clone();
+        tokio::spawn(async move {
+            let resolved = crate::url_service::resolve_urls_in_text(&body).await;
+            if resolved == body {
+                return;
+            }
+            let result = async {
+                let mut conn = bg_pool.get().await?;
+                // Skip if the comment was edited while titles were fetched.
+                diesel::update(
+                    task_comments::table
+                        .filter(task_comments::id.eq(id))
+                        .filter(task_comments::body.eq(&body)),
+                )
+                .set(task_comments::body.eq(&resolved))
+                .execute(&mut conn)
+                .await?;
+                Ok::<_, ServiceError>(())
+            }
+            .await;
+            if let Err(e) = result {
+                tracing::error!(comment_id = id, error = %e, "Background URL resolution failed");
+            }
+        });
+    }
+}
+
+async fn ensure_task_owned(
+    conn: &mut AsyncPgConnection,
+    user_id: i64,
+    task_id: i64,
+) -> ServiceResult<()> {
+    let owned: i64 = tasks::table
+        .filter(tasks::id.eq(task_id))
+        .filter(tasks::user_id.eq(user_id))
+        .count()
+        .get_result(conn)
+        .await?;
+    if owned == 0 {
+        return Err(ServiceError::NotFound("Task not found".into()));
+    }
+    Ok(())
+}
+
+fn normalize_body(body: &str) -> ServiceResult<&str> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err(ServiceError::BadRequest("Comment cannot be empty".into()));
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_body() {
+        assert_eq!(normalize_body("  note\n").unwrap(), "note");
+        assert!(matches!(
+            normalize_body(" \n "),
+            Err(ServiceError::BadRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use north_db::models::{NewTaskDependency, TaskRow};
+use north_db::schema::{task_dependencies, tasks};
+use north_db::DbPool;
+use north_dto::{Task, TaskEventKind};
+
+use crate::task_event_service::TaskChange;
+use crate::{ServiceError, ServiceResult, TaskEventService, TaskService};
+
+pub struct TaskDependencyService;
+
+impl TaskDependencyService {
+    /// Makes `task_id` wait on `blocker_id`. Both tasks may live in any of
+    /// the user's projects. Rejects links that would close a cycle.
+    pub async fn add(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+        blocker_id: i64,
+    ) -> ServiceResult<Task> {
+        if task_id == blocker_id {
+            return Err(ServiceError::BadRequest(
+                "A task cannot block itself".into(),
+            ));
+        }
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                live_task_title(conn, user_id, task_id).await?;
+                let blocker_title = live_task_title(conn, user_id, blocker_id).await?;
+
+                let edges: Vec<(i64, i64)> = task_dependencies::table
+                    .inner_join(tasks::table.on(tasks::id.eq(task_dependencies::task_id)))
+                    .filter(tasks::user_id.eq(user_id))
+                    .select((task_dependencies::task_id, task_dependencies::blocker_id))
+                    .load(conn)
+                    .await?;
+                if edges.contains(&(task_id, blocker_id)) {
+                    return Ok(());
+                }
+                if creates_cycle(&edges, task_id, blocker_id) {
+                    return Err(ServiceError::BadRequest(
+                        "Dependency would create a cycle".into(),
+                    ));
+                }
+
+                diesel::insert_into(task_dependencies::table)
+                    .values(&NewTaskDependency {
+                        task_id,
+                        blocker_id,
+                    })
+                    .execute(conn)
+                    .await?;
+                TaskEventService::record(
+                    conn,
+                    user_id,
+                    task_id,
+                    vec![blocker_change(None, Some(blocker_title))],
+                )
+                .await
+            }
+            .scope_boxed()
+        })
+        .await?;
+        Self::load(pool, task_id).await
+    }
+
+    /// Stops `task_id` from waiting on `blocker_id`.
+    pub async fn remove(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+        blocker_id: i64,
+    ) -> ServiceResult<Task> {
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                live_task_title(conn, user_id, task_id).await?;
+                let deleted = diesel::delete(
+                    task_dependencies::table
+                        .filter(task_dependencies::task_id.eq(task_id))
+                        .filter(task_dependencies::blocker_id.eq(blocker_id)),
+                )
+                .execute(conn)
+                .await?;
+                if deleted == 0 {
+                    return Err(ServiceError::NotFound("Dependency not found".into()));
+                }
+                let blocker_title: String = tasks::table
+                    .filter(tasks::id.eq(blocker_id))
+                    .select(tasks::title)
+                    .first(conn)
+                    .await?;
+                TaskEventService::record(
+                    conn,
+                    user_id,
+                    task_id,
+                    vec![blocker_change(Some(blocker_title), None)],
+                )
+                .await
+            }
+            .scope_boxed()
+        })
+        .await?;
+        Self::load(pool, task_id).await
+    }
+
+    async fn load(pool: &DbPool, task_id: i64) -> ServiceResult<Task> {
+        let mut conn = pool.get().await?;
+        let row: TaskRow = tasks::table
+            .filter(tasks::id.eq(task_id))
+            .select(TaskRow::as_select())
+            .first(&mut conn)
+            .await?;
+        let mut tasks = TaskService::load_with_meta(pool, vec![row]).await?;
+        Ok(tasks.remove(0))
+    }
+}
+
+/// The title of a task the user owns and hasn't trashed.
+async fn live_task_title(
+    conn: &mut AsyncPgConnection,
+    user_id: i64,
+    task_id: i64,
+) -> ServiceResult<String> {
+    tasks::table
+        .filter(tasks::id.eq(task_id))
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::deleted_at.is_null())
+        .select(tasks::title)
+        .first(conn)
+        .await
+        .optional()?
+        .ok_or_else(|| ServiceError::NotFound("Task not found".into()))
+}
+
+fn blocker_change(old_value: Option<String>, new_value: Option<String>) -> TaskChange {
+    TaskChange {
+        kind: TaskEventKind::Updated,
+        field: Some("blocker"),
+        old_value,
+        new_value,
+    }
+}
+
+/// Whether making `task_id` wait on `blocker_id` would close a loop, i.e.
+/// `blocker_id` already waits on `task_id`, directly or transitively.
+/// `edges` are existing `(task_id, blocker_id)` pairs.
+pub fn creates_cycle(edges: &[(i64, i64)], task_id: i64, blocker_id: i64) -> bool {
+    let mut blockers: HashMap<i64, Vec<i64>> = HashMap::new();
+    for &(task, blocker) in edges {
+        blockers.entry(task).or_default().push(blocker);
+    }
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([blocker_id]);
+    while let Some(id) = queue.pop_front() {
+        if id == task_id {
+            return true;
+        }
+        if seen.insert(id) {
+            if let Some(next) = blockers.get(&id) {
+                queue.extend(next);
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creates_cycle() {
+        // 1 waits on 2, 2 waits on 3.
+        let edges = [(1, 2), (2, 3)];
+        assert!(creates_cycle(&edges, 3, 1));
+        assert!(creates_cycle(&edges, 2, 1));
+        assert!(creates_cycle(&edges, 1, 1));
+        assert!(!creates_cycle(&edges, 1, 3));
+        assert!(!creates_cycle(&edges, 4, 1));
+        assert!(!creates_cycle(&[], 1, 2));
+    }
+
+    #[test]
+    fn test_creates_cycle_with_diamond() {
+        // 1 waits on 2 and 3, both of which wait on 4.
+        let edges = [(1, 2), (1, 3), (2, 4), (3, 4)];
+        assert!(!creates_cycle(&edges, 2, 3));
+        assert!(creates_cycle(&edges, 4, 1));
+        assert!(creates_cycle(&edges, 4, 3));
+    }
+}
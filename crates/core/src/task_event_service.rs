@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use north_db::models::{NewTaskEvent, TaskEventRow};
+use north_db::schema::{project_columns, project_sections, projects, task_events, tasks, users};
+use north_db::DbPool;
+use north_dto::{Task, TaskEvent, TaskEventKind};
+
+use crate::{ServiceError, ServiceResult};
+
+/// One change found between two versions of a task. Moves carry ids as
+/// values until `TaskEventService::record` resolves them to titles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskChange {
+    pub kind: TaskEventKind,
+    pub field: Option<&'static str>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl TaskChange {
+    pub fn new(kind: TaskEventKind) -> Self {
+        Self {
+            kind,
+            field: None,
+            old_value: None,
+            new_value: None,
+        }
+    }
+
+    fn field(
+        kind: TaskEventKind,
+        field: &'static str,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            field: Some(field),
+            old_value,
+            new_value,
+        }
+    }
+}
+
+pub struct TaskEventService;
+
+impl TaskEventService {
+    /// The task's activity log, newest first.
+    pub async fn list_for_task(
+        pool: &DbPool,
+        user_id: i64,
+        task_id: i64,
+    ) -> ServiceResult<Vec<TaskEvent>> {
+        let mut conn = pool.get().await?;
+        let owned: i64 = tasks::table
+            .filter(tasks::id.eq(task_id))
+            .filter(tasks::user_id.eq(user_id))
+            .count()
+            .get_result(&mut conn)
+            .await?;
+        if owned == 0 {
+            return Err(ServiceError::NotFound("Task not found".into()));
+        }
+
+        let rows: Vec<(TaskEventRow, String)> = task_events::table
+            .inner_join(users::table.on(users::id.eq(task_events::user_id)))
+            .filter(task_events::task_id.eq(task_id))
+            .order((task_events::created_at.desc(), task_events::id.desc()))
+            .select((TaskEventRow::as_select(), users::name))
+            .load(&mut conn)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(row, name)| row.into_dto(name))
+            .collect())
+    }
+
+    /// Field-level changes between two versions of a task. Sort keys and
+    /// bookkeeping fields are ignored; the body is recorded without values.
+    pub fn diff(before: &Task, after: &Task) -> Vec<TaskChange> {
+        use TaskEventKind::*;
+
+        let mut changes = Vec::new();
+        let mut updated = |field, old: Option<String>, new: Option<String>| {
+            if old != new {
+                changes.push(TaskChange::field(Updated, field, old, new));
+            }
+        };
+        let start = |t: &Task| {
+            t.start_at
+                .map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+        };
+        updated(
+            "title",
+            Some(before.title.clone()),
+            Some(after.title.clone()),
+        );
+        updated("start_at", start(before), start(after));
+        updated(
+            "due_date",
+            before.due_date.map(|d| d.to_string()),
+            after.due_date.map(|d| d.to_string()),
+        );
+        updated(
+            "someday",
+            Some(before.someday.to_string()),
+            Some(after.someday.to_string()),
+        );
+        updated(
+            "waiting_for",
+            before.waiting_for.clone(),
+            after.waiting_for.clone(),
+        );
+        updated(
+            "follow_up_date",
+            before.follow_up_date.map(|d| d.to_string()),
+            after.follow_up_date.map(|d| d.to_string()),
+        );
+        updated(
+            "priority",
+            before.priority.map(|p| format!("P{p}")),
+            after.priority.map(|p| format!("P{p}")),
+        );
+        updated(
+            "estimate_minutes",
+            before
+                .estimate_minutes
+                .map(|m| north_dto::format_duration(m.into())),
+            after
+                .estimate_minutes
+                .map(|m| north_dto::format_duration(m.into())),
+        );
+        updated(
+            "sequential_limit",
+            Some(before.sequential_limit.to_string()),
+            Some(after.sequential_limit.to_string()),
+        );
+        updated(
+            "recurrence",
+            before.recurrence_rule.clone(),
+            after.recurrence_rule.clone(),
+        );
+        let exdates = |dates: &[chrono::NaiveDate]| {
+            (!dates.is_empty()).then(|| {
+                dates
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+        };
+        updated(
+            "recurrence_exdates",
+            exdates(&before.recurrence_exdates),
+            exdates(&after.recurrence_exdates),
+        );
+        if before.body != after.body {
+            // Bodies can be long; only note that one changed.
+            changes.push(TaskChange::field(Updated, "body", None, None));
+        }
+
+        let ids = [
+            ("project", before.project_id, after.project_id),
+            ("parent", before.parent_id, after.parent_id),
+            ("column", before.column_id, after.column_id),
+            ("section", before.section_id, after.section_id),
+        ];
+        for (field, old, new) in ids {
+            if old != new {
+                changes.push(TaskChange::field(
+                    Moved,
+                    field,
+                    old.map(|id| id.to_string()),
+                    new.map(|id| id.to_string()),
+                ));
+            }
+        }
+
+        match (before.completed_at, after.completed_at) {
+            (None, Some(_)) => changes.push(TaskChange::new(Completed)),
+            (Some(_), None) => changes.push(TaskChange::new(Uncompleted)),
+            _ => {}
+        }
+        if after.reviewed_at.is_some() && before.reviewed_at != after.reviewed_at {
+            changes.push(TaskChange::new(Reviewed));
+        }
+        changes
+    }
+
+    /// Stores changes for a task, resolving project, parent, column and section ids
+    /// to their titles so the log survives later renames and deletions.
+    pub async fn record(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        task_id: i64,
+        changes: Vec<TaskChange>,
+    ) -> ServiceResult<()> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let ids_for = |field: &str| -> Vec<i64> {
+            changes
+                .iter()
+                .filter(|c| c.field == Some(field) && c.kind == TaskEventKind::Moved)
+                .flat_map(|c| [c.old_value.as_deref(), c.new_value.as_deref()])
+                .flatten()
+                .filter_map(|v| v.parse().ok())
+                .collect()
+        };
+        let mut titles: HashMap<(&str, i64), String> = HashMap::new();
+        let project_ids = ids_for("project");
+        if !project_ids.is_empty() {
+            let rows: Vec<(i64, String)> = projects::table
+                .filter(projects::id.eq_any(project_ids))
+                .select((projects::id, projects::title))
+                .load(conn)
+                .await?;
+            titles.extend(rows.into_iter().map(|(id, t)| (("project", id), t)));
+        }
+        let parent_ids = ids_for("parent");
+        if !parent_ids.is_empty() {
+            let rows: Vec<(i64, String)> = tasks::table
+                .filter(tasks::id.eq_any(parent_ids))
+                .select((tasks::id, tasks::title))
+                .load(conn)
+                .await?;
+            titles.extend(rows.into_iter().map(|(id, t)| (("parent", id), t)));
+        }
+        let column_ids = ids_for("column");
+        if !column_ids.is_empty() {
+            let rows: Vec<(i64, String)> = project_columns::table
+                .filter(project_columns::id.eq_any(column_ids))
+                .select((project_columns::id, project_columns::title))
+                .load(conn)
+                .await?;
+            titles.extend(rows.into_iter().map(|(id, t)| (("column", id), t)));
+        }
+        let section_ids = ids_for("section");
+        if !section_ids.is_empty() {
+            let rows: Vec<(i64, String)> = project_sections::table
+                .filter(project_sections::id.eq_any(section_ids))
+                .select((project_sections::id, project_sections::title))
+                .load(conn)
+                .await?;
+            titles.extend(rows.into_iter().map(|(id, t)| (("section", id), t)));
+        }
+
+        let resolve = |field: Option<&'static str>, value: Option<String>| match field {
+            Some(f @ ("project" | "parent" | "column" | "section")) => value.map(|v| {
+                v.parse()
+                    .ok()
+                    .and_then(|id: i64| titles.get(&(f, id)).cloned())
+                    .unwrap_or(v)
+            }),
+            _ => value,
+        };
+        let rows: Vec<NewTaskEvent> = changes
+            .into_iter()
+            .map(|c| {
+                let field = c.field;
+                let old_value = if c.kind == TaskEventKind::Moved {
+                    resolve(field, c.old_value)
+                } else {
+                    c.old_value
+                };
+                let new_value = if c.kind == TaskEventKind::Moved {
+                    resolve(field, c.new_value)
+                } else {
+                    c.new_value
+                };
+                NewTaskEvent {
+                    task_id,
+                    user_id,
+                    kind: c.kind.into(),
+                    field: field.map(str::to_string),
+                    old_value,
+                    new_value,
+                }
+            })
+            .collect();
+        diesel::insert_into(task_events::table)
+            .values(&rows)
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Records a tag change if the set of tags differs.
+    pub async fn record_tags(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        task_id: i64,
+        mut before: Vec<String>,
+        mut after: Vec<String>,
+    ) -> ServiceResult<()> {
+        before.sort();
+        before.dedup();
+        after.sort();
+        after.dedup();
+        if before == after {
+            return Ok(());
+        }
+        let join = |names: Vec<String>| (!names.is_empty()).then(|| names.join(" "));
+        let change = TaskChange {
+            kind: TaskEventKind::TagsChanged,
+            field: None,
+            old_value: join(before),
+            new_value: join(after),
+        };
+        Self::record(conn, user_id, task_id, vec![change]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn task() -> Task {
+        let created = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        Task {
+            id: 1,
+            project_id: Some(3),
+            parent_id: None,
+            user_id: 1,
+            title: "Write report".into(),
+            body: None,
+            sort_key: "a".into(),
+            sequential_limit: 1,
+            start_at: None,
+            due_date: NaiveDate::from_ymd_opt(2026, 3, 10),
+            completed_at: None,
+            reviewed_at: None,
+            created_at: created,
+            updated_at: created,
+            recurrence_type: None,
+            recurrence_rule: None,
+            recurrence_exdates: vec![],
+            is_url_fetching: None,
+            someday: false,
+            series_id: None,
+            column_id: None,
+            waiting_for: None,
+            follow_up_date: None,
+            priority: None,
+            estimate_minutes: None,
+            section_id: None,
+            project_title: None,
+            tags: vec![],
+            subtask_count: 0,
+            completed_subtask_count: 0,
+            blocked_by: vec![],
+            spent_minutes: 0,
+            tracking_since: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_unchanged_ignores_bookkeeping() {
+        let before = task();
+        let mut after = task();
+        after.sort_key = "b".into();
+        after.updated_at = Utc::now();
+        after.is_url_fetching = Some(Utc::now());
+        assert!(TaskEventService::diff(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_diff_fields_moves_and_completion() {
+        let before = task();
+        let mut after = task();
+        after.due_date = NaiveDate::from_ymd_opt(2026, 3, 12);
+        after.body = Some("notes".into());
+        after.project_id = None;
+        after.completed_at = Some(Utc::now());
+
+        let changes = TaskEventService::diff(&before, &after);
+        assert_eq!(
+            changes,
+            vec![
+                TaskChange::field(
+                    TaskEventKind::Updated,
+                    "due_date",
+                    Some("2026-03-10".into()),
+                    Some("2026-03-12".into()),
+                ),
+                TaskChange::field(TaskEventKind::Updated, "body", None, None),
+                TaskChange::field(TaskEventKind::Moved, "project", Some("3".into()), None),
+                TaskChange::new(TaskEventKind::Completed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_review_and_reopen() {
+        let mut before = task();
+        before.completed_at = Some(Utc::now());
+        let mut after = task();
+        after.reviewed_at = NaiveDate::from_ymd_opt(2026, 3, 5);
+
+        let kinds: Vec<TaskEventKind> = TaskEventService::diff(&before, &after)
+            .into_iter()
+            .map(|c| c.kind)
+            .collect();
+        assert_eq!(kinds, [TaskEventKind::Uncompleted, TaskEventKind::Reviewed]);
+    }
+}
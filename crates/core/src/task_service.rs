@@ -2,18 +2,33 @@ use std::collections::HashMap;
 
 use chrono::Utc;
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
 use north_db::models::{NewTask, NewTaskTag, TagRow, TaskChangeset, TaskRow};
-use north_db::schema::{projects, tags, task_tags, tasks, users};
+use north_db::schema::{
+    project_columns, project_sections, projects, tags, task_dependencies, task_tags, tasks, users,
+};
 use north_db::sql_types::RecurrenceTypeMapping;
 use north_db::DbPool;
-use north_dto::RecurrenceType;
-use north_dto::{CreateTask, TagInfo, Task, TaskFilter, UpdateTask, UserSettings};
+use north_dto::{
+    BulkTaskAction, BulkTaskUpdate, CreateTask, MoveTask, TagInfo, Task, TaskEventKind, TaskFilter,
+    UpdateTask, UserSettings,
+};
+use north_dto::{Frequency, RecurrenceRule, RecurrenceType, SeriesOccurrence, TaskSeries};
 
+use crate::task_event_service::TaskChange;
 use crate::{ServiceError, ServiceResult};
 
 pub struct TaskService;
 
+/// Dates of a recurring task's next instance. A due-only task stays
+/// due-only, so `start_at` is `None` for it.
+#[derive(Debug, PartialEq)]
+struct NextOccurrence {
+    start_at: Option<chrono::DateTime<Utc>>,
+    due_date: Option<chrono::NaiveDate>,
+}
+
 impl TaskService {
     pub async fn list(
         pool: &DbPool,
@@ -25,6 +40,7 @@ impl TaskService {
         let mut query = tasks::table
             .left_join(projects::table.on(projects::id.nullable().eq(tasks::project_id)))
             .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
             .into_boxed();
 
         if let Some(project_id) = filter.project {
@@ -49,6 +65,13 @@ impl TaskService {
                 query = query.filter(tasks::someday.eq(false));
             }
         }
+        if let Some(waiting) = filter.waiting {
+            if waiting {
+                query = query.filter(tasks::waiting_for.is_not_null());
+            } else {
+                query = query.filter(tasks::waiting_for.is_null());
+            }
+        }
         if let Some(ref q) = filter.q {
             let pattern = format!("%{q}%");
             query = query.filter(
@@ -137,11 +160,52 @@ impl TaskService {
         Ok(results)
     }
 
+    /// Open top-level tasks on Today as of `until`, with `today` the user's
+    /// local date; see [`north_dto::is_on_today`].
+    pub async fn today(
+        pool: &DbPool,
+        user_id: i64,
+        until: chrono::DateTime<Utc>,
+        today: chrono::NaiveDate,
+    ) -> ServiceResult<Vec<Task>> {
+        let mut conn = pool.get().await?;
+        let rows: Vec<TaskRow> = tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::parent_id.is_null())
+            .filter(tasks::completed_at.is_null())
+            .filter(tasks::deleted_at.is_null())
+            .filter(
+                tasks::start_at
+                    .lt(until)
+                    .or(tasks::waiting_for.is_not_null()),
+            )
+            .order((tasks::start_at.asc().nulls_last(), tasks::sort_key.asc()))
+            .select(TaskRow::as_select())
+            .load(&mut conn)
+            .await?;
+        drop(conn);
+
+        let rows = rows
+            .into_iter()
+            .filter(|t| {
+                north_dto::is_on_today(
+                    t.start_at,
+                    t.waiting_for.is_some(),
+                    t.follow_up_date,
+                    until,
+                    today,
+                )
+            })
+            .collect();
+        Self::load_with_meta(pool, rows).await
+    }
+
     pub async fn get_by_id(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<Task> {
         let mut conn = pool.get().await?;
         let row = tasks::table
             .filter(tasks::id.eq(id))
             .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
             .select(TaskRow::as_select())
             .first(&mut conn)
             .await
@@ -173,9 +237,31 @@ impl TaskService {
         } else {
             parsed.cleaned
         };
+        let priority = parsed.priority.or(input.priority);
+        validate_priority(priority)?;
+        let estimate_minutes = parsed.estimate_minutes.or(input.estimate_minutes);
+        validate_estimate(estimate_minutes)?;
 
         let mut conn = pool.get().await?;
 
+        let section_id = match input.section_id {
+            Some(sid) if input.parent_id.is_none() => {
+                let section_project: Option<i64> = project_sections::table
+                    .filter(project_sections::id.eq(sid))
+                    .select(project_sections::project_id)
+                    .first(&mut conn)
+                    .await
+                    .optional()?;
+                if section_project.is_none() || section_project != resolved_project_id {
+                    return Err(ServiceError::BadRequest(
+                        "Section does not belong to the task's project".into(),
+                    ));
+                }
+                Some(sid)
+            }
+            _ => None,
+        };
+
         let sort_key = if let Some(ref sk) = input.sort_key {
             sk.clone()
         } else {
@@ -227,12 +313,25 @@ impl TaskService {
                 recurrence_rule: None,
                 is_url_fetching: None,
                 someday: false,
+                recurrence_exdates: vec![],
+                series_id: None,
+                column_id: None,
+                priority,
+                estimate_minutes,
+                section_id,
             })
             .returning(TaskRow::as_returning())
             .get_result(&mut conn)
             .await?;
 
         let mut task = Task::from(row);
+        crate::TaskEventService::record(
+            &mut conn,
+            user_id,
+            task.id,
+            vec![TaskChange::new(TaskEventKind::Created)],
+        )
+        .await?;
 
         // Enrich with project_title
         if let Some(pid) = task.project_id {
@@ -279,6 +378,12 @@ impl TaskService {
             }
             resolved_input.title = Some(cleaned);
             tags_to_add = parsed.tags;
+            if let Some(priority) = parsed.priority {
+                resolved_input.priority = Some(Some(priority));
+            }
+            if let Some(estimate) = parsed.estimate_minutes {
+                resolved_input.estimate_minutes = Some(Some(estimate));
+            }
 
             if let Some(ref project_name) = parsed.project {
                 if let Ok(Some(pid)) =
@@ -346,12 +451,68 @@ impl TaskService {
         resolved_input: &UpdateTask,
     ) -> ServiceResult<Task> {
         let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let (task, completed) =
+                    Self::update_with_conn(conn, user_id, id, resolved_input).await?;
+                // Spawn next recurring instance if this task has recurrence
+                if let Some(completed) = completed {
+                    Self::spawn_next_recurring(conn, user_id, &completed).await?;
+                }
+                Ok(task)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Reopens a task as the undo of completing it. For a recurring task
+    /// this also takes back the instance the completion spawned, which a
+    /// plain reopen leaves alone.
+    pub async fn undo_complete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<Task> {
+        let reopen = UpdateTask {
+            completed_at: Some(None),
+            ..Default::default()
+        };
+        let reopen = &reopen;
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let existing = tasks::table
+                    .filter(tasks::id.eq(id))
+                    .filter(tasks::user_id.eq(user_id))
+                    .filter(tasks::deleted_at.is_null())
+                    .select(TaskRow::as_select())
+                    .first(conn)
+                    .await
+                    .optional()?
+                    .ok_or_else(|| ServiceError::NotFound("Task not found".into()))?;
+                let (task, _) = Self::update_with_conn(conn, user_id, id, reopen).await?;
+                if existing.completed_at.is_some() && existing.recurrence_rule.is_some() {
+                    Self::trash_spawned_successor(conn, user_id, &existing).await?;
+                }
+                Ok(task)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
 
+    /// Applies an update on the given connection. Also returns the previous
+    /// row when the update completed a recurring task; the caller spawns
+    /// its next instance.
+    async fn update_with_conn(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+        resolved_input: &UpdateTask,
+    ) -> ServiceResult<(Task, Option<TaskRow>)> {
         let existing = tasks::table
             .filter(tasks::id.eq(id))
             .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
             .select(TaskRow::as_select())
-            .first(&mut conn)
+            .first(conn)
             .await
             .optional()?
             .ok_or_else(|| ServiceError::NotFound("Task not found".into()))?;
@@ -365,6 +526,18 @@ impl TaskService {
             changeset.body = Some(body.as_deref());
         }
         if let Some(ref project_id) = resolved_input.project_id {
+            if let Some(pid) = project_id {
+                let owned: i64 = projects::table
+                    .filter(projects::id.eq(*pid))
+                    .filter(projects::user_id.eq(user_id))
+                    .filter(projects::deleted_at.is_null())
+                    .count()
+                    .get_result(conn)
+                    .await?;
+                if owned == 0 {
+                    return Err(ServiceError::NotFound("Project not found".into()));
+                }
+            }
             changeset.project_id = Some(*project_id);
         }
         if let Some(ref parent_id) = resolved_input.parent_id {
@@ -373,7 +546,7 @@ impl TaskService {
                 let parent_project: Option<i64> = tasks::table
                     .filter(tasks::id.eq(*pid))
                     .select(tasks::project_id)
-                    .first(&mut conn)
+                    .first(conn)
                     .await
                     .optional()?
                     .flatten();
@@ -399,7 +572,7 @@ impl TaskService {
                     .filter(tasks::id.ne(id))
                     .order(tasks::sort_key.desc())
                     .select(tasks::sort_key)
-                    .first(&mut conn)
+                    .first(conn)
                     .await
                     .optional()?
             } else if let Some(proj) = resolved_project {
@@ -410,7 +583,7 @@ impl TaskService {
                     .filter(tasks::id.ne(id))
                     .order(tasks::sort_key.desc())
                     .select(tasks::sort_key)
-                    .first(&mut conn)
+                    .first(conn)
                     .await
                     .optional()?
             } else {
@@ -421,7 +594,7 @@ impl TaskService {
                     .filter(tasks::id.ne(id))
                     .order(tasks::sort_key.desc())
                     .select(tasks::sort_key)
-                    .first(&mut conn)
+                    .first(conn)
                     .await
                     .optional()?
             };
@@ -447,14 +620,93 @@ impl TaskService {
             changeset.recurrence_type = Some(recurrence_type.map(RecurrenceTypeMapping::from));
         }
         if let Some(ref recurrence_rule) = resolved_input.recurrence_rule {
+            if let Some(rule) = recurrence_rule {
+                RecurrenceRule::try_parse(rule).map_err(|e| {
+                    ServiceError::BadRequest(format!("Invalid recurrence rule: {e}"))
+                })?;
+            }
             changeset.recurrence_rule = Some(recurrence_rule.as_deref());
         }
+        if let Some(ref exdates) = resolved_input.recurrence_exdates {
+            let mut exdates = exdates.clone();
+            exdates.sort();
+            exdates.dedup();
+            changeset.recurrence_exdates = Some(exdates);
+        }
         if let Some(ref is_url_fetching) = resolved_input.is_url_fetching {
             changeset.is_url_fetching = Some(*is_url_fetching);
         }
         if let Some(someday) = resolved_input.someday {
             changeset.someday = Some(someday);
         }
+        if let Some(priority) = resolved_input.priority {
+            validate_priority(priority)?;
+            changeset.priority = Some(priority);
+        }
+        if let Some(estimate_minutes) = resolved_input.estimate_minutes {
+            validate_estimate(estimate_minutes)?;
+            changeset.estimate_minutes = Some(estimate_minutes);
+        }
+        if let Some(column_id) = resolved_input.column_id {
+            if let Some(cid) = column_id {
+                let column_project: Option<i64> = project_columns::table
+                    .filter(project_columns::id.eq(cid))
+                    .select(project_columns::project_id)
+                    .first(conn)
+                    .await
+                    .optional()?;
+                if column_project.is_none() || column_project != resolved_project {
+                    return Err(ServiceError::BadRequest(
+                        "Column does not belong to the task's project".into(),
+                    ));
+                }
+            }
+            changeset.column_id = Some(column_id);
+        } else if resolved_project != existing.project_id && existing.column_id.is_some() {
+            // Columns are per project; a moved task starts in the new
+            // project's first column.
+            changeset.column_id = Some(None);
+        }
+        if let Some(section_id) = resolved_input.section_id {
+            if let Some(sid) = section_id {
+                let section_project: Option<i64> = project_sections::table
+                    .filter(project_sections::id.eq(sid))
+                    .select(project_sections::project_id)
+                    .first(conn)
+                    .await
+                    .optional()?;
+                if section_project.is_none() || section_project != resolved_project {
+                    return Err(ServiceError::BadRequest(
+                        "Section does not belong to the task's project".into(),
+                    ));
+                }
+                if resolved_parent.is_some() {
+                    return Err(ServiceError::BadRequest(
+                        "Only top-level tasks can be put in a section".into(),
+                    ));
+                }
+            }
+            changeset.section_id = Some(section_id);
+        } else if existing.section_id.is_some()
+            && (resolved_project != existing.project_id || resolved_parent.is_some())
+        {
+            // Sections are per project and hold top-level tasks only.
+            changeset.section_id = Some(None);
+        }
+        let waiting_for;
+        if let Some(ref who) = resolved_input.waiting_for {
+            waiting_for = who.as_deref().map(str::trim).filter(|w| !w.is_empty());
+            changeset.waiting_for = Some(waiting_for);
+            if waiting_for.is_none() {
+                // A follow-up only makes sense while waiting.
+                changeset.follow_up_date = Some(None);
+            }
+        }
+        if let Some(follow_up_date) = resolved_input.follow_up_date {
+            if changeset.follow_up_date.is_none() {
+                changeset.follow_up_date = Some(follow_up_date);
+            }
+        }
 
         // When completing and no explicit sort_key, reset to empty.
         // When uncompleting and no explicit sort_key, place at end of list.
@@ -474,7 +726,7 @@ impl TaskService {
                             .filter(tasks::id.ne(id))
                             .order(tasks::sort_key.desc())
                             .select(tasks::sort_key)
-                            .first(&mut conn)
+                            .first(conn)
                             .await
                             .optional()?
                     } else if let Some(proj) = resolved_project {
@@ -486,7 +738,7 @@ impl TaskService {
                             .filter(tasks::id.ne(id))
                             .order(tasks::sort_key.desc())
                             .select(tasks::sort_key)
-                            .first(&mut conn)
+                            .first(conn)
                             .await
                             .optional()?
                     } else {
@@ -498,7 +750,7 @@ impl TaskService {
                             .filter(tasks::id.ne(id))
                             .order(tasks::sort_key.desc())
                             .select(tasks::sort_key)
-                            .first(&mut conn)
+                            .first(conn)
                             .await
                             .optional()?
                     };
@@ -511,14 +763,20 @@ impl TaskService {
         let row = diesel::update(
             tasks::table
                 .filter(tasks::id.eq(id))
-                .filter(tasks::user_id.eq(user_id)),
+                .filter(tasks::user_id.eq(user_id))
+                .filter(tasks::deleted_at.is_null()),
         )
         .set(&changeset)
         .returning(TaskRow::as_returning())
-        .get_result(&mut conn)
+        .get_result(conn)
         .await?;
 
+        let changes =
+            crate::TaskEventService::diff(&Task::from(existing.clone()), &Task::from(row.clone()));
+        crate::TaskEventService::record(conn, user_id, id, changes).await?;
+
         // If completing (was null, now set), cascade to descendants
+        let mut completed_recurring = None;
         if let Some(Some(_)) = resolved_input.completed_at {
             if existing.completed_at.is_none() {
                 let now = Utc::now();
@@ -530,8 +788,9 @@ impl TaskService {
                     let child_ids: Vec<i64> = tasks::table
                         .filter(tasks::parent_id.eq_any(&parent_ids))
                         .filter(tasks::completed_at.is_null())
+                        .filter(tasks::deleted_at.is_null())
                         .select(tasks::id)
-                        .load(&mut conn)
+                        .load(conn)
                         .await?;
                     if child_ids.is_empty() {
                         break;
@@ -546,90 +805,483 @@ impl TaskService {
                         tasks::recurrence_type.eq(None::<RecurrenceTypeMapping>),
                         tasks::recurrence_rule.eq(None::<String>),
                     ))
-                    .execute(&mut conn)
+                    .execute(conn)
                     .await?;
 
                     diesel::update(tasks::table.filter(tasks::id.eq_any(&child_ids)))
                         .set(tasks::completed_at.eq(Some(now)))
-                        .execute(&mut conn)
+                        .execute(conn)
                         .await?;
+                    for &child_id in &child_ids {
+                        crate::TaskEventService::record(
+                            conn,
+                            user_id,
+                            child_id,
+                            vec![TaskChange::new(TaskEventKind::Completed)],
+                        )
+                        .await?;
+                    }
                     parent_ids = child_ids;
                 }
 
-                // Spawn next recurring instance if this task has recurrence
                 if existing.recurrence_rule.is_some() {
-                    let _ = Self::spawn_next_recurring(pool, user_id, &existing).await;
+                    completed_recurring = Some(existing);
                 }
             }
         }
 
-        Ok(Task::from(row))
+        Ok((Task::from(row), completed_recurring))
     }
 
+    /// Trashes the instance a recurring task's completion spawned: the next
+    /// one in the series, while it is still open.
+    async fn trash_spawned_successor(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        reopened: &TaskRow,
+    ) -> ServiceResult<()> {
+        let Some(series_id) = reopened.series_id else {
+            return Ok(());
+        };
+        let successor: Option<(i64, Option<chrono::DateTime<Utc>>)> = tasks::table
+            .filter(tasks::series_id.eq(series_id))
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::id.gt(reopened.id))
+            .filter(tasks::deleted_at.is_null())
+            .order(tasks::id.asc())
+            .select((tasks::id, tasks::completed_at))
+            .first(conn)
+            .await
+            .optional()?;
+        if let Some((id, None)) = successor {
+            Self::trash_with_conn(conn, user_id, id, Utc::now()).await?;
+        }
+        Ok(())
+    }
+
+    /// Moves a task and its subtasks to the trash. Everything trashed in
+    /// one go shares a `deleted_at`, which is how `TrashService` restores
+    /// it together. Subtasks already in the trash keep their own timestamp.
     pub async fn delete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
         let mut conn = pool.get().await?;
-        let affected = diesel::delete(
+        let now = Utc::now();
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                if !Self::trash_with_conn(conn, user_id, id, now).await? {
+                    return Err(ServiceError::NotFound("Task not found".into()));
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Trashes a task and its subtasks; `false` if it was not found.
+    async fn trash_with_conn(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+        now: chrono::DateTime<Utc>,
+    ) -> ServiceResult<bool> {
+        let affected = diesel::update(
             tasks::table
                 .filter(tasks::id.eq(id))
-                .filter(tasks::user_id.eq(user_id)),
+                .filter(tasks::user_id.eq(user_id))
+                .filter(tasks::deleted_at.is_null()),
         )
-        .execute(&mut conn)
+        .set(tasks::deleted_at.eq(now))
+        .execute(&mut *conn)
         .await?;
         if affected == 0 {
-            return Err(ServiceError::NotFound("Task not found".into()));
+            return Ok(false);
         }
-        Ok(())
+
+        let mut frontier = vec![id];
+        while !frontier.is_empty() {
+            frontier = diesel::update(
+                tasks::table
+                    .filter(tasks::parent_id.eq_any(&frontier))
+                    .filter(tasks::deleted_at.is_null()),
+            )
+            .set(tasks::deleted_at.eq(now))
+            .returning(tasks::id)
+            .get_results(&mut *conn)
+            .await?;
+        }
+        Ok(true)
+    }
+
+    // ── Bulk ───────────────────────────────────────────────────────
+
+    /// Applies each action to every task in `input.ids`, in one
+    /// transaction, together with the next instances of completed
+    /// recurring tasks.
+    pub async fn bulk_update(
+        pool: &DbPool,
+        user_id: i64,
+        input: &BulkTaskUpdate,
+    ) -> ServiceResult<()> {
+        let mut ids = input.ids.clone();
+        ids.sort_unstable();
+        ids.dedup();
+        if ids.is_empty() || input.actions.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = pool.get().await?;
+        let actions = &input.actions;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let owned: i64 = tasks::table
+                    .filter(tasks::id.eq_any(&ids))
+                    .filter(tasks::user_id.eq(user_id))
+                    .filter(tasks::deleted_at.is_null())
+                    .count()
+                    .get_result(conn)
+                    .await?;
+                if owned as usize != ids.len() {
+                    return Err(ServiceError::NotFound("Task not found".into()));
+                }
+
+                let mut completed = Vec::new();
+                for action in actions {
+                    completed.extend(Self::bulk_apply(conn, user_id, &ids, action).await?);
+                }
+                for task in &completed {
+                    Self::spawn_next_recurring(conn, user_id, task).await?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Applies one bulk action. Tasks trashed by an earlier action (e.g.
+    /// along with a selected parent) are skipped, as are tasks already in
+    /// the requested completion state.
+    async fn bulk_apply(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        ids: &[i64],
+        action: &BulkTaskAction,
+    ) -> ServiceResult<Vec<TaskRow>> {
+        let live: Vec<i64> = tasks::table
+            .filter(tasks::id.eq_any(ids))
+            .filter(tasks::deleted_at.is_null())
+            .select(tasks::id)
+            .load(conn)
+            .await?;
+
+        let update = match action {
+            BulkTaskAction::Delete => {
+                let now = Utc::now();
+                for id in live {
+                    Self::trash_with_conn(conn, user_id, id, now).await?;
+                }
+                return Ok(vec![]);
+            }
+            BulkTaskAction::AddTags(names) | BulkTaskAction::RemoveTags(names) => {
+                let names: Vec<String> = names
+                    .iter()
+                    .filter_map(|n| north_dto::normalize_tag_name(n))
+                    .collect();
+                for id in live {
+                    if matches!(action, BulkTaskAction::AddTags(_)) {
+                        crate::TagService::add_task_tags(conn, user_id, id, &names).await?;
+                    } else {
+                        crate::TagService::remove_task_tags(conn, user_id, id, &names).await?;
+                    }
+                }
+                return Ok(vec![]);
+            }
+            BulkTaskAction::Complete => UpdateTask {
+                completed_at: Some(Some(Utc::now())),
+                ..Default::default()
+            },
+            BulkTaskAction::Uncomplete => UpdateTask {
+                completed_at: Some(None),
+                ..Default::default()
+            },
+            BulkTaskAction::SetProject(project_id) => UpdateTask {
+                project_id: Some(*project_id),
+                ..Default::default()
+            },
+            BulkTaskAction::SetStartAt(start_at) => UpdateTask {
+                start_at: Some(*start_at),
+                ..Default::default()
+            },
+            BulkTaskAction::SetDueDate(due_date) => UpdateTask {
+                due_date: Some(*due_date),
+                ..Default::default()
+            },
+            BulkTaskAction::MarkReviewed => UpdateTask {
+                reviewed_at: Some(Some(Utc::now().date_naive())),
+                ..Default::default()
+            },
+            BulkTaskAction::SetSomeday(someday) => UpdateTask {
+                someday: Some(*someday),
+                ..Default::default()
+            },
+            BulkTaskAction::SetPriority(priority) => UpdateTask {
+                priority: Some(*priority),
+                ..Default::default()
+            },
+        };
+
+        let mut completed = Vec::new();
+        for id in live {
+            if let Some(target) = update.completed_at {
+                // Completing a parent also completes its subtasks.
+                let is_completed: bool = tasks::table
+                    .filter(tasks::id.eq(id))
+                    .select(tasks::completed_at.is_not_null())
+                    .first(conn)
+                    .await?;
+                if is_completed == target.is_some() {
+                    continue;
+                }
+            }
+            let (_, done) = Self::update_with_conn(conn, user_id, id, &update).await?;
+            completed.extend(done);
+        }
+        Ok(completed)
     }
 
     // ── Recurrence ─────────────────────────────────────────────────
 
-    async fn spawn_next_recurring(
+    /// Returns every instance of the recurring series `id` belongs to,
+    /// oldest first, with on-time status judged in the user's timezone.
+    pub async fn get_series(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<TaskSeries> {
+        let mut conn = pool.get().await?;
+        let task = tasks::table
+            .filter(tasks::id.eq(id))
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .select(TaskRow::as_select())
+            .first(&mut conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Task not found".into()))?;
+
+        let rows = match task.series_id {
+            Some(series_id) => {
+                tasks::table
+                    .filter(tasks::series_id.eq(series_id))
+                    .filter(tasks::user_id.eq(user_id))
+                    .filter(tasks::deleted_at.is_null())
+                    .order(tasks::id.asc())
+                    .select(TaskRow::as_select())
+                    .load(&mut conn)
+                    .await?
+            }
+            None => vec![task.clone()],
+        };
+        drop(conn);
+
+        let tz = Self::user_timezone(pool, user_id).await?;
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        let occurrences = rows
+            .into_iter()
+            .map(|row| {
+                let scheduled_on = row
+                    .due_date
+                    .or_else(|| row.start_at.map(|s| s.with_timezone(&tz).date_naive()));
+                let completed_on = row.completed_at.map(|c| c.with_timezone(&tz).date_naive());
+                SeriesOccurrence::new(row.id, scheduled_on, row.completed_at, completed_on, today)
+            })
+            .collect();
+
+        Ok(TaskSeries::new(
+            task.series_id.unwrap_or(task.id),
+            occurrences,
+        ))
+    }
+
+    /// Moves a recurring task to its next occurrence in place, without
+    /// completing it. COUNT caps the instances of a series, so a skipped
+    /// date doesn't use one up.
+    pub async fn skip_occurrence(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<Task> {
+        let mut conn = pool.get().await?;
+        let task = tasks::table
+            .filter(tasks::id.eq(id))
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .select(TaskRow::as_select())
+            .first(&mut conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Task not found".into()))?;
+
+        if task.recurrence_rule.is_none() || task.recurrence_type.is_none() {
+            return Err(ServiceError::BadRequest("Task is not recurring".into()));
+        }
+        let next = Self::next_occurrence(&mut conn, user_id, &task)
+            .await?
+            .ok_or_else(|| ServiceError::BadRequest("No further occurrences to skip to".into()))?;
+        drop(conn);
+
+        let input = UpdateTask {
+            start_at: Some(next.start_at),
+            due_date: Some(next.due_date),
+            ..Default::default()
+        };
+        Self::update_raw(pool, user_id, id, &input).await
+    }
+
+    /// Moves a task to `date`, keeping the local time of its start and the
+    /// gap to its due date. The recurrence rule is left untouched.
+    pub async fn postpone(
         pool: &DbPool,
         user_id: i64,
-        completed_task: &TaskRow,
-    ) -> ServiceResult<Option<Task>> {
-        let rec_rule = match completed_task.recurrence_rule.as_deref() {
+        id: i64,
+        date: chrono::NaiveDate,
+    ) -> ServiceResult<Task> {
+        use chrono::TimeZone;
+
+        let mut conn = pool.get().await?;
+        let task = tasks::table
+            .filter(tasks::id.eq(id))
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_null())
+            .select(TaskRow::as_select())
+            .first(&mut conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Task not found".into()))?;
+        drop(conn);
+
+        let tz = Self::user_timezone(pool, user_id).await?;
+        let mut input = UpdateTask::default();
+        match (task.start_at, task.due_date) {
+            (Some(start), due) => {
+                let local = start.with_timezone(&tz);
+                let shift = date.signed_duration_since(local.date_naive());
+                let new_start = tz
+                    .from_local_datetime(&date.and_time(local.time()))
+                    .earliest()
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or(start + shift);
+                input.start_at = Some(Some(new_start));
+                input.due_date = Some(due.map(|d| d + shift));
+            }
+            (None, Some(_)) => input.due_date = Some(Some(date)),
+            (None, None) => {
+                let midnight = tz
+                    .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+                    .earliest()
+                    .map(|dt| dt.with_timezone(&Utc));
+                input.start_at = Some(midnight);
+            }
+        }
+        Self::update_raw(pool, user_id, id, &input).await
+    }
+
+    /// Moves a task to a Kanban column (`None` for the project's first
+    /// column), optionally repositioning it within the column.
+    pub async fn move_to_column(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &MoveTask,
+    ) -> ServiceResult<Task> {
+        let update = UpdateTask {
+            column_id: Some(input.column_id),
+            sort_key: input.sort_key.clone(),
+            ..Default::default()
+        };
+        Self::update_raw(pool, user_id, id, &update).await
+    }
+
+    /// Works out the start and due dates of a recurring task's next
+    /// occurrence. `None` when the task doesn't recur or its series has
+    /// ended.
+    async fn next_occurrence(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        task: &TaskRow,
+    ) -> ServiceResult<Option<NextOccurrence>> {
+        let rec_rule = match task.recurrence_rule.as_deref() {
             Some(r) if !r.is_empty() => r,
             _ => return Ok(None),
         };
-        let rec_type = match completed_task.recurrence_type {
+        let rec_type = match task.recurrence_type {
             Some(rt) => rt,
             None => return Ok(None),
         };
+        let Some(rule) = RecurrenceRule::parse(rec_rule) else {
+            return Ok(None);
+        };
 
-        // Load user settings for timezone
-        let mut conn = pool.get().await?;
-        let settings_val: serde_json::Value = users::table
-            .filter(users::id.eq(user_id))
-            .select(users::settings)
-            .first(&mut conn)
-            .await?;
-        let settings: UserSettings = serde_json::from_value(settings_val).unwrap_or_default();
-        let tz: chrono_tz::Tz = settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+        // COUNT caps the instances in the series, including this one.
+        if rule.count.is_some() {
+            let instances = match task.series_id {
+                Some(series_id) => {
+                    tasks::table
+                        .filter(tasks::series_id.eq(series_id))
+                        .filter(tasks::id.ne(task.id))
+                        .filter(tasks::deleted_at.is_null())
+                        .count()
+                        .get_result::<i64>(conn)
+                        .await?
+                        + 1
+                }
+                None => 1,
+            };
+            if !rule.allows_another(instances) {
+                return Ok(None);
+            }
+        }
+
+        let tz = Self::user_timezone_with_conn(conn, user_id).await?;
+        let exdates = &task.recurrence_exdates;
 
-        // Compute next occurrence
         let next_start = match RecurrenceType::from(rec_type) {
-            RecurrenceType::Scheduled => Self::next_scheduled_date(
-                rec_rule,
-                completed_task.start_at,
-                completed_task.due_date,
-                tz,
-            )?,
-            RecurrenceType::AfterCompletion => Self::next_after_completion_date(rec_rule)?,
+            RecurrenceType::Scheduled => {
+                Self::next_scheduled_date(&rule, exdates, task.start_at, task.due_date, tz)?
+            }
+            RecurrenceType::AfterCompletion => Self::next_after_completion_date(&rule, exdates, tz),
         };
 
         let Some(next_start) = next_start else {
             return Ok(None);
         };
+        let next_date = next_start.with_timezone(&tz).date_naive();
+        if rule.is_past_end(next_date) {
+            return Ok(None);
+        }
 
-        // Preserve due_date offset if original had both start_at and due_date
-        let next_due = match (completed_task.start_at, completed_task.due_date) {
+        // The due date keeps its distance in local days from the start; a
+        // due-only task's occurrence is its due date.
+        let next = match (task.start_at, task.due_date) {
             (Some(orig_start), Some(orig_due)) => {
-                let offset = orig_due.signed_duration_since(orig_start.date_naive());
-                Some(next_start.date_naive() + offset)
+                let offset =
+                    orig_due.signed_duration_since(orig_start.with_timezone(&tz).date_naive());
+                NextOccurrence {
+                    start_at: Some(next_start),
+                    due_date: Some(next_date + offset),
+                }
             }
-            (None, Some(orig_due)) => Some(orig_due),
-            _ => None,
+            (None, Some(_)) => NextOccurrence {
+                start_at: None,
+                due_date: Some(next_date),
+            },
+            _ => NextOccurrence {
+                start_at: Some(next_start),
+                due_date: None,
+            },
+        };
+        Ok(Some(next))
+    }
+
+    async fn spawn_next_recurring(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+        completed_task: &TaskRow,
+    ) -> ServiceResult<Option<Task>> {
+        let Some(next) = Self::next_occurrence(conn, user_id, completed_task).await? else {
+            return Ok(None);
         };
 
         // Compute sort_key for the new task
@@ -639,7 +1291,7 @@ impl TaskService {
                 .filter(tasks::user_id.eq(user_id))
                 .order(tasks::sort_key.desc())
                 .select(tasks::sort_key)
-                .first(&mut conn)
+                .first(conn)
                 .await
                 .optional()?
         } else if let Some(proj) = completed_task.project_id {
@@ -649,7 +1301,7 @@ impl TaskService {
                 .filter(tasks::user_id.eq(user_id))
                 .order(tasks::sort_key.desc())
                 .select(tasks::sort_key)
-                .first(&mut conn)
+                .first(conn)
                 .await
                 .optional()?
         } else {
@@ -659,12 +1311,22 @@ impl TaskService {
                 .filter(tasks::user_id.eq(user_id))
                 .order(tasks::sort_key.desc())
                 .select(tasks::sort_key)
-                .first(&mut conn)
+                .first(conn)
                 .await
                 .optional()?
         };
         let sort_key = north_dto::sort_key_after(last_key.as_deref());
 
+        // Every instance of a recurring task shares the id of the first one
+        // as its series id; tasks that predate the column get it lazily.
+        let series_id = completed_task.series_id.unwrap_or(completed_task.id);
+        if completed_task.series_id.is_none() {
+            diesel::update(tasks::table.filter(tasks::id.eq(completed_task.id)))
+                .set(tasks::series_id.eq(series_id))
+                .execute(conn)
+                .await?;
+        }
+
         let new_row = diesel::insert_into(tasks::table)
             .values(&NewTask {
                 user_id,
@@ -673,25 +1335,34 @@ impl TaskService {
                 project_id: completed_task.project_id,
                 parent_id: completed_task.parent_id,
                 sort_key: &sort_key,
-                start_at: Some(next_start),
-                due_date: next_due,
+                start_at: next.start_at,
+                due_date: next.due_date,
                 reviewed_at: None,
                 recurrence_type: completed_task.recurrence_type,
                 recurrence_rule: completed_task.recurrence_rule.as_deref(),
                 is_url_fetching: None,
                 someday: false,
+                recurrence_exdates: completed_task.recurrence_exdates.clone(),
+                series_id: Some(series_id),
+                column_id: completed_task.column_id,
+                priority: completed_task.priority,
+                estimate_minutes: completed_task.estimate_minutes,
+                section_id: completed_task.section_id,
             })
             .returning(TaskRow::as_returning())
-            .get_result(&mut conn)
+            .get_result(conn)
             .await?;
 
         let new_task_id = new_row.id;
 
+        crate::ReminderService::copy_to_task(conn, completed_task.id, new_task_id).await?;
+
         // Clone subtasks from completed task
         let child_rows: Vec<TaskRow> = tasks::table
             .filter(tasks::parent_id.eq(completed_task.id))
+            .filter(tasks::deleted_at.is_null())
             .select(TaskRow::as_select())
-            .load(&mut conn)
+            .load(conn)
             .await?;
 
         for child in &child_rows {
@@ -711,16 +1382,22 @@ impl TaskService {
                     recurrence_rule: None,
                     is_url_fetching: None,
                     someday: false,
+                    recurrence_exdates: vec![],
+                    series_id: None,
+                    column_id: None,
+                    priority: child.priority,
+                    estimate_minutes: child.estimate_minutes,
+                    section_id: None,
                 })
                 .returning(TaskRow::as_returning())
-                .get_result(&mut conn)
+                .get_result(conn)
                 .await?;
 
             // Copy tags from child
             let child_tag_ids: Vec<i64> = task_tags::table
                 .filter(task_tags::task_id.eq(child.id))
                 .select(task_tags::tag_id)
-                .load(&mut conn)
+                .load(conn)
                 .await?;
             if !child_tag_ids.is_empty() {
                 let links: Vec<NewTaskTag> = child_tag_ids
@@ -732,7 +1409,7 @@ impl TaskService {
                     .collect();
                 diesel::insert_into(task_tags::table)
                     .values(&links)
-                    .execute(&mut conn)
+                    .execute(conn)
                     .await?;
             }
         }
@@ -741,7 +1418,7 @@ impl TaskService {
         let parent_tag_ids: Vec<i64> = task_tags::table
             .filter(task_tags::task_id.eq(completed_task.id))
             .select(task_tags::tag_id)
-            .load(&mut conn)
+            .load(conn)
             .await?;
         if !parent_tag_ids.is_empty() {
             let links: Vec<NewTaskTag> = parent_tag_ids
@@ -753,66 +1430,109 @@ impl TaskService {
                 .collect();
             diesel::insert_into(task_tags::table)
                 .values(&links)
-                .execute(&mut conn)
+                .execute(conn)
                 .await?;
         }
 
         Ok(Some(Task::from(new_row)))
     }
 
+    /// Candidates examined when skipping EXDATEs before giving up.
+    const MAX_EXCLUDED_SKIPS: u16 = 64;
+
     fn next_scheduled_date(
-        rrule_str: &str,
+        rule: &RecurrenceRule,
+        exdates: &[chrono::NaiveDate],
         start_at: Option<chrono::DateTime<Utc>>,
         due_date: Option<chrono::NaiveDate>,
         tz: chrono_tz::Tz,
     ) -> ServiceResult<Option<chrono::DateTime<Utc>>> {
         use chrono::TimeZone;
 
+        // Due-only tasks recur from local midnight of their due date.
         let dt_start = start_at
-            .or_else(|| due_date.map(|d| Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap())))
+            .or_else(|| {
+                due_date.and_then(|d| {
+                    tz.from_local_datetime(&d.and_hms_opt(0, 0, 0).unwrap())
+                        .earliest()
+                        .map(|dt| dt.with_timezone(&Utc))
+                })
+            })
             .unwrap_or_else(Utc::now);
 
+        // Built from the zoned start rather than a DTSTART line, so the
+        // rule steps through the user's local days across DST changes. A
+        // due date has no time of day, so a rule's BYHOUR doesn't apply.
+        let mut pattern = rule.pattern();
+        if start_at.is_none() {
+            pattern.by_hour = None;
+            pattern.by_minute = None;
+        }
         let rrule_tz: rrule::Tz = tz.into();
-        let local_start = dt_start.with_timezone(&rrule_tz);
-        let full_rule = format!(
-            "DTSTART:{}\nRRULE:{rrule_str}",
-            local_start.format("%Y%m%dT%H%M%S")
-        );
-
-        let rrule_set: rrule::RRuleSet = full_rule
-            .parse()
+        let rrule_set = pattern
+            .to_rrule_string()
+            .parse::<rrule::RRule<rrule::Unvalidated>>()
+            .and_then(|r| r.build(dt_start.with_timezone(&rrule_tz)))
             .map_err(|e| ServiceError::BadRequest(format!("Invalid RRULE: {e}")))?;
 
-        let now_local = Utc::now().with_timezone(&rrule_tz);
-        let results = rrule_set.after(now_local).all(1);
+        // Never hand back the occurrence being replaced, even when it lies
+        // in the future (completed or skipped early). `after` is inclusive.
+        let after = dt_start.max(Utc::now());
+        let results = rrule_set
+            .after(after.with_timezone(&rrule_tz))
+            .all(Self::MAX_EXCLUDED_SKIPS);
 
         Ok(results
             .dates
             .into_iter()
-            .next()
-            .map(|dt| dt.with_timezone(&Utc)))
+            .map(|dt| dt.with_timezone(&Utc))
+            .filter(|dt| *dt > after)
+            .find(|dt| !exdates.contains(&dt.with_timezone(&tz).date_naive())))
     }
 
-    fn next_after_completion_date(rrule_str: &str) -> ServiceResult<Option<chrono::DateTime<Utc>>> {
-        let rule = match north_dto::RecurrenceRule::parse(rrule_str) {
-            Some(r) => r,
-            None => return Ok(None),
-        };
-
+    fn next_after_completion_date(
+        rule: &RecurrenceRule,
+        exdates: &[chrono::NaiveDate],
+        tz: chrono_tz::Tz,
+    ) -> Option<chrono::DateTime<Utc>> {
         let interval = rule.interval as i64;
-        let now = Utc::now();
-        let next = match rule.freq {
-            north_dto::Frequency::Daily => now + chrono::Duration::days(interval),
-            north_dto::Frequency::Weekly => now + chrono::Duration::weeks(interval),
-            north_dto::Frequency::Monthly => now + chrono::Duration::days(interval * 30),
-            north_dto::Frequency::Yearly => now + chrono::Duration::days(interval * 365),
+        let step = match rule.freq {
+            Frequency::Daily => chrono::Duration::days(interval),
+            Frequency::Weekly => chrono::Duration::weeks(interval),
+            Frequency::Monthly => chrono::Duration::days(interval * 30),
+            Frequency::Yearly => chrono::Duration::days(interval * 365),
         };
 
-        Ok(Some(next))
+        let mut next = Utc::now() + step;
+        for _ in 0..Self::MAX_EXCLUDED_SKIPS {
+            if !exdates.contains(&next.with_timezone(&tz).date_naive()) {
+                return Some(next);
+            }
+            next += step;
+        }
+        None
     }
 
     // ── Internal helpers ───────────────────────────────────────────
 
+    async fn user_timezone(pool: &DbPool, user_id: i64) -> ServiceResult<chrono_tz::Tz> {
+        let settings = crate::UserService::get_settings(pool, user_id).await?;
+        Ok(settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC))
+    }
+
+    async fn user_timezone_with_conn(
+        conn: &mut AsyncPgConnection,
+        user_id: i64,
+    ) -> ServiceResult<chrono_tz::Tz> {
+        let settings_val: serde_json::Value = users::table
+            .filter(users::id.eq(user_id))
+            .select(users::settings)
+            .first(conn)
+            .await?;
+        let settings: UserSettings = serde_json::from_value(settings_val).unwrap_or_default();
+        Ok(settings.timezone.parse().unwrap_or(chrono_tz::Tz::UTC))
+    }
+
     async fn maybe_resolve_urls(
         pool: &DbPool,
         user_id: i64,
@@ -860,7 +1580,10 @@ impl TaskService {
         Ok(task)
     }
 
-    async fn load_with_meta(pool: &DbPool, task_rows: Vec<TaskRow>) -> ServiceResult<Vec<Task>> {
+    pub(crate) async fn load_with_meta(
+        pool: &DbPool,
+        task_rows: Vec<TaskRow>,
+    ) -> ServiceResult<Vec<Task>> {
         if task_rows.is_empty() {
             return Ok(vec![]);
         }
@@ -906,6 +1629,7 @@ impl TaskService {
         // Batch load subtask counts
         let counts: Vec<(Option<i64>, i64)> = tasks::table
             .filter(tasks::parent_id.eq_any(&task_ids))
+            .filter(tasks::deleted_at.is_null())
             .group_by(tasks::parent_id)
             .select((tasks::parent_id, diesel::dsl::count_star()))
             .load(&mut conn)
@@ -918,6 +1642,7 @@ impl TaskService {
         // Batch load completed subtask counts
         let completed_counts: Vec<(Option<i64>, i64)> = tasks::table
             .filter(tasks::parent_id.eq_any(&task_ids))
+            .filter(tasks::deleted_at.is_null())
             .filter(tasks::completed_at.is_not_null())
             .group_by(tasks::parent_id)
             .select((tasks::parent_id, diesel::dsl::count_star()))
@@ -928,6 +1653,30 @@ impl TaskService {
             .filter_map(|(pid, cnt)| pid.map(|id| (id, cnt)))
             .collect();
 
+        // Batch load blockers, skipping trashed ones
+        let blocker_rows: Vec<(i64, i64)> = task_dependencies::table
+            .inner_join(tasks::table.on(tasks::id.eq(task_dependencies::blocker_id)))
+            .filter(task_dependencies::task_id.eq_any(&task_ids))
+            .filter(tasks::deleted_at.is_null())
+            .order(task_dependencies::created_at.asc())
+            .select((task_dependencies::task_id, task_dependencies::blocker_id))
+            .load(&mut conn)
+            .await?;
+        let mut blockers_map: HashMap<i64, Vec<i64>> = HashMap::new();
+        for (task_id, blocker_id) in blocker_rows {
+            blockers_map.entry(task_id).or_default().push(blocker_id);
+        }
+
+        // Tracked time, rolled up through subtasks, and running timers
+        let user_ids: Vec<i64> = task_rows
+            .iter()
+            .map(|t| t.user_id)
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+        let spent_map = crate::TimeEntryService::spent_minutes(&mut conn, &user_ids).await?;
+        let running_map = crate::TimeEntryService::running(&mut conn, &task_ids).await?;
+
         Ok(task_rows
             .into_iter()
             .map(|row| {
@@ -941,6 +1690,9 @@ impl TaskService {
                 task.tags = tags;
                 task.subtask_count = subtask_count;
                 task.completed_subtask_count = completed_subtask_count;
+                task.blocked_by = blockers_map.remove(&id).unwrap_or_default();
+                task.spent_minutes = spent_map.get(&id).copied().unwrap_or(0);
+                task.tracking_since = running_map.get(&id).copied();
                 task
             })
             .collect())
@@ -969,6 +1721,7 @@ impl TaskService {
             tasks::table
                 .filter(tasks::user_id.eq(user_id))
                 .filter(tasks::parent_id.is_null())
+                .filter(tasks::deleted_at.is_null())
                 .select(tasks::id)
                 .load(&mut conn)
                 .await?
@@ -980,6 +1733,7 @@ impl TaskService {
 
         let rows: Vec<TaskRow> = tasks::table
             .filter(tasks::id.eq_any(&matching_ids))
+            .filter(tasks::deleted_at.is_null())
             .select(TaskRow::as_select())
             .load(&mut conn)
             .await?;
@@ -995,6 +1749,17 @@ impl TaskService {
                     FilterField::StartAt => a.start_at.cmp(&b.start_at),
                     FilterField::Created => a.created_at.cmp(&b.created_at),
                     FilterField::Updated => a.updated_at.cmp(&b.updated_at),
+                    // Tasks without a priority rank below P4.
+                    FilterField::Priority => a
+                        .priority
+                        .unwrap_or(i16::MAX)
+                        .cmp(&b.priority.unwrap_or(i16::MAX)),
+                    // Tasks without an estimate sort after estimated ones.
+                    FilterField::Estimate => a
+                        .estimate_minutes
+                        .unwrap_or(i32::MAX)
+                        .cmp(&b.estimate_minutes.unwrap_or(i32::MAX)),
+                    FilterField::Spent => a.spent_minutes.cmp(&b.spent_minutes),
                     _ => a.sort_key.cmp(&b.sort_key),
                 };
                 match order_by.direction {
@@ -1007,3 +1772,460 @@ impl TaskService {
         Ok(results)
     }
 }
+
+fn validate_priority(priority: Option<i16>) -> ServiceResult<()> {
+    match priority {
+        Some(p) if !north_dto::PRIORITY_LEVELS.contains(&p) => Err(ServiceError::BadRequest(
+            "Priority must be between 1 and 4".into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_estimate(estimate_minutes: Option<i32>) -> ServiceResult<()> {
+    match estimate_minutes {
+        Some(m) if m <= 0 => Err(ServiceError::BadRequest(
+            "Estimate must be a positive duration".into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, NaiveDate};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    /// A user in New York, where DST starts on 2031-03-09.
+    async fn new_york_user(pool: &DbPool) -> i64 {
+        let settings = UserSettings {
+            timezone: "America/New_York".into(),
+            ..Default::default()
+        };
+        crate::test_support::user(pool, settings).await
+    }
+
+    async fn recurring(
+        pool: &DbPool,
+        user_id: i64,
+        start_at: Option<DateTime<Utc>>,
+        due_date: Option<NaiveDate>,
+        rule: &str,
+    ) -> Task {
+        let input = CreateTask {
+            title: "Water the plants".into(),
+            start_at,
+            due_date,
+            ..Default::default()
+        };
+        let task = TaskService::create(pool, user_id, &input).await.unwrap();
+        let input = UpdateTask {
+            recurrence_type: Some(Some(RecurrenceType::Scheduled)),
+            recurrence_rule: Some(Some(rule.into())),
+            ..Default::default()
+        };
+        TaskService::update(pool, user_id, task.id, &input)
+            .await
+            .unwrap()
+    }
+
+    async fn complete(pool: &DbPool, user_id: i64, id: i64) {
+        let input = UpdateTask {
+            completed_at: Some(Some(Utc::now())),
+            ..Default::default()
+        };
+        TaskService::update(pool, user_id, id, &input)
+            .await
+            .unwrap();
+    }
+
+    async fn open_tasks(pool: &DbPool, user_id: i64) -> Vec<Task> {
+        let mut conn = pool.get().await.unwrap();
+        tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::completed_at.is_null())
+            .filter(tasks::deleted_at.is_null())
+            .order(tasks::id.asc())
+            .select(TaskRow::as_select())
+            .load(&mut conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Task::from)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_skip_daily_keeps_local_time_across_dst() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        // 09:00 EST, the rule's own hour.
+        let start = utc("2031-03-08T14:00:00Z");
+        let task = recurring(
+            &pool,
+            user_id,
+            Some(start),
+            None,
+            "FREQ=DAILY;INTERVAL=1;BYHOUR=9;BYMINUTE=0",
+        )
+        .await;
+
+        let task = TaskService::skip_occurrence(&pool, user_id, task.id)
+            .await
+            .unwrap();
+        assert_eq!(task.start_at, Some(utc("2031-03-09T13:00:00Z")));
+        let task = TaskService::skip_occurrence(&pool, user_id, task.id)
+            .await
+            .unwrap();
+        assert_eq!(task.start_at, Some(utc("2031-03-10T13:00:00Z")));
+        assert_eq!(task.due_date, None);
+    }
+
+    #[tokio::test]
+    async fn test_due_only_task_stays_due_only() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let task = recurring(
+            &pool,
+            user_id,
+            None,
+            Some(date("2031-03-10")),
+            "FREQ=DAILY;INTERVAL=1;BYHOUR=9;BYMINUTE=0",
+        )
+        .await;
+
+        let skipped = TaskService::skip_occurrence(&pool, user_id, task.id)
+            .await
+            .unwrap();
+        assert_eq!(skipped.start_at, None);
+        assert_eq!(skipped.due_date, Some(date("2031-03-11")));
+
+        complete(&pool, user_id, task.id).await;
+        let open = open_tasks(&pool, user_id).await;
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].start_at, None);
+        assert_eq!(open[0].due_date, Some(date("2031-03-12")));
+    }
+
+    #[tokio::test]
+    async fn test_count_caps_instances_not_skips() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let task = recurring(
+            &pool,
+            user_id,
+            Some(utc("2031-03-10T13:00:00Z")),
+            None,
+            "FREQ=DAILY;INTERVAL=1;COUNT=2",
+        )
+        .await;
+
+        // Skipping moves the one instance along without using up COUNT.
+        TaskService::skip_occurrence(&pool, user_id, task.id)
+            .await
+            .unwrap();
+        complete(&pool, user_id, task.id).await;
+        let open = open_tasks(&pool, user_id).await;
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].start_at, Some(utc("2031-03-12T13:00:00Z")));
+
+        // The second instance is the last.
+        let err = TaskService::skip_occurrence(&pool, user_id, open[0].id).await;
+        assert!(matches!(err, Err(ServiceError::BadRequest(_))));
+        complete(&pool, user_id, open[0].id).await;
+        assert!(open_tasks(&pool, user_id).await.is_empty());
+        let series = TaskService::get_series(&pool, user_id, task.id)
+            .await
+            .unwrap();
+        assert_eq!(series.occurrences.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_count_ignores_trashed_instances() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let task = recurring(
+            &pool,
+            user_id,
+            Some(utc("2031-03-10T13:00:00Z")),
+            None,
+            "FREQ=DAILY;INTERVAL=1;COUNT=2",
+        )
+        .await;
+        complete(&pool, user_id, task.id).await;
+        let second = open_tasks(&pool, user_id).await.remove(0);
+        TaskService::delete(&pool, user_id, second.id)
+            .await
+            .unwrap();
+
+        // The trashed instance no longer counts, so completing the first
+        // again spawns a replacement.
+        let reopen = UpdateTask {
+            completed_at: Some(None),
+            ..Default::default()
+        };
+        TaskService::update(&pool, user_id, task.id, &reopen)
+            .await
+            .unwrap();
+        complete(&pool, user_id, task.id).await;
+        let open = open_tasks(&pool, user_id).await;
+        assert_eq!(open.len(), 1);
+        assert_ne!(open[0].id, second.id);
+    }
+
+    #[tokio::test]
+    async fn test_undoing_completion_takes_back_the_next_instance() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let first = recurring(
+            &pool,
+            user_id,
+            Some(utc("2031-03-10T13:00:00Z")),
+            None,
+            "FREQ=DAILY;INTERVAL=1",
+        )
+        .await;
+        complete(&pool, user_id, first.id).await;
+        let second = open_tasks(&pool, user_id).await.remove(0);
+        complete(&pool, user_id, second.id).await;
+        let third = open_tasks(&pool, user_id).await.remove(0);
+        let open_ids = || async {
+            open_tasks(&pool, user_id)
+                .await
+                .iter()
+                .map(|t| t.id)
+                .collect::<Vec<_>>()
+        };
+
+        // The first's successor is done, so undoing it takes nothing.
+        TaskService::undo_complete(&pool, user_id, first.id)
+            .await
+            .unwrap();
+        assert_eq!(open_ids().await, [first.id, third.id]);
+
+        // Undoing the second completion takes back the third instance.
+        TaskService::undo_complete(&pool, user_id, second.id)
+            .await
+            .unwrap();
+        assert_eq!(open_ids().await, [first.id, second.id]);
+        assert!(matches!(
+            TaskService::get_by_id(&pool, user_id, third.id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+
+        // A plain reopen leaves the spawned instance alone.
+        complete(&pool, user_id, second.id).await;
+        let fourth = *open_ids().await.last().unwrap();
+        let reopen = UpdateTask {
+            completed_at: Some(None),
+            ..Default::default()
+        };
+        TaskService::update(&pool, user_id, second.id, &reopen)
+            .await
+            .unwrap();
+        assert_eq!(open_ids().await, [first.id, second.id, fourth]);
+    }
+
+    #[tokio::test]
+    async fn test_postpone() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let create = |start_at, due_date| CreateTask {
+            title: "Call the bank".into(),
+            start_at,
+            due_date,
+            ..Default::default()
+        };
+        let to = date("2031-03-20");
+
+        // Before DST: 09:00 EST stays 09:00 local after the change.
+        let both = create(Some(utc("2031-03-07T14:00:00Z")), Some(date("2031-03-09")));
+        let both = TaskService::create(&pool, user_id, &both).await.unwrap();
+        let both = TaskService::postpone(&pool, user_id, both.id, to)
+            .await
+            .unwrap();
+        assert_eq!(both.start_at, Some(utc("2031-03-20T13:00:00Z")));
+        assert_eq!(both.due_date, Some(date("2031-03-22")));
+
+        let due_only = create(None, Some(date("2031-03-09")));
+        let due_only = TaskService::create(&pool, user_id, &due_only)
+            .await
+            .unwrap();
+        let due_only = TaskService::postpone(&pool, user_id, due_only.id, to)
+            .await
+            .unwrap();
+        assert_eq!(due_only.start_at, None);
+        assert_eq!(due_only.due_date, Some(to));
+
+        let undated = TaskService::create(&pool, user_id, &create(None, None))
+            .await
+            .unwrap();
+        let undated = TaskService::postpone(&pool, user_id, undated.id, to)
+            .await
+            .unwrap();
+        assert_eq!(undated.start_at, Some(utc("2031-03-20T04:00:00Z")));
+    }
+
+    async fn new_project(pool: &DbPool, user_id: i64) -> i64 {
+        let input = north_dto::CreateProject {
+            title: "Garden".into(),
+            description: None,
+            view_type: None,
+            area_id: None,
+            due_date: None,
+            outcome: None,
+        };
+        crate::ProjectService::create(pool, user_id, &input)
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn test_bulk_set_project() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let other_user = new_york_user(&pool).await;
+        let project = new_project(&pool, user_id).await;
+        let foreign = new_project(&pool, other_user).await;
+        let mut ids = Vec::new();
+        for title in ["Buy seeds", "Dig beds"] {
+            let input = CreateTask {
+                title: title.into(),
+                ..Default::default()
+            };
+            ids.push(
+                TaskService::create(&pool, user_id, &input)
+                    .await
+                    .unwrap()
+                    .id,
+            );
+        }
+        let bulk = |project_id| BulkTaskUpdate {
+            ids: ids.clone(),
+            actions: vec![BulkTaskAction::SetProject(project_id)],
+        };
+
+        TaskService::bulk_update(&pool, user_id, &bulk(Some(project)))
+            .await
+            .unwrap();
+        let projects = || async {
+            let open = open_tasks(&pool, user_id).await;
+            open.iter().map(|t| t.project_id).collect::<Vec<_>>()
+        };
+        assert_eq!(projects().await, [Some(project), Some(project)]);
+
+        // Another user's project is refused, and nothing moves.
+        assert!(matches!(
+            TaskService::bulk_update(&pool, user_id, &bulk(Some(foreign))).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        assert_eq!(projects().await, [Some(project), Some(project)]);
+
+        TaskService::bulk_update(&pool, user_id, &bulk(None))
+            .await
+            .unwrap();
+        assert_eq!(projects().await, [None, None]);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_complete_spawns_next_instance() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let task = recurring(
+            &pool,
+            user_id,
+            Some(utc("2031-03-10T13:00:00Z")),
+            None,
+            "FREQ=DAILY;INTERVAL=1",
+        )
+        .await;
+        let input = CreateTask {
+            title: "Buy seeds".into(),
+            ..Default::default()
+        };
+        let plain = TaskService::create(&pool, user_id, &input).await.unwrap();
+        let input = BulkTaskUpdate {
+            ids: vec![task.id, plain.id],
+            actions: vec![BulkTaskAction::Complete],
+        };
+
+        TaskService::bulk_update(&pool, user_id, &input)
+            .await
+            .unwrap();
+        let done = TaskService::get_by_id(&pool, user_id, task.id)
+            .await
+            .unwrap();
+        assert!(done.completed_at.is_some());
+        let open = open_tasks(&pool, user_id).await;
+        assert_eq!(open.len(), 1);
+        assert!(open[0].series_id.is_some());
+        assert_eq!(open[0].series_id, done.series_id);
+        assert_eq!(open[0].start_at, Some(utc("2031-03-11T13:00:00Z")));
+    }
+
+    #[tokio::test]
+    async fn test_clearing_waiting_for_clears_follow_up() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = new_york_user(&pool).await;
+        let input = CreateTask {
+            title: "Get the quote".into(),
+            ..Default::default()
+        };
+        let task = TaskService::create(&pool, user_id, &input).await.unwrap();
+        let input = UpdateTask {
+            waiting_for: Some(Some("  Sam ".into())),
+            follow_up_date: Some(Some(date("2031-03-14"))),
+            ..Default::default()
+        };
+        let task = TaskService::update(&pool, user_id, task.id, &input)
+            .await
+            .unwrap();
+        assert_eq!(task.waiting_for.as_deref(), Some("Sam"));
+        assert_eq!(task.follow_up_date, Some(date("2031-03-14")));
+        let filter = TaskFilter {
+            waiting: Some(true),
+            ..Default::default()
+        };
+        let waiting = TaskService::list(&pool, user_id, &filter).await.unwrap();
+        assert_eq!(waiting.len(), 1);
+
+        let input = UpdateTask {
+            waiting_for: Some(None),
+            ..Default::default()
+        };
+        let task = TaskService::update(&pool, user_id, task.id, &input)
+            .await
+            .unwrap();
+        assert_eq!(task.waiting_for, None);
+        assert_eq!(task.follow_up_date, None);
+        let waiting = TaskService::list(&pool, user_id, &filter).await.unwrap();
+        assert!(waiting.is_empty());
+    }
+}
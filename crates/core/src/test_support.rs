@@ -0,0 +1,49 @@
+//! Helpers for service tests that need Postgres. They run against the
+//! migrated database in `DATABASE_URL`, as CI provides, and are skipped
+//! when it is unset.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use diesel_async::pooled_connection::AsyncDieselConnectionManager;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use north_db::models::NewUser;
+use north_db::schema::users;
+use north_db::sql_types::UserRoleMapping;
+use north_db::DbPool;
+use north_dto::UserSettings;
+
+/// A pool on the test database, or `None` to skip the test.
+pub fn pool() -> Option<DbPool> {
+    let Ok(url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping database test");
+        return None;
+    };
+    let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(url);
+    // Sized like the server's: services take nested connections, which
+    // the default (four per CPU) can run out of.
+    Some(DbPool::builder(manager).max_size(20).build().unwrap())
+}
+
+/// Creates a user of its own for one test, so tests can share a database.
+pub async fn user(pool: &DbPool, settings: UserSettings) -> i64 {
+    static SEQ: AtomicU32 = AtomicU32::new(0);
+    let email = format!(
+        "test-{}-{}-{}@north.test",
+        std::process::id(),
+        chrono::Utc::now().timestamp_micros(),
+        SEQ.fetch_add(1, Ordering::Relaxed)
+    );
+    let mut conn = pool.get().await.unwrap();
+    diesel::insert_into(users::table)
+        .values(&NewUser {
+            email: &email,
+            password_hash: "",
+            name: "Test",
+            role: UserRoleMapping::User,
+            settings: serde_json::to_value(settings).unwrap(),
+        })
+        .returning(users::id)
+        .get_result(&mut conn)
+        .await
+        .unwrap()
+}
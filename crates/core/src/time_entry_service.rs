@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use north_db::models::NewTimeEntry;
+use north_db::schema::{tasks, time_entries};
+use north_db::DbPool;
+use north_dto::Task;
+
+use crate::{ServiceError, ServiceResult, TaskService};
+
+pub struct TimeEntryService;
+
+impl TimeEntryService {
+    /// Starts a timer on the task. A user tracks one task at a time, so a
+    /// timer running elsewhere is stopped first.
+    pub async fn start(pool: &DbPool, user_id: i64, task_id: i64) -> ServiceResult<Task> {
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let exists: Option<i64> = tasks::table
+                    .filter(tasks::id.eq(task_id))
+                    .filter(tasks::user_id.eq(user_id))
+                    .filter(tasks::deleted_at.is_null())
+                    .select(tasks::id)
+                    .first(conn)
+                    .await
+                    .optional()?;
+                if exists.is_none() {
+                    return Err(ServiceError::NotFound("Task not found".into()));
+                }
+
+                let now = Utc::now();
+                diesel::update(
+                    time_entries::table
+                        .filter(time_entries::user_id.eq(user_id))
+                        .filter(time_entries::ended_at.is_null()),
+                )
+                .set(time_entries::ended_at.eq(now))
+                .execute(conn)
+                .await?;
+                diesel::insert_into(time_entries::table)
+                    .values(&NewTimeEntry {
+                        task_id,
+                        user_id,
+                        started_at: now,
+                    })
+                    .execute(conn)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+        TaskService::get_by_id(pool, user_id, task_id).await
+    }
+
+    /// Stops the timer running on the task.
+    pub async fn stop(pool: &DbPool, user_id: i64, task_id: i64) -> ServiceResult<Task> {
+        let mut conn = pool.get().await?;
+        let stopped = diesel::update(
+            time_entries::table
+                .filter(time_entries::user_id.eq(user_id))
+                .filter(time_entries::task_id.eq(task_id))
+                .filter(time_entries::ended_at.is_null()),
+        )
+        .set(time_entries::ended_at.eq(Utc::now()))
+        .execute(&mut conn)
+        .await?;
+        if stopped == 0 {
+            return Err(ServiceError::NotFound(
+                "No timer running on this task".into(),
+            ));
+        }
+        TaskService::get_by_id(pool, user_id, task_id).await
+    }
+
+    /// Finished tracked minutes per task for the given users, each task
+    /// including the time of all its live subtasks.
+    pub(crate) async fn spent_minutes(
+        conn: &mut AsyncPgConnection,
+        user_ids: &[i64],
+    ) -> ServiceResult<HashMap<i64, i64>> {
+        let entries: Vec<(i64, DateTime<Utc>, Option<DateTime<Utc>>)> = time_entries::table
+            .filter(time_entries::user_id.eq_any(user_ids))
+            .filter(time_entries::ended_at.is_not_null())
+            .select((
+                time_entries::task_id,
+                time_entries::started_at,
+                time_entries::ended_at,
+            ))
+            .load(conn)
+            .await?;
+        if entries.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut own_seconds: HashMap<i64, i64> = HashMap::new();
+        for (task_id, started_at, ended_at) in entries {
+            if let Some(ended_at) = ended_at {
+                *own_seconds.entry(task_id).or_default() += (ended_at - started_at).num_seconds();
+            }
+        }
+
+        let parents: Vec<(i64, Option<i64>)> = tasks::table
+            .filter(tasks::user_id.eq_any(user_ids))
+            .filter(tasks::deleted_at.is_null())
+            .select((tasks::id, tasks::parent_id))
+            .load(conn)
+            .await?;
+        let parents: HashMap<i64, Option<i64>> = parents.into_iter().collect();
+
+        Ok(roll_up(&parents, &own_seconds)
+            .into_iter()
+            .map(|(id, seconds)| (id, seconds / 60))
+            .collect())
+    }
+
+    /// Start of the running timer per task, for the given tasks.
+    pub(crate) async fn running(
+        conn: &mut AsyncPgConnection,
+        task_ids: &[i64],
+    ) -> ServiceResult<HashMap<i64, DateTime<Utc>>> {
+        let rows: Vec<(i64, DateTime<Utc>)> = time_entries::table
+            .filter(time_entries::task_id.eq_any(task_ids))
+            .filter(time_entries::ended_at.is_null())
+            .select((time_entries::task_id, time_entries::started_at))
+            .load(conn)
+            .await?;
+        Ok(rows.into_iter().collect())
+    }
+}
+
+/// Adds every task's own amount to itself and all of its ancestors. Tasks
+/// missing from `parents` (e.g. trashed) don't count anywhere.
+fn roll_up(parents: &HashMap<i64, Option<i64>>, own: &HashMap<i64, i64>) -> HashMap<i64, i64> {
+    let mut totals: HashMap<i64, i64> = HashMap::new();
+    for (&task_id, &amount) in own {
+        let mut current = Some(task_id);
+        // Bounded by the number of tasks in case of a corrupt parent chain.
+        for _ in 0..=parents.len() {
+            let Some(id) = current else { break };
+            let Some(&parent_id) = parents.get(&id) else {
+                break;
+            };
+            *totals.entry(id).or_default() += amount;
+            current = parent_id;
+        }
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roll_up() {
+        // 1 ─┬─ 2 ── 3
+        //    └─ 4
+        // 5 (trashed child 6)
+        let parents: HashMap<i64, Option<i64>> = [
+            (1, None),
+            (2, Some(1)),
+            (3, Some(2)),
+            (4, Some(1)),
+            (5, None),
+        ]
+        .into_iter()
+        .collect();
+        let own: HashMap<i64, i64> = [(1, 10), (3, 20), (4, 5), (6, 100)].into_iter().collect();
+
+        let totals = roll_up(&parents, &own);
+
+        assert_eq!(totals.get(&1), Some(&35));
+        assert_eq!(totals.get(&2), Some(&20));
+        assert_eq!(totals.get(&3), Some(&20));
+        assert_eq!(totals.get(&4), Some(&5));
+        assert_eq!(totals.get(&5), None);
+        assert_eq!(totals.get(&6), None);
+    }
+}
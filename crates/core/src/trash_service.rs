@@ -0,0 +1,589 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use north_db::models::UserRow;
+use north_db::schema::{projects, tasks, users};
+use north_db::DbPool;
+use north_dto::{TrashItem, TrashItemKind, UserSettings};
+
+use crate::notifications::Clock;
+use crate::{AttachmentService, ServiceError, ServiceResult};
+
+/// A trashed task as needed to group the trash listing.
+#[derive(Debug, Clone, Queryable)]
+struct TrashedTask {
+    id: i64,
+    parent_id: Option<i64>,
+    project_id: Option<i64>,
+    title: String,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Queryable)]
+struct TrashedProject {
+    id: i64,
+    title: String,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+pub struct TrashService;
+
+impl TrashService {
+    /// Trashed items, most recently deleted first. Subtasks and project
+    /// tasks deleted together with their parent are folded into it.
+    pub async fn list(pool: &DbPool, user_id: i64) -> ServiceResult<Vec<TrashItem>> {
+        let mut conn = pool.get().await?;
+        let tasks: Vec<TrashedTask> = tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_not_null())
+            .select((
+                tasks::id,
+                tasks::parent_id,
+                tasks::project_id,
+                tasks::title,
+                tasks::deleted_at,
+            ))
+            .load(&mut conn)
+            .await?;
+        let projects: Vec<TrashedProject> = projects::table
+            .filter(projects::user_id.eq(user_id))
+            .filter(projects::deleted_at.is_not_null())
+            .select((projects::id, projects::title, projects::deleted_at))
+            .load(&mut conn)
+            .await?;
+
+        let mut project_ids: Vec<i64> = tasks.iter().filter_map(|t| t.project_id).collect();
+        project_ids.sort_unstable();
+        project_ids.dedup();
+        let project_titles: HashMap<i64, String> = projects::table
+            .filter(projects::id.eq_any(&project_ids))
+            .select((projects::id, projects::title))
+            .load::<(i64, String)>(&mut conn)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(group_trash(&tasks, &projects, &project_titles))
+    }
+
+    /// Restores a task with the subtasks trashed along with it. A trashed
+    /// parent or project is restored too, so the task has somewhere to go.
+    pub async fn restore_task(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let task = trashed_task(conn, user_id, id).await?;
+                // Subtasks may carry no project; their top task does.
+                let mut project_id = None;
+                let mut next = Some(task);
+                while let Some(task) = next {
+                    restore_task_batch(conn, &task).await?;
+                    project_id = task.project_id.or(project_id);
+                    next = match task.parent_id {
+                        Some(pid) => match trashed_task(conn, user_id, pid).await {
+                            Ok(parent) => Some(parent),
+                            Err(ServiceError::NotFound(_)) => None,
+                            Err(e) => return Err(e),
+                        },
+                        None => None,
+                    };
+                }
+                if let Some(pid) = project_id {
+                    diesel::update(
+                        projects::table
+                            .filter(projects::id.eq(pid))
+                            .filter(projects::deleted_at.is_not_null()),
+                    )
+                    .set(projects::deleted_at.eq(None::<DateTime<Utc>>))
+                    .execute(conn)
+                    .await?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Restores a project with the tasks trashed along with it.
+    pub async fn restore_project(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        conn.transaction::<_, ServiceError, _>(|conn| {
+            async move {
+                let deleted_at = trashed_project(conn, user_id, id).await?.deleted_at;
+                diesel::update(projects::table.filter(projects::id.eq(id)))
+                    .set(projects::deleted_at.eq(None::<DateTime<Utc>>))
+                    .execute(conn)
+                    .await?;
+                diesel::update(
+                    tasks::table
+                        .filter(tasks::project_id.eq(id))
+                        .filter(tasks::deleted_at.eq(deleted_at)),
+                )
+                .set(tasks::deleted_at.eq(None::<DateTime<Utc>>))
+                .execute(conn)
+                .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Deletes a trashed task, its subtasks and their attachments for good.
+    pub async fn delete_task(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<()> {
+        {
+            let mut conn = pool.get().await?;
+            trashed_task(&mut conn, user_id, id).await?;
+        }
+        AttachmentService::delete_for_task(pool, upload_dir, user_id, id).await?;
+        let mut conn = pool.get().await?;
+        diesel::delete(tasks::table.filter(tasks::id.eq(id)))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a trashed project, its tasks and their attachments for good.
+    pub async fn delete_project(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<()> {
+        let task_ids: Vec<i64> = {
+            let mut conn = pool.get().await?;
+            trashed_project(&mut conn, user_id, id).await?;
+            tasks::table
+                .filter(tasks::project_id.eq(id))
+                .filter(tasks::parent_id.is_null())
+                .select(tasks::id)
+                .load(&mut conn)
+                .await?
+        };
+        for task_id in task_ids {
+            AttachmentService::delete_for_task(pool, upload_dir, user_id, task_id).await?;
+        }
+        let mut conn = pool.get().await?;
+        diesel::delete(projects::table.filter(projects::id.eq(id)))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Permanently deletes everything in the trash deleted before `cutoff`
+    /// (everything if `None`). Returns how many items were removed.
+    pub async fn purge(
+        pool: &DbPool,
+        upload_dir: &Path,
+        user_id: i64,
+        cutoff: Option<DateTime<Utc>>,
+    ) -> ServiceResult<usize> {
+        let items = Self::list(pool, user_id).await?;
+        let mut purged = 0;
+        // Projects first: they take their tasks with them.
+        for item in items
+            .iter()
+            .filter(|i| i.kind == TrashItemKind::Project)
+            .chain(items.iter().filter(|i| i.kind == TrashItemKind::Task))
+        {
+            if cutoff.is_some_and(|c| item.deleted_at >= c) {
+                continue;
+            }
+            let result = match item.kind {
+                TrashItemKind::Project => {
+                    Self::delete_project(pool, upload_dir, user_id, item.id).await
+                }
+                TrashItemKind::Task => Self::delete_task(pool, upload_dir, user_id, item.id).await,
+            };
+            match result {
+                Ok(()) => purged += 1,
+                // Already removed along with a purged project or parent.
+                Err(ServiceError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(purged)
+    }
+}
+
+/// Restores `task` and the subtasks that share its `deleted_at`.
+async fn restore_task_batch(conn: &mut AsyncPgConnection, task: &TrashedTask) -> ServiceResult<()> {
+    diesel::update(tasks::table.filter(tasks::id.eq(task.id)))
+        .set(tasks::deleted_at.eq(None::<DateTime<Utc>>))
+        .execute(conn)
+        .await?;
+    let mut frontier = vec![task.id];
+    while !frontier.is_empty() {
+        frontier = diesel::update(
+            tasks::table
+                .filter(tasks::parent_id.eq_any(&frontier))
+                .filter(tasks::deleted_at.eq(task.deleted_at)),
+        )
+        .set(tasks::deleted_at.eq(None::<DateTime<Utc>>))
+        .returning(tasks::id)
+        .get_results(conn)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn trashed_task(
+    conn: &mut AsyncPgConnection,
+    user_id: i64,
+    id: i64,
+) -> ServiceResult<TrashedTask> {
+    tasks::table
+        .filter(tasks::id.eq(id))
+        .filter(tasks::user_id.eq(user_id))
+        .filter(tasks::deleted_at.is_not_null())
+        .select((
+            tasks::id,
+            tasks::parent_id,
+            tasks::project_id,
+            tasks::title,
+            tasks::deleted_at,
+        ))
+        .first(conn)
+        .await
+        .optional()?
+        .ok_or_else(|| ServiceError::NotFound("Task not in trash".into()))
+}
+
+async fn trashed_project(
+    conn: &mut AsyncPgConnection,
+    user_id: i64,
+    id: i64,
+) -> ServiceResult<TrashedProject> {
+    projects::table
+        .filter(projects::id.eq(id))
+        .filter(projects::user_id.eq(user_id))
+        .filter(projects::deleted_at.is_not_null())
+        .select((projects::id, projects::title, projects::deleted_at))
+        .first(conn)
+        .await
+        .optional()?
+        .ok_or_else(|| ServiceError::NotFound("Project not in trash".into()))
+}
+
+/// Folds items trashed together into the one the user deleted: a task is
+/// folded into its parent or project when they share its `deleted_at`.
+fn group_trash(
+    tasks: &[TrashedTask],
+    projects: &[TrashedProject],
+    project_titles: &HashMap<i64, String>,
+) -> Vec<TrashItem> {
+    let by_id: HashMap<i64, &TrashedTask> = tasks.iter().map(|t| (t.id, t)).collect();
+    let project_deleted: HashMap<i64, Option<DateTime<Utc>>> =
+        projects.iter().map(|p| (p.id, p.deleted_at)).collect();
+
+    let mut task_counts: HashMap<i64, i64> = HashMap::new();
+    let mut project_counts: HashMap<i64, i64> = HashMap::new();
+    let mut roots = Vec::new();
+    for task in tasks {
+        // Climb to the task the user actually deleted.
+        let mut root = task;
+        while let Some(parent) = root
+            .parent_id
+            .and_then(|pid| by_id.get(&pid))
+            .filter(|p| p.deleted_at == root.deleted_at)
+        {
+            root = parent;
+        }
+        let trashed_with_project = root
+            .project_id
+            .filter(|pid| project_deleted.get(pid) == Some(&root.deleted_at));
+        if let Some(pid) = trashed_with_project {
+            *project_counts.entry(pid).or_default() += 1;
+        } else if root.id == task.id {
+            roots.push(task);
+        } else {
+            *task_counts.entry(root.id).or_default() += 1;
+        }
+    }
+
+    let mut items: Vec<TrashItem> = roots
+        .into_iter()
+        .filter_map(|t| {
+            Some(TrashItem {
+                kind: TrashItemKind::Task,
+                id: t.id,
+                title: t.title.clone(),
+                project_title: t
+                    .project_id
+                    .and_then(|pid| project_titles.get(&pid).cloned()),
+                contained_count: task_counts.get(&t.id).copied().unwrap_or(0),
+                deleted_at: t.deleted_at?,
+            })
+        })
+        .chain(projects.iter().filter_map(|p| {
+            Some(TrashItem {
+                kind: TrashItemKind::Project,
+                id: p.id,
+                title: p.title.clone(),
+                project_title: None,
+                contained_count: project_counts.get(&p.id).copied().unwrap_or(0),
+                deleted_at: p.deleted_at?,
+            })
+        }))
+        .collect();
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then(b.id.cmp(&a.id)));
+    items
+}
+
+/// Purges trash older than each user's `trash_retention_days`.
+pub struct TrashScheduler {
+    pool: DbPool,
+    clock: Arc<dyn Clock>,
+    upload_dir: PathBuf,
+}
+
+impl TrashScheduler {
+    pub fn new(pool: DbPool, clock: Arc<dyn Clock>, upload_dir: PathBuf) -> Self {
+        Self {
+            pool,
+            clock,
+            upload_dir,
+        }
+    }
+
+    /// Purges expired trash for every user; returns how many items went.
+    pub async fn tick(&self) -> ServiceResult<usize> {
+        let now = self.clock.now();
+        let user_rows: Vec<UserRow> = {
+            let mut conn = self.pool.get().await?;
+            users::table
+                .select(UserRow::as_select())
+                .load(&mut conn)
+                .await?
+        };
+
+        let mut purged = 0;
+        for user in user_rows {
+            let settings: UserSettings = serde_json::from_value(user.settings).unwrap_or_default();
+            if settings.trash_retention_days <= 0 {
+                continue;
+            }
+            let cutoff = now - Duration::days(settings.trash_retention_days.into());
+            purged +=
+                TrashService::purge(&self.pool, &self.upload_dir, user.id, Some(cutoff)).await?;
+        }
+        Ok(purged)
+    }
+
+    /// Runs [`Self::tick`] every `period` on the current runtime.
+    pub fn spawn(self, period: StdDuration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.tick().await {
+                    tracing::error!(error = %e, "Trash purge tick failed");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProjectService, TaskService};
+    use chrono::TimeZone;
+    use north_dto::{CreateProject, CreateTask};
+
+    fn at(day: u32) -> Option<DateTime<Utc>> {
+        Some(Utc.with_ymd_and_hms(2026, 10, day, 12, 0, 0).unwrap())
+    }
+
+    fn task(id: i64, parent_id: Option<i64>, project_id: Option<i64>, day: u32) -> TrashedTask {
+        TrashedTask {
+            id,
+            parent_id,
+            project_id,
+            title: format!("Task {id}"),
+            deleted_at: at(day),
+        }
+    }
+
+    #[test]
+    fn test_group_trash() {
+        let tasks = vec![
+            // Parent deleted with two subtasks; a third subtask went earlier.
+            task(1, None, None, 10),
+            task(2, Some(1), None, 10),
+            task(3, Some(2), None, 10),
+            task(4, Some(1), None, 5),
+            // Project deleted with its task; another task went earlier.
+            task(5, None, Some(9), 12),
+            task(6, None, Some(9), 3),
+        ];
+        let projects = vec![TrashedProject {
+            id: 9,
+            title: "Home".into(),
+            deleted_at: at(12),
+        }];
+        let titles = HashMap::from([(9, "Home".to_string())]);
+
+        let items = group_trash(&tasks, &projects, &titles);
+        let summary: Vec<(TrashItemKind, i64, i64)> = items
+            .iter()
+            .map(|i| (i.kind, i.id, i.contained_count))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (TrashItemKind::Project, 9, 1),
+                (TrashItemKind::Task, 1, 2),
+                (TrashItemKind::Task, 4, 0),
+                (TrashItemKind::Task, 6, 0),
+            ]
+        );
+        assert_eq!(items[3].project_title.as_deref(), Some("Home"));
+    }
+
+    async fn new_task(
+        pool: &DbPool,
+        user_id: i64,
+        title: &str,
+        parent_id: Option<i64>,
+        project_id: Option<i64>,
+    ) -> i64 {
+        let input = CreateTask {
+            title: title.into(),
+            parent_id,
+            project_id,
+            ..Default::default()
+        };
+        TaskService::create(pool, user_id, &input).await.unwrap().id
+    }
+
+    async fn new_project(pool: &DbPool, user_id: i64, title: &str) -> i64 {
+        let input = CreateProject {
+            title: title.into(),
+            description: None,
+            view_type: None,
+            area_id: None,
+            due_date: None,
+            outcome: None,
+        };
+        ProjectService::create(pool, user_id, &input)
+            .await
+            .unwrap()
+            .id
+    }
+
+    /// The user's trashed task ids, in id order.
+    async fn trashed_task_ids(pool: &DbPool, user_id: i64) -> Vec<i64> {
+        let mut conn = pool.get().await.unwrap();
+        tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .filter(tasks::deleted_at.is_not_null())
+            .order(tasks::id.asc())
+            .select(tasks::id)
+            .load(&mut conn)
+            .await
+            .unwrap()
+    }
+
+    async fn task_rows(pool: &DbPool, user_id: i64) -> i64 {
+        let mut conn = pool.get().await.unwrap();
+        tasks::table
+            .filter(tasks::user_id.eq(user_id))
+            .count()
+            .get_result(&mut conn)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_restore_task_brings_back_its_subtree() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = crate::test_support::user(&pool, UserSettings::default()).await;
+        let project = new_project(&pool, user_id, "Holiday").await;
+        let parent = new_task(&pool, user_id, "Plan the trip", None, Some(project)).await;
+        let child = new_task(&pool, user_id, "Book flights", Some(parent), None).await;
+        let grandchild = new_task(&pool, user_id, "Compare fares", Some(child), None).await;
+        let earlier = new_task(&pool, user_id, "Pack", Some(parent), None).await;
+
+        TaskService::delete(&pool, user_id, earlier).await.unwrap();
+        TaskService::delete(&pool, user_id, parent).await.unwrap();
+        ProjectService::delete(&pool, user_id, project)
+            .await
+            .unwrap();
+        assert_eq!(
+            trashed_task_ids(&pool, user_id).await,
+            [parent, child, grandchild, earlier]
+        );
+        // Trashing twice is not found, and leaves the trash as it was.
+        let again = TaskService::delete(&pool, user_id, parent).await;
+        assert!(matches!(again, Err(ServiceError::NotFound(_))));
+
+        // The subtask brings back what was trashed with it, and the
+        // parent and project it lives in, but not the earlier deletion.
+        TrashService::restore_task(&pool, user_id, grandchild)
+            .await
+            .unwrap();
+        assert_eq!(trashed_task_ids(&pool, user_id).await, [earlier]);
+        assert!(ProjectService::get_by_id(&pool, user_id, project)
+            .await
+            .is_ok());
+
+        TrashService::restore_task(&pool, user_id, earlier)
+            .await
+            .unwrap();
+        assert!(trashed_task_ids(&pool, user_id).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_purge() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let upload_dir = std::env::temp_dir();
+        let user_id = crate::test_support::user(&pool, UserSettings::default()).await;
+        let old = new_task(&pool, user_id, "Old", None, None).await;
+        new_task(&pool, user_id, "Old subtask", Some(old), None).await;
+        TaskService::delete(&pool, user_id, old).await.unwrap();
+        let cutoff = Utc::now();
+        let kept = new_task(&pool, user_id, "Kept", None, None).await;
+        let recent = new_task(&pool, user_id, "Recent", None, None).await;
+        TaskService::delete(&pool, user_id, recent).await.unwrap();
+        let project = new_project(&pool, user_id, "Archive").await;
+        new_task(&pool, user_id, "Filed", None, Some(project)).await;
+        ProjectService::delete(&pool, user_id, project)
+            .await
+            .unwrap();
+        assert_eq!(task_rows(&pool, user_id).await, 5);
+
+        // Only what went before the cutoff, subtask included.
+        let purged = TrashService::purge(&pool, &upload_dir, user_id, Some(cutoff))
+            .await
+            .unwrap();
+        assert_eq!(purged, 1);
+        assert_eq!(task_rows(&pool, user_id).await, 3);
+
+        // The project takes its task with it, and live tasks stay.
+        let purged = TrashService::purge(&pool, &upload_dir, user_id, None)
+            .await
+            .unwrap();
+        assert_eq!(purged, 2);
+        assert!(TrashService::list(&pool, user_id).await.unwrap().is_empty());
+        assert_eq!(task_rows(&pool, user_id).await, 1);
+        assert!(TaskService::get_by_id(&pool, user_id, kept).await.is_ok());
+    }
+}
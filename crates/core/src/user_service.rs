@@ -3,7 +3,7 @@ use diesel_async::RunQueryDsl;
 use north_db::models::UserRow;
 use north_db::schema::users;
 use north_db::DbPool;
-use north_dto::{UpdateSettings, UserSettings};
+use north_dto::{ReviewStepKind, UpdateSettings, UserSettings};
 
 use crate::{ServiceError, ServiceResult};
 
@@ -47,6 +47,90 @@ impl UserService {
         if let Some(collapsed) = input.sidebar_collapsed {
             settings.sidebar_collapsed = collapsed;
         }
+        if let Some(email) = input.reminder_email {
+            settings.reminder_email = email;
+        }
+        if let Some(push) = input.reminder_push {
+            settings.reminder_push = push;
+        }
+        if let Some(ref url) = input.reminder_webhook_url {
+            let url = url.trim();
+            if !url.is_empty() {
+                crate::notifications::WebhookPolicy::from_env()
+                    .check(url)
+                    .await
+                    .map_err(ServiceError::BadRequest)?;
+            }
+            settings.reminder_webhook_url = (!url.is_empty()).then(|| url.to_string());
+        }
+        if let Some(frequency) = input.digest_frequency {
+            settings.digest_frequency = frequency;
+        }
+        if let Some(hour) = input.digest_hour {
+            if hour > 23 {
+                return Err(ServiceError::BadRequest(
+                    "Digest hour must be between 0 and 23".into(),
+                ));
+            }
+            settings.digest_hour = hour;
+        }
+        if let Some(filter_id) = input.digest_filter_id {
+            if let Some(id) = filter_id {
+                crate::FilterService::get_by_id(pool, user_id, id).await?;
+            }
+            settings.digest_filter_id = filter_id;
+        }
+        if let Some(ref namespaces) = input.exclusive_tag_namespaces {
+            let mut normalized = Vec::new();
+            for ns in namespaces {
+                // Accept `energy`, `energy:` and `energy:*` alike.
+                let raw = ns.trim().trim_end_matches('*').trim_end_matches(':');
+                if raw.is_empty() {
+                    continue;
+                }
+                let ns = north_dto::normalize_tag_name(raw).ok_or_else(|| {
+                    ServiceError::BadRequest(format!("Invalid tag namespace: {ns}"))
+                })?;
+                if !ns.is_empty() && !normalized.contains(&ns) {
+                    normalized.push(ns);
+                }
+            }
+            settings.exclusive_tag_namespaces = normalized;
+        }
+        if let Some(days) = input.trash_retention_days {
+            if days < 0 {
+                return Err(ServiceError::BadRequest(
+                    "Trash retention cannot be negative".into(),
+                ));
+            }
+            settings.trash_retention_days = days;
+        }
+        if let Some(hours) = input.available_hours {
+            if !(0.0..=24.0).contains(&hours) {
+                return Err(ServiceError::BadRequest(
+                    "Available hours must be between 0 and 24".into(),
+                ));
+            }
+            settings.available_hours = hours;
+        }
+        if let Some(ref steps) = input.review_steps {
+            let mut kept = Vec::new();
+            for &kind in steps {
+                // Checklist steps come from `review_checklist`.
+                if kind != ReviewStepKind::Checklist && !kept.contains(&kind) {
+                    kept.push(kind);
+                }
+            }
+            settings.review_steps = kept;
+        }
+        if let Some(ref items) = input.review_checklist {
+            settings.review_checklist = items
+                .iter()
+                .map(|i| i.trim())
+                .filter(|i| !i.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
 
         let val =
             serde_json::to_value(&settings).map_err(|e| ServiceError::BadRequest(e.to_string()))?;
@@ -0,0 +1,287 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use north_db::models::{NewWeeklyReview, WeeklyReviewChangeset, WeeklyReviewRow};
+use north_db::schema::weekly_reviews;
+use north_db::DbPool;
+use north_dto::{ReviewStep, ReviewStepKind, UpdateWeeklyReview, UserSettings, WeeklyReview};
+
+use crate::{ServiceError, ServiceResult};
+
+/// Completed reviews returned by [`WeeklyReviewService::history`].
+const HISTORY_LIMIT: i64 = 50;
+
+pub struct WeeklyReviewService;
+
+impl WeeklyReviewService {
+    /// The review in progress, if any.
+    pub async fn current(pool: &DbPool, user_id: i64) -> ServiceResult<Option<WeeklyReview>> {
+        let mut conn = pool.get().await?;
+        let row = weekly_reviews::table
+            .filter(weekly_reviews::user_id.eq(user_id))
+            .filter(weekly_reviews::completed_at.is_null())
+            .select(WeeklyReviewRow::as_select())
+            .first(&mut conn)
+            .await
+            .optional()?;
+        Ok(row.map(WeeklyReview::from))
+    }
+
+    /// Starts a review with the user's configured steps, or returns the one
+    /// already in progress.
+    pub async fn start(pool: &DbPool, user_id: i64) -> ServiceResult<WeeklyReview> {
+        if let Some(review) = Self::current(pool, user_id).await? {
+            return Ok(review);
+        }
+        let settings = crate::UserService::get_settings(pool, user_id).await?;
+        let steps = serde_json::to_value(plan(&settings))
+            .map_err(|e| ServiceError::BadRequest(e.to_string()))?;
+        let mut conn = pool.get().await?;
+        // A concurrent start loses on the unique index; return the winner.
+        let row = diesel::insert_into(weekly_reviews::table)
+            .values(&NewWeeklyReview { user_id, steps })
+            .on_conflict_do_nothing()
+            .returning(WeeklyReviewRow::as_returning())
+            .get_result(&mut conn)
+            .await
+            .optional()?;
+        match row {
+            Some(row) => Ok(WeeklyReview::from(row)),
+            None => Self::current(pool, user_id)
+                .await?
+                .ok_or_else(|| ServiceError::NotFound("Review not found".into())),
+        }
+    }
+
+    pub async fn update(
+        pool: &DbPool,
+        user_id: i64,
+        id: i64,
+        input: &UpdateWeeklyReview,
+    ) -> ServiceResult<WeeklyReview> {
+        let mut conn = pool.get().await?;
+        let existing = WeeklyReview::from(Self::owned_open(&mut conn, user_id, id).await?);
+        let step_count = existing.steps.len() as i32;
+        if input
+            .current_step
+            .is_some_and(|i| i < 0 || i >= step_count.max(1))
+        {
+            return Err(ServiceError::BadRequest("No such review step".into()));
+        }
+        let steps = match input.steps_done {
+            Some(ref done) => {
+                if done.len() != existing.steps.len() {
+                    return Err(ServiceError::BadRequest(
+                        "Expected one done flag per review step".into(),
+                    ));
+                }
+                let steps: Vec<ReviewStep> = existing
+                    .steps
+                    .into_iter()
+                    .zip(done)
+                    .map(|(step, &done)| ReviewStep { done, ..step })
+                    .collect();
+                Some(
+                    serde_json::to_value(steps)
+                        .map_err(|e| ServiceError::BadRequest(e.to_string()))?,
+                )
+            }
+            None => None,
+        };
+        // Guarded again in case the review was finished since it was read.
+        let row = diesel::update(
+            weekly_reviews::table
+                .filter(weekly_reviews::id.eq(id))
+                .filter(weekly_reviews::user_id.eq(user_id))
+                .filter(weekly_reviews::completed_at.is_null()),
+        )
+        .set(&WeeklyReviewChangeset {
+            steps,
+            current_step: input.current_step,
+            completed_at: None,
+        })
+        .returning(WeeklyReviewRow::as_returning())
+        .get_result(&mut conn)
+        .await
+        .optional()?
+        .ok_or_else(no_longer_open)?;
+        Ok(WeeklyReview::from(row))
+    }
+
+    /// Finishes the review, moving it into the history.
+    pub async fn complete(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<WeeklyReview> {
+        let mut conn = pool.get().await?;
+        Self::owned_open(&mut conn, user_id, id).await?;
+        let row = diesel::update(
+            weekly_reviews::table
+                .filter(weekly_reviews::id.eq(id))
+                .filter(weekly_reviews::user_id.eq(user_id))
+                .filter(weekly_reviews::completed_at.is_null()),
+        )
+        .set(&WeeklyReviewChangeset {
+            steps: None,
+            current_step: None,
+            completed_at: Some(Some(Utc::now())),
+        })
+        .returning(WeeklyReviewRow::as_returning())
+        .get_result(&mut conn)
+        .await
+        .optional()?
+        .ok_or_else(no_longer_open)?;
+        Ok(WeeklyReview::from(row))
+    }
+
+    /// Drops a review in progress without keeping it in the history.
+    pub async fn discard(pool: &DbPool, user_id: i64, id: i64) -> ServiceResult<()> {
+        let mut conn = pool.get().await?;
+        Self::owned_open(&mut conn, user_id, id).await?;
+        let deleted = diesel::delete(
+            weekly_reviews::table
+                .filter(weekly_reviews::id.eq(id))
+                .filter(weekly_reviews::user_id.eq(user_id))
+                .filter(weekly_reviews::completed_at.is_null()),
+        )
+        .execute(&mut conn)
+        .await?;
+        if deleted == 0 {
+            return Err(no_longer_open());
+        }
+        Ok(())
+    }
+
+    /// Completed reviews, most recent first.
+    pub async fn history(pool: &DbPool, user_id: i64) -> ServiceResult<Vec<WeeklyReview>> {
+        let mut conn = pool.get().await?;
+        let rows = weekly_reviews::table
+            .filter(weekly_reviews::user_id.eq(user_id))
+            .filter(weekly_reviews::completed_at.is_not_null())
+            .order(weekly_reviews::completed_at.desc())
+            .limit(HISTORY_LIMIT)
+            .select(WeeklyReviewRow::as_select())
+            .load(&mut conn)
+            .await?;
+        Ok(rows.into_iter().map(WeeklyReview::from).collect())
+    }
+
+    async fn owned_open(
+        conn: &mut diesel_async::AsyncPgConnection,
+        user_id: i64,
+        id: i64,
+    ) -> ServiceResult<WeeklyReviewRow> {
+        let row = weekly_reviews::table
+            .filter(weekly_reviews::id.eq(id))
+            .filter(weekly_reviews::user_id.eq(user_id))
+            .select(WeeklyReviewRow::as_select())
+            .first(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| ServiceError::NotFound("Review not found".into()))?;
+        if row.completed_at.is_some() {
+            return Err(ServiceError::BadRequest(
+                "Review is already completed".into(),
+            ));
+        }
+        Ok(row)
+    }
+}
+
+/// A review that was open when checked but was completed or discarded
+/// before the write landed.
+fn no_longer_open() -> ServiceError {
+    ServiceError::BadRequest("Review is no longer open".into())
+}
+
+/// The steps a new review walks through: the configured built-in steps,
+/// then one step per checklist item.
+fn plan(settings: &UserSettings) -> Vec<ReviewStep> {
+    settings
+        .review_steps
+        .iter()
+        .filter(|&&kind| kind != ReviewStepKind::Checklist)
+        .map(|&kind| ReviewStep {
+            kind,
+            title: kind.title().to_string(),
+            done: false,
+        })
+        .chain(settings.review_checklist.iter().map(|item| ReviewStep {
+            kind: ReviewStepKind::Checklist,
+            title: item.clone(),
+            done: false,
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan() {
+        let settings = UserSettings {
+            review_steps: vec![ReviewStepKind::Projects, ReviewStepKind::Inbox],
+            review_checklist: vec!["Clear the desk".into()],
+            ..Default::default()
+        };
+        let steps = plan(&settings);
+        let kinds: Vec<_> = steps.iter().map(|s| s.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ReviewStepKind::Projects,
+                ReviewStepKind::Inbox,
+                ReviewStepKind::Checklist,
+            ]
+        );
+        assert_eq!(steps[0].title, "Review projects");
+        assert_eq!(steps[2].title, "Clear the desk");
+        assert!(steps.iter().all(|s| !s.done));
+
+        let defaults = plan(&UserSettings::default());
+        assert_eq!(defaults.len(), ReviewStepKind::BUILT_IN.len());
+    }
+
+    #[tokio::test]
+    async fn test_writes_need_an_open_review_of_the_user() {
+        let Some(pool) = crate::test_support::pool() else {
+            return;
+        };
+        let user_id = crate::test_support::user(&pool, Default::default()).await;
+        let other_user = crate::test_support::user(&pool, Default::default()).await;
+        let review = WeeklyReviewService::start(&pool, user_id).await.unwrap();
+        let input = UpdateWeeklyReview {
+            current_step: Some(1),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            WeeklyReviewService::update(&pool, other_user, review.id, &input).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        assert!(matches!(
+            WeeklyReviewService::discard(&pool, other_user, review.id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        let updated = WeeklyReviewService::update(&pool, user_id, review.id, &input)
+            .await
+            .unwrap();
+        assert_eq!(updated.current_step, 1);
+
+        WeeklyReviewService::complete(&pool, user_id, review.id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            WeeklyReviewService::complete(&pool, user_id, review.id).await,
+            Err(ServiceError::BadRequest(_))
+        ));
+        assert!(matches!(
+            WeeklyReviewService::update(&pool, user_id, review.id, &input).await,
+            Err(ServiceError::BadRequest(_))
+        ));
+        assert!(matches!(
+            WeeklyReviewService::discard(&pool, user_id, review.id).await,
+            Err(ServiceError::BadRequest(_))
+        ));
+        let history = WeeklyReviewService::history(&pool, user_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+    }
+}
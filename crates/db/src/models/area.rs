@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::areas;
+use crate::sql_types::ProjectStatusMapping;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = areas)]
+pub struct AreaRow {
+    pub id: i64,
+    pub user_id: i64,
+    pub title: String,
+    pub position: i32,
+    pub status: ProjectStatusMapping,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = areas)]
+pub struct NewArea<'a> {
+    pub user_id: i64,
+    pub title: &'a str,
+    pub position: i32,
+}
+
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = areas)]
+pub struct AreaChangeset<'a> {
+    pub title: Option<&'a str>,
+    pub position: Option<i32>,
+    pub status: Option<ProjectStatusMapping>,
+}
+
+impl From<AreaRow> for north_dto::Area {
+    fn from(row: AreaRow) -> Self {
+        north_dto::Area {
+            id: row.id,
+            user_id: row.user_id,
+            title: row.title,
+            position: row.position,
+            status: row.status.into(),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
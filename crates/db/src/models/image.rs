@@ -26,3 +26,16 @@ pub struct NewImage<'a> {
     pub content_type: &'a str,
     pub size_bytes: i64,
 }
+
+impl From<ImageRow> for north_dto::Attachment {
+    fn from(row: ImageRow) -> Self {
+        north_dto::Attachment {
+            id: row.id,
+            task_id: row.task_id,
+            filename: row.filename,
+            content_type: row.content_type,
+            size_bytes: row.size_bytes,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -1,15 +1,33 @@
+pub mod area;
 pub mod image;
 pub mod project;
+pub mod project_column;
+pub mod project_section;
+pub mod reminder;
 pub mod saved_filter;
 pub mod tag;
 pub mod task;
+pub mod task_comment;
+pub mod task_dependency;
+pub mod task_event;
 pub mod task_tag;
+pub mod time_entry;
 pub mod user;
+pub mod weekly_review;
 
+pub use area::*;
 pub use image::*;
 pub use project::*;
+pub use project_column::*;
+pub use project_section::*;
+pub use reminder::*;
 pub use saved_filter::*;
 pub use tag::*;
 pub use task::*;
+pub use task_comment::*;
+pub use task_dependency::*;
+pub use task_event::*;
 pub use task_tag::*;
+pub use time_entry::*;
 pub use user::*;
+pub use weekly_review::*;
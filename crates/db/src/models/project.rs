@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::prelude::*;
 
 use crate::schema::projects;
@@ -17,6 +17,13 @@ pub struct ProjectRow {
     pub status: ProjectStatusMapping,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set while the project is in the trash.
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub area_id: Option<i64>,
+    pub due_date: Option<NaiveDate>,
+    pub outcome: Option<String>,
+    pub reviewed_at: Option<NaiveDate>,
+    pub review_interval_days: Option<i16>,
 }
 
 #[derive(Debug, Insertable)]
@@ -27,6 +34,9 @@ pub struct NewProject<'a> {
     pub description: Option<&'a str>,
     pub view_type: ProjectViewTypeMapping,
     pub position: i32,
+    pub area_id: Option<i64>,
+    pub due_date: Option<NaiveDate>,
+    pub outcome: Option<&'a str>,
 }
 
 #[derive(Debug, AsChangeset)]
@@ -38,6 +48,11 @@ pub struct ProjectChangeset<'a> {
     pub position: Option<i32>,
     pub color: Option<&'a str>,
     pub status: Option<ProjectStatusMapping>,
+    pub area_id: Option<Option<i64>>,
+    pub due_date: Option<Option<NaiveDate>>,
+    pub outcome: Option<Option<&'a str>>,
+    pub reviewed_at: Option<Option<NaiveDate>>,
+    pub review_interval_days: Option<Option<i16>>,
 }
 
 impl From<ProjectRow> for north_dto::Project {
@@ -51,6 +66,15 @@ impl From<ProjectRow> for north_dto::Project {
             position: row.position,
             color: row.color,
             status: row.status.into(),
+            area_id: row.area_id,
+            due_date: row.due_date,
+            outcome: row.outcome,
+            reviewed_at: row.reviewed_at,
+            review_interval_days: row.review_interval_days,
+            task_count: 0,
+            completed_task_count: 0,
+            progress: 0,
+            stalled: false,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::project_columns;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = project_columns)]
+pub struct ProjectColumnRow {
+    pub id: i64,
+    pub project_id: i64,
+    pub title: String,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = project_columns)]
+pub struct NewProjectColumn<'a> {
+    pub project_id: i64,
+    pub title: &'a str,
+    pub position: i32,
+}
+
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = project_columns)]
+pub struct ProjectColumnChangeset<'a> {
+    pub title: Option<&'a str>,
+    pub position: Option<i32>,
+}
+
+impl From<ProjectColumnRow> for north_dto::ProjectColumn {
+    fn from(row: ProjectColumnRow) -> Self {
+        north_dto::ProjectColumn {
+            id: row.id,
+            project_id: row.project_id,
+            title: row.title,
+            position: row.position,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::project_sections;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = project_sections)]
+pub struct ProjectSectionRow {
+    pub id: i64,
+    pub project_id: i64,
+    pub title: String,
+    pub sort_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = project_sections)]
+pub struct NewProjectSection<'a> {
+    pub project_id: i64,
+    pub title: &'a str,
+    pub sort_key: &'a str,
+}
+
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = project_sections)]
+pub struct ProjectSectionChangeset<'a> {
+    pub title: Option<&'a str>,
+    pub sort_key: Option<&'a str>,
+}
+
+impl From<ProjectSectionRow> for north_dto::ProjectSection {
+    fn from(row: ProjectSectionRow) -> Self {
+        north_dto::ProjectSection {
+            id: row.id,
+            project_id: row.project_id,
+            title: row.title,
+            sort_key: row.sort_key,
+        }
+    }
+}
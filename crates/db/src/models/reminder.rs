@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::{push_subscriptions, reminders};
+use crate::sql_types::ReminderAnchorMapping;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = reminders)]
+pub struct ReminderRow {
+    pub id: i64,
+    pub task_id: i64,
+    pub user_id: i64,
+    pub anchor: ReminderAnchorMapping,
+    pub offset_minutes: i32,
+    pub fired_for: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = reminders)]
+pub struct NewReminder {
+    pub task_id: i64,
+    pub user_id: i64,
+    pub anchor: ReminderAnchorMapping,
+    pub offset_minutes: i32,
+}
+
+impl From<ReminderRow> for north_dto::Reminder {
+    fn from(row: ReminderRow) -> Self {
+        north_dto::Reminder {
+            id: row.id,
+            task_id: row.task_id,
+            anchor: row.anchor.into(),
+            offset_minutes: row.offset_minutes,
+            fired_for: row.fired_for,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = push_subscriptions)]
+pub struct PushSubscriptionRow {
+    pub id: i64,
+    pub user_id: i64,
+    pub endpoint: String,
+    pub p256dh: String,
+    pub auth: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = push_subscriptions)]
+pub struct NewPushSubscription<'a> {
+    pub user_id: i64,
+    pub endpoint: &'a str,
+    pub p256dh: &'a str,
+    pub auth: &'a str,
+}
@@ -19,6 +19,13 @@ pub struct NewTag<'a> {
     pub color: &'a str,
 }
 
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = tags)]
+pub struct TagChangeset<'a> {
+    pub name: Option<&'a str>,
+    pub color: Option<&'a str>,
+}
+
 impl From<TagRow> for north_dto::Tag {
     fn from(row: TagRow) -> Self {
         north_dto::Tag {
@@ -26,6 +33,7 @@ impl From<TagRow> for north_dto::Tag {
             user_id: row.user_id,
             name: row.name,
             color: row.color,
+            task_count: 0,
         }
     }
 }
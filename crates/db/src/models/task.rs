@@ -26,6 +26,16 @@ pub struct TaskRow {
     pub recurrence_rule: Option<String>,
     pub is_url_fetching: Option<DateTime<Utc>>,
     pub someday: bool,
+    pub recurrence_exdates: Vec<NaiveDate>,
+    pub series_id: Option<i64>,
+    pub column_id: Option<i64>,
+    /// Set while the task is in the trash.
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub waiting_for: Option<String>,
+    pub follow_up_date: Option<NaiveDate>,
+    pub priority: Option<i16>,
+    pub estimate_minutes: Option<i32>,
+    pub section_id: Option<i64>,
 }
 
 #[derive(Debug, Insertable)]
@@ -44,6 +54,12 @@ pub struct NewTask<'a> {
     pub recurrence_rule: Option<&'a str>,
     pub is_url_fetching: Option<DateTime<Utc>>,
     pub someday: bool,
+    pub recurrence_exdates: Vec<NaiveDate>,
+    pub series_id: Option<i64>,
+    pub column_id: Option<i64>,
+    pub priority: Option<i16>,
+    pub estimate_minutes: Option<i32>,
+    pub section_id: Option<i64>,
 }
 
 #[derive(Debug, Default, AsChangeset)]
@@ -63,6 +79,13 @@ pub struct TaskChangeset<'a> {
     pub recurrence_rule: Option<Option<&'a str>>,
     pub is_url_fetching: Option<Option<DateTime<Utc>>>,
     pub someday: Option<bool>,
+    pub recurrence_exdates: Option<Vec<NaiveDate>>,
+    pub column_id: Option<Option<i64>>,
+    pub waiting_for: Option<Option<&'a str>>,
+    pub follow_up_date: Option<Option<NaiveDate>>,
+    pub priority: Option<Option<i16>>,
+    pub estimate_minutes: Option<Option<i32>>,
+    pub section_id: Option<Option<i64>>,
 }
 
 impl From<TaskRow> for north_dto::Task {
@@ -84,12 +107,23 @@ impl From<TaskRow> for north_dto::Task {
             updated_at: row.updated_at,
             recurrence_type: row.recurrence_type.map(Into::into),
             recurrence_rule: row.recurrence_rule,
+            recurrence_exdates: row.recurrence_exdates,
             is_url_fetching: row.is_url_fetching,
             someday: row.someday,
+            series_id: row.series_id,
+            column_id: row.column_id,
+            waiting_for: row.waiting_for,
+            follow_up_date: row.follow_up_date,
+            priority: row.priority,
+            estimate_minutes: row.estimate_minutes,
+            section_id: row.section_id,
             project_title: None,
             tags: vec![],
             subtask_count: 0,
             completed_subtask_count: 0,
+            blocked_by: vec![],
+            spent_minutes: 0,
+            tracking_since: None,
         }
     }
 }
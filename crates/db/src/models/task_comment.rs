@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::task_comments;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = task_comments)]
+pub struct TaskCommentRow {
+    pub id: i64,
+    pub task_id: i64,
+    pub user_id: i64,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub edited_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = task_comments)]
+pub struct NewTaskComment<'a> {
+    pub task_id: i64,
+    pub user_id: i64,
+    pub body: &'a str,
+}
+
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = task_comments)]
+pub struct TaskCommentChangeset<'a> {
+    pub body: Option<&'a str>,
+    pub edited_at: Option<DateTime<Utc>>,
+}
+
+impl TaskCommentRow {
+    pub fn into_dto(self, user_name: String) -> north_dto::TaskComment {
+        north_dto::TaskComment {
+            id: self.id,
+            task_id: self.task_id,
+            user_id: self.user_id,
+            user_name,
+            body: self.body,
+            created_at: self.created_at,
+            edited_at: self.edited_at,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::task_dependencies;
+
+/// `task_id` stays blocked until `blocker_id` is completed.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = task_dependencies)]
+#[diesel(primary_key(task_id, blocker_id))]
+pub struct TaskDependencyRow {
+    pub task_id: i64,
+    pub blocker_id: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = task_dependencies)]
+pub struct NewTaskDependency {
+    pub task_id: i64,
+    pub blocker_id: i64,
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::task_events;
+use crate::sql_types::TaskEventKindMapping;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = task_events)]
+pub struct TaskEventRow {
+    pub id: i64,
+    pub task_id: i64,
+    pub user_id: i64,
+    pub kind: TaskEventKindMapping,
+    pub field: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = task_events)]
+pub struct NewTaskEvent {
+    pub task_id: i64,
+    pub user_id: i64,
+    pub kind: TaskEventKindMapping,
+    pub field: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl TaskEventRow {
+    pub fn into_dto(self, user_name: String) -> north_dto::TaskEvent {
+        north_dto::TaskEvent {
+            id: self.id,
+            task_id: self.task_id,
+            user_id: self.user_id,
+            user_name,
+            kind: self.kind.into(),
+            field: self.field,
+            old_value: self.old_value,
+            new_value: self.new_value,
+            created_at: self.created_at,
+        }
+    }
+}
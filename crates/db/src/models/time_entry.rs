@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::time_entries;
+
+/// A stretch of time tracked on a task; `ended_at` is unset while running.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = time_entries)]
+pub struct TimeEntryRow {
+    pub id: i64,
+    pub task_id: i64,
+    pub user_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = time_entries)]
+pub struct NewTimeEntry {
+    pub task_id: i64,
+    pub user_id: i64,
+    pub started_at: DateTime<Utc>,
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::prelude::*;
 
 use crate::schema::users;
@@ -15,6 +15,7 @@ pub struct UserRow {
     pub settings: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub digest_sent_on: Option<NaiveDate>,
 }
 
 #[derive(Debug, Insertable)]
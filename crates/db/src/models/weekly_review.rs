@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::schema::weekly_reviews;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = weekly_reviews)]
+pub struct WeeklyReviewRow {
+    pub id: i64,
+    pub user_id: i64,
+    /// `Vec<north_dto::ReviewStep>` as JSON.
+    pub steps: serde_json::Value,
+    pub current_step: i32,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = weekly_reviews)]
+pub struct NewWeeklyReview {
+    pub user_id: i64,
+    pub steps: serde_json::Value,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = weekly_reviews)]
+pub struct WeeklyReviewChangeset {
+    pub steps: Option<serde_json::Value>,
+    pub current_step: Option<i32>,
+    pub completed_at: Option<Option<DateTime<Utc>>>,
+}
+
+impl From<WeeklyReviewRow> for north_dto::WeeklyReview {
+    fn from(row: WeeklyReviewRow) -> Self {
+        north_dto::WeeklyReview {
+            id: row.id,
+            steps: serde_json::from_value(row.steps).unwrap_or_default(),
+            current_step: row.current_step,
+            started_at: row.started_at,
+            updated_at: row.updated_at,
+            completed_at: row.completed_at,
+        }
+    }
+}
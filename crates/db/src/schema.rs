@@ -13,6 +13,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "recurrence_type"))]
     pub struct RecurrenceType;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "reminder_anchor"))]
+    pub struct ReminderAnchor;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "task_event_kind"))]
+    pub struct TaskEventKind;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "user_role"))]
     pub struct UserRole;
@@ -31,6 +39,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ProjectStatus;
+
+    areas (id) {
+        id -> Int8,
+        user_id -> Int8,
+        title -> Text,
+        position -> Int4,
+        status -> ProjectStatus,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    project_columns (id) {
+        id -> Int8,
+        project_id -> Int8,
+        title -> Text,
+        position -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    project_sections (id) {
+        id -> Int8,
+        project_id -> Int8,
+        title -> Text,
+        sort_key -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ProjectViewType;
@@ -47,6 +90,38 @@ diesel::table! {
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
         status -> ProjectStatus,
+        deleted_at -> Nullable<Timestamptz>,
+        area_id -> Nullable<Int8>,
+        due_date -> Nullable<Date>,
+        outcome -> Nullable<Text>,
+        reviewed_at -> Nullable<Date>,
+        review_interval_days -> Nullable<Int2>,
+    }
+}
+
+diesel::table! {
+    push_subscriptions (id) {
+        id -> Int8,
+        user_id -> Int8,
+        endpoint -> Text,
+        p256dh -> Text,
+        auth -> Text,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReminderAnchor;
+
+    reminders (id) {
+        id -> Int8,
+        task_id -> Int8,
+        user_id -> Int8,
+        anchor -> ReminderAnchor,
+        offset_minutes -> Int4,
+        fired_for -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
     }
 }
 
@@ -71,6 +146,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    task_comments (id) {
+        id -> Int8,
+        task_id -> Int8,
+        user_id -> Int8,
+        body -> Text,
+        created_at -> Timestamptz,
+        edited_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    task_dependencies (task_id, blocker_id) {
+        task_id -> Int8,
+        blocker_id -> Int8,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TaskEventKind;
+
+    task_events (id) {
+        id -> Int8,
+        task_id -> Int8,
+        user_id -> Int8,
+        kind -> TaskEventKind,
+        field -> Nullable<Text>,
+        old_value -> Nullable<Text>,
+        new_value -> Nullable<Text>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     task_tags (task_id, tag_id) {
         task_id -> Int8,
@@ -101,6 +211,26 @@ diesel::table! {
         recurrence_rule -> Nullable<Varchar>,
         is_url_fetching -> Nullable<Timestamptz>,
         someday -> Bool,
+        recurrence_exdates -> Array<Date>,
+        series_id -> Nullable<Int8>,
+        column_id -> Nullable<Int8>,
+        deleted_at -> Nullable<Timestamptz>,
+        waiting_for -> Nullable<Text>,
+        follow_up_date -> Nullable<Date>,
+        priority -> Nullable<Int2>,
+        estimate_minutes -> Nullable<Int4>,
+        section_id -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    time_entries (id) {
+        id -> Int8,
+        task_id -> Int8,
+        user_id -> Int8,
+        started_at -> Timestamptz,
+        ended_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
     }
 }
 
@@ -117,25 +247,64 @@ diesel::table! {
         settings -> Jsonb,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        digest_sent_on -> Nullable<Date>,
+    }
+}
+
+diesel::table! {
+    weekly_reviews (id) {
+        id -> Int8,
+        user_id -> Int8,
+        steps -> Jsonb,
+        current_step -> Int4,
+        started_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        completed_at -> Nullable<Timestamptz>,
     }
 }
 
+diesel::joinable!(areas -> users (user_id));
 diesel::joinable!(images -> tasks (task_id));
 diesel::joinable!(images -> users (user_id));
+diesel::joinable!(project_columns -> projects (project_id));
+diesel::joinable!(project_sections -> projects (project_id));
+diesel::joinable!(projects -> areas (area_id));
 diesel::joinable!(projects -> users (user_id));
+diesel::joinable!(push_subscriptions -> users (user_id));
+diesel::joinable!(reminders -> tasks (task_id));
+diesel::joinable!(reminders -> users (user_id));
 diesel::joinable!(saved_filters -> users (user_id));
 diesel::joinable!(tags -> users (user_id));
+diesel::joinable!(task_comments -> tasks (task_id));
+diesel::joinable!(task_comments -> users (user_id));
+diesel::joinable!(task_events -> tasks (task_id));
+diesel::joinable!(task_events -> users (user_id));
 diesel::joinable!(task_tags -> tags (tag_id));
 diesel::joinable!(task_tags -> tasks (task_id));
+diesel::joinable!(tasks -> project_columns (column_id));
+diesel::joinable!(tasks -> project_sections (section_id));
 diesel::joinable!(tasks -> projects (project_id));
 diesel::joinable!(tasks -> users (user_id));
+diesel::joinable!(time_entries -> tasks (task_id));
+diesel::joinable!(time_entries -> users (user_id));
+diesel::joinable!(weekly_reviews -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    areas,
     images,
+    project_columns,
+    project_sections,
     projects,
+    push_subscriptions,
+    reminders,
     saved_filters,
     tags,
+    task_comments,
+    task_dependencies,
+    task_events,
     task_tags,
     tasks,
+    time_entries,
     users,
+    weekly_reviews,
 );
@@ -107,3 +107,77 @@ impl From<north_dto::RecurrenceType> for RecurrenceTypeMapping {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::ReminderAnchor"]
+pub enum ReminderAnchorMapping {
+    #[db_rename = "start"]
+    Start,
+    #[db_rename = "due"]
+    Due,
+}
+
+impl From<ReminderAnchorMapping> for north_dto::ReminderAnchor {
+    fn from(val: ReminderAnchorMapping) -> Self {
+        match val {
+            ReminderAnchorMapping::Start => north_dto::ReminderAnchor::Start,
+            ReminderAnchorMapping::Due => north_dto::ReminderAnchor::Due,
+        }
+    }
+}
+
+impl From<north_dto::ReminderAnchor> for ReminderAnchorMapping {
+    fn from(val: north_dto::ReminderAnchor) -> Self {
+        match val {
+            north_dto::ReminderAnchor::Start => ReminderAnchorMapping::Start,
+            north_dto::ReminderAnchor::Due => ReminderAnchorMapping::Due,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::TaskEventKind"]
+pub enum TaskEventKindMapping {
+    #[db_rename = "created"]
+    Created,
+    #[db_rename = "updated"]
+    Updated,
+    #[db_rename = "completed"]
+    Completed,
+    #[db_rename = "uncompleted"]
+    Uncompleted,
+    #[db_rename = "reviewed"]
+    Reviewed,
+    #[db_rename = "moved"]
+    Moved,
+    #[db_rename = "tags_changed"]
+    TagsChanged,
+}
+
+impl From<TaskEventKindMapping> for north_dto::TaskEventKind {
+    fn from(val: TaskEventKindMapping) -> Self {
+        match val {
+            TaskEventKindMapping::Created => north_dto::TaskEventKind::Created,
+            TaskEventKindMapping::Updated => north_dto::TaskEventKind::Updated,
+            TaskEventKindMapping::Completed => north_dto::TaskEventKind::Completed,
+            TaskEventKindMapping::Uncompleted => north_dto::TaskEventKind::Uncompleted,
+            TaskEventKindMapping::Reviewed => north_dto::TaskEventKind::Reviewed,
+            TaskEventKindMapping::Moved => north_dto::TaskEventKind::Moved,
+            TaskEventKindMapping::TagsChanged => north_dto::TaskEventKind::TagsChanged,
+        }
+    }
+}
+
+impl From<north_dto::TaskEventKind> for TaskEventKindMapping {
+    fn from(val: north_dto::TaskEventKind) -> Self {
+        match val {
+            north_dto::TaskEventKind::Created => TaskEventKindMapping::Created,
+            north_dto::TaskEventKind::Updated => TaskEventKindMapping::Updated,
+            north_dto::TaskEventKind::Completed => TaskEventKindMapping::Completed,
+            north_dto::TaskEventKind::Uncompleted => TaskEventKindMapping::Uncompleted,
+            north_dto::TaskEventKind::Reviewed => TaskEventKindMapping::Reviewed,
+            north_dto::TaskEventKind::Moved => TaskEventKindMapping::Moved,
+            north_dto::TaskEventKind::TagsChanged => TaskEventKindMapping::TagsChanged,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A file uploaded to a task. The file itself is served from [`Attachment::url`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: i64,
+    pub task_id: Option<i64>,
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    pub fn url(&self) -> String {
+        attachment_url(self.id)
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.content_type.starts_with("image/")
+    }
+
+    /// Markdown that embeds the attachment: an image for images, a link otherwise.
+    pub fn markdown(&self) -> String {
+        let name = self.filename.replace(['[', ']'], "");
+        if self.is_image() {
+            format!("![{name}]({})", self.url())
+        } else {
+            format!("[{name}]({})", self.url())
+        }
+    }
+
+    /// Human-readable size, e.g. "12 KB".
+    pub fn size_label(&self) -> String {
+        let bytes = self.size_bytes.max(0) as f64;
+        if bytes < 1024.0 {
+            format!("{} B", self.size_bytes)
+        } else if bytes < 1024.0 * 1024.0 {
+            format!("{:.0} KB", bytes / 1024.0)
+        } else {
+            format!("{:.1} MB", bytes / (1024.0 * 1024.0))
+        }
+    }
+}
+
+pub fn attachment_url(id: i64) -> String {
+    format!("/api/attachments/{id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(filename: &str, content_type: &str, size_bytes: i64) -> Attachment {
+        Attachment {
+            id: 7,
+            task_id: Some(1),
+            filename: filename.into(),
+            content_type: content_type.into(),
+            size_bytes,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            attachment("shot.png", "image/png", 10).markdown(),
+            "![shot.png](/api/attachments/7)"
+        );
+        assert_eq!(
+            attachment("notes [v2].pdf", "application/pdf", 10).markdown(),
+            "[notes v2.pdf](/api/attachments/7)"
+        );
+    }
+
+    #[test]
+    fn test_size_label() {
+        assert_eq!(attachment("a", "text/plain", 512).size_label(), "512 B");
+        assert_eq!(
+            attachment("a", "text/plain", 12 * 1024).size_label(),
+            "12 KB"
+        );
+        assert_eq!(
+            attachment("a", "text/plain", 3 * 1024 * 1024 / 2).size_label(),
+            "1.5 MB"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+/// Parses a duration such as `30m`, `2h`, `1h30m` or `1.5h` into minutes.
+/// A bare number counts as minutes. Returns `None` for anything else or for
+/// a zero duration.
+pub fn parse_duration(s: &str) -> Option<i32> {
+    let s = s.trim().to_lowercase();
+    if s.is_empty() {
+        return None;
+    }
+    if let Ok(minutes) = s.parse::<i32>() {
+        return (minutes > 0).then_some(minutes);
+    }
+
+    let mut total = 0.0;
+    let mut number = String::new();
+    for ch in s.chars() {
+        match ch {
+            '0'..='9' | '.' => number.push(ch),
+            'h' | 'm' => {
+                let value: f64 = number.parse().ok()?;
+                total += if ch == 'h' { value * 60.0 } else { value };
+                number.clear();
+            }
+            ' ' => {}
+            _ => return None,
+        }
+    }
+    if !number.is_empty() {
+        return None;
+    }
+    let minutes = total.round() as i32;
+    (minutes > 0).then_some(minutes)
+}
+
+/// Formats minutes as `45m`, `2h` or `1h 30m`.
+pub fn format_duration(minutes: i64) -> String {
+    let (hours, mins) = (minutes / 60, minutes % 60);
+    match (hours, mins) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30m"), Some(30));
+        assert_eq!(parse_duration("2h"), Some(120));
+        assert_eq!(parse_duration("1h30m"), Some(90));
+        assert_eq!(parse_duration("1h 15m"), Some(75));
+        assert_eq!(parse_duration("1.5h"), Some(90));
+        assert_eq!(parse_duration("45"), Some(45));
+        assert_eq!(parse_duration(" 2H "), Some(120));
+        assert_eq!(parse_duration("0m"), None);
+        assert_eq!(parse_duration("1h30"), None);
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(45), "45m");
+        assert_eq!(format_duration(120), "2h");
+        assert_eq!(format_duration(90), "1h 30m");
+        assert_eq!(format_duration(0), "0m");
+    }
+}
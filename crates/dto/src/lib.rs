@@ -1,20 +1,40 @@
+pub mod attachment;
 pub mod colors;
 pub mod dsl_suggestion;
+pub mod duration;
 pub mod project;
 pub mod recurrence;
+pub mod reminder;
 pub mod saved_filter;
+pub mod search;
 pub mod serde_helpers;
+pub mod series;
 pub mod sort_key;
+pub mod stats;
 pub mod tag;
 pub mod task;
+pub mod task_comment;
+pub mod task_event;
+pub mod trash;
 pub mod user;
+pub mod weekly_review;
 
+pub use attachment::*;
 pub use colors::*;
 pub use dsl_suggestion::*;
+pub use duration::*;
 pub use project::*;
 pub use recurrence::*;
+pub use reminder::*;
 pub use saved_filter::*;
+pub use search::*;
+pub use series::*;
 pub use sort_key::*;
+pub use stats::*;
 pub use tag::*;
 pub use task::*;
+pub use task_comment::*;
+pub use task_event::*;
+pub use trash::*;
 pub use user::*;
+pub use weekly_review::*;
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -24,9 +24,55 @@ pub struct Project {
     pub view_type: ProjectViewType,
     pub position: i32,
     pub color: String,
+    /// Archived if either the project or its area is.
     pub status: ProjectStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub area_id: Option<i64>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    /// What "done" looks like for the project.
+    #[serde(default)]
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub reviewed_at: Option<NaiveDate>,
+    /// Days between reviews; `None` uses the user's review interval.
+    #[serde(default)]
+    pub review_interval_days: Option<i16>,
+    /// Live tasks in the project, subtasks included.
+    #[serde(default)]
+    pub task_count: i64,
+    #[serde(default)]
+    pub completed_task_count: i64,
+    /// Percentage of `task_count` completed; 0 for an empty project.
+    #[serde(default)]
+    pub progress: i32,
+    /// Active, but without an actionable open task to do next.
+    #[serde(default)]
+    pub stalled: bool,
+}
+
+impl Project {
+    /// Whole percentage of `completed` out of `total`, rounded down.
+    pub fn progress_of(completed: i64, total: i64) -> i32 {
+        if total <= 0 {
+            0
+        } else {
+            (completed.clamp(0, total) * 100 / total) as i32
+        }
+    }
+
+    /// Whether the project is due for a review on `today`: never reviewed,
+    /// or last reviewed at least its interval (else `default_interval_days`)
+    /// ago. Same rule as for tasks.
+    pub fn is_review_due(&self, default_interval_days: i64, today: NaiveDate) -> bool {
+        let interval = self
+            .review_interval_days
+            .map_or(default_interval_days, i64::from);
+        self.reviewed_at
+            .is_none_or(|d| d <= today - chrono::Duration::days(interval))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +80,12 @@ pub struct CreateProject {
     pub title: String,
     pub description: Option<String>,
     pub view_type: Option<ProjectViewType>,
+    #[serde(default)]
+    pub area_id: Option<i64>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub outcome: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -44,9 +96,168 @@ pub struct UpdateProject {
     pub position: Option<i32>,
     pub status: Option<ProjectStatus>,
     pub color: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub area_id: Option<Option<i64>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub due_date: Option<Option<NaiveDate>>,
+    /// Blank clears it.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub outcome: Option<Option<String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub reviewed_at: Option<Option<NaiveDate>>,
+    /// `null` goes back to the user's review interval.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub review_interval_days: Option<Option<i16>>,
+}
+
+/// Body of `POST /api/projects/:id/move`: puts a project into an area (or
+/// none) just before `before_id`, or at the end of that area.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveProject {
+    pub area_id: Option<i64>,
+    pub before_id: Option<i64>,
+}
+
+/// A sidebar group of projects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Area {
+    pub id: i64,
+    pub user_id: i64,
+    pub title: String,
+    pub position: i32,
+    pub status: ProjectStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateArea {
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateArea {
+    pub title: Option<String>,
+    pub position: Option<i32>,
+    pub status: Option<ProjectStatus>,
+}
+
+/// A column on a project's Kanban board.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectColumn {
+    pub id: i64,
+    pub project_id: i64,
+    pub title: String,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateProjectColumn {
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateProjectColumn {
+    pub title: Option<String>,
+    pub position: Option<i32>,
+}
+
+/// A heading in a project's list view. Top-level tasks can belong to one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectSection {
+    pub id: i64,
+    pub project_id: i64,
+    pub title: String,
+    pub sort_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateProjectSection {
+    pub title: String,
+    /// Defaults to after the project's last section.
+    #[serde(default)]
+    pub sort_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateProjectSection {
+    pub title: Option<String>,
+    pub sort_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectFilter {
     pub status: Option<ProjectStatus>,
+    pub area_id: Option<i64>,
+    /// Only projects due for a review (see [`Project::is_review_due`]).
+    pub review_due: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(reviewed_at: Option<NaiveDate>, review_interval_days: Option<i16>) -> Project {
+        Project {
+            id: 1,
+            user_id: 1,
+            title: "Project".into(),
+            description: None,
+            view_type: ProjectViewType::List,
+            position: 0,
+            color: crate::DEFAULT_COLOR.into(),
+            status: ProjectStatus::Active,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            area_id: None,
+            due_date: None,
+            outcome: None,
+            reviewed_at,
+            review_interval_days,
+            task_count: 0,
+            completed_task_count: 0,
+            progress: 0,
+            stalled: false,
+        }
+    }
+
+    #[test]
+    fn test_progress_of() {
+        assert_eq!(Project::progress_of(0, 0), 0);
+        assert_eq!(Project::progress_of(1, 3), 33);
+        assert_eq!(Project::progress_of(3, 3), 100);
+    }
+
+    #[test]
+    fn test_is_review_due() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let days_ago = |n| Some(today - chrono::Duration::days(n));
+
+        assert!(project(None, None).is_review_due(7, today));
+        assert!(!project(days_ago(6), None).is_review_due(7, today));
+        assert!(project(days_ago(7), None).is_review_due(7, today));
+        // The project's own interval wins over the default.
+        assert!(!project(days_ago(7), Some(14)).is_review_due(7, today));
+        assert!(project(days_ago(2), Some(1)).is_review_due(7, today));
+    }
 }
@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -103,6 +104,8 @@ impl Weekday {
         }
     }
 
+    pub const WORKDAYS: [Weekday; 5] = [Self::Mo, Self::Tu, Self::We, Self::Th, Self::Fr];
+
     pub fn label(self) -> &'static str {
         match self {
             Self::Mo => "Mo",
@@ -114,6 +117,33 @@ impl Weekday {
             Self::Su => "Su",
         }
     }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Mo => "Monday",
+            Self::Tu => "Tuesday",
+            Self::We => "Wednesday",
+            Self::Th => "Thursday",
+            Self::Fr => "Friday",
+            Self::Sa => "Saturday",
+            Self::Su => "Sunday",
+        }
+    }
+
+    /// Parse a BYDAY entry with an optional ordinal prefix (`FR`, `2MO`, `-1FR`).
+    pub fn parse_with_ordinal(s: &str) -> Option<(Option<i32>, Self)> {
+        if !s.is_ascii() {
+            return None;
+        }
+        let split = s.len().checked_sub(2)?;
+        let (prefix, code) = s.split_at(split);
+        let day = Self::from_code(code)?;
+        if prefix.is_empty() {
+            return Some((None, day));
+        }
+        let n = prefix.trim_start_matches('+').parse::<i32>().ok()?;
+        Some((Some(n), day))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -121,10 +151,18 @@ pub struct RecurrenceRule {
     pub freq: Frequency,
     pub interval: u32,
     pub by_day: BTreeSet<Weekday>,
+    /// Ordinal applied to every `by_day` entry in monthly/yearly rules
+    /// (`-1` + `FR` → `BYDAY=-1FR`, "the last Friday"). Rules whose entries
+    /// carry different ordinals (`BYDAY=1MO,-1FR`) don't parse.
+    pub by_day_ordinal: Option<i32>,
+    pub by_set_pos: Option<i32>,
     pub by_hour: Option<u32>,
     pub by_minute: Option<u32>,
     pub by_month_day: Option<u32>,
     pub by_month: Option<u32>,
+    /// Total occurrences in the series.
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
 }
 
 impl Default for RecurrenceRule {
@@ -133,16 +171,25 @@ impl Default for RecurrenceRule {
             freq: Frequency::Daily,
             interval: 1,
             by_day: BTreeSet::new(),
+            by_day_ordinal: None,
+            by_set_pos: None,
             by_hour: Some(9),
             by_minute: Some(0),
             by_month_day: None,
             by_month: None,
+            count: None,
+            until: None,
         }
     }
 }
 
 impl RecurrenceRule {
     pub fn parse(s: &str) -> Option<Self> {
+        Self::try_parse(s).ok()
+    }
+
+    /// Like [`parse`](Self::parse), but says what is wrong with the rule.
+    pub fn try_parse(s: &str) -> Result<Self, String> {
         let mut freq = None;
         let mut interval = 1u32;
         let mut by_day = BTreeSet::new();
@@ -150,6 +197,10 @@ impl RecurrenceRule {
         let mut by_minute = None;
         let mut by_month_day = None;
         let mut by_month = None;
+        let mut by_day_ordinal = None;
+        let mut by_set_pos = None;
+        let mut count = None;
+        let mut until = None;
 
         for part in s.split(';') {
             let mut kv = part.splitn(2, '=');
@@ -159,28 +210,41 @@ impl RecurrenceRule {
                 "FREQ" => freq = Frequency::from_code(val),
                 "INTERVAL" => interval = val.parse().unwrap_or(1),
                 "BYDAY" => {
+                    let mut ordinals = BTreeSet::new();
                     for day_code in val.split(',') {
-                        if let Some(d) = Weekday::from_code(day_code.trim()) {
+                        if let Some((n, d)) = Weekday::parse_with_ordinal(day_code.trim()) {
                             by_day.insert(d);
+                            ordinals.insert(n);
                         }
                     }
+                    if ordinals.len() > 1 {
+                        return Err(format!("BYDAY entries must share one ordinal: {val}"));
+                    }
+                    by_day_ordinal = ordinals.into_iter().next().flatten();
                 }
+                "BYSETPOS" => by_set_pos = val.parse().ok(),
                 "BYHOUR" => by_hour = val.parse().ok(),
                 "BYMINUTE" => by_minute = val.parse().ok(),
                 "BYMONTHDAY" => by_month_day = val.parse().ok(),
                 "BYMONTH" => by_month = val.parse().ok(),
+                "COUNT" => count = val.parse().ok(),
+                "UNTIL" => until = parse_rrule_date(val),
                 _ => {}
             }
         }
 
-        Some(Self {
-            freq: freq?,
+        Ok(Self {
+            freq: freq.ok_or_else(|| "Missing or unknown FREQ".to_string())?,
             interval,
             by_day,
+            by_day_ordinal,
+            by_set_pos,
             by_hour,
             by_minute,
             by_month_day,
             by_month,
+            count,
+            until,
         })
     }
 
@@ -192,8 +256,26 @@ impl RecurrenceRule {
             rule.push_str(&format!(";BYDAY={}", days.join(",")));
         }
 
+        if self.has_weekday_position() {
+            let prefix = self
+                .by_day_ordinal
+                .map(|n| n.to_string())
+                .unwrap_or_default();
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|d| format!("{prefix}{}", d.code()))
+                .collect();
+            rule.push_str(&format!(";BYDAY={}", days.join(",")));
+            if let Some(pos) = self.by_set_pos {
+                rule.push_str(&format!(";BYSETPOS={pos}"));
+            }
+        }
+
         if let Some(md) = self.by_month_day {
-            if self.freq == Frequency::Monthly || self.freq == Frequency::Yearly {
+            if !self.has_weekday_position()
+                && (self.freq == Frequency::Monthly || self.freq == Frequency::Yearly)
+            {
                 rule.push_str(&format!(";BYMONTHDAY={md}"));
             }
         }
@@ -209,9 +291,47 @@ impl RecurrenceRule {
             rule.push_str(&format!(";BYMINUTE={}", self.by_minute.unwrap_or(0)));
         }
 
+        if let Some(c) = self.count {
+            rule.push_str(&format!(";COUNT={c}"));
+        }
+
+        if let Some(until) = self.until {
+            rule.push_str(&format!(";UNTIL={}", until.format("%Y%m%d")));
+        }
+
         rule
     }
 
+    /// The repeating pattern alone, without end conditions. This is what
+    /// gets handed to the RRULE expander; COUNT and UNTIL are enforced by
+    /// the caller across instances of the series.
+    pub fn pattern(&self) -> Self {
+        Self {
+            count: None,
+            until: None,
+            ..self.clone()
+        }
+    }
+
+    /// True when a monthly/yearly rule picks days by weekday position
+    /// ("last Friday", "first weekday") rather than by day of month.
+    pub fn has_weekday_position(&self) -> bool {
+        matches!(self.freq, Frequency::Monthly | Frequency::Yearly)
+            && !self.by_day.is_empty()
+            && (self.by_day_ordinal.is_some() || self.by_set_pos.is_some())
+    }
+
+    /// Whether `date` falls after the series' UNTIL date.
+    pub fn is_past_end(&self, date: NaiveDate) -> bool {
+        self.until.is_some_and(|until| date > until)
+    }
+
+    /// Whether a series that already has `instances` instances may get
+    /// another one under COUNT.
+    pub fn allows_another(&self, instances: i64) -> bool {
+        self.count.is_none_or(|c| instances < i64::from(c))
+    }
+
     pub fn summarize(&self) -> String {
         let base = if self.interval == 1 {
             format!("Every {}", self.freq.unit_singular())
@@ -230,7 +350,12 @@ impl RecurrenceRule {
             if let Some(m) = self.by_month {
                 if (1..=12).contains(&m) {
                     let month_name = MONTH_NAMES[m as usize - 1];
-                    if let Some(md) = self.by_month_day {
+                    if self.has_weekday_position() {
+                        result = format!(
+                            "{result} on the {} of {month_name}",
+                            self.weekday_position_label()
+                        );
+                    } else if let Some(md) = self.by_month_day {
                         result = format!("{result} on {month_name} {md}");
                     } else {
                         result = format!("{result} in {month_name}");
@@ -240,7 +365,9 @@ impl RecurrenceRule {
                 result = format!("{result} on the {}", ordinal_suffix(md));
             }
         } else if self.freq == Frequency::Monthly {
-            if let Some(md) = self.by_month_day {
+            if self.has_weekday_position() {
+                result = format!("{result} on the {}", self.weekday_position_label());
+            } else if let Some(md) = self.by_month_day {
                 result = format!("{result} on the {}", ordinal_suffix(md));
             }
         }
@@ -250,9 +377,41 @@ impl RecurrenceRule {
             result = format!("{result} at {}", format_time_12h(h, m));
         }
 
+        if let Some(until) = self.until {
+            result = format!("{result}, until {}", format_date(until));
+        } else if let Some(c) = self.count {
+            let times = if c == 1 { "time" } else { "times" };
+            result = format!("{result}, {c} {times}");
+        }
+
         result
     }
 
+    /// Summary of the rule together with the number of dates excluded from
+    /// it (EXDATE).
+    pub fn summarize_with_exceptions(&self, exceptions: usize) -> String {
+        let result = self.summarize();
+        match exceptions {
+            0 => result,
+            1 => format!("{result}, except 1 date"),
+            n => format!("{result}, except {n} dates"),
+        }
+    }
+
+    fn weekday_position_label(&self) -> String {
+        let pos = self.by_set_pos.or(self.by_day_ordinal).unwrap_or(1);
+        let days = if self.by_set_pos.is_some() && is_workdays(&self.by_day) {
+            "weekday".to_string()
+        } else {
+            self.by_day
+                .iter()
+                .map(|d| d.name())
+                .collect::<Vec<_>>()
+                .join("/")
+        };
+        format!("{} {days}", ordinal_word(pos))
+    }
+
     pub fn time_str(&self) -> String {
         let h = self.by_hour.unwrap_or(9);
         let m = self.by_minute.unwrap_or(0);
@@ -275,6 +434,39 @@ const MONTH_NAMES: [&str; 12] = [
     "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
 ];
 
+/// Accepts both the date (`20270630`) and UTC datetime (`20270630T235959Z`)
+/// RRULE forms; only the date part is kept.
+fn parse_rrule_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.get(..8)?, "%Y%m%d").ok()
+}
+
+fn is_workdays(days: &BTreeSet<Weekday>) -> bool {
+    days.len() == Weekday::WORKDAYS.len() && Weekday::WORKDAYS.iter().all(|d| days.contains(d))
+}
+
+fn ordinal_word(n: i32) -> String {
+    match n {
+        1 => "first".to_string(),
+        2 => "second".to_string(),
+        3 => "third".to_string(),
+        4 => "fourth".to_string(),
+        5 => "fifth".to_string(),
+        -1 => "last".to_string(),
+        -2 => "second-to-last".to_string(),
+        n if n > 0 => ordinal_suffix(n as u32),
+        n => format!("{} from last", ordinal_suffix(n.unsigned_abs())),
+    }
+}
+
+fn format_date(d: NaiveDate) -> String {
+    format!(
+        "{} {}, {}",
+        MONTH_NAMES[d.month0() as usize],
+        d.day(),
+        d.year()
+    )
+}
+
 fn ordinal_suffix(n: u32) -> String {
     let suffix = match n % 10 {
         1 if n % 100 != 11 => "st",
@@ -498,4 +690,123 @@ mod tests {
         let rule = RecurrenceRule::default();
         assert_eq!(rule.time_str(), "09:00");
     }
+
+    #[test]
+    fn parse_byday_rejects_non_ascii() {
+        assert_eq!(Weekday::parse_with_ordinal("éa"), None);
+        assert_eq!(Weekday::parse_with_ordinal("1é"), None);
+        let rule = RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=éa,MO").unwrap();
+        assert_eq!(rule.by_day, BTreeSet::from([Weekday::Mo]));
+    }
+
+    #[test]
+    fn parse_last_friday_of_month() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY;INTERVAL=1;BYDAY=-1FR;BYHOUR=9;BYMINUTE=0")
+            .unwrap();
+        assert_eq!(rule.by_day_ordinal, Some(-1));
+        assert!(rule.by_day.contains(&Weekday::Fr));
+        assert_eq!(rule.summarize(), "Every month on the last Friday at 9 AM");
+        assert_eq!(
+            rule.to_rrule_string(),
+            "FREQ=MONTHLY;INTERVAL=1;BYDAY=-1FR;BYHOUR=9;BYMINUTE=0"
+        );
+    }
+
+    #[test]
+    fn roundtrip_last_weekday_with_setpos() {
+        let rule = RecurrenceRule {
+            freq: Frequency::Monthly,
+            by_day: Weekday::WORKDAYS.into_iter().collect(),
+            by_set_pos: Some(-1),
+            ..Default::default()
+        };
+        let s = rule.to_rrule_string();
+        assert!(s.contains(";BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"));
+        let parsed = RecurrenceRule::parse(&s).unwrap();
+        assert_eq!(parsed, rule);
+        assert_eq!(
+            parsed.summarize(),
+            "Every month on the last weekday at 9 AM"
+        );
+    }
+
+    #[test]
+    fn monthly_day_position_without_ordinal_omits_byday() {
+        let mut rule = RecurrenceRule {
+            freq: Frequency::Monthly,
+            by_month_day: Some(15),
+            ..Default::default()
+        };
+        rule.by_day.insert(Weekday::Mo);
+        assert_eq!(
+            rule.to_rrule_string(),
+            "FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=15;BYHOUR=9;BYMINUTE=0"
+        );
+    }
+
+    #[test]
+    fn roundtrip_end_conditions() {
+        let rule = RecurrenceRule {
+            freq: Frequency::Weekly,
+            by_day: Weekday::WORKDAYS.into_iter().collect(),
+            until: NaiveDate::from_ymd_opt(2027, 6, 30),
+            ..Default::default()
+        };
+        let s = rule.to_rrule_string();
+        assert!(s.ends_with(";UNTIL=20270630"));
+        assert_eq!(RecurrenceRule::parse(&s).unwrap(), rule);
+        assert_eq!(
+            rule.summarize_with_exceptions(2),
+            "Every week (MO,TU,WE,TH,FR) at 9 AM, until Jun 30, 2027, except 2 dates"
+        );
+    }
+
+    #[test]
+    fn exdate_is_not_part_of_the_rule() {
+        let rule = RecurrenceRule::parse("FREQ=DAILY;EXDATE=20261225").unwrap();
+        assert_eq!(rule, RecurrenceRule::parse("FREQ=DAILY").unwrap());
+        assert!(!rule.to_rrule_string().contains("EXDATE"));
+    }
+
+    #[test]
+    fn mixed_byday_ordinals_are_rejected() {
+        assert!(RecurrenceRule::try_parse("FREQ=MONTHLY;BYDAY=1MO,-1FR").is_err());
+        assert!(RecurrenceRule::try_parse("FREQ=MONTHLY;BYDAY=MO,-1FR").is_err());
+        let rule = RecurrenceRule::try_parse("FREQ=MONTHLY;BYDAY=-1MO,-1FR").unwrap();
+        assert_eq!(rule.by_day_ordinal, Some(-1));
+        assert_eq!(rule.by_day.len(), 2);
+        assert!(RecurrenceRule::try_parse("INTERVAL=2").is_err());
+    }
+
+    #[test]
+    fn parse_until_datetime_form() {
+        let rule = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20270630T235959Z").unwrap();
+        assert_eq!(rule.until, NaiveDate::from_ymd_opt(2027, 6, 30));
+    }
+
+    #[test]
+    fn count_is_the_series_total() {
+        let rule = RecurrenceRule {
+            count: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(rule.summarize(), "Every day at 9 AM, 2 times");
+        assert_eq!(
+            rule.to_rrule_string(),
+            "FREQ=DAILY;INTERVAL=1;BYHOUR=9;BYMINUTE=0;COUNT=2"
+        );
+        assert!(rule.allows_another(1));
+        assert!(!rule.allows_another(2));
+        assert!(RecurrenceRule::default().allows_another(1000));
+    }
+
+    #[test]
+    fn pattern_strips_bounds() {
+        let rule = RecurrenceRule::parse("FREQ=DAILY;COUNT=3;UNTIL=20270101").unwrap();
+        let pattern = rule.pattern().to_rrule_string();
+        assert!(!pattern.contains("COUNT"));
+        assert!(!pattern.contains("UNTIL"));
+        assert!(rule.is_past_end(NaiveDate::from_ymd_opt(2027, 1, 2).unwrap()));
+        assert!(!rule.is_past_end(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()));
+    }
 }
@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What a reminder's offset is measured back from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderAnchor {
+    Start,
+    Due,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: i64,
+    pub task_id: i64,
+    pub anchor: ReminderAnchor,
+    /// Minutes before the anchor; 0 fires exactly at it.
+    pub offset_minutes: i32,
+    /// Fire time of the last delivered notification, so a reminder fires
+    /// once per schedule and again after the task is rescheduled.
+    pub fired_for: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Reminder {
+    /// Human-readable description, e.g. "15 min before start".
+    pub fn describe(&self) -> String {
+        describe_offset(self.anchor, self.offset_minutes)
+    }
+}
+
+pub fn describe_offset(anchor: ReminderAnchor, offset_minutes: i32) -> String {
+    let anchor = match anchor {
+        ReminderAnchor::Start => "start",
+        ReminderAnchor::Due => "due",
+    };
+    if offset_minutes <= 0 {
+        return format!("At {anchor}");
+    }
+    let amount = if offset_minutes % 1440 == 0 {
+        let days = offset_minutes / 1440;
+        format!("{days} day{}", if days == 1 { "" } else { "s" })
+    } else if offset_minutes % 60 == 0 {
+        let hours = offset_minutes / 60;
+        format!("{hours} hour{}", if hours == 1 { "" } else { "s" })
+    } else {
+        format!("{offset_minutes} min")
+    };
+    format!("{amount} before {anchor}")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReminder {
+    pub anchor: ReminderAnchor,
+    #[serde(default)]
+    pub offset_minutes: i32,
+}
+
+/// Browser `PushSubscription` keys, as returned by `subscription.toJSON()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushSubscriptionInput {
+    pub endpoint: String,
+    pub p256dh: String,
+    pub auth: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_offset() {
+        assert_eq!(describe_offset(ReminderAnchor::Start, 0), "At start");
+        assert_eq!(
+            describe_offset(ReminderAnchor::Start, 15),
+            "15 min before start"
+        );
+        assert_eq!(
+            describe_offset(ReminderAnchor::Due, 60),
+            "1 hour before due"
+        );
+        assert_eq!(
+            describe_offset(ReminderAnchor::Due, 180),
+            "3 hours before due"
+        );
+        assert_eq!(
+            describe_offset(ReminderAnchor::Due, 1440),
+            "1 day before due"
+        );
+        assert_eq!(
+            describe_offset(ReminderAnchor::Due, 2880),
+            "2 days before due"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchResultKind {
+    Project,
+    Filter,
+    Tag,
+    Task,
+}
+
+/// One match of `GET /api/search`, best first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub kind: SearchResultKind,
+    /// Row id; tags are addressed by `title`.
+    pub id: i64,
+    pub title: String,
+    /// Project color, tag color, or `None`.
+    pub color: Option<String>,
+    /// Extra context, e.g. the project of a task.
+    pub detail: Option<String>,
+    pub score: i32,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+    pub limit: Option<i64>,
+}
+
+/// Scores `candidate` against `query` as a case-insensitive subsequence
+/// match, or `None` if some query character is missing. Consecutive
+/// characters, word starts and an early first match score higher, so
+/// "tdy" finds "Today" and "prj x" prefers "Project X" over "supreme
+/// jinx".
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    let mut score = 0;
+    let mut qi = 0;
+    let mut prev_match: Option<usize> = None;
+    for (i, c) in chars.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if !c.to_lowercase().eq(std::iter::once(query[qi])) {
+            continue;
+        }
+        score += 1;
+        if prev_match.is_some_and(|p| p + 1 == i) {
+            score += 5;
+        }
+        if i == 0 || !chars[i - 1].is_alphanumeric() {
+            score += 8;
+        }
+        if qi == 0 {
+            score -= i.min(10) as i32;
+        }
+        prev_match = Some(i);
+        qi += 1;
+    }
+    if qi < query.len() {
+        return None;
+    }
+    // Prefer shorter candidates among equal matches.
+    Some(score * 4 - (chars.len() as i32).min(40) / 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_matches_subsequence() {
+        assert!(fuzzy_score("tdy", "Today").is_some());
+        assert!(fuzzy_score("TODAY", "go to today").is_some());
+        assert!(fuzzy_score("", "anything").is_some());
+        assert!(fuzzy_score("xyz", "Today").is_none());
+        assert!(fuzzy_score("yadot", "Today").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_word_starts_and_runs() {
+        let word_starts = fuzzy_score("prj x", "Project X").unwrap();
+        let scattered = fuzzy_score("prj x", "supreme jinx").unwrap();
+        assert!(word_starts > scattered);
+
+        let prefix = fuzzy_score("rev", "Review").unwrap();
+        let inner = fuzzy_score("rev", "Start a preview").unwrap();
+        assert!(prefix > inner);
+    }
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OccurrenceStatus {
+    OnTime,
+    Late,
+    Missed,
+    Pending,
+}
+
+impl OccurrenceStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OccurrenceStatus::OnTime => "On time",
+            OccurrenceStatus::Late => "Late",
+            OccurrenceStatus::Missed => "Overdue",
+            OccurrenceStatus::Pending => "Upcoming",
+        }
+    }
+}
+
+/// One instance of a recurring task, with dates in the user's timezone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeriesOccurrence {
+    pub task_id: i64,
+    pub scheduled_on: Option<NaiveDate>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub completed_on: Option<NaiveDate>,
+    pub status: OccurrenceStatus,
+}
+
+impl SeriesOccurrence {
+    pub fn new(
+        task_id: i64,
+        scheduled_on: Option<NaiveDate>,
+        completed_at: Option<DateTime<Utc>>,
+        completed_on: Option<NaiveDate>,
+        today: NaiveDate,
+    ) -> Self {
+        let status = match (scheduled_on, completed_on) {
+            (Some(scheduled), Some(done)) if done > scheduled => OccurrenceStatus::Late,
+            (_, Some(_)) => OccurrenceStatus::OnTime,
+            (Some(scheduled), None) if scheduled < today => OccurrenceStatus::Missed,
+            (_, None) => OccurrenceStatus::Pending,
+        };
+        Self {
+            task_id,
+            scheduled_on,
+            completed_at,
+            completed_on,
+            status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskSeries {
+    pub series_id: i64,
+    /// Oldest first.
+    pub occurrences: Vec<SeriesOccurrence>,
+    pub completed_count: usize,
+    pub on_time_count: usize,
+    pub current_streak: usize,
+    pub longest_streak: usize,
+}
+
+impl TaskSeries {
+    /// Builds the series summary from occurrences ordered oldest first.
+    /// A streak is a run of on-time completions; a late completion or an
+    /// overdue open occurrence breaks it, an upcoming one does not.
+    pub fn new(series_id: i64, occurrences: Vec<SeriesOccurrence>) -> Self {
+        let mut completed_count = 0;
+        let mut on_time_count = 0;
+        let mut current_streak = 0;
+        let mut longest_streak = 0;
+
+        for occurrence in &occurrences {
+            match occurrence.status {
+                OccurrenceStatus::OnTime => {
+                    completed_count += 1;
+                    on_time_count += 1;
+                    current_streak += 1;
+                    longest_streak = longest_streak.max(current_streak);
+                }
+                OccurrenceStatus::Late => {
+                    completed_count += 1;
+                    current_streak = 0;
+                }
+                OccurrenceStatus::Missed => current_streak = 0,
+                OccurrenceStatus::Pending => {}
+            }
+        }
+
+        Self {
+            series_id,
+            occurrences,
+            completed_count,
+            on_time_count,
+            current_streak,
+            longest_streak,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn occ(id: i64, scheduled: u32, done: Option<u32>, today: u32) -> SeriesOccurrence {
+        SeriesOccurrence::new(id, Some(d(scheduled)), None, done.map(d), d(today))
+    }
+
+    #[test]
+    fn test_occurrence_status() {
+        assert_eq!(occ(1, 5, Some(5), 10).status, OccurrenceStatus::OnTime);
+        assert_eq!(occ(1, 5, Some(4), 10).status, OccurrenceStatus::OnTime);
+        assert_eq!(occ(1, 5, Some(6), 10).status, OccurrenceStatus::Late);
+        assert_eq!(occ(1, 5, None, 10).status, OccurrenceStatus::Missed);
+        assert_eq!(occ(1, 10, None, 10).status, OccurrenceStatus::Pending);
+        let unscheduled = SeriesOccurrence::new(1, None, None, Some(d(3)), d(10));
+        assert_eq!(unscheduled.status, OccurrenceStatus::OnTime);
+    }
+
+    #[test]
+    fn test_streaks() {
+        let series = TaskSeries::new(
+            1,
+            vec![
+                occ(1, 1, Some(1), 10),
+                occ(2, 2, Some(2), 10),
+                occ(3, 3, Some(3), 10),
+                occ(4, 4, Some(6), 10),
+                occ(5, 7, Some(7), 10),
+                occ(6, 8, Some(8), 10),
+                occ(7, 10, None, 10),
+            ],
+        );
+        assert_eq!(series.completed_count, 6);
+        assert_eq!(series.on_time_count, 5);
+        assert_eq!(series.current_streak, 2);
+        assert_eq!(series.longest_streak, 3);
+    }
+
+    #[test]
+    fn test_overdue_breaks_streak() {
+        let series = TaskSeries::new(1, vec![occ(1, 1, Some(1), 10), occ(2, 2, None, 10)]);
+        assert_eq!(series.current_streak, 0);
+        assert_eq!(series.longest_streak, 1);
+    }
+}
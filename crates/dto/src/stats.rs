@@ -0,0 +1,253 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Task counters, with "today" and "this week" (from Monday) in the user's
+/// timezone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub created_today: i64,
+    pub completed_today: i64,
+    pub created_week: i64,
+    pub completed_week: i64,
+    pub total_open: i64,
+    pub total_completed: i64,
+}
+
+/// Period the dashboard covers, ending today.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsRange {
+    Week,
+    #[default]
+    Month,
+    Quarter,
+    Year,
+}
+
+impl StatsRange {
+    pub const ALL: [StatsRange; 4] = [
+        StatsRange::Week,
+        StatsRange::Month,
+        StatsRange::Quarter,
+        StatsRange::Year,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatsRange::Week => "7 days",
+            StatsRange::Month => "30 days",
+            StatsRange::Quarter => "90 days",
+            StatsRange::Year => "12 months",
+        }
+    }
+
+    pub fn days(&self) -> i64 {
+        match self {
+            StatsRange::Week => 7,
+            StatsRange::Month => 30,
+            StatsRange::Quarter => 90,
+            StatsRange::Year => 365,
+        }
+    }
+
+    /// How the trend is grouped, so every range charts a readable number
+    /// of bars.
+    pub fn bucket(&self) -> StatsBucket {
+        match self {
+            StatsRange::Week | StatsRange::Month => StatsBucket::Day,
+            StatsRange::Quarter => StatsBucket::Week,
+            StatsRange::Year => StatsBucket::Month,
+        }
+    }
+
+    /// First day of the range ending on `today`, inclusive.
+    pub fn start(&self, today: NaiveDate) -> NaiveDate {
+        today - Duration::days(self.days() - 1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsBucket {
+    Day,
+    /// Weeks start on Monday.
+    Week,
+    Month,
+}
+
+impl StatsBucket {
+    /// Unit name as understood by Postgres' `date_trunc`.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            StatsBucket::Day => "day",
+            StatsBucket::Week => "week",
+            StatsBucket::Month => "month",
+        }
+    }
+
+    /// First day of the bucket `date` falls in.
+    pub fn truncate(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            StatsBucket::Day => date,
+            StatsBucket::Week => {
+                date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+            }
+            StatsBucket::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the bucket after the one starting on `start`.
+    pub fn next(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            StatsBucket::Day => start + Duration::days(1),
+            StatsBucket::Week => start + Duration::days(7),
+            StatsBucket::Month => start
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(start + Duration::days(31)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatsQuery {
+    #[serde(default)]
+    pub range: StatsRange,
+}
+
+/// Tasks created and completed in one trend bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendPoint {
+    /// First day of the bucket.
+    pub date: NaiveDate,
+    pub created: i64,
+    pub completed: i64,
+}
+
+/// How much of a project's or tag's work got done in the range: of the
+/// tasks open at some point in it, `completed` were completed within it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompletionRate {
+    pub id: i64,
+    pub name: String,
+    pub color: String,
+    pub completed: i64,
+    pub total: i64,
+}
+
+impl CompletionRate {
+    /// Whole percentage completed.
+    pub fn percent(&self) -> i32 {
+        if self.total == 0 {
+            return 0;
+        }
+        (self.completed * 100 / self.total) as i32
+    }
+}
+
+/// Open tasks overdue by `min_days` to `max_days` days (no upper bound
+/// when `None`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverdueBucket {
+    pub min_days: i32,
+    pub max_days: Option<i32>,
+    pub count: i64,
+}
+
+impl OverdueBucket {
+    pub fn label(&self) -> String {
+        match self.max_days {
+            Some(max) if max == self.min_days => {
+                format!("{max} day{}", if max == 1 { "" } else { "s" })
+            }
+            Some(max) => format!("{}–{max} days", self.min_days),
+            None => format!("{}+ days", self.min_days),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewBacklog {
+    /// Tasks the Review page lists as due.
+    pub tasks_due: i64,
+    /// Active projects due for a review.
+    pub projects_due: i64,
+    pub last_weekly_review: Option<DateTime<Utc>>,
+}
+
+/// How a recurring task kept up over the range, counting occurrences
+/// scheduled in it up to today.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HabitAdherence {
+    pub series_id: i64,
+    /// Title of the latest occurrence.
+    pub title: String,
+    pub on_time: i64,
+    pub late: i64,
+    pub missed: i64,
+}
+
+impl HabitAdherence {
+    /// Whole percentage of occurrences done on time.
+    pub fn percent(&self) -> i32 {
+        let total = self.on_time + self.late + self.missed;
+        if total == 0 {
+            return 0;
+        }
+        (self.on_time * 100 / total) as i32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dashboard {
+    pub range: StatsRange,
+    /// First day of the range in the user's timezone.
+    pub start: NaiveDate,
+    pub today: NaiveDate,
+    pub bucket: StatsBucket,
+    pub totals: Stats,
+    /// One point per bucket from `start` to `today`, oldest first.
+    pub trend: Vec<TrendPoint>,
+    pub projects: Vec<CompletionRate>,
+    pub tags: Vec<CompletionRate>,
+    pub overdue: Vec<OverdueBucket>,
+    pub review: ReviewBacklog,
+    pub habits: Vec<HabitAdherence>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_bucket_truncate() {
+        // 2026-10-21 is a Wednesday.
+        let wed = date(2026, 10, 21);
+        assert_eq!(StatsBucket::Day.truncate(wed), wed);
+        assert_eq!(StatsBucket::Week.truncate(wed), date(2026, 10, 19));
+        assert_eq!(StatsBucket::Month.truncate(wed), date(2026, 10, 1));
+        assert_eq!(StatsBucket::Month.next(date(2026, 1, 1)), date(2026, 2, 1));
+    }
+
+    #[test]
+    fn test_range_start() {
+        let today = date(2026, 10, 19);
+        assert_eq!(StatsRange::Week.start(today), date(2026, 10, 13));
+        assert_eq!(StatsRange::Month.start(today), date(2026, 9, 20));
+    }
+
+    #[test]
+    fn test_overdue_label() {
+        let bucket = |min_days, max_days| OverdueBucket {
+            min_days,
+            max_days,
+            count: 0,
+        };
+        assert_eq!(bucket(1, Some(1)).label(), "1 day");
+        assert_eq!(bucket(2, Some(7)).label(), "2–7 days");
+        assert_eq!(bucket(31, None).label(), "31+ days");
+    }
+}
@@ -12,16 +12,246 @@ pub struct Tag {
     pub user_id: i64,
     pub name: String,
     pub color: String,
+    /// Number of tasks carrying the tag; 0 for unused tags.
+    #[serde(default)]
+    pub task_count: i64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateTag {
     pub name: String,
     pub color: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateTag {
     pub name: Option<String>,
     pub color: Option<String>,
 }
+
+/// Moves every task from one tag onto `into_id` and deletes the source tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeTags {
+    pub into_id: i64,
+}
+
+/// Normalizes a tag name the way `#tag` tokens are parsed: a leading `#` is
+/// dropped and the name lowercased. Returns `None` if it contains characters
+/// a `#tag` token can't.
+pub fn normalize_tag_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    let name = name.strip_prefix('#').unwrap_or(name).to_lowercase();
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.'));
+    valid.then_some(name)
+}
+
+/// Separates namespace levels in tag names, e.g. `area:work`.
+pub const TAG_NAMESPACE_SEPARATOR: char = ':';
+
+/// The namespace a tag belongs to: everything before the last `:`.
+/// `area:work:client` → `area:work`; `urgent` → `None`.
+pub fn tag_namespace(name: &str) -> Option<&str> {
+    name.rfind(TAG_NAMESPACE_SEPARATOR)
+        .map(|pos| &name[..pos])
+        .filter(|ns| !ns.is_empty())
+}
+
+/// Whether `name` is `path` itself or lies anywhere beneath it.
+pub fn tag_in_namespace(name: &str, path: &str) -> bool {
+    name == path
+        || name
+            .strip_prefix(path)
+            .is_some_and(|rest| rest.starts_with(TAG_NAMESPACE_SEPARATOR))
+}
+
+/// Drops tags that violate an exclusive namespace: of the tags under each
+/// namespace in `exclusive`, only the last one is kept. Order is otherwise
+/// preserved, so callers put newly added tags last to have them win.
+pub fn enforce_exclusive_namespaces(names: &[String], exclusive: &[String]) -> Vec<String> {
+    let mut keep = vec![true; names.len()];
+    for ns in exclusive {
+        let mut members = names
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| *n != ns && tag_in_namespace(n, ns))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        members.pop();
+        for i in members {
+            keep[i] = false;
+        }
+    }
+    names
+        .iter()
+        .zip(keep)
+        .filter(|(_, k)| *k)
+        .map(|(n, _)| n.clone())
+        .collect()
+}
+
+/// A node of the tag tree. Namespaces that exist only as prefixes of other
+/// tags (e.g. `area` for `area:work`) have no `tag`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagNode {
+    /// Full name, e.g. `area:work`.
+    pub path: String,
+    /// Last segment, e.g. `work`.
+    pub label: String,
+    pub tag: Option<Tag>,
+    pub children: Vec<TagNode>,
+}
+
+impl TagNode {
+    /// Task links on this node and everything beneath it. A task tagged
+    /// with several tags in the namespace is counted once per tag.
+    pub fn total_count(&self) -> i64 {
+        self.tag.as_ref().map_or(0, |t| t.task_count)
+            + self.children.iter().map(TagNode::total_count).sum::<i64>()
+    }
+}
+
+/// Arranges tags into a tree by their `:`-separated namespaces, sorted by
+/// segment at every level.
+pub fn build_tag_tree(tags: &[Tag]) -> Vec<TagNode> {
+    let mut sorted: Vec<&Tag> = tags.iter().collect();
+    sorted.sort_by(|a, b| {
+        a.name
+            .split(TAG_NAMESPACE_SEPARATOR)
+            .cmp(b.name.split(TAG_NAMESPACE_SEPARATOR))
+    });
+
+    let mut roots: Vec<TagNode> = Vec::new();
+    for tag in sorted {
+        let mut level = &mut roots;
+        let mut path = String::new();
+        let segments: Vec<&str> = tag.name.split(TAG_NAMESPACE_SEPARATOR).collect();
+        for (i, segment) in segments.iter().enumerate() {
+            if !path.is_empty() {
+                path.push(TAG_NAMESPACE_SEPARATOR);
+            }
+            path.push_str(segment);
+            let pos = match level.iter().position(|n| n.path == path) {
+                Some(pos) => pos,
+                None => {
+                    level.push(TagNode {
+                        path: path.clone(),
+                        label: segment.to_string(),
+                        tag: None,
+                        children: Vec::new(),
+                    });
+                    level.len() - 1
+                }
+            };
+            if i == segments.len() - 1 {
+                level[pos].tag = Some(tag.clone());
+            }
+            level = &mut level[pos].children;
+        }
+    }
+    roots
+}
+
+/// Whether `color` is a `#rrggbb` hex color.
+pub fn is_hex_color(color: &str) -> bool {
+    color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tag_name() {
+        assert_eq!(normalize_tag_name("Work"), Some("work".into()));
+        assert_eq!(
+            normalize_tag_name(" #home:chores "),
+            Some("home:chores".into())
+        );
+        assert_eq!(
+            normalize_tag_name("v2.0_final-x"),
+            Some("v2.0_final-x".into())
+        );
+        assert_eq!(normalize_tag_name(""), None);
+        assert_eq!(normalize_tag_name("#"), None);
+        assert_eq!(normalize_tag_name("two words"), None);
+        assert_eq!(normalize_tag_name("a/b"), None);
+    }
+
+    fn tag(id: i64, name: &str, task_count: i64) -> Tag {
+        Tag {
+            id,
+            user_id: 1,
+            name: name.into(),
+            color: crate::DEFAULT_COLOR.into(),
+            task_count,
+        }
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_tag_namespace() {
+        assert_eq!(tag_namespace("urgent"), None);
+        assert_eq!(tag_namespace("area:work"), Some("area"));
+        assert_eq!(tag_namespace("area:work:client"), Some("area:work"));
+        assert_eq!(tag_namespace(":odd"), None);
+        assert!(tag_in_namespace("area:work", "area"));
+        assert!(tag_in_namespace("area", "area"));
+        assert!(!tag_in_namespace("areas:x", "area"));
+    }
+
+    #[test]
+    fn test_enforce_exclusive_namespaces() {
+        let exclusive = names(&["energy"]);
+        assert_eq!(
+            enforce_exclusive_namespaces(
+                &names(&["energy:low", "urgent", "energy:high"]),
+                &exclusive
+            ),
+            names(&["urgent", "energy:high"])
+        );
+        assert_eq!(
+            enforce_exclusive_namespaces(&names(&["energy", "energy:low"]), &exclusive),
+            names(&["energy", "energy:low"])
+        );
+        assert_eq!(
+            enforce_exclusive_namespaces(&names(&["energy:low", "ctx:a", "ctx:b"]), &exclusive),
+            names(&["energy:low", "ctx:a", "ctx:b"])
+        );
+    }
+
+    #[test]
+    fn test_build_tag_tree() {
+        let tree = build_tag_tree(&[
+            tag(1, "urgent", 2),
+            tag(2, "area:work", 3),
+            tag(3, "area:home", 1),
+            tag(4, "area:work:client", 4),
+        ]);
+        assert_eq!(tree.len(), 2);
+        let area = &tree[0];
+        assert_eq!(area.path, "area");
+        assert!(area.tag.is_none());
+        assert_eq!(area.total_count(), 8);
+        let labels: Vec<&str> = area.children.iter().map(|n| n.label.as_str()).collect();
+        assert_eq!(labels, ["home", "work"]);
+        let work = &area.children[1];
+        assert_eq!(work.tag.as_ref().map(|t| t.id), Some(2));
+        assert_eq!(work.children[0].path, "area:work:client");
+        assert_eq!(tree[1].path, "urgent");
+    }
+
+    #[test]
+    fn test_is_hex_color() {
+        assert!(is_hex_color("#6b7280"));
+        assert!(is_hex_color("#ABCDEF"));
+        assert!(!is_hex_color("6b7280"));
+        assert!(!is_hex_color("#6b728"));
+        assert!(!is_hex_color("#zzzzzz"));
+    }
+}
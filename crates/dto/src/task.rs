@@ -21,11 +21,33 @@ pub struct Task {
     pub updated_at: DateTime<Utc>,
     pub recurrence_type: Option<RecurrenceType>,
     pub recurrence_rule: Option<String>,
+    /// Dates excluded from the recurrence (EXDATE), kept apart from the
+    /// RRULE in `recurrence_rule`.
+    #[serde(default)]
+    pub recurrence_exdates: Vec<NaiveDate>,
     #[serde(default)]
     pub is_url_fetching: Option<DateTime<Utc>>,
     #[serde(default)]
     pub someday: bool,
     #[serde(default)]
+    pub series_id: Option<i64>,
+    #[serde(default)]
+    pub column_id: Option<i64>,
+    /// Who the task is delegated to; set while it is waiting.
+    #[serde(default)]
+    pub waiting_for: Option<String>,
+    /// When to chase up a waiting task.
+    #[serde(default)]
+    pub follow_up_date: Option<NaiveDate>,
+    /// 1 (highest) to 4; `None` for no priority.
+    #[serde(default)]
+    pub priority: Option<i16>,
+    #[serde(default)]
+    pub estimate_minutes: Option<i32>,
+    /// Section of the project's list view; only set on top-level tasks.
+    #[serde(default)]
+    pub section_id: Option<i64>,
+    #[serde(default)]
     pub project_title: Option<String>,
     #[serde(default)]
     pub tags: Vec<crate::TagInfo>,
@@ -33,6 +55,35 @@ pub struct Task {
     pub subtask_count: i64,
     #[serde(default)]
     pub completed_subtask_count: i64,
+    /// Live tasks that must be completed before this one, in any project.
+    #[serde(default)]
+    pub blocked_by: Vec<i64>,
+    /// Finished tracked time on this task and all its subtasks.
+    #[serde(default)]
+    pub spent_minutes: i64,
+    /// Start of the running timer, if it is on this task.
+    #[serde(default)]
+    pub tracking_since: Option<DateTime<Utc>>,
+}
+
+/// Valid task priorities, 1 being the highest.
+pub const PRIORITY_LEVELS: std::ops::RangeInclusive<i16> = 1..=4;
+
+/// Whether an open top-level task belongs on Today: it starts before
+/// `until`, or it is waiting and its follow-up date has come by `today`.
+/// Shared by the Today page and the digest so the two stay in step.
+pub fn is_on_today(
+    start_at: Option<DateTime<Utc>>,
+    waiting: bool,
+    follow_up_date: Option<NaiveDate>,
+    until: DateTime<Utc>,
+    today: NaiveDate,
+) -> bool {
+    if waiting {
+        follow_up_date.is_some_and(|d| d <= today)
+    } else {
+        start_at.is_some_and(|dt| dt < until)
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -45,6 +96,12 @@ pub struct CreateTask {
     pub start_at: Option<DateTime<Utc>>,
     pub due_date: Option<NaiveDate>,
     pub reviewed_at: Option<NaiveDate>,
+    #[serde(default)]
+    pub priority: Option<i16>,
+    #[serde(default)]
+    pub estimate_minutes: Option<i32>,
+    #[serde(default)]
+    pub section_id: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -117,6 +174,8 @@ pub struct UpdateTask {
     )]
     pub recurrence_rule: Option<Option<String>>,
 
+    pub recurrence_exdates: Option<Vec<NaiveDate>>,
+
     #[serde(
         default,
         skip_serializing_if = "crate::serde_helpers::is_none_outer",
@@ -125,6 +184,95 @@ pub struct UpdateTask {
     pub is_url_fetching: Option<Option<DateTime<Utc>>>,
 
     pub someday: Option<bool>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub column_id: Option<Option<i64>>,
+
+    /// Clearing it also clears `follow_up_date`.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub waiting_for: Option<Option<String>>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub follow_up_date: Option<Option<NaiveDate>>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub priority: Option<Option<i16>>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub estimate_minutes: Option<Option<i32>>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub section_id: Option<Option<i64>>,
+}
+
+/// Body of `POST /api/tasks/:id/move`: puts a task on a board column
+/// (`None` for the project's first column) at the given position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveTask {
+    pub column_id: Option<i64>,
+    pub sort_key: Option<String>,
+}
+
+/// Body of `POST /api/tasks/:id/postpone`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostponeTask {
+    pub date: NaiveDate,
+}
+
+/// Body of `POST /api/tasks/:id/blockers`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddBlocker {
+    pub blocker_id: i64,
+}
+
+/// A change applied to every task of a bulk operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum BulkTaskAction {
+    Complete,
+    Uncomplete,
+    SetProject(Option<i64>),
+    AddTags(Vec<String>),
+    RemoveTags(Vec<String>),
+    SetStartAt(Option<DateTime<Utc>>),
+    SetDueDate(Option<NaiveDate>),
+    MarkReviewed,
+    SetSomeday(bool),
+    SetPriority(Option<i16>),
+    /// Moves the tasks to the trash.
+    Delete,
+}
+
+/// Body of `POST /api/tasks/bulk`: the actions are applied in order to
+/// all tasks, in one transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkTaskUpdate {
+    pub ids: Vec<i64>,
+    pub actions: Vec<BulkTaskAction>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -137,7 +285,29 @@ pub struct TaskFilter {
     pub completed: Option<bool>,
     pub q: Option<String>,
     pub someday: Option<bool>,
+    pub waiting: Option<bool>,
     pub sort: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_is_on_today() {
+        let until = Utc.with_ymd_and_hms(2026, 3, 11, 0, 0, 0).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let started = Some(until - chrono::Duration::hours(1));
+
+        assert!(is_on_today(started, false, None, until, today));
+        assert!(!is_on_today(Some(until), false, None, until, today));
+        assert!(!is_on_today(None, false, None, until, today));
+        // Waiting tasks wait for their follow-up, whatever their start.
+        assert!(!is_on_today(started, true, None, until, today));
+        assert!(!is_on_today(started, true, today.succ_opt(), until, today));
+        assert!(is_on_today(None, true, Some(today), until, today));
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Markdown note in a task's comment thread.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskComment {
+    pub id: i64,
+    pub task_id: i64,
+    pub user_id: i64,
+    pub user_name: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    /// Last time the author changed the text; `None` if never edited.
+    pub edited_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaskComment {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaskComment {
+    pub body: String,
+}
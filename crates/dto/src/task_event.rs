@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskEventKind {
+    Created,
+    /// A plain field edit; `field` names it.
+    Updated,
+    Completed,
+    Uncompleted,
+    Reviewed,
+    /// Project, parent, Kanban column or list section changed; `field`
+    /// says which.
+    Moved,
+    /// Values are space-separated tag names, sorted.
+    TagsChanged,
+}
+
+/// One entry of a task's activity log. Values are stored as display text:
+/// dates as `YYYY-MM-DD`, projects, parents, columns and sections by title.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskEvent {
+    pub id: i64,
+    pub task_id: i64,
+    pub user_id: i64,
+    pub user_name: String,
+    pub kind: TaskEventKind,
+    pub field: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskEvent {
+    /// Human-readable summary, e.g. "Changed due date from 2026-10-01 to
+    /// 2026-10-03".
+    pub fn describe(&self) -> String {
+        let old = self.old_value.as_deref();
+        let new = self.new_value.as_deref();
+        match self.kind {
+            TaskEventKind::Created => "Created the task".into(),
+            TaskEventKind::Completed => "Completed the task".into(),
+            TaskEventKind::Uncompleted => "Reopened the task".into(),
+            TaskEventKind::Reviewed => "Reviewed the task".into(),
+            TaskEventKind::TagsChanged => describe_tags(old, new),
+            TaskEventKind::Moved => {
+                let place = |v: Option<&str>| match (self.field.as_deref(), v) {
+                    (Some("parent"), None) => "top level".to_string(),
+                    (Some("parent"), Some(t)) => format!("under \"{t}\""),
+                    (Some("column"), None) => "no column".to_string(),
+                    (Some("section"), None) => "no section".to_string(),
+                    (_, None) => "Inbox".to_string(),
+                    (_, Some(t)) => t.to_string(),
+                };
+                format!("Moved from {} to {}", place(old), place(new))
+            }
+            TaskEventKind::Updated if self.field.as_deref() == Some("body") => {
+                "Edited the description".into()
+            }
+            TaskEventKind::Updated if self.field.as_deref() == Some("someday") => {
+                if new == Some("true") {
+                    "Deferred to Someday".into()
+                } else {
+                    "Brought back from Someday".into()
+                }
+            }
+            TaskEventKind::Updated if self.field.as_deref() == Some("blocker") => {
+                match (old, new) {
+                    (_, Some(t)) => format!("Added blocker \"{t}\""),
+                    (Some(t), None) => format!("Removed blocker \"{t}\""),
+                    (None, None) => "Changed blockers".into(),
+                }
+            }
+            TaskEventKind::Updated if self.field.as_deref() == Some("waiting_for") => {
+                match (old, new) {
+                    (Some(o), Some(n)) => format!("Now waiting on {n} (was {o})"),
+                    (_, Some(n)) => format!("Waiting on {n}"),
+                    (Some(o), None) => format!("No longer waiting on {o}"),
+                    (None, None) => "Changed waiting for".into(),
+                }
+            }
+            TaskEventKind::Updated => {
+                let field = self.field.as_deref().map(field_label).unwrap_or("task");
+                match (old, new) {
+                    (None, Some(n)) => format!("Set {field} to {n}"),
+                    (Some(o), None) => format!("Cleared {field} (was {o})"),
+                    (Some(o), Some(n)) => format!("Changed {field} from {o} to {n}"),
+                    (None, None) => format!("Changed {field}"),
+                }
+            }
+        }
+    }
+}
+
+fn field_label(field: &str) -> &str {
+    match field {
+        "due_date" => "due date",
+        "start_at" => "start",
+        "sequential_limit" => "sequential limit",
+        "waiting_for" => "waiting for",
+        "follow_up_date" => "follow-up date",
+        "estimate_minutes" => "estimate",
+        "recurrence" => "recurrence",
+        "recurrence_exdates" => "exception dates",
+        other => other,
+    }
+}
+
+fn describe_tags(old: Option<&str>, new: Option<&str>) -> String {
+    let old: Vec<&str> = old.unwrap_or_default().split_whitespace().collect();
+    let new: Vec<&str> = new.unwrap_or_default().split_whitespace().collect();
+    let hashed = |tags: Vec<&&str>| {
+        tags.iter()
+            .map(|t| format!("#{t}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let added: Vec<&&str> = new.iter().filter(|t| !old.contains(t)).collect();
+    let removed: Vec<&&str> = old.iter().filter(|t| !new.contains(t)).collect();
+    match (added.is_empty(), removed.is_empty()) {
+        (false, true) => format!("Added {}", hashed(added)),
+        (true, false) => format!("Removed {}", hashed(removed)),
+        (false, false) => format!("Added {}; removed {}", hashed(added), hashed(removed)),
+        (true, true) => "Changed tags".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(
+        kind: TaskEventKind,
+        field: Option<&str>,
+        old: Option<&str>,
+        new: Option<&str>,
+    ) -> TaskEvent {
+        TaskEvent {
+            id: 1,
+            task_id: 1,
+            user_id: 1,
+            user_name: "Ann".into(),
+            kind,
+            field: field.map(str::to_string),
+            old_value: old.map(str::to_string),
+            new_value: new.map(str::to_string),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        use TaskEventKind::*;
+        let cases = [
+            (event(Created, None, None, None), "Created the task"),
+            (
+                event(
+                    Updated,
+                    Some("due_date"),
+                    Some("2026-10-01"),
+                    Some("2026-10-03"),
+                ),
+                "Changed due date from 2026-10-01 to 2026-10-03",
+            ),
+            (
+                event(Updated, Some("due_date"), None, Some("2026-10-03")),
+                "Set due date to 2026-10-03",
+            ),
+            (
+                event(Updated, Some("body"), None, None),
+                "Edited the description",
+            ),
+            (
+                event(Moved, Some("project"), Some("Work"), None),
+                "Moved from Work to Inbox",
+            ),
+            (
+                event(Moved, Some("parent"), None, Some("Plan")),
+                "Moved from top level to under \"Plan\"",
+            ),
+            (
+                event(TagsChanged, None, Some("a b"), Some("b c")),
+                "Added #c; removed #a",
+            ),
+            (event(TagsChanged, None, None, Some("x")), "Added #x"),
+            (
+                event(Updated, Some("blocker"), None, Some("Deploy")),
+                "Added blocker \"Deploy\"",
+            ),
+            (
+                event(Updated, Some("blocker"), Some("Deploy"), None),
+                "Removed blocker \"Deploy\"",
+            ),
+            (
+                event(Updated, Some("waiting_for"), None, Some("Sam")),
+                "Waiting on Sam",
+            ),
+            (
+                event(Updated, Some("waiting_for"), Some("Sam"), None),
+                "No longer waiting on Sam",
+            ),
+            (
+                event(Updated, Some("follow_up_date"), None, Some("2026-10-20")),
+                "Set follow-up date to 2026-10-20",
+            ),
+            (
+                event(Updated, Some("estimate_minutes"), Some("30m"), Some("1h")),
+                "Changed estimate from 30m to 1h",
+            ),
+            (
+                event(Moved, Some("section"), None, Some("Design")),
+                "Moved from no section to Design",
+            ),
+        ];
+        for (event, expected) in cases {
+            assert_eq!(event.describe(), expected);
+        }
+    }
+}
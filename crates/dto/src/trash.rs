@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashItemKind {
+    Task,
+    Project,
+}
+
+/// Something the user deleted, together with whatever was trashed with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashItem {
+    pub kind: TrashItemKind,
+    pub id: i64,
+    pub title: String,
+    /// Project a trashed task belonged to.
+    pub project_title: Option<String>,
+    /// Subtasks of a task, or tasks of a project, trashed along with it.
+    pub contained_count: i64,
+    pub deleted_at: DateTime<Utc>,
+}
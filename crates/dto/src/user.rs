@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::ReviewStepKind;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum UserRole {
@@ -18,6 +20,64 @@ pub struct UserSettings {
     pub timezone: String,
     #[serde(default)]
     pub sidebar_collapsed: bool,
+    #[serde(default)]
+    pub reminder_email: bool,
+    #[serde(default)]
+    pub reminder_push: bool,
+    #[serde(default)]
+    pub reminder_webhook_url: Option<String>,
+    #[serde(default)]
+    pub digest_frequency: DigestFrequency,
+    /// Local hour (0-23) the digest is sent at.
+    #[serde(default = "default_digest_hour")]
+    pub digest_hour: u8,
+    /// Saved filter whose results are appended to the digest.
+    #[serde(default)]
+    pub digest_filter_id: Option<i64>,
+    /// Tag namespaces (e.g. `energy`) a task may carry at most one tag from.
+    #[serde(default)]
+    pub exclusive_tag_namespaces: Vec<String>,
+    /// Days trashed items are kept before being purged; 0 keeps them forever.
+    #[serde(default = "default_trash_retention")]
+    pub trash_retention_days: i16,
+    /// Hours a day the user can spend on tasks; Today compares estimates
+    /// against it.
+    #[serde(default = "default_available_hours")]
+    pub available_hours: f32,
+    /// Built-in steps of the weekly review, in order.
+    #[serde(default = "default_review_steps")]
+    pub review_steps: Vec<ReviewStepKind>,
+    /// Custom items checked off at the end of the weekly review.
+    #[serde(default)]
+    pub review_checklist: Vec<String>,
+}
+
+/// How often the summary email is sent. Weekly digests go out on Mondays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    #[default]
+    Off,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigestFrequency::Off => "off",
+            DigestFrequency::Daily => "daily",
+            DigestFrequency::Weekly => "weekly",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "daily" => DigestFrequency::Daily,
+            "weekly" => DigestFrequency::Weekly,
+            _ => DigestFrequency::Off,
+        }
+    }
 }
 
 impl Default for UserSettings {
@@ -27,6 +87,17 @@ impl Default for UserSettings {
             default_sequential_limit: default_sequential_limit(),
             timezone: default_timezone(),
             sidebar_collapsed: false,
+            reminder_email: false,
+            reminder_push: false,
+            reminder_webhook_url: None,
+            digest_frequency: DigestFrequency::Off,
+            digest_hour: default_digest_hour(),
+            digest_filter_id: None,
+            exclusive_tag_namespaces: Vec::new(),
+            trash_retention_days: default_trash_retention(),
+            available_hours: default_available_hours(),
+            review_steps: default_review_steps(),
+            review_checklist: Vec::new(),
         }
     }
 }
@@ -39,6 +110,22 @@ fn default_sequential_limit() -> i16 {
     1
 }
 
+fn default_digest_hour() -> u8 {
+    7
+}
+
+fn default_trash_retention() -> i16 {
+    30
+}
+
+fn default_available_hours() -> f32 {
+    8.0
+}
+
+fn default_review_steps() -> Vec<ReviewStepKind> {
+    ReviewStepKind::BUILT_IN.to_vec()
+}
+
 fn default_timezone() -> String {
     "UTC".to_string()
 }
@@ -74,6 +161,22 @@ pub struct UpdateSettings {
     pub default_sequential_limit: Option<i16>,
     pub timezone: Option<String>,
     pub sidebar_collapsed: Option<bool>,
+    pub reminder_email: Option<bool>,
+    pub reminder_push: Option<bool>,
+    pub reminder_webhook_url: Option<String>,
+    pub digest_frequency: Option<DigestFrequency>,
+    pub digest_hour: Option<u8>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde_helpers::is_none_outer",
+        with = "crate::serde_helpers::double_option"
+    )]
+    pub digest_filter_id: Option<Option<i64>>,
+    pub exclusive_tag_namespaces: Option<Vec<String>>,
+    pub trash_retention_days: Option<i16>,
+    pub available_hours: Option<f32>,
+    pub review_steps: Option<Vec<ReviewStepKind>>,
+    pub review_checklist: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
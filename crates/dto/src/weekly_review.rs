@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What a weekly review step asks the user to go through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStepKind {
+    Inbox,
+    Today,
+    Projects,
+    Someday,
+    Waiting,
+    /// A custom checklist item; its text is the step's title.
+    Checklist,
+}
+
+impl ReviewStepKind {
+    /// The built-in steps, in their default order.
+    pub const BUILT_IN: [ReviewStepKind; 5] = [
+        ReviewStepKind::Inbox,
+        ReviewStepKind::Today,
+        ReviewStepKind::Projects,
+        ReviewStepKind::Someday,
+        ReviewStepKind::Waiting,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ReviewStepKind::Inbox => "Empty the inbox",
+            ReviewStepKind::Today => "Review today and overdue",
+            ReviewStepKind::Projects => "Review projects",
+            ReviewStepKind::Someday => "Review someday",
+            ReviewStepKind::Waiting => "Review waiting for",
+            ReviewStepKind::Checklist => "Checklist",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewStep {
+    pub kind: ReviewStepKind,
+    pub title: String,
+    #[serde(default)]
+    pub done: bool,
+}
+
+/// One run through the review steps. The steps are copied from the user's
+/// settings when the review starts, so changing them doesn't affect a
+/// review in progress or the history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeeklyReview {
+    pub id: i64,
+    pub steps: Vec<ReviewStep>,
+    /// Index into `steps` the user is on.
+    pub current_step: i32,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl WeeklyReview {
+    pub fn done_count(&self) -> usize {
+        self.steps.iter().filter(|s| s.done).count()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateWeeklyReview {
+    pub current_step: Option<i32>,
+    /// Done flag of every step, in order.
+    pub steps_done: Option<Vec<bool>>,
+}
@@ -0,0 +1,24 @@
+use leptos::prelude::ServerFnError;
+use north_dto::Attachment;
+
+use crate::notify_on_error;
+
+pub struct AttachmentRepository;
+
+impl AttachmentRepository {
+    pub async fn list(task_id: i64) -> Result<Vec<Attachment>, ServerFnError> {
+        notify_on_error(north_server_fns::attachments::list_attachments(task_id).await)
+    }
+
+    pub async fn upload(task_id: i64, file: web_sys::File) -> Result<Attachment, ServerFnError> {
+        let form = web_sys::FormData::new()
+            .map_err(|_| ServerFnError::new("Could not build upload form"))?;
+        let _ = form.append_with_str("task_id", &task_id.to_string());
+        let _ = form.append_with_blob_and_filename("file", &file, &file.name());
+        notify_on_error(north_server_fns::attachments::upload_attachment(form.into()).await)
+    }
+
+    pub async fn delete(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::attachments::delete_attachment(id).await)
+    }
+}
@@ -0,0 +1,26 @@
+use leptos::prelude::ServerFnError;
+use north_dto::{CreateTaskComment, TaskComment, UpdateTaskComment};
+
+use crate::notify_on_error;
+
+pub struct CommentRepository;
+
+impl CommentRepository {
+    pub async fn list(task_id: i64) -> Result<Vec<TaskComment>, ServerFnError> {
+        notify_on_error(north_server_fns::comments::list_comments(task_id).await)
+    }
+
+    pub async fn create(task_id: i64, body: String) -> Result<TaskComment, ServerFnError> {
+        let input = CreateTaskComment { body };
+        notify_on_error(north_server_fns::comments::create_comment(task_id, input).await)
+    }
+
+    pub async fn update(id: i64, body: String) -> Result<TaskComment, ServerFnError> {
+        let input = UpdateTaskComment { body };
+        notify_on_error(north_server_fns::comments::update_comment(id, input).await)
+    }
+
+    pub async fn delete(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::comments::delete_comment(id).await)
+    }
+}
@@ -1,16 +1,30 @@
+pub mod attachment_repo;
+pub mod comment_repo;
 pub mod filter_repo;
 pub mod models;
 pub mod project_repo;
+pub mod reminder_repo;
+pub mod search_repo;
 pub mod settings_repo;
+pub mod stats_repo;
 pub mod tag_repo;
 pub mod task_repo;
+pub mod trash_repo;
+pub mod weekly_review_repo;
 
+pub use attachment_repo::AttachmentRepository;
+pub use comment_repo::CommentRepository;
 pub use filter_repo::FilterRepository;
 pub use models::{Recurrence, TaskModel};
 pub use project_repo::ProjectRepository;
+pub use reminder_repo::ReminderRepository;
+pub use search_repo::SearchRepository;
 pub use settings_repo::SettingsRepository;
+pub use stats_repo::StatsRepository;
 pub use tag_repo::TagRepository;
 pub use task_repo::TaskRepository;
+pub use trash_repo::TrashRepository;
+pub use weekly_review_repo::WeeklyReviewRepository;
 
 use leptos::prelude::*;
 
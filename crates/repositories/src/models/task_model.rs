@@ -6,11 +6,12 @@ use north_dto::{TagInfo, Task};
 pub struct Recurrence {
     pub recurrence_type: RecurrenceType,
     pub rule: RecurrenceRule,
+    pub exdates: Vec<NaiveDate>,
 }
 
 impl Recurrence {
     pub fn summarize(&self) -> String {
-        self.rule.summarize()
+        self.rule.summarize_with_exceptions(self.exdates.len())
     }
 
     pub fn rule_string(&self) -> String {
@@ -42,14 +43,25 @@ pub struct TaskModel {
     pub recurrence: Option<Recurrence>,
     pub is_url_fetching: Option<DateTime<Utc>>,
     pub someday: bool,
+    pub series_id: Option<i64>,
+    pub column_id: Option<i64>,
+    pub waiting_for: Option<String>,
+    pub follow_up_date: Option<NaiveDate>,
+    pub priority: Option<i16>,
+    pub estimate_minutes: Option<i32>,
+    pub section_id: Option<i64>,
     pub project_title: Option<String>,
     pub tags: Vec<TagInfo>,
     pub subtask_count: i64,
     pub completed_subtask_count: i64,
+    pub blocked_by: Vec<i64>,
+    pub spent_minutes: i64,
+    pub tracking_since: Option<DateTime<Utc>>,
 }
 
 impl From<Task> for TaskModel {
     fn from(t: Task) -> Self {
+        let exdates = t.recurrence_exdates;
         let recurrence = t.recurrence_type.and_then(|rt| {
             t.recurrence_rule
                 .as_deref()
@@ -57,6 +69,7 @@ impl From<Task> for TaskModel {
                 .map(|rule| Recurrence {
                     recurrence_type: rt,
                     rule,
+                    exdates,
                 })
         });
 
@@ -78,10 +91,20 @@ impl From<Task> for TaskModel {
             recurrence,
             is_url_fetching: t.is_url_fetching,
             someday: t.someday,
+            series_id: t.series_id,
+            column_id: t.column_id,
+            waiting_for: t.waiting_for,
+            follow_up_date: t.follow_up_date,
+            priority: t.priority,
+            estimate_minutes: t.estimate_minutes,
+            section_id: t.section_id,
             project_title: t.project_title,
             tags: t.tags,
             subtask_count: t.subtask_count,
             completed_subtask_count: t.completed_subtask_count,
+            blocked_by: t.blocked_by,
+            spent_minutes: t.spent_minutes,
+            tracking_since: t.tracking_since,
         }
     }
 }
@@ -108,12 +131,23 @@ mod tests {
             updated_at: Utc::now(),
             recurrence_type: None,
             recurrence_rule: None,
+            recurrence_exdates: vec![],
             is_url_fetching: None,
             someday: false,
+            series_id: None,
+            column_id: None,
+            waiting_for: None,
+            follow_up_date: None,
+            priority: None,
+            estimate_minutes: None,
+            section_id: None,
             project_title: None,
             tags: vec![],
             subtask_count: 0,
             completed_subtask_count: 0,
+            blocked_by: vec![],
+            spent_minutes: 0,
+            tracking_since: None,
         }
     }
 
@@ -141,6 +175,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn exdates_join_the_recurrence() {
+        let mut dto = make_dto_task();
+        dto.recurrence_type = Some(RecurrenceType::Scheduled);
+        dto.recurrence_rule = Some("FREQ=DAILY;INTERVAL=1;BYHOUR=9;BYMINUTE=0".to_string());
+        dto.recurrence_exdates = vec![NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()];
+
+        let rec = TaskModel::from(dto)
+            .recurrence
+            .expect("should have recurrence");
+        assert_eq!(rec.exdates.len(), 1);
+        assert_eq!(rec.summarize(), "Every day at 9 AM, except 1 date");
+        assert!(!rec.rule_string().contains("EXDATE"));
+    }
+
     #[test]
     fn type_present_but_rule_missing() {
         let mut dto = make_dto_task();
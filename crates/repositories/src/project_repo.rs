@@ -1,5 +1,9 @@
 use leptos::prelude::ServerFnError;
-use north_dto::{CreateProject, Project, ProjectFilter, UpdateProject};
+use north_dto::{
+    Area, CreateArea, CreateProject, CreateProjectColumn, CreateProjectSection, MoveProject,
+    Project, ProjectColumn, ProjectFilter, ProjectSection, UpdateArea, UpdateProject,
+    UpdateProjectColumn, UpdateProjectSection,
+};
 
 use crate::notify_on_error;
 
@@ -25,4 +29,89 @@ impl ProjectRepository {
     pub async fn delete(id: i64) -> Result<(), ServerFnError> {
         notify_on_error(north_server_fns::projects::delete_project(id).await)
     }
+
+    pub async fn move_to(
+        id: i64,
+        area_id: Option<i64>,
+        before_id: Option<i64>,
+    ) -> Result<Project, ServerFnError> {
+        notify_on_error(
+            north_server_fns::projects::move_project(id, MoveProject { area_id, before_id }).await,
+        )
+    }
+
+    pub async fn list_columns(project_id: i64) -> Result<Vec<ProjectColumn>, ServerFnError> {
+        notify_on_error(north_server_fns::projects::list_project_columns(project_id).await)
+    }
+
+    pub async fn create_column(
+        project_id: i64,
+        title: String,
+    ) -> Result<ProjectColumn, ServerFnError> {
+        notify_on_error(
+            north_server_fns::projects::create_project_column(
+                project_id,
+                CreateProjectColumn { title },
+            )
+            .await,
+        )
+    }
+
+    pub async fn update_column(
+        id: i64,
+        input: UpdateProjectColumn,
+    ) -> Result<ProjectColumn, ServerFnError> {
+        notify_on_error(north_server_fns::projects::update_project_column(id, input).await)
+    }
+
+    pub async fn delete_column(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::projects::delete_project_column(id).await)
+    }
+
+    pub async fn list_sections(project_id: i64) -> Result<Vec<ProjectSection>, ServerFnError> {
+        notify_on_error(north_server_fns::projects::list_project_sections(project_id).await)
+    }
+
+    pub async fn create_section(
+        project_id: i64,
+        title: String,
+    ) -> Result<ProjectSection, ServerFnError> {
+        notify_on_error(
+            north_server_fns::projects::create_project_section(
+                project_id,
+                CreateProjectSection {
+                    title,
+                    sort_key: None,
+                },
+            )
+            .await,
+        )
+    }
+
+    pub async fn update_section(
+        id: i64,
+        input: UpdateProjectSection,
+    ) -> Result<ProjectSection, ServerFnError> {
+        notify_on_error(north_server_fns::projects::update_project_section(id, input).await)
+    }
+
+    pub async fn delete_section(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::projects::delete_project_section(id).await)
+    }
+
+    pub async fn list_areas() -> Result<Vec<Area>, ServerFnError> {
+        notify_on_error(north_server_fns::projects::list_areas().await)
+    }
+
+    pub async fn create_area(title: String) -> Result<Area, ServerFnError> {
+        notify_on_error(north_server_fns::projects::create_area(CreateArea { title }).await)
+    }
+
+    pub async fn update_area(id: i64, input: UpdateArea) -> Result<Area, ServerFnError> {
+        notify_on_error(north_server_fns::projects::update_area(id, input).await)
+    }
+
+    pub async fn delete_area(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::projects::delete_area(id).await)
+    }
 }
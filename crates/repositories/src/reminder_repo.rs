@@ -0,0 +1,28 @@
+use leptos::prelude::ServerFnError;
+use north_dto::{CreateReminder, PushSubscriptionInput, Reminder};
+
+use crate::notify_on_error;
+
+pub struct ReminderRepository;
+
+impl ReminderRepository {
+    pub async fn list(task_id: i64) -> Result<Vec<Reminder>, ServerFnError> {
+        notify_on_error(north_server_fns::reminders::list_reminders(task_id).await)
+    }
+
+    pub async fn create(task_id: i64, input: CreateReminder) -> Result<Reminder, ServerFnError> {
+        notify_on_error(north_server_fns::reminders::create_reminder(task_id, input).await)
+    }
+
+    pub async fn delete(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::reminders::delete_reminder(id).await)
+    }
+
+    pub async fn push_public_key() -> Result<Option<String>, ServerFnError> {
+        notify_on_error(north_server_fns::reminders::get_push_public_key().await)
+    }
+
+    pub async fn save_push_subscription(input: PushSubscriptionInput) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::reminders::save_push_subscription(input).await)
+    }
+}
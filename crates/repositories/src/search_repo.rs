@@ -0,0 +1,12 @@
+use leptos::prelude::ServerFnError;
+use north_dto::SearchResult;
+
+use crate::notify_on_error;
+
+pub struct SearchRepository;
+
+impl SearchRepository {
+    pub async fn search(query: String) -> Result<Vec<SearchResult>, ServerFnError> {
+        notify_on_error(north_server_fns::search::search(query).await)
+    }
+}
@@ -0,0 +1,12 @@
+use leptos::prelude::ServerFnError;
+use north_dto::{Dashboard, StatsRange};
+
+use crate::notify_on_error;
+
+pub struct StatsRepository;
+
+impl StatsRepository {
+    pub async fn dashboard(range: StatsRange) -> Result<Dashboard, ServerFnError> {
+        notify_on_error(north_server_fns::stats::get_dashboard(range).await)
+    }
+}
@@ -1,5 +1,5 @@
 use leptos::prelude::ServerFnError;
-use north_dto::Tag;
+use north_dto::{CreateTag, Tag, UpdateTag};
 
 use crate::notify_on_error;
 
@@ -9,4 +9,24 @@ impl TagRepository {
     pub async fn list() -> Result<Vec<Tag>, ServerFnError> {
         notify_on_error(north_server_fns::tags::list_tags().await)
     }
+
+    pub async fn create(input: CreateTag) -> Result<Tag, ServerFnError> {
+        notify_on_error(north_server_fns::tags::create_tag(input).await)
+    }
+
+    pub async fn update(id: i64, input: UpdateTag) -> Result<Tag, ServerFnError> {
+        notify_on_error(north_server_fns::tags::update_tag(id, input).await)
+    }
+
+    pub async fn delete(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::tags::delete_tag(id).await)
+    }
+
+    pub async fn merge(source_id: i64, into_id: i64) -> Result<Tag, ServerFnError> {
+        notify_on_error(north_server_fns::tags::merge_tags(source_id, into_id).await)
+    }
+
+    pub async fn delete_unused() -> Result<usize, ServerFnError> {
+        notify_on_error(north_server_fns::tags::delete_unused_tags().await)
+    }
 }
@@ -1,5 +1,7 @@
 use leptos::prelude::ServerFnError;
-use north_dto::{CreateTask, UpdateTask};
+use north_dto::{
+    BulkTaskUpdate, CreateTask, MoveTask, PostponeTask, TaskEvent, TaskSeries, UpdateTask,
+};
 
 use crate::{notify_on_error, TaskModel};
 
@@ -22,6 +24,14 @@ impl TaskRepository {
         )
     }
 
+    pub async fn get_series(id: i64) -> Result<TaskSeries, ServerFnError> {
+        notify_on_error(north_server_fns::tasks::get_task_series(id).await)
+    }
+
+    pub async fn history(id: i64) -> Result<Vec<TaskEvent>, ServerFnError> {
+        notify_on_error(north_server_fns::tasks::get_task_history(id).await)
+    }
+
     pub async fn create(input: CreateTask) -> Result<TaskModel, ServerFnError> {
         notify_on_error(
             north_server_fns::tasks::create_task(input)
@@ -46,10 +56,84 @@ impl TaskRepository {
         notify_on_error(north_server_fns::tasks::uncomplete_task(id).await)
     }
 
+    pub async fn undo_complete(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::tasks::undo_complete_task(id).await)
+    }
+
+    pub async fn skip_occurrence(id: i64) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::skip_task_occurrence(id)
+                .await
+                .map(TaskModel::from),
+        )
+    }
+
+    pub async fn postpone(id: i64, date: chrono::NaiveDate) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::postpone_task(id, PostponeTask { date })
+                .await
+                .map(TaskModel::from),
+        )
+    }
+
+    pub async fn move_to_column(
+        id: i64,
+        column_id: Option<i64>,
+        sort_key: Option<String>,
+    ) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::move_task_to_column(
+                id,
+                MoveTask {
+                    column_id,
+                    sort_key,
+                },
+            )
+            .await
+            .map(TaskModel::from),
+        )
+    }
+
     pub async fn delete(id: i64) -> Result<(), ServerFnError> {
         notify_on_error(north_server_fns::tasks::delete_task(id).await)
     }
 
+    pub async fn add_blocker(id: i64, blocker_id: i64) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::add_task_blocker(id, blocker_id)
+                .await
+                .map(TaskModel::from),
+        )
+    }
+
+    pub async fn remove_blocker(id: i64, blocker_id: i64) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::remove_task_blocker(id, blocker_id)
+                .await
+                .map(TaskModel::from),
+        )
+    }
+
+    pub async fn start_timer(id: i64) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::start_task_timer(id)
+                .await
+                .map(TaskModel::from),
+        )
+    }
+
+    pub async fn stop_timer(id: i64) -> Result<TaskModel, ServerFnError> {
+        notify_on_error(
+            north_server_fns::tasks::stop_task_timer(id)
+                .await
+                .map(TaskModel::from),
+        )
+    }
+
+    pub async fn bulk_update(input: BulkTaskUpdate) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::tasks::bulk_update_tasks(input).await)
+    }
+
     pub async fn set_tags(task_id: i64, tag_names: Vec<String>) -> Result<(), ServerFnError> {
         notify_on_error(north_server_fns::tasks::set_task_tags(task_id, tag_names).await)
     }
@@ -0,0 +1,30 @@
+use leptos::prelude::ServerFnError;
+use north_dto::{TrashItem, TrashItemKind};
+
+use crate::notify_on_error;
+
+pub struct TrashRepository;
+
+impl TrashRepository {
+    pub async fn list() -> Result<Vec<TrashItem>, ServerFnError> {
+        notify_on_error(north_server_fns::trash::list_trash().await)
+    }
+
+    pub async fn restore(kind: TrashItemKind, id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(match kind {
+            TrashItemKind::Task => north_server_fns::trash::restore_task(id).await,
+            TrashItemKind::Project => north_server_fns::trash::restore_project(id).await,
+        })
+    }
+
+    pub async fn delete(kind: TrashItemKind, id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(match kind {
+            TrashItemKind::Task => north_server_fns::trash::delete_task(id).await,
+            TrashItemKind::Project => north_server_fns::trash::delete_project(id).await,
+        })
+    }
+
+    pub async fn empty() -> Result<usize, ServerFnError> {
+        notify_on_error(north_server_fns::trash::empty_trash().await)
+    }
+}
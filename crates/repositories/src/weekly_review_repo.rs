@@ -0,0 +1,32 @@
+use leptos::prelude::ServerFnError;
+use north_dto::{UpdateWeeklyReview, WeeklyReview};
+
+use crate::notify_on_error;
+
+pub struct WeeklyReviewRepository;
+
+impl WeeklyReviewRepository {
+    pub async fn current() -> Result<Option<WeeklyReview>, ServerFnError> {
+        notify_on_error(north_server_fns::weekly_reviews::get_current_weekly_review().await)
+    }
+
+    pub async fn start() -> Result<WeeklyReview, ServerFnError> {
+        notify_on_error(north_server_fns::weekly_reviews::start_weekly_review().await)
+    }
+
+    pub async fn update(id: i64, input: UpdateWeeklyReview) -> Result<WeeklyReview, ServerFnError> {
+        notify_on_error(north_server_fns::weekly_reviews::update_weekly_review(id, input).await)
+    }
+
+    pub async fn complete(id: i64) -> Result<WeeklyReview, ServerFnError> {
+        notify_on_error(north_server_fns::weekly_reviews::complete_weekly_review(id).await)
+    }
+
+    pub async fn discard(id: i64) -> Result<(), ServerFnError> {
+        notify_on_error(north_server_fns::weekly_reviews::discard_weekly_review(id).await)
+    }
+
+    pub async fn history() -> Result<Vec<WeeklyReview>, ServerFnError> {
+        notify_on_error(north_server_fns::weekly_reviews::list_weekly_reviews().await)
+    }
+}
@@ -0,0 +1,91 @@
+use leptos::prelude::*;
+use north_dto::Attachment;
+use server_fn::codec::{MultipartData, MultipartFormData};
+
+#[server(ApiListAttachmentsFn, "/api")]
+pub async fn list_attachments(task_id: i64) -> Result<Vec<Attachment>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::AttachmentService::list_for_task(&pool, user_id, task_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Uploads a file to a task. The form carries `task_id` followed by `file`.
+#[server(name = ApiUploadAttachmentFn, prefix = "/api", input = MultipartFormData)]
+pub async fn upload_attachment(data: MultipartData) -> Result<Attachment, ServerFnError> {
+    use north_core::attachment_service::MAX_ATTACHMENT_BYTES;
+
+    let pool = expect_context::<north_core::DbPool>();
+    let upload_dir = expect_context::<north_core::UploadDir>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+
+    let mut form = data
+        .into_inner()
+        .ok_or_else(|| ServerFnError::new("Expected multipart form data"))?;
+    let mut task_id = None;
+
+    while let Some(mut field) = form
+        .next_field()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+    {
+        match field.name() {
+            Some("task_id") => {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|e| ServerFnError::new(e.to_string()))?;
+                task_id = text.trim().parse::<i64>().ok();
+            }
+            Some("file") => {
+                let task_id = task_id.ok_or_else(|| ServerFnError::new("Missing task_id"))?;
+                let filename = field.file_name().unwrap_or("file").to_string();
+                let content_type = field
+                    .content_type()
+                    .map(|m| m.essence_str().to_string())
+                    .unwrap_or_else(|| "application/octet-stream".into());
+
+                // Read in chunks so an oversized upload is cut off early.
+                let mut bytes = Vec::new();
+                while let Some(chunk) = field
+                    .chunk()
+                    .await
+                    .map_err(|e| ServerFnError::new(e.to_string()))?
+                {
+                    bytes.extend_from_slice(&chunk);
+                    if bytes.len() > MAX_ATTACHMENT_BYTES {
+                        return Err(ServerFnError::new(format!(
+                            "File is larger than {} MB",
+                            MAX_ATTACHMENT_BYTES / (1024 * 1024)
+                        )));
+                    }
+                }
+
+                return north_core::AttachmentService::upload(
+                    &pool,
+                    upload_dir.path(),
+                    user_id,
+                    task_id,
+                    &filename,
+                    &content_type,
+                    &bytes,
+                )
+                .await
+                .map_err(|e| ServerFnError::new(e.to_string()));
+            }
+            _ => {}
+        }
+    }
+    Err(ServerFnError::new("Missing file"))
+}
+
+#[server(ApiDeleteAttachmentFn, "/api")]
+pub async fn delete_attachment(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let upload_dir = expect_context::<north_core::UploadDir>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::AttachmentService::delete(&pool, upload_dir.path(), user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,44 @@
+use leptos::prelude::*;
+use north_dto::{CreateTaskComment, TaskComment, UpdateTaskComment};
+
+#[server(ApiListCommentsFn, "/api")]
+pub async fn list_comments(task_id: i64) -> Result<Vec<TaskComment>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskCommentService::list_for_task(&pool, user_id, task_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiCreateCommentFn, "/api")]
+pub async fn create_comment(
+    task_id: i64,
+    input: CreateTaskComment,
+) -> Result<TaskComment, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskCommentService::create(&pool, user_id, task_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiUpdateCommentFn, "/api")]
+pub async fn update_comment(
+    id: i64,
+    input: UpdateTaskComment,
+) -> Result<TaskComment, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskCommentService::update(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteCommentFn, "/api")]
+pub async fn delete_comment(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskCommentService::delete(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
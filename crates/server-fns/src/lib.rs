@@ -1,7 +1,14 @@
+pub mod attachments;
 pub mod auth;
+pub mod comments;
 pub mod filters;
 pub mod ping;
 pub mod projects;
+pub mod reminders;
+pub mod search;
 pub mod settings;
+pub mod stats;
 pub mod tags;
 pub mod tasks;
+pub mod trash;
+pub mod weekly_reviews;
@@ -1,5 +1,9 @@
 use leptos::prelude::*;
-use north_dto::{CreateProject, Project, ProjectFilter, UpdateProject};
+use north_dto::{
+    Area, CreateArea, CreateProject, CreateProjectColumn, CreateProjectSection, MoveProject,
+    Project, ProjectColumn, ProjectFilter, ProjectSection, UpdateArea, UpdateProject,
+    UpdateProjectColumn, UpdateProjectSection,
+};
 
 #[server(ApiListProjectsFn, "/api")]
 pub async fn list_projects(filter: ProjectFilter) -> Result<Vec<Project>, ServerFnError> {
@@ -45,3 +49,132 @@ pub async fn delete_project(id: i64) -> Result<(), ServerFnError> {
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
+
+#[server(ApiMoveProjectFn, "/api")]
+pub async fn move_project(id: i64, input: MoveProject) -> Result<Project, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::move_to(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiListProjectColumnsFn, "/api")]
+pub async fn list_project_columns(project_id: i64) -> Result<Vec<ProjectColumn>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::list_columns(&pool, user_id, project_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiCreateProjectColumnFn, "/api")]
+pub async fn create_project_column(
+    project_id: i64,
+    input: CreateProjectColumn,
+) -> Result<ProjectColumn, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::create_column(&pool, user_id, project_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiUpdateProjectColumnFn, "/api")]
+pub async fn update_project_column(
+    id: i64,
+    input: UpdateProjectColumn,
+) -> Result<ProjectColumn, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::update_column(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteProjectColumnFn, "/api")]
+pub async fn delete_project_column(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::delete_column(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiListProjectSectionsFn, "/api")]
+pub async fn list_project_sections(project_id: i64) -> Result<Vec<ProjectSection>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::list_sections(&pool, user_id, project_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiCreateProjectSectionFn, "/api")]
+pub async fn create_project_section(
+    project_id: i64,
+    input: CreateProjectSection,
+) -> Result<ProjectSection, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::create_section(&pool, user_id, project_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiUpdateProjectSectionFn, "/api")]
+pub async fn update_project_section(
+    id: i64,
+    input: UpdateProjectSection,
+) -> Result<ProjectSection, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::update_section(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteProjectSectionFn, "/api")]
+pub async fn delete_project_section(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ProjectService::delete_section(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiListAreasFn, "/api")]
+pub async fn list_areas() -> Result<Vec<Area>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::AreaService::list(&pool, user_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiCreateAreaFn, "/api")]
+pub async fn create_area(input: CreateArea) -> Result<Area, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::AreaService::create(&pool, user_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiUpdateAreaFn, "/api")]
+pub async fn update_area(id: i64, input: UpdateArea) -> Result<Area, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::AreaService::update(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteAreaFn, "/api")]
+pub async fn delete_area(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::AreaService::delete(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
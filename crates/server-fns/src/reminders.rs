@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use north_dto::{CreateReminder, PushSubscriptionInput, Reminder};
+
+#[server(ApiListRemindersFn, "/api")]
+pub async fn list_reminders(task_id: i64) -> Result<Vec<Reminder>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ReminderService::list_for_task(&pool, user_id, task_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiCreateReminderFn, "/api")]
+pub async fn create_reminder(
+    task_id: i64,
+    input: CreateReminder,
+) -> Result<Reminder, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ReminderService::create(&pool, user_id, task_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteReminderFn, "/api")]
+pub async fn delete_reminder(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ReminderService::delete(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiGetPushPublicKeyFn, "/api")]
+pub async fn get_push_public_key() -> Result<Option<String>, ServerFnError> {
+    Ok(std::env::var("VAPID_PRIVATE_KEY")
+        .ok()
+        .and_then(|k| {
+            use north_core::notifications::{WebPushNotifier, WebhookPolicy};
+            WebPushNotifier::new(&k, "", WebhookPolicy::default()).ok()
+        })
+        .map(|n| n.public_key()))
+}
+
+#[server(ApiSavePushSubscriptionFn, "/api")]
+pub async fn save_push_subscription(input: PushSubscriptionInput) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::ReminderService::save_push_subscription(&pool, user_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
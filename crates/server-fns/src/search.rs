@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+use north_dto::SearchResult;
+
+#[server(ApiSearchFn, "/api")]
+pub async fn search(query: String) -> Result<Vec<SearchResult>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::SearchService::search(&pool, user_id, &query, None)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+use north_dto::{Dashboard, StatsRange};
+
+#[server(ApiGetDashboardFn, "/api")]
+pub async fn get_dashboard(range: StatsRange) -> Result<Dashboard, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::StatsService::dashboard(&pool, user_id, range)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
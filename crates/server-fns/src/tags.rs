@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use north_dto::Tag;
+use north_dto::{CreateTag, Tag, UpdateTag};
 
 #[server(ApiListTagsFn, "/api")]
 pub async fn list_tags() -> Result<Vec<Tag>, ServerFnError> {
@@ -9,3 +9,48 @@ pub async fn list_tags() -> Result<Vec<Tag>, ServerFnError> {
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
+
+#[server(ApiCreateTagFn, "/api")]
+pub async fn create_tag(input: CreateTag) -> Result<Tag, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TagService::create(&pool, user_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiUpdateTagFn, "/api")]
+pub async fn update_tag(id: i64, input: UpdateTag) -> Result<Tag, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TagService::update(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteTagFn, "/api")]
+pub async fn delete_tag(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TagService::delete(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiMergeTagsFn, "/api")]
+pub async fn merge_tags(source_id: i64, into_id: i64) -> Result<Tag, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TagService::merge(&pool, user_id, source_id, into_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteUnusedTagsFn, "/api")]
+pub async fn delete_unused_tags() -> Result<usize, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TagService::delete_unused(&pool, user_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
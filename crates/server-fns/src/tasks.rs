@@ -1,6 +1,8 @@
 use leptos::prelude::*;
 use leptos::server_fn::codec::Json;
-use north_dto::{CreateTask, Task, UpdateTask};
+use north_dto::{
+    BulkTaskUpdate, CreateTask, MoveTask, PostponeTask, Task, TaskEvent, TaskSeries, UpdateTask,
+};
 
 #[server(ApiListTasksFn, "/api")]
 pub async fn list_tasks() -> Result<Vec<Task>, ServerFnError> {
@@ -21,6 +23,24 @@ pub async fn get_task(id: i64) -> Result<Task, ServerFnError> {
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
+#[server(ApiGetTaskSeriesFn, "/api")]
+pub async fn get_task_series(id: i64) -> Result<TaskSeries, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskService::get_series(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiGetTaskHistoryFn, "/api")]
+pub async fn get_task_history(id: i64) -> Result<Vec<TaskEvent>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskEventService::list_for_task(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
 #[server(ApiCreateTaskFn, "/api")]
 pub async fn create_task(input: CreateTask) -> Result<Task, ServerFnError> {
     let pool = expect_context::<north_core::DbPool>();
@@ -68,6 +88,45 @@ pub async fn uncomplete_task(id: i64) -> Result<(), ServerFnError> {
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
+/// Undoes a completion. Unlike `uncomplete_task`, this also takes back the
+/// next instance a recurring task spawned.
+#[server(ApiUndoCompleteTaskFn, "/api")]
+pub async fn undo_complete_task(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskService::undo_complete(&pool, user_id, id)
+        .await
+        .map(|_| ())
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiSkipTaskOccurrenceFn, "/api")]
+pub async fn skip_task_occurrence(id: i64) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskService::skip_occurrence(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiPostponeTaskFn, "/api")]
+pub async fn postpone_task(id: i64, input: PostponeTask) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskService::postpone(&pool, user_id, id, input.date)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiMoveTaskToColumnFn, "/api")]
+pub async fn move_task_to_column(id: i64, input: MoveTask) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskService::move_to_column(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
 #[server(ApiDeleteTaskFn, "/api")]
 pub async fn delete_task(id: i64) -> Result<(), ServerFnError> {
     let pool = expect_context::<north_core::DbPool>();
@@ -77,6 +136,51 @@ pub async fn delete_task(id: i64) -> Result<(), ServerFnError> {
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
+#[server(ApiAddTaskBlockerFn, "/api")]
+pub async fn add_task_blocker(id: i64, blocker_id: i64) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskDependencyService::add(&pool, user_id, id, blocker_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiRemoveTaskBlockerFn, "/api")]
+pub async fn remove_task_blocker(id: i64, blocker_id: i64) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskDependencyService::remove(&pool, user_id, id, blocker_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiStartTaskTimerFn, "/api")]
+pub async fn start_task_timer(id: i64) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TimeEntryService::start(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiStopTaskTimerFn, "/api")]
+pub async fn stop_task_timer(id: i64) -> Result<Task, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TimeEntryService::stop(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiBulkUpdateTasksFn, "/api")]
+pub async fn bulk_update_tasks(input: BulkTaskUpdate) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TaskService::bulk_update(&pool, user_id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
 #[server(ApiSetTaskTagsFn, "/api")]
 pub async fn set_task_tags(task_id: i64, tag_names: Vec<String>) -> Result<(), ServerFnError> {
     let pool = expect_context::<north_core::DbPool>();
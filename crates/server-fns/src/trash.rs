@@ -0,0 +1,59 @@
+use leptos::prelude::*;
+use north_dto::TrashItem;
+
+#[server(ApiListTrashFn, "/api")]
+pub async fn list_trash() -> Result<Vec<TrashItem>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TrashService::list(&pool, user_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiRestoreTrashedTaskFn, "/api")]
+pub async fn restore_task(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TrashService::restore_task(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiRestoreTrashedProjectFn, "/api")]
+pub async fn restore_project(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TrashService::restore_project(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteTrashedTaskFn, "/api")]
+pub async fn delete_task(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let upload_dir = expect_context::<north_core::UploadDir>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TrashService::delete_task(&pool, upload_dir.path(), user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDeleteTrashedProjectFn, "/api")]
+pub async fn delete_project(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let upload_dir = expect_context::<north_core::UploadDir>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TrashService::delete_project(&pool, upload_dir.path(), user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiEmptyTrashFn, "/api")]
+pub async fn empty_trash() -> Result<usize, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let upload_dir = expect_context::<north_core::UploadDir>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::TrashService::purge(&pool, upload_dir.path(), user_id, None)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,59 @@
+use leptos::prelude::*;
+use north_dto::{UpdateWeeklyReview, WeeklyReview};
+
+#[server(ApiGetCurrentWeeklyReviewFn, "/api")]
+pub async fn get_current_weekly_review() -> Result<Option<WeeklyReview>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::WeeklyReviewService::current(&pool, user_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiStartWeeklyReviewFn, "/api")]
+pub async fn start_weekly_review() -> Result<WeeklyReview, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::WeeklyReviewService::start(&pool, user_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiUpdateWeeklyReviewFn, "/api")]
+pub async fn update_weekly_review(
+    id: i64,
+    input: UpdateWeeklyReview,
+) -> Result<WeeklyReview, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::WeeklyReviewService::update(&pool, user_id, id, &input)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiCompleteWeeklyReviewFn, "/api")]
+pub async fn complete_weekly_review(id: i64) -> Result<WeeklyReview, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::WeeklyReviewService::complete(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiDiscardWeeklyReviewFn, "/api")]
+pub async fn discard_weekly_review(id: i64) -> Result<(), ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::WeeklyReviewService::discard(&pool, user_id, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server(ApiListWeeklyReviewsFn, "/api")]
+pub async fn list_weekly_reviews() -> Result<Vec<WeeklyReview>, ServerFnError> {
+    let pool = expect_context::<north_core::DbPool>();
+    let user_id = crate::auth::get_auth_user_id().await?;
+    north_core::WeeklyReviewService::history(&pool, user_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -13,17 +13,13 @@ use diesel_async::AsyncPgConnection;
 use leptos::prelude::*;
 use leptos_axum::{generate_route_list, LeptosRoutes};
 use north_db::DbPool;
-use tower_http::services::ServeDir;
+use tower_http::services::{ServeDir, ServeFile};
 use tracing_subscriber::EnvFilter;
 
 /// Newtype wrapper so server functions can extract the JWT secret.
 #[derive(Debug, Clone)]
 pub struct JwtSecret(pub String);
 
-/// Newtype wrapper so server functions can extract the upload directory.
-#[derive(Debug, Clone)]
-pub struct UploadDir(pub String);
-
 /// Shared application state available to all Axum handlers.
 #[derive(Clone)]
 pub struct AppState {
@@ -67,6 +63,11 @@ async fn main() {
         return;
     }
 
+    let smtp = smtp_from_env();
+    start_reminder_scheduler(pool.clone(), smtp.clone());
+    start_digest_scheduler(pool.clone(), smtp);
+    start_trash_scheduler(pool.clone(), &upload_dir);
+
     // Leptos configuration
     let conf = get_configuration(None).unwrap();
     let leptos_options = conf.leptos_options;
@@ -82,7 +83,7 @@ async fn main() {
     // Context values for Leptos server functions
     let pool_ctx = pool.clone();
     let jwt_ctx = JwtSecret(jwt_secret);
-    let upload_ctx = UploadDir(upload_dir);
+    let upload_ctx = north_core::UploadDir(upload_dir);
 
     let app = Router::new()
         .nest("/api/auth", routes::public_api_router())
@@ -105,6 +106,11 @@ async fn main() {
                 move || north_app::shell(leptos_options.clone())
             },
         )
+        // Served from the root so the push service worker's scope covers the app.
+        .route_service(
+            "/sw.js",
+            ServeFile::new(format!("{}/sw.js", leptos_options.site_root)),
+        )
         .nest_service(
             "/pkg",
             ServeDir::new(format!("{}/pkg", leptos_options.site_root)),
@@ -127,3 +133,71 @@ async fn main() {
         .await
         .expect("Server error");
 }
+
+/// SMTP relay from `SMTP_URL` / `SMTP_FROM`, shared by reminders and digests.
+fn smtp_from_env() -> Option<north_core::notifications::SmtpNotifier> {
+    let url = std::env::var("SMTP_URL").ok()?;
+    let from = std::env::var("SMTP_FROM").unwrap_or_else(|_| "North <north@localhost>".into());
+    match north_core::notifications::SmtpNotifier::from_url(&url, &from) {
+        Ok(smtp) => Some(smtp),
+        Err(e) => {
+            tracing::warn!("Email delivery disabled: {e}");
+            None
+        }
+    }
+}
+
+/// Starts the background reminder loop with whichever delivery backends
+/// are configured. Webhooks need no server-side setup beyond the optional
+/// `WEBHOOK_ALLOWED_HOSTS` for internal targets.
+fn start_reminder_scheduler(pool: DbPool, smtp: Option<north_core::notifications::SmtpNotifier>) {
+    use north_core::notifications::{
+        Notifier, SystemClock, WebPushNotifier, WebhookNotifier, WebhookPolicy,
+    };
+    use std::sync::Arc;
+
+    let mut notifiers: Vec<Arc<dyn Notifier>> =
+        vec![Arc::new(WebhookNotifier::new(WebhookPolicy::from_env()))];
+
+    if let Some(smtp) = smtp {
+        notifiers.push(Arc::new(smtp));
+    }
+
+    if let Ok(key) = std::env::var("VAPID_PRIVATE_KEY") {
+        let subject =
+            std::env::var("VAPID_SUBJECT").unwrap_or_else(|_| "mailto:admin@localhost".into());
+        match WebPushNotifier::new(&key, &subject, WebhookPolicy::from_env()) {
+            Ok(push) => notifiers.push(Arc::new(push)),
+            Err(e) => tracing::warn!("Push reminders disabled: {e}"),
+        }
+    }
+
+    let period = std::env::var("REMINDER_POLL_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(60);
+
+    north_core::ReminderScheduler::new(pool, Arc::new(SystemClock), notifiers)
+        .spawn(std::time::Duration::from_secs(period));
+}
+
+/// Starts the digest email loop. Digests need SMTP, so without it this is a no-op.
+fn start_digest_scheduler(pool: DbPool, smtp: Option<north_core::notifications::SmtpNotifier>) {
+    use north_core::notifications::SystemClock;
+    use std::sync::Arc;
+
+    let Some(smtp) = smtp else {
+        return;
+    };
+    north_core::DigestScheduler::new(pool, Arc::new(SystemClock), smtp)
+        .spawn(std::time::Duration::from_secs(5 * 60));
+}
+
+/// Starts the hourly purge of trash older than each user's retention period.
+fn start_trash_scheduler(pool: DbPool, upload_dir: &str) {
+    use north_core::notifications::SystemClock;
+    use std::sync::Arc;
+
+    north_core::TrashScheduler::new(pool, Arc::new(SystemClock), upload_dir.into())
+        .spawn(std::time::Duration::from_secs(60 * 60));
+}
@@ -0,0 +1,122 @@
+use std::path::Path as FsPath;
+
+use axum::extract::{Multipart, Path, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use north_core::AttachmentService;
+use north_dto::Attachment;
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn list_attachments(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(task_id): Path<i64>,
+) -> Result<Json<Vec<Attachment>>, AppError> {
+    let attachments = AttachmentService::list_for_task(&state.pool, auth_user.id, task_id).await?;
+    Ok(Json(attachments))
+}
+
+/// Accepts a multipart body with a single `file` field.
+pub async fn upload_attachment(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(task_id): Path<i64>,
+    mut multipart: Multipart,
+) -> Result<Json<Attachment>, AppError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(e.to_string()))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let filename = field.file_name().unwrap_or("file").to_string();
+        let content_type = field
+            .content_type()
+            .and_then(|ct| ct.parse::<mime::Mime>().ok())
+            .map(|m| m.essence_str().to_string())
+            .unwrap_or_else(|| "application/octet-stream".into());
+        let data = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+        let attachment = AttachmentService::upload(
+            &state.pool,
+            FsPath::new(&state.upload_dir),
+            auth_user.id,
+            task_id,
+            &filename,
+            &content_type,
+            &data,
+        )
+        .await?;
+        return Ok(Json(attachment));
+    }
+    Err(AppError::BadRequest("Missing `file` field".into()))
+}
+
+/// Serves the file. Images display inline; everything else downloads.
+pub async fn get_attachment(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Response, AppError> {
+    let (attachment, data) = AttachmentService::read(
+        &state.pool,
+        FsPath::new(&state.upload_dir),
+        auth_user.id,
+        id,
+    )
+    .await?;
+
+    let disposition = if attachment.is_image() {
+        "inline"
+    } else {
+        "attachment"
+    };
+    let filename: String = attachment
+        .filename
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, attachment.content_type.clone()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("{disposition}; filename=\"{filename}\""),
+            ),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            (header::CACHE_CONTROL, "private, max-age=86400".to_string()),
+        ],
+        data,
+    )
+        .into_response())
+}
+
+pub async fn delete_attachment(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    AttachmentService::delete(
+        &state.pool,
+        FsPath::new(&state.upload_dir),
+        auth_user.id,
+        id,
+    )
+    .await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
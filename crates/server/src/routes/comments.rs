@@ -0,0 +1,46 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use north_core::TaskCommentService;
+use north_dto::{CreateTaskComment, TaskComment, UpdateTaskComment};
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn list_comments(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(task_id): Path<i64>,
+) -> Result<Json<Vec<TaskComment>>, AppError> {
+    let comments = TaskCommentService::list_for_task(&state.pool, auth_user.id, task_id).await?;
+    Ok(Json(comments))
+}
+
+pub async fn create_comment(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(task_id): Path<i64>,
+    Json(body): Json<CreateTaskComment>,
+) -> Result<Json<TaskComment>, AppError> {
+    let comment = TaskCommentService::create(&state.pool, auth_user.id, task_id, &body).await?;
+    Ok(Json(comment))
+}
+
+pub async fn update_comment(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<UpdateTaskComment>,
+) -> Result<Json<TaskComment>, AppError> {
+    let comment = TaskCommentService::update(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(comment))
+}
+
+pub async fn delete_comment(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    TaskCommentService::delete(&state.pool, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
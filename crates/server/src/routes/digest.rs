@@ -0,0 +1,18 @@
+use axum::extract::State;
+use axum::response::Html;
+use chrono::Utc;
+use north_core::DigestService;
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+/// Renders the current user's digest as it would be emailed right now,
+/// without sending it.
+pub async fn preview_digest(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Html<String>, AppError> {
+    let digest = DigestService::build(&state.pool, auth_user.id, Utc::now()).await?;
+    Ok(Html(DigestService::render_html(&digest)))
+}
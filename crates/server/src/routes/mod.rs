@@ -1,11 +1,21 @@
+mod attachments;
 mod auth;
+mod comments;
+mod digest;
 mod filters;
 mod projects;
+mod reminders;
+mod search;
 mod stats;
+mod tags;
 mod tasks;
+mod trash;
+mod weekly_reviews;
 
-use axum::routing::{delete, get, post};
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, patch, post};
 use axum::{middleware, Router};
+use north_core::attachment_service::MAX_ATTACHMENT_BYTES;
 
 use crate::auth::middleware::auth_middleware;
 use crate::AppState;
@@ -26,6 +36,53 @@ pub fn protected_api_router(state: AppState) -> Router<AppState> {
                 .patch(tasks::update_task)
                 .delete(tasks::delete_task),
         )
+        .route("/tasks/bulk", post(tasks::bulk_update_tasks))
+        .route("/tasks/:id/series", get(tasks::get_task_series))
+        .route("/tasks/:id/history", get(tasks::get_task_history))
+        .route("/tasks/:id/skip", post(tasks::skip_occurrence))
+        .route("/tasks/:id/postpone", post(tasks::postpone_task))
+        .route("/tasks/:id/move", post(tasks::move_task))
+        .route("/tasks/:id/blockers", post(tasks::add_blocker))
+        .route(
+            "/tasks/:id/blockers/:blocker_id",
+            delete(tasks::remove_blocker),
+        )
+        .route(
+            "/tasks/:id/timer",
+            post(tasks::start_timer).delete(tasks::stop_timer),
+        )
+        // Attachment routes
+        .route(
+            "/tasks/:id/attachments",
+            get(attachments::list_attachments)
+                .post(attachments::upload_attachment)
+                // Leave room for the multipart framing around the file.
+                .layer(DefaultBodyLimit::max(MAX_ATTACHMENT_BYTES + 64 * 1024)),
+        )
+        .route(
+            "/attachments/:id",
+            get(attachments::get_attachment).delete(attachments::delete_attachment),
+        )
+        // Comment routes
+        .route(
+            "/tasks/:id/comments",
+            get(comments::list_comments).post(comments::create_comment),
+        )
+        .route(
+            "/comments/:id",
+            patch(comments::update_comment).delete(comments::delete_comment),
+        )
+        // Reminder routes
+        .route(
+            "/tasks/:id/reminders",
+            get(reminders::list_reminders).post(reminders::create_reminder),
+        )
+        .route("/reminders/:id", delete(reminders::delete_reminder))
+        .route("/push/public-key", get(reminders::get_push_public_key))
+        .route(
+            "/push/subscriptions",
+            post(reminders::save_push_subscription),
+        )
         // Project routes
         .route(
             "/projects",
@@ -37,11 +94,74 @@ pub fn protected_api_router(state: AppState) -> Router<AppState> {
                 .patch(projects::update_project)
                 .delete(projects::delete_project),
         )
+        .route("/projects/:id/move", post(projects::move_project))
+        .route(
+            "/projects/:id/columns",
+            get(projects::list_columns).post(projects::create_column),
+        )
+        .route(
+            "/columns/:id",
+            patch(projects::update_column).delete(projects::delete_column),
+        )
+        .route(
+            "/projects/:id/sections",
+            get(projects::list_sections).post(projects::create_section),
+        )
+        .route(
+            "/sections/:id",
+            patch(projects::update_section).delete(projects::delete_section),
+        )
+        .route(
+            "/areas",
+            get(projects::list_areas).post(projects::create_area),
+        )
+        .route(
+            "/areas/:id",
+            patch(projects::update_area).delete(projects::delete_area),
+        )
+        // Tag routes
+        .route("/tags", get(tags::list_tags).post(tags::create_tag))
+        .route("/tags/unused", delete(tags::delete_unused_tags))
+        .route(
+            "/tags/:id",
+            get(tags::get_tag)
+                .patch(tags::update_tag)
+                .delete(tags::delete_tag),
+        )
+        .route("/tags/:id/merge", post(tags::merge_tag))
         // Filter routes
         .route("/filters", get(filters::list_filters))
         .route("/filters/:id", delete(filters::delete_filter))
+        // Trash routes
+        .route("/trash", get(trash::list_trash).delete(trash::empty_trash))
+        .route("/trash/tasks/:id/restore", post(trash::restore_task))
+        .route("/trash/tasks/:id", delete(trash::delete_task))
+        .route("/trash/projects/:id/restore", post(trash::restore_project))
+        .route("/trash/projects/:id", delete(trash::delete_project))
+        // Search routes
+        .route("/search", get(search::search))
         // Stats routes
         .route("/stats", get(stats::get_stats))
+        .route("/stats/dashboard", get(stats::get_dashboard))
+        .route("/digest/preview", get(digest::preview_digest))
+        // Weekly review routes
+        .route(
+            "/weekly-reviews",
+            get(weekly_reviews::list_weekly_reviews).post(weekly_reviews::start_weekly_review),
+        )
+        .route(
+            "/weekly-reviews/current",
+            get(weekly_reviews::get_current_weekly_review),
+        )
+        .route(
+            "/weekly-reviews/:id",
+            patch(weekly_reviews::update_weekly_review)
+                .delete(weekly_reviews::discard_weekly_review),
+        )
+        .route(
+            "/weekly-reviews/:id/complete",
+            post(weekly_reviews::complete_weekly_review),
+        )
         // Auth middleware layer
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
@@ -1,7 +1,11 @@
 use axum::extract::{Path, Query, State};
 use axum::Json;
-use north_core::ProjectService;
-use north_dto::{CreateProject, Project, ProjectFilter, UpdateProject};
+use north_core::{AreaService, ProjectService};
+use north_dto::{
+    Area, CreateArea, CreateProject, CreateProjectColumn, CreateProjectSection, MoveProject,
+    Project, ProjectColumn, ProjectFilter, ProjectSection, UpdateArea, UpdateProject,
+    UpdateProjectColumn, UpdateProjectSection,
+};
 
 use crate::auth::AuthUser;
 use crate::error::AppError;
@@ -52,3 +56,125 @@ pub async fn delete_project(
     ProjectService::delete(&state.pool, auth_user.id, id).await?;
     Ok(())
 }
+
+pub async fn move_project(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<MoveProject>,
+) -> Result<Json<Project>, AppError> {
+    let result = ProjectService::move_to(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(result))
+}
+
+pub async fn list_columns(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<ProjectColumn>>, AppError> {
+    let columns = ProjectService::list_columns(&state.pool, auth_user.id, id).await?;
+    Ok(Json(columns))
+}
+
+pub async fn create_column(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<CreateProjectColumn>,
+) -> Result<Json<ProjectColumn>, AppError> {
+    let column = ProjectService::create_column(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(column))
+}
+
+pub async fn update_column(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<UpdateProjectColumn>,
+) -> Result<Json<ProjectColumn>, AppError> {
+    let column = ProjectService::update_column(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(column))
+}
+
+pub async fn delete_column(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<(), AppError> {
+    ProjectService::delete_column(&state.pool, auth_user.id, id).await?;
+    Ok(())
+}
+
+pub async fn list_sections(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<ProjectSection>>, AppError> {
+    let sections = ProjectService::list_sections(&state.pool, auth_user.id, id).await?;
+    Ok(Json(sections))
+}
+
+pub async fn create_section(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<CreateProjectSection>,
+) -> Result<Json<ProjectSection>, AppError> {
+    let section = ProjectService::create_section(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(section))
+}
+
+pub async fn update_section(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<UpdateProjectSection>,
+) -> Result<Json<ProjectSection>, AppError> {
+    let section = ProjectService::update_section(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(section))
+}
+
+pub async fn delete_section(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<(), AppError> {
+    ProjectService::delete_section(&state.pool, auth_user.id, id).await?;
+    Ok(())
+}
+
+pub async fn list_areas(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Area>>, AppError> {
+    let areas = AreaService::list(&state.pool, auth_user.id).await?;
+    Ok(Json(areas))
+}
+
+pub async fn create_area(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Json(body): Json<CreateArea>,
+) -> Result<Json<Area>, AppError> {
+    let area = AreaService::create(&state.pool, auth_user.id, &body).await?;
+    Ok(Json(area))
+}
+
+pub async fn update_area(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<UpdateArea>,
+) -> Result<Json<Area>, AppError> {
+    let area = AreaService::update(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(area))
+}
+
+pub async fn delete_area(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<(), AppError> {
+    AreaService::delete(&state.pool, auth_user.id, id).await?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use north_core::notifications::{WebPushNotifier, WebhookPolicy};
+use north_core::ReminderService;
+use north_dto::{CreateReminder, PushSubscriptionInput, Reminder};
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn list_reminders(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(task_id): Path<i64>,
+) -> Result<Json<Vec<Reminder>>, AppError> {
+    let reminders = ReminderService::list_for_task(&state.pool, auth_user.id, task_id).await?;
+    Ok(Json(reminders))
+}
+
+pub async fn create_reminder(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(task_id): Path<i64>,
+    Json(body): Json<CreateReminder>,
+) -> Result<Json<Reminder>, AppError> {
+    let reminder = ReminderService::create(&state.pool, auth_user.id, task_id, &body).await?;
+    Ok(Json(reminder))
+}
+
+pub async fn delete_reminder(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    ReminderService::delete(&state.pool, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+pub async fn get_push_public_key() -> Result<Json<Option<String>>, AppError> {
+    let key = std::env::var("VAPID_PRIVATE_KEY")
+        .ok()
+        .and_then(|k| WebPushNotifier::new(&k, "", WebhookPolicy::default()).ok())
+        .map(|n| n.public_key());
+    Ok(Json(key))
+}
+
+pub async fn save_push_subscription(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Json(body): Json<PushSubscriptionInput>,
+) -> Result<axum::http::StatusCode, AppError> {
+    ReminderService::save_push_subscription(&state.pool, auth_user.id, &body).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
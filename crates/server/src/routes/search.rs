@@ -0,0 +1,17 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use north_core::SearchService;
+use north_dto::{SearchQuery, SearchResult};
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn search(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<Vec<SearchResult>>, AppError> {
+    let results = SearchService::search(&state.pool, auth_user.id, &query.q, query.limit).await?;
+    Ok(Json(results))
+}
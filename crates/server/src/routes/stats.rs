@@ -1,7 +1,7 @@
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::Json;
-use north_core::stats_service::Stats;
 use north_core::StatsService;
+use north_dto::{Dashboard, Stats, StatsQuery};
 
 use crate::auth::AuthUser;
 use crate::error::AppError;
@@ -14,3 +14,12 @@ pub async fn get_stats(
     let stats = StatsService::get_stats(&state.pool, auth_user.id).await?;
     Ok(Json(stats))
 }
+
+pub async fn get_dashboard(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<Dashboard>, AppError> {
+    let dashboard = StatsService::dashboard(&state.pool, auth_user.id, query.range).await?;
+    Ok(Json(dashboard))
+}
@@ -0,0 +1,72 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use north_core::TagService;
+use north_dto::{CreateTag, MergeTags, Tag, UpdateTag};
+use serde_json::{json, Value};
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn list_tags(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Tag>>, AppError> {
+    let tags = TagService::list(&state.pool, auth_user.id).await?;
+    Ok(Json(tags))
+}
+
+pub async fn create_tag(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Json(body): Json<CreateTag>,
+) -> Result<Json<Tag>, AppError> {
+    let tag = TagService::create(&state.pool, auth_user.id, &body).await?;
+    Ok(Json(tag))
+}
+
+pub async fn get_tag(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Tag>, AppError> {
+    let tag = TagService::get_by_id(&state.pool, auth_user.id, id).await?;
+    Ok(Json(tag))
+}
+
+pub async fn update_tag(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<UpdateTag>,
+) -> Result<Json<Tag>, AppError> {
+    let tag = TagService::update(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(tag))
+}
+
+pub async fn delete_tag(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    TagService::delete(&state.pool, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+pub async fn merge_tag(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<MergeTags>,
+) -> Result<Json<Tag>, AppError> {
+    let tag = TagService::merge(&state.pool, auth_user.id, id, body.into_id).await?;
+    Ok(Json(tag))
+}
+
+pub async fn delete_unused_tags(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, AppError> {
+    let deleted = TagService::delete_unused(&state.pool, auth_user.id).await?;
+    Ok(Json(json!({ "deleted": deleted })))
+}
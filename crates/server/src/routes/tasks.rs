@@ -1,7 +1,10 @@
 use axum::extract::{Path, Query, State};
 use axum::Json;
-use north_core::TaskService;
-use north_dto::{CreateTask, Task, TaskFilter, UpdateTask};
+use north_core::{TaskDependencyService, TaskEventService, TaskService, TimeEntryService};
+use north_dto::{
+    AddBlocker, BulkTaskUpdate, CreateTask, MoveTask, PostponeTask, Task, TaskEvent, TaskFilter,
+    TaskSeries, UpdateTask,
+};
 
 use crate::auth::AuthUser;
 use crate::error::AppError;
@@ -52,3 +55,96 @@ pub async fn delete_task(
     TaskService::delete(&state.pool, auth_user.id, id).await?;
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
+
+pub async fn bulk_update_tasks(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Json(input): Json<BulkTaskUpdate>,
+) -> Result<axum::http::StatusCode, AppError> {
+    TaskService::bulk_update(&state.pool, auth_user.id, &input).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+pub async fn get_task_series(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<TaskSeries>, AppError> {
+    let series = TaskService::get_series(&state.pool, auth_user.id, id).await?;
+    Ok(Json(series))
+}
+
+pub async fn get_task_history(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<TaskEvent>>, AppError> {
+    let events = TaskEventService::list_for_task(&state.pool, auth_user.id, id).await?;
+    Ok(Json(events))
+}
+
+pub async fn skip_occurrence(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Task>, AppError> {
+    let task = TaskService::skip_occurrence(&state.pool, auth_user.id, id).await?;
+    Ok(Json(task))
+}
+
+pub async fn postpone_task(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<PostponeTask>,
+) -> Result<Json<Task>, AppError> {
+    let task = TaskService::postpone(&state.pool, auth_user.id, id, body.date).await?;
+    Ok(Json(task))
+}
+
+pub async fn move_task(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<MoveTask>,
+) -> Result<Json<Task>, AppError> {
+    let task = TaskService::move_to_column(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(task))
+}
+
+pub async fn add_blocker(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<AddBlocker>,
+) -> Result<Json<Task>, AppError> {
+    let task = TaskDependencyService::add(&state.pool, auth_user.id, id, body.blocker_id).await?;
+    Ok(Json(task))
+}
+
+pub async fn remove_blocker(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path((id, blocker_id)): Path<(i64, i64)>,
+) -> Result<Json<Task>, AppError> {
+    let task = TaskDependencyService::remove(&state.pool, auth_user.id, id, blocker_id).await?;
+    Ok(Json(task))
+}
+
+pub async fn start_timer(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Task>, AppError> {
+    let task = TimeEntryService::start(&state.pool, auth_user.id, id).await?;
+    Ok(Json(task))
+}
+
+pub async fn stop_timer(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Task>, AppError> {
+    let task = TimeEntryService::stop(&state.pool, auth_user.id, id).await?;
+    Ok(Json(task))
+}
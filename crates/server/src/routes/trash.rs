@@ -0,0 +1,63 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use north_core::TrashService;
+use north_dto::TrashItem;
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn list_trash(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TrashItem>>, AppError> {
+    let items = TrashService::list(&state.pool, auth_user.id).await?;
+    Ok(Json(items))
+}
+
+pub async fn empty_trash(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<usize>, AppError> {
+    let upload_dir = std::path::Path::new(&state.upload_dir);
+    let purged = TrashService::purge(&state.pool, upload_dir, auth_user.id, None).await?;
+    Ok(Json(purged))
+}
+
+pub async fn restore_task(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    TrashService::restore_task(&state.pool, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+pub async fn delete_task(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    let upload_dir = std::path::Path::new(&state.upload_dir);
+    TrashService::delete_task(&state.pool, upload_dir, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+pub async fn restore_project(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    TrashService::restore_project(&state.pool, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+pub async fn delete_project(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    let upload_dir = std::path::Path::new(&state.upload_dir);
+    TrashService::delete_project(&state.pool, upload_dir, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use north_core::WeeklyReviewService;
+use north_dto::{UpdateWeeklyReview, WeeklyReview};
+
+use crate::auth::AuthUser;
+use crate::error::AppError;
+use crate::AppState;
+
+pub async fn list_weekly_reviews(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<WeeklyReview>>, AppError> {
+    let reviews = WeeklyReviewService::history(&state.pool, auth_user.id).await?;
+    Ok(Json(reviews))
+}
+
+pub async fn start_weekly_review(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<WeeklyReview>, AppError> {
+    let review = WeeklyReviewService::start(&state.pool, auth_user.id).await?;
+    Ok(Json(review))
+}
+
+pub async fn get_current_weekly_review(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+) -> Result<Json<Option<WeeklyReview>>, AppError> {
+    let review = WeeklyReviewService::current(&state.pool, auth_user.id).await?;
+    Ok(Json(review))
+}
+
+pub async fn update_weekly_review(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(body): Json<UpdateWeeklyReview>,
+) -> Result<Json<WeeklyReview>, AppError> {
+    let review = WeeklyReviewService::update(&state.pool, auth_user.id, id, &body).await?;
+    Ok(Json(review))
+}
+
+pub async fn complete_weekly_review(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<WeeklyReview>, AppError> {
+    let review = WeeklyReviewService::complete(&state.pool, auth_user.id, id).await?;
+    Ok(Json(review))
+}
+
+pub async fn discard_weekly_review(
+    auth_user: axum::Extension<AuthUser>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<axum::http::StatusCode, AppError> {
+    WeeklyReviewService::discard(&state.pool, auth_user.id, id).await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
@@ -25,7 +25,8 @@ impl Default for AppStore {
 
 impl AppStore {
     pub fn new() -> Self {
-        let tasks = TaskStore::new();
+        let status_bar = StatusBarStore::new();
+        let tasks = TaskStore::new(status_bar);
         let projects = ProjectStore::new();
         let tags = TagStore::new();
         let saved_filters = SavedFilterStore::new();
@@ -33,7 +34,6 @@ impl AppStore {
         let modal = ModalStore::new();
         let task_detail_modal = TaskDetailModalStore::new(tasks, modal);
         let filter_dsl = FilterDslStore::new();
-        let status_bar = StatusBarStore::new();
         let browser_storage = BrowserStorageStore::new();
 
         Self {
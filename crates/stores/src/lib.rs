@@ -10,6 +10,7 @@ pub mod status_bar_store;
 pub mod tag_store;
 pub mod task_detail_modal_store;
 pub mod task_store;
+pub mod undo_stack;
 
 pub use app_store::AppStore;
 pub use browser_storage_store::BrowserStorageStore;
@@ -19,9 +20,10 @@ pub use modal_store::ModalStore;
 pub use project_store::ProjectStore;
 pub use saved_filter_store::SavedFilterStore;
 pub use settings_store::SettingsStore;
-pub use status_bar_store::{StatusBarStore, StatusBarStyle, StatusBarVariant};
+pub use status_bar_store::{StatusBarAction, StatusBarStore, StatusBarStyle, StatusBarVariant};
 pub use tag_store::TagStore;
 pub use task_detail_modal_store::TaskDetailModalStore;
 pub use task_store::{IdFilter, TaskStore, TaskStoreFilter};
+pub use undo_stack::{TaskCommand, UndoStack};
 
 pub use north_repositories::{Recurrence, TaskModel};
@@ -1,11 +1,17 @@
+use chrono::NaiveDate;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use north_dto::{CreateProject, Project, ProjectFilter, ProjectStatus, UpdateProject};
+use north_dto::{
+    Area, CreateProject, Project, ProjectFilter, ProjectStatus, ProjectViewType, UpdateArea,
+    UpdateProject,
+};
 use north_repositories::ProjectRepository;
 
 #[derive(Clone, Copy)]
 pub struct ProjectStore {
     projects: RwSignal<Vec<Project>>,
+    /// All areas, archived ones included.
+    areas: RwSignal<Vec<Area>>,
     loaded: RwSignal<bool>,
 }
 
@@ -19,6 +25,7 @@ impl ProjectStore {
     pub fn new() -> Self {
         Self {
             projects: RwSignal::new(vec![]),
+            areas: RwSignal::new(vec![]),
             loaded: RwSignal::new(false),
         }
     }
@@ -28,11 +35,17 @@ impl ProjectStore {
         spawn_local(async move {
             let filter = ProjectFilter {
                 status: Some(ProjectStatus::Active),
+                ..Default::default()
             };
             if let Ok(list) = ProjectRepository::list(filter).await {
                 store.load(list);
             }
         });
+        spawn_local(async move {
+            if let Ok(areas) = ProjectRepository::list_areas().await {
+                store.areas.set(areas);
+            }
+        });
     }
 
     pub fn load(&self, projects: Vec<Project>) {
@@ -44,6 +57,91 @@ impl ProjectStore {
         self.projects.get()
     }
 
+    pub fn get_untracked(&self) -> Vec<Project> {
+        self.projects.get_untracked()
+    }
+
+    pub fn loaded_signal(&self) -> Signal<bool> {
+        self.loaded.into()
+    }
+
+    pub fn areas(&self) -> Vec<Area> {
+        self.areas.get()
+    }
+
+    /// Puts a project into an area (or none) before `before_id`, or at the
+    /// end of the area.
+    pub fn move_project(&self, id: i64, area_id: Option<i64>, before_id: Option<i64>) {
+        let store = *self;
+        store.projects.update(|list| {
+            let Some(from) = list.iter().position(|p| p.id == id) else {
+                return;
+            };
+            let mut project = list.remove(from);
+            project.area_id = area_id;
+            let at = match before_id {
+                Some(before_id) => list.iter().position(|p| p.id == before_id),
+                None => list
+                    .iter()
+                    .rposition(|p| p.area_id == area_id)
+                    .map(|i| i + 1),
+            };
+            list.insert(at.unwrap_or(list.len()), project);
+        });
+        spawn_local(async move {
+            if ProjectRepository::move_to(id, area_id, before_id)
+                .await
+                .is_err()
+            {
+                store.refetch();
+            }
+        });
+    }
+
+    pub fn create_area(&self, title: String) {
+        let store = *self;
+        spawn_local(async move {
+            if let Ok(area) = ProjectRepository::create_area(title).await {
+                store.areas.update(|list| list.push(area));
+            }
+        });
+    }
+
+    pub fn rename_area(&self, id: i64, title: String) {
+        self.update_area(
+            id,
+            UpdateArea {
+                title: Some(title),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Archives or restores an area; its projects follow it.
+    pub fn set_area_status(&self, id: i64, status: ProjectStatus) {
+        self.update_area(
+            id,
+            UpdateArea {
+                status: Some(status),
+                ..Default::default()
+            },
+        );
+    }
+
+    fn update_area(&self, id: i64, input: UpdateArea) {
+        let store = *self;
+        spawn_local(async move {
+            if let Ok(area) = ProjectRepository::update_area(id, input).await {
+                store.areas.update(|list| {
+                    if let Some(a) = list.iter_mut().find(|a| a.id == id) {
+                        *a = area;
+                    }
+                });
+                store.refetch();
+            }
+        });
+    }
+
     pub fn create(&self, title: String) {
         let store = *self;
         spawn_local(async move {
@@ -51,6 +149,9 @@ impl ProjectStore {
                 title,
                 description: None,
                 view_type: None,
+                area_id: None,
+                due_date: None,
+                outcome: None,
             };
             if let Ok(project) = ProjectRepository::create(input).await {
                 store.projects.update(|list| list.push(project));
@@ -88,6 +189,89 @@ impl ProjectStore {
         });
     }
 
+    pub fn set_due_date(&self, id: i64, due_date: Option<NaiveDate>) {
+        self.update_project(
+            id,
+            |p| p.due_date = due_date,
+            UpdateProject {
+                due_date: Some(due_date),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Sets the project's desired outcome; blank clears it.
+    pub fn set_outcome(&self, id: i64, outcome: String) {
+        let outcome = Some(outcome.trim().to_string()).filter(|o| !o.is_empty());
+        self.update_project(
+            id,
+            {
+                let outcome = outcome.clone();
+                move |p| p.outcome = outcome
+            },
+            UpdateProject {
+                outcome: Some(outcome),
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn mark_reviewed(&self, id: i64) {
+        let today = chrono::Utc::now().date_naive();
+        self.update_project(
+            id,
+            |p| p.reviewed_at = Some(today),
+            UpdateProject {
+                reviewed_at: Some(Some(today)),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Sets how often the project comes up for review; `None` follows the
+    /// user's review interval.
+    pub fn set_review_interval(&self, id: i64, days: Option<i16>) {
+        self.update_project(
+            id,
+            |p| p.review_interval_days = days,
+            UpdateProject {
+                review_interval_days: Some(days),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Applies `apply` right away, then saves `input`.
+    fn update_project(&self, id: i64, apply: impl FnOnce(&mut Project), input: UpdateProject) {
+        self.projects.update(|list| {
+            if let Some(p) = list.iter_mut().find(|p| p.id == id) {
+                apply(p);
+            }
+        });
+        spawn_local(async move {
+            let _ = ProjectRepository::update(id, input).await;
+        });
+    }
+
+    /// Switches a project between list and board rendering. Applied once the
+    /// server responds, since it seeds the board's default columns.
+    pub fn set_view_type(&self, id: i64, view_type: ProjectViewType) {
+        let store = *self;
+        spawn_local(async move {
+            let input = UpdateProject {
+                view_type: Some(view_type),
+                ..Default::default()
+            };
+            if let Ok(project) = ProjectRepository::update(id, input).await {
+                store.projects.update(|list| {
+                    if let Some(p) = list.iter_mut().find(|p| p.id == id) {
+                        *p = project;
+                    }
+                });
+            }
+        });
+    }
+
     pub fn delete(&self, id: i64) {
         self.projects.update(|list| list.retain(|p| p.id != id));
         spawn_local(async move {
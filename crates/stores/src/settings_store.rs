@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use north_dto::{UpdateSettings, UserSettings};
+use north_dto::{DigestFrequency, ReviewStepKind, UpdateSettings, UserSettings};
 use north_repositories::SettingsRepository;
 
 #[derive(Clone, Copy)]
@@ -42,6 +42,19 @@ impl SettingsStore {
         Signal::derive(move || settings.get().timezone)
     }
 
+    /// Today's date in the user's timezone.
+    pub fn today(&self) -> Signal<chrono::NaiveDate> {
+        let settings = self.settings;
+        Signal::derive(move || {
+            let tz: chrono_tz::Tz = settings
+                .get()
+                .timezone
+                .parse()
+                .unwrap_or(chrono_tz::Tz::UTC);
+            chrono::Utc::now().with_timezone(&tz).date_naive()
+        })
+    }
+
     pub fn review_interval_days(&self) -> Signal<i64> {
         let settings = self.settings;
         Signal::derive(move || settings.get().review_interval_days as i64)
@@ -52,6 +65,61 @@ impl SettingsStore {
         Signal::derive(move || settings.get().sidebar_collapsed)
     }
 
+    pub fn reminder_email(&self) -> Signal<bool> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().reminder_email)
+    }
+
+    pub fn reminder_push(&self) -> Signal<bool> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().reminder_push)
+    }
+
+    pub fn reminder_webhook_url(&self) -> Signal<String> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().reminder_webhook_url.unwrap_or_default())
+    }
+
+    pub fn digest_frequency(&self) -> Signal<DigestFrequency> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().digest_frequency)
+    }
+
+    pub fn digest_hour(&self) -> Signal<u8> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().digest_hour)
+    }
+
+    pub fn digest_filter_id(&self) -> Signal<Option<i64>> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().digest_filter_id)
+    }
+
+    pub fn exclusive_tag_namespaces(&self) -> Signal<Vec<String>> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().exclusive_tag_namespaces)
+    }
+
+    pub fn trash_retention_days(&self) -> Signal<i16> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().trash_retention_days)
+    }
+
+    pub fn available_hours(&self) -> Signal<f32> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().available_hours)
+    }
+
+    pub fn review_steps(&self) -> Signal<Vec<ReviewStepKind>> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().review_steps)
+    }
+
+    pub fn review_checklist(&self) -> Signal<Vec<String>> {
+        let settings = self.settings;
+        Signal::derive(move || settings.get().review_checklist)
+    }
+
     /// Optimistically toggle sidebar_collapsed and persist to server.
     pub fn toggle_sidebar_collapsed(&self) {
         let new_val = !self.settings.get_untracked().sidebar_collapsed;
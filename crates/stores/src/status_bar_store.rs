@@ -5,6 +5,7 @@ pub struct StatusBarMessage {
     pub text: String,
     pub variant: StatusBarVariant,
     pub style: StatusBarStyle,
+    pub action: Option<StatusBarAction>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -23,6 +24,13 @@ pub enum StatusBarStyle {
     Toast,
 }
 
+/// Button shown next to a toast's text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusBarAction {
+    /// Reverts the latest task mutation.
+    Undo,
+}
+
 #[derive(Clone, Copy)]
 pub struct StatusBarStore {
     pub message: RwSignal<Option<StatusBarMessage>>,
@@ -46,6 +54,7 @@ impl StatusBarStore {
             text: text.into(),
             variant,
             style: StatusBarStyle::Spinner,
+            action: None,
         }));
     }
 
@@ -53,6 +62,16 @@ impl StatusBarStore {
     /// The actual timeout is handled by the StatusBar component.
     /// Does not override persistent spinner messages (e.g. connectivity warning).
     pub fn notify(&self, variant: StatusBarVariant, text: impl Into<String>) {
+        self.notify_with_action(variant, text, None);
+    }
+
+    /// Like `notify`, with an optional action button.
+    pub fn notify_with_action(
+        &self,
+        variant: StatusBarVariant,
+        text: impl Into<String>,
+        action: Option<StatusBarAction>,
+    ) {
         if let Some(current) = self.message.get_untracked() {
             if current.style == StatusBarStyle::Spinner {
                 return;
@@ -62,6 +81,7 @@ impl StatusBarStore {
             text: text.into(),
             variant,
             style: StatusBarStyle::Toast,
+            action,
         }));
     }
 
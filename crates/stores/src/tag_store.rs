@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use north_dto::Tag;
+use north_dto::{CreateTag, Tag, UpdateTag};
 use north_repositories::TagRepository;
 
 #[derive(Clone, Copy)]
@@ -40,4 +40,53 @@ impl TagStore {
     pub fn get(&self) -> Vec<Tag> {
         self.tags.get()
     }
+
+    pub fn loaded_signal(&self) -> Signal<bool> {
+        self.loaded.into()
+    }
+
+    pub async fn create(&self, input: CreateTag) -> Option<Tag> {
+        let tag = TagRepository::create(input).await.ok()?;
+        self.upsert(tag.clone());
+        Some(tag)
+    }
+
+    /// Renames and/or recolors a tag. Task rows carry tag names, so callers
+    /// refetch tasks once this resolves.
+    pub async fn update(&self, id: i64, input: UpdateTag) -> Option<Tag> {
+        let tag = TagRepository::update(id, input).await.ok()?;
+        self.upsert(tag.clone());
+        Some(tag)
+    }
+
+    pub async fn delete(&self, id: i64) -> bool {
+        let ok = TagRepository::delete(id).await.is_ok();
+        if ok {
+            self.tags.update(|list| list.retain(|t| t.id != id));
+        }
+        ok
+    }
+
+    /// Merges `source_id` into `into_id`; the source tag disappears.
+    pub async fn merge(&self, source_id: i64, into_id: i64) -> Option<Tag> {
+        let tag = TagRepository::merge(source_id, into_id).await.ok()?;
+        self.tags.update(|list| list.retain(|t| t.id != source_id));
+        self.upsert(tag.clone());
+        Some(tag)
+    }
+
+    /// Deletes tags no task uses.
+    pub async fn delete_unused(&self) -> Option<usize> {
+        let deleted = TagRepository::delete_unused().await.ok()?;
+        self.tags.update(|list| list.retain(|t| t.task_count > 0));
+        Some(deleted)
+    }
+
+    fn upsert(&self, tag: Tag) {
+        self.tags.update(|list| {
+            list.retain(|t| t.id != tag.id);
+            list.push(tag);
+            list.sort_by(|a, b| a.name.cmp(&b.name));
+        });
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{ModalStore, TaskStore};
+use chrono::NaiveDate;
 use leptos::prelude::*;
 use north_dto::RecurrenceType;
 use north_repositories::TaskModel;
@@ -235,6 +236,55 @@ impl TaskDetailModalStore {
         self.task_store.clear_due_date(task.id);
     }
 
+    pub fn set_priority(&self, priority: Option<i16>) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.set_priority(task.id, priority);
+    }
+
+    pub fn set_estimate(&self, estimate_minutes: Option<i32>) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.set_estimate(task.id, estimate_minutes);
+    }
+
+    pub fn toggle_timer(&self) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.toggle_timer(task.id);
+    }
+
+    pub fn set_waiting_for(&self, who: String) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.set_waiting_for(task.id, who);
+    }
+
+    pub fn clear_waiting(&self) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.clear_waiting(task.id);
+    }
+
+    pub fn set_follow_up_date(&self, date: String) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.set_follow_up_date(task.id, date);
+    }
+
+    pub fn clear_follow_up_date(&self) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.clear_follow_up_date(task.id);
+    }
+
     pub fn set_sequential_limit(&self, limit: i16) {
         let Some(task) = self.task_untracked() else {
             return;
@@ -246,11 +296,23 @@ impl TaskDetailModalStore {
         &self,
         recurrence_type: Option<RecurrenceType>,
         recurrence_rule: Option<String>,
+        recurrence_exdates: Vec<NaiveDate>,
     ) {
         let Some(task) = self.task_untracked() else {
             return;
         };
-        self.task_store
-            .set_recurrence(task.id, recurrence_type, recurrence_rule);
+        self.task_store.set_recurrence(
+            task.id,
+            recurrence_type,
+            recurrence_rule,
+            recurrence_exdates,
+        );
+    }
+
+    pub fn skip_occurrence(&self) {
+        let Some(task) = self.task_untracked() else {
+            return;
+        };
+        self.task_store.skip_occurrence(task.id);
     }
 }
@@ -1,9 +1,12 @@
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use north_dto::RecurrenceType;
-use north_dto::{CreateTask, TagInfo, UpdateTask};
-use north_repositories::{TaskModel, TaskRepository};
+use north_dto::{BulkTaskAction, BulkTaskUpdate, CreateTask, TagInfo, TrashItemKind, UpdateTask};
+use north_repositories::{TaskModel, TaskRepository, TrashRepository};
+
+use crate::status_bar_store::{StatusBarAction, StatusBarStore, StatusBarVariant};
+use crate::undo_stack::{TaskCommand, UndoStack};
 
 #[cfg(feature = "hydrate")]
 const REORDER_DEBOUNCE_MS: i32 = 1000;
@@ -23,6 +26,8 @@ pub struct TaskStore {
     reorder_timeout: RwSignal<i32>,
     #[cfg_attr(not(feature = "hydrate"), allow(dead_code))]
     pending_reorder: RwSignal<Option<PendingReorder>>,
+    history: RwSignal<UndoStack>,
+    status_bar: StatusBarStore,
 }
 
 #[derive(Clone, Default)]
@@ -31,6 +36,7 @@ pub struct TaskStoreFilter {
     pub parent_id: IdFilter,
     pub is_completed: Option<bool>,
     pub is_someday: Option<bool>,
+    pub is_waiting: Option<bool>,
 }
 
 #[derive(Clone, Default)]
@@ -43,17 +49,19 @@ pub enum IdFilter {
 
 impl Default for TaskStore {
     fn default() -> Self {
-        Self::new()
+        Self::new(StatusBarStore::new())
     }
 }
 
 impl TaskStore {
-    pub fn new() -> Self {
+    pub fn new(status_bar: StatusBarStore) -> Self {
         Self {
             tasks: RwSignal::new(vec![]),
             loaded: RwSignal::new(false),
             reorder_timeout: RwSignal::new(0),
             pending_reorder: RwSignal::new(None),
+            history: RwSignal::new(UndoStack::default()),
+            status_bar,
         }
     }
 
@@ -128,6 +136,37 @@ impl TaskStore {
                     Some(true) => t.someday,
                     Some(false) => !t.someday,
                 })
+                .filter(|t| match filter.is_waiting {
+                    None => true,
+                    Some(waiting) => t.waiting_for.is_some() == waiting,
+                })
+                .collect()
+        })
+    }
+
+    /// Tasks `id` waits on, in the order they were added.
+    pub fn blockers(&self, id: i64) -> Memo<Vec<TaskModel>> {
+        let tasks = self.tasks;
+        Memo::new(move |_| {
+            let all = tasks.get();
+            let Some(task) = all.iter().find(|t| t.id == id) else {
+                return vec![];
+            };
+            task.blocked_by
+                .iter()
+                .filter_map(|b| all.iter().find(|t| t.id == *b).cloned())
+                .collect()
+        })
+    }
+
+    /// Tasks waiting on `id`.
+    pub fn dependents(&self, id: i64) -> Memo<Vec<TaskModel>> {
+        let tasks = self.tasks;
+        Memo::new(move |_| {
+            tasks
+                .get()
+                .into_iter()
+                .filter(|t| t.blocked_by.contains(&id))
                 .collect()
         })
     }
@@ -164,42 +203,79 @@ impl TaskStore {
     }
 
     pub fn toggle_complete(&self, id: i64, was_completed: bool) {
-        let store = *self;
-        if was_completed {
-            store.update_in_place(id, |t| t.completed_at = None);
-            spawn_local(async move {
-                if TaskRepository::uncomplete(id).await.is_ok() {
-                    store.refetch_async().await;
-                }
-            });
+        let label = if was_completed {
+            "Task reopened"
         } else {
-            let now = Utc::now();
-            store.update_in_place(id, |t| {
-                t.completed_at = Some(now);
-            });
-            spawn_local(async move {
-                if TaskRepository::complete(id).await.is_ok() {
-                    store.refetch_async().await;
-                }
-            });
-        }
+            "Task completed"
+        };
+        let command = TaskCommand::SetCompleted {
+            id,
+            completed: !was_completed,
+        };
+        self.record(label, command.clone());
+        self.apply(command);
     }
 
     pub fn delete_task(&self, id: i64) {
+        self.record("Task deleted", TaskCommand::Delete { id });
         self.remove(id);
         spawn_local(async move {
             let _ = TaskRepository::delete(id).await;
         });
     }
 
+    /// Applies one action to several tasks in a single request. Undo
+    /// reverts the whole selection as one step.
+    pub fn bulk_update(&self, ids: Vec<i64>, action: BulkTaskAction, label: &str) {
+        let commands: Vec<TaskCommand> = ids
+            .iter()
+            .filter_map(|&id| self.snapshot(id))
+            .filter_map(|task| bulk_command(&task, &action))
+            .collect();
+        if commands.is_empty() {
+            return;
+        }
+
+        for command in &commands {
+            match command {
+                TaskCommand::Update { id, after, .. } => {
+                    self.update_in_place(*id, |t| apply_update(t, after));
+                }
+                TaskCommand::SetCompleted { id, completed } => {
+                    let completed_at = completed.then(Utc::now);
+                    self.update_in_place(*id, |t| t.completed_at = completed_at);
+                }
+                TaskCommand::Delete { id } => self.remove(*id),
+                _ => {}
+            }
+        }
+        self.record(label, TaskCommand::Batch(commands));
+
+        let store = *self;
+        let input = BulkTaskUpdate {
+            ids,
+            actions: vec![action],
+        };
+        spawn_local(async move {
+            let _ = TaskRepository::bulk_update(input).await;
+            store.refetch_async().await;
+        });
+    }
+
     pub fn update_task(&self, id: i64, title: String, body: Option<String>) {
         let store = *self;
+        let changed = self
+            .snapshot(id)
+            .is_some_and(|t| t.title != title || t.body != body);
+        let input = UpdateTask {
+            title: Some(title),
+            body: Some(body),
+            ..Default::default()
+        };
+        if changed {
+            self.record_update("Task edited", id, &input);
+        }
         spawn_local(async move {
-            let input = UpdateTask {
-                title: Some(title),
-                body: Some(body),
-                ..Default::default()
-            };
             if let Ok(task) = TaskRepository::update(id, input).await {
                 store.refetch_async().await;
                 if task.is_url_fetching.is_some() {
@@ -245,168 +321,313 @@ impl TaskStore {
     }
 
     pub fn set_start_at(&self, id: i64, start_at: String) {
-        let store = *self;
-        spawn_local(async move {
-            let dt = chrono::NaiveDateTime::parse_from_str(&start_at, "%Y-%m-%dT%H:%M")
-                .or_else(|_| chrono::NaiveDateTime::parse_from_str(&start_at, "%Y-%m-%dT%H:%M:%S"));
-            if let Ok(dt) = dt {
-                let input = UpdateTask {
-                    start_at: Some(Some(dt.and_utc())),
-                    ..Default::default()
-                };
-                if TaskRepository::update(id, input).await.is_ok() {
-                    store.refetch_async().await;
-                }
-            }
-        });
-    }
-
-    pub fn clear_start_at(&self, id: i64) {
-        let store = *self;
-        spawn_local(async move {
+        let dt = chrono::NaiveDateTime::parse_from_str(&start_at, "%Y-%m-%dT%H:%M")
+            .or_else(|_| chrono::NaiveDateTime::parse_from_str(&start_at, "%Y-%m-%dT%H:%M:%S"));
+        if let Ok(dt) = dt {
             let input = UpdateTask {
-                start_at: Some(None),
+                start_at: Some(Some(dt.and_utc())),
                 ..Default::default()
             };
-            if TaskRepository::update(id, input).await.is_ok() {
-                store.refetch_async().await;
-            }
-        });
+            self.update_recorded("Start date changed", id, input);
+        }
+    }
+
+    pub fn clear_start_at(&self, id: i64) {
+        let input = UpdateTask {
+            start_at: Some(None),
+            ..Default::default()
+        };
+        self.update_recorded("Start date cleared", id, input);
     }
 
     pub fn set_project(&self, task_id: i64, project_id: i64) {
-        let store = *self;
-        spawn_local(async move {
-            let input = UpdateTask {
-                project_id: Some(Some(project_id)),
-                ..Default::default()
-            };
-            if TaskRepository::update(task_id, input).await.is_ok() {
-                store.refetch_async().await;
-            }
-        });
+        let input = UpdateTask {
+            project_id: Some(Some(project_id)),
+            ..Default::default()
+        };
+        self.update_recorded("Task moved to project", task_id, input);
     }
 
     pub fn clear_project(&self, task_id: i64) {
+        let input = UpdateTask {
+            project_id: Some(None),
+            ..Default::default()
+        };
+        self.update_recorded("Task moved to inbox", task_id, input);
+    }
+
+    pub fn set_tags(&self, task_id: i64, tag_names: Vec<String>) {
+        let before = self
+            .snapshot(task_id)
+            .map(|task| task.tags.iter().map(|t| t.name.clone()).collect());
+        let command = TaskCommand::SetTags {
+            id: task_id,
+            before: before.clone().unwrap_or_default(),
+            after: tag_names,
+        };
+        if before.is_some() {
+            self.record("Tags changed", command.clone());
+        }
+        self.apply(command);
+    }
+
+    /// Makes `id` wait on `blocker_id` until the blocker is completed.
+    pub fn add_blocker(&self, id: i64, blocker_id: i64) {
+        self.set_blocker(id, blocker_id, true, "Blocker added");
+    }
+
+    pub fn remove_blocker(&self, id: i64, blocker_id: i64) {
+        self.set_blocker(id, blocker_id, false, "Blocker removed");
+    }
+
+    fn set_blocker(&self, id: i64, blocker_id: i64, blocked: bool, label: &str) {
+        let Some(task) = self.snapshot(id) else {
+            return;
+        };
+        if task.blocked_by.contains(&blocker_id) == blocked {
+            return;
+        }
+        let command = TaskCommand::SetBlocker {
+            id,
+            blocker_id,
+            blocked,
+        };
+        self.record(label, command.clone());
+        self.apply(command);
+    }
+
+    pub fn review_task(&self, id: i64) {
+        let input = UpdateTask {
+            reviewed_at: Some(Some(Utc::now().date_naive())),
+            ..Default::default()
+        };
+        self.update_recorded("Task reviewed", id, input);
+    }
+
+    pub fn toggle_someday(&self, id: i64) {
+        let was_someday = self.snapshot(id).map(|t| t.someday).unwrap_or(false);
+        let input = UpdateTask {
+            someday: Some(!was_someday),
+            ..Default::default()
+        };
+        let label = if was_someday {
+            "Task moved out of someday"
+        } else {
+            "Task moved to someday"
+        };
+        self.update_recorded(label, id, input);
+    }
+
+    pub fn skip_occurrence(&self, id: i64) {
         let store = *self;
+        let is_recurring = self
+            .tasks
+            .get_untracked()
+            .iter()
+            .any(|t| t.id == id && t.recurrence.is_some());
+        if !is_recurring {
+            return;
+        }
         spawn_local(async move {
-            let input = UpdateTask {
-                project_id: Some(None),
-                ..Default::default()
-            };
-            if TaskRepository::update(task_id, input).await.is_ok() {
+            if TaskRepository::skip_occurrence(id).await.is_ok() {
                 store.refetch_async().await;
             }
         });
     }
 
-    pub fn set_tags(&self, task_id: i64, tag_names: Vec<String>) {
+    /// Where postponing a task lands unless the user picks another date.
+    pub fn default_postpone_date(&self, id: i64, timezone: &str) -> Option<NaiveDate> {
+        let task = self
+            .tasks
+            .get_untracked()
+            .into_iter()
+            .find(|t| t.id == id)?;
+        Some(default_postpone_date(
+            task.start_at,
+            task.due_date,
+            timezone,
+            Utc::now(),
+        ))
+    }
+
+    pub fn postpone(&self, id: i64, date: chrono::NaiveDate) {
         let store = *self;
-        // Optimistic update — build new tags list from desired names
-        store.update_in_place(task_id, |t| {
-            let new_tags: Vec<TagInfo> = tag_names
-                .iter()
-                .map(|name| {
-                    // Preserve color for existing tags, use default for new ones
-                    let color = t
-                        .tags
-                        .iter()
-                        .find(|ti| ti.name == *name)
-                        .map(|ti| ti.color.clone())
-                        .unwrap_or_else(|| north_dto::DEFAULT_COLOR.to_string());
-                    TagInfo {
-                        name: name.clone(),
-                        color,
-                    }
-                })
-                .collect();
-            t.tags = new_tags;
-        });
+        let Some(task) = self.snapshot(id) else {
+            return;
+        };
         spawn_local(async move {
-            if TaskRepository::set_tags(task_id, tag_names).await.is_ok() {
+            if let Ok(postponed) = TaskRepository::postpone(id, date).await {
+                // The server works out the new dates, so record once it answers.
+                store.record(
+                    "Task postponed",
+                    TaskCommand::Update {
+                        id,
+                        before: Box::new(UpdateTask {
+                            start_at: Some(task.start_at),
+                            due_date: Some(task.due_date),
+                            ..Default::default()
+                        }),
+                        after: Box::new(UpdateTask {
+                            start_at: Some(postponed.start_at),
+                            due_date: Some(postponed.due_date),
+                            ..Default::default()
+                        }),
+                    },
+                );
                 store.refetch_async().await;
             }
         });
     }
 
-    pub fn review_task(&self, id: i64) {
+    /// Moves a card to a Kanban column, optimistically, then persists it.
+    pub fn move_to_column(&self, id: i64, column_id: Option<i64>, sort_key: String) {
         let store = *self;
-        let today = Utc::now().date_naive();
-        store.update_in_place(id, move |t| {
-            t.reviewed_at = Some(today);
+        let input = UpdateTask {
+            column_id: Some(column_id),
+            sort_key: Some(sort_key.clone()),
+            ..Default::default()
+        };
+        self.record_update("Task moved", id, &input);
+        self.update_in_place(id, |t| {
+            t.column_id = column_id;
+            t.sort_key = sort_key.clone();
         });
         spawn_local(async move {
-            let input = UpdateTask {
-                reviewed_at: Some(Some(today)),
-                ..Default::default()
-            };
-            if TaskRepository::update(id, input).await.is_ok() {
+            if TaskRepository::move_to_column(id, column_id, Some(sort_key))
+                .await
+                .is_err()
+            {
                 store.refetch_async().await;
             }
         });
     }
 
-    pub fn toggle_someday(&self, id: i64) {
-        let store = *self;
-        let was_someday = self
-            .tasks
-            .get_untracked()
-            .iter()
-            .find(|t| t.id == id)
-            .map(|t| t.someday)
-            .unwrap_or(false);
-        store.update_in_place(id, |t| t.someday = !was_someday);
-        spawn_local(async move {
+    /// Puts a task at the top level of a list section (`None` for outside
+    /// any section) at the given position.
+    pub fn move_to_section(&self, id: i64, section_id: Option<i64>, sort_key: String) {
+        let input = UpdateTask {
+            section_id: Some(section_id),
+            parent_id: Some(None),
+            sort_key: Some(sort_key),
+            ..Default::default()
+        };
+        self.update_recorded("Task moved", id, input);
+    }
+
+    pub fn set_due_date(&self, id: i64, due_date: String) {
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(&due_date, "%Y-%m-%d") {
             let input = UpdateTask {
-                someday: Some(!was_someday),
+                due_date: Some(Some(date)),
                 ..Default::default()
             };
-            if TaskRepository::update(id, input).await.is_ok() {
-                store.refetch_async().await;
-            }
-        });
+            self.update_recorded("Due date changed", id, input);
+        }
     }
 
-    pub fn set_due_date(&self, id: i64, due_date: String) {
-        let store = *self;
-        spawn_local(async move {
-            let date = chrono::NaiveDate::parse_from_str(&due_date, "%Y-%m-%d");
-            if let Ok(date) = date {
-                let input = UpdateTask {
-                    due_date: Some(Some(date)),
-                    ..Default::default()
-                };
-                if TaskRepository::update(id, input).await.is_ok() {
-                    store.refetch_async().await;
-                }
-            }
-        });
+    pub fn clear_due_date(&self, id: i64) {
+        let input = UpdateTask {
+            due_date: Some(None),
+            ..Default::default()
+        };
+        self.update_recorded("Due date cleared", id, input);
     }
 
-    pub fn clear_due_date(&self, id: i64) {
+    /// Sets the priority (1 highest to 4), or clears it with `None`.
+    pub fn set_priority(&self, id: i64, priority: Option<i16>) {
+        let input = UpdateTask {
+            priority: Some(priority),
+            ..Default::default()
+        };
+        let label = if priority.is_some() {
+            "Priority changed"
+        } else {
+            "Priority cleared"
+        };
+        self.update_recorded(label, id, input);
+    }
+
+    /// Sets the estimate in minutes, or clears it with `None`.
+    pub fn set_estimate(&self, id: i64, estimate_minutes: Option<i32>) {
+        let input = UpdateTask {
+            estimate_minutes: Some(estimate_minutes),
+            ..Default::default()
+        };
+        let label = if estimate_minutes.is_some() {
+            "Estimate changed"
+        } else {
+            "Estimate cleared"
+        };
+        self.update_recorded(label, id, input);
+    }
+
+    /// Starts the timer on the task, or stops it if it is already running.
+    /// Timers aren't undoable; a refetch picks up the stopped timer on any
+    /// other task and the new tracked totals.
+    pub fn toggle_timer(&self, id: i64) {
+        let Some(task) = self.snapshot(id) else {
+            return;
+        };
         let store = *self;
         spawn_local(async move {
-            let input = UpdateTask {
-                due_date: Some(None),
-                ..Default::default()
+            let result = if task.tracking_since.is_some() {
+                TaskRepository::stop_timer(id).await
+            } else {
+                TaskRepository::start_timer(id).await
             };
-            if TaskRepository::update(id, input).await.is_ok() {
+            if result.is_ok() {
                 store.refetch_async().await;
             }
         });
     }
 
-    pub fn set_sequential_limit(&self, id: i64, limit: i16) {
-        let store = *self;
-        spawn_local(async move {
+    /// Marks the task as waiting on `who`; a blank name clears it.
+    pub fn set_waiting_for(&self, id: i64, who: String) {
+        let who = who.trim().to_string();
+        if who.is_empty() {
+            self.clear_waiting(id);
+            return;
+        }
+        let input = UpdateTask {
+            waiting_for: Some(Some(who)),
+            ..Default::default()
+        };
+        self.update_recorded("Waiting for changed", id, input);
+    }
+
+    pub fn set_follow_up_date(&self, id: i64, date: String) {
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
             let input = UpdateTask {
-                sequential_limit: Some(limit),
+                follow_up_date: Some(Some(date)),
                 ..Default::default()
             };
-            if TaskRepository::update(id, input).await.is_ok() {
-                store.refetch_async().await;
-            }
-        });
+            self.update_recorded("Follow-up date changed", id, input);
+        }
+    }
+
+    pub fn clear_follow_up_date(&self, id: i64) {
+        let input = UpdateTask {
+            follow_up_date: Some(None),
+            ..Default::default()
+        };
+        self.update_recorded("Follow-up date cleared", id, input);
+    }
+
+    /// Takes the task out of the waiting state, dropping its follow-up.
+    pub fn clear_waiting(&self, id: i64) {
+        let input = UpdateTask {
+            waiting_for: Some(None),
+            follow_up_date: Some(None),
+            ..Default::default()
+        };
+        self.update_recorded("No longer waiting", id, input);
+    }
+
+    pub fn set_sequential_limit(&self, id: i64, limit: i16) {
+        let input = UpdateTask {
+            sequential_limit: Some(limit),
+            ..Default::default()
+        };
+        self.update_recorded("Sequential limit changed", id, input);
     }
 
     pub fn set_recurrence(
@@ -414,21 +635,38 @@ impl TaskStore {
         id: i64,
         recurrence_type: Option<RecurrenceType>,
         recurrence_rule: Option<String>,
+        recurrence_exdates: Vec<NaiveDate>,
     ) {
-        let store = *self;
-        spawn_local(async move {
-            let input = UpdateTask {
-                recurrence_type: Some(recurrence_type),
-                recurrence_rule: Some(recurrence_rule),
-                ..Default::default()
-            };
-            if TaskRepository::update(id, input).await.is_ok() {
-                store.refetch_async().await;
-            }
-        });
+        let input = UpdateTask {
+            recurrence_type: Some(recurrence_type),
+            recurrence_rule: Some(recurrence_rule),
+            recurrence_exdates: Some(recurrence_exdates),
+            ..Default::default()
+        };
+        self.update_recorded("Recurrence changed", id, input);
     }
 
     pub fn reorder_task(&self, task_id: i64, sort_key: String, parent_id: Option<Option<i64>>) {
+        let input = UpdateTask {
+            sort_key: Some(sort_key.clone()),
+            parent_id,
+            ..Default::default()
+        };
+        if let Some(task) = self.snapshot(task_id) {
+            let before = previous_values(&task, &input);
+            self.history.update(|h| {
+                h.push_or_merge(
+                    "Task moved",
+                    TaskCommand::Update {
+                        id: task_id,
+                        before: Box::new(before),
+                        after: Box::new(input),
+                    },
+                )
+            });
+            self.offer_undo("Task moved");
+        }
+
         // Optimistic update — UI reacts immediately
         self.update_in_place(task_id, |t| {
             t.sort_key = sort_key.clone();
@@ -520,8 +758,263 @@ impl TaskStore {
         });
     }
 
+    // ── Undo / redo ─────────────────────────────────────────────
+
+    /// Reverts the latest recorded mutation. The entry moves to the redo
+    /// history once the server has taken the change; if it refuses, the
+    /// entry stays undoable and the tasks are reloaded.
+    pub fn undo(&self) {
+        let mut entry = None;
+        self.history.update(|h| entry = h.undo());
+        let Some(entry) = entry else {
+            self.status_bar
+                .notify(StatusBarVariant::Info, "Nothing to undo");
+            return;
+        };
+        let inverse = entry.command.inverse();
+        self.apply_locally(&inverse);
+        let store = *self;
+        spawn_local(async move {
+            if store.persist(inverse).await {
+                store
+                    .status_bar
+                    .notify(StatusBarVariant::Info, format!("Undone: {}", entry.label));
+                store.history.update(|h| h.push_undone(entry));
+            } else {
+                store.status_bar.notify(
+                    StatusBarVariant::Danger,
+                    format!("Couldn't undo: {}", entry.label),
+                );
+                store.history.update(|h| h.push_done(entry));
+                store.refetch_async().await;
+            }
+        });
+    }
+
+    /// Re-applies the latest undone mutation, moving it back to the undo
+    /// history once the server has taken it.
+    pub fn redo(&self) {
+        let mut entry = None;
+        self.history.update(|h| entry = h.redo());
+        let Some(entry) = entry else {
+            self.status_bar
+                .notify(StatusBarVariant::Info, "Nothing to redo");
+            return;
+        };
+        self.apply_locally(&entry.command);
+        let store = *self;
+        spawn_local(async move {
+            if store.persist(entry.command.clone()).await {
+                store
+                    .status_bar
+                    .notify(StatusBarVariant::Info, format!("Redone: {}", entry.label));
+                store.history.update(|h| h.push_done(entry));
+            } else {
+                store.status_bar.notify(
+                    StatusBarVariant::Danger,
+                    format!("Couldn't redo: {}", entry.label),
+                );
+                store.history.update(|h| h.push_undone(entry));
+                store.refetch_async().await;
+            }
+        });
+    }
+
+    fn record(&self, label: &str, command: TaskCommand) {
+        self.history.update(|h| h.push(label, command));
+        self.offer_undo(label);
+    }
+
+    /// Records an update of the fields `input` sets, remembering their
+    /// current values.
+    fn record_update(&self, label: &str, id: i64, input: &UpdateTask) {
+        let Some(task) = self.snapshot(id) else {
+            return;
+        };
+        let before = previous_values(&task, input);
+        self.record(
+            label,
+            TaskCommand::Update {
+                id,
+                before: Box::new(before),
+                after: Box::new(input.clone()),
+            },
+        );
+    }
+
+    fn offer_undo(&self, label: &str) {
+        self.status_bar.notify_with_action(
+            StatusBarVariant::Info,
+            label,
+            Some(StatusBarAction::Undo),
+        );
+    }
+
+    /// Records, optimistically applies and persists a plain field update.
+    fn update_recorded(&self, label: &str, id: i64, input: UpdateTask) {
+        self.record_update(label, id, &input);
+        self.apply(TaskCommand::Update {
+            id,
+            before: Box::default(),
+            after: Box::new(input),
+        });
+    }
+
+    /// Carries out a command without recording it.
+    fn apply(&self, command: TaskCommand) {
+        self.apply_locally(&command);
+        let store = *self;
+        spawn_local(async move {
+            store.persist(command).await;
+        });
+    }
+
+    /// The optimistic part of a command: updates the local tasks.
+    fn apply_locally(&self, command: &TaskCommand) {
+        match command {
+            TaskCommand::Update { id, after, .. } => {
+                // A debounced reorder of this task would overwrite the result.
+                if self
+                    .pending_reorder
+                    .get_untracked()
+                    .is_some_and(|p| p.task_id == *id)
+                {
+                    self.pending_reorder.set(None);
+                }
+                self.update_in_place(*id, |t| apply_update(t, after));
+            }
+            TaskCommand::SetCompleted { id, completed } => {
+                let completed_at = completed.then(Utc::now);
+                self.update_in_place(*id, |t| t.completed_at = completed_at);
+            }
+            // The taken-back instance goes with the refetch.
+            TaskCommand::UndoComplete { id } => {
+                self.update_in_place(*id, |t| t.completed_at = None);
+            }
+            TaskCommand::Delete { id } => self.remove(*id),
+            // Comes back with the refetch once restored.
+            TaskCommand::Restore { .. } => {}
+            TaskCommand::SetTags { id, after, .. } => {
+                // Keep the colors of tags the task already has.
+                self.update_in_place(*id, |t| {
+                    t.tags = after
+                        .iter()
+                        .map(|name| TagInfo {
+                            name: name.clone(),
+                            color: t
+                                .tags
+                                .iter()
+                                .find(|ti| ti.name == *name)
+                                .map(|ti| ti.color.clone())
+                                .unwrap_or_else(|| north_dto::DEFAULT_COLOR.to_string()),
+                        })
+                        .collect();
+                });
+            }
+            TaskCommand::SetBlocker {
+                id,
+                blocker_id,
+                blocked,
+            } => {
+                self.update_in_place(*id, |t| {
+                    t.blocked_by.retain(|b| b != blocker_id);
+                    if *blocked {
+                        t.blocked_by.push(*blocker_id);
+                    }
+                });
+            }
+            TaskCommand::Batch(commands) => {
+                for command in commands {
+                    self.apply_locally(command);
+                }
+            }
+        }
+    }
+
+    /// Sends a command to the server, reloading the tasks it touched.
+    /// `false` if the server refused any part of it.
+    async fn persist(self, command: TaskCommand) -> bool {
+        match command {
+            TaskCommand::Update { id, after, .. } => {
+                let ok = TaskRepository::update(id, *after).await.is_ok();
+                if ok {
+                    self.refetch_async().await;
+                }
+                ok
+            }
+            TaskCommand::SetCompleted { id, completed } => {
+                let result = if completed {
+                    TaskRepository::complete(id).await
+                } else {
+                    TaskRepository::uncomplete(id).await
+                };
+                if result.is_ok() {
+                    self.refetch_async().await;
+                }
+                result.is_ok()
+            }
+            TaskCommand::UndoComplete { id } => {
+                let ok = TaskRepository::undo_complete(id).await.is_ok();
+                if ok {
+                    self.refetch_async().await;
+                }
+                ok
+            }
+            TaskCommand::Delete { id } => TaskRepository::delete(id).await.is_ok(),
+            TaskCommand::Restore { id } => {
+                let ok = TrashRepository::restore(TrashItemKind::Task, id)
+                    .await
+                    .is_ok();
+                if ok {
+                    self.refetch_async().await;
+                }
+                ok
+            }
+            TaskCommand::SetTags { id, after, .. } => {
+                let ok = TaskRepository::set_tags(id, after).await.is_ok();
+                if ok {
+                    self.refetch_async().await;
+                }
+                ok
+            }
+            TaskCommand::SetBlocker {
+                id,
+                blocker_id,
+                blocked,
+            } => {
+                let result = if blocked {
+                    TaskRepository::add_blocker(id, blocker_id).await
+                } else {
+                    TaskRepository::remove_blocker(id, blocker_id).await
+                };
+                match result {
+                    Ok(task) => {
+                        self.update_in_place(id, |t| t.blocked_by = task.blocked_by);
+                        true
+                    }
+                    // E.g. a cycle: drop the optimistic link.
+                    Err(_) => {
+                        self.refetch_async().await;
+                        false
+                    }
+                }
+            }
+            TaskCommand::Batch(commands) => {
+                let mut ok = true;
+                for command in commands {
+                    ok &= Box::pin(self.persist(command)).await;
+                }
+                ok
+            }
+        }
+    }
+
     // ── Internal helpers ────────────────────────────────────────
 
+    fn snapshot(&self, id: i64) -> Option<TaskModel> {
+        self.tasks.get_untracked().into_iter().find(|t| t.id == id)
+    }
+
     #[cfg(feature = "hydrate")]
     fn poll_url_resolution(&self, task_id: i64) {
         let store = *self;
@@ -562,3 +1055,232 @@ impl TaskStore {
         }
     }
 }
+
+/// The day after a task's current date, or tomorrow if that has passed,
+/// in the user's timezone.
+pub fn default_postpone_date(
+    start_at: Option<chrono::DateTime<Utc>>,
+    due_date: Option<NaiveDate>,
+    timezone: &str,
+    now: chrono::DateTime<Utc>,
+) -> NaiveDate {
+    let tz: chrono_tz::Tz = timezone.parse().unwrap_or(chrono_tz::Tz::UTC);
+    let today = now.with_timezone(&tz).date_naive();
+    let current = start_at
+        .map(|s| s.with_timezone(&tz).date_naive())
+        .or(due_date)
+        .unwrap_or(today)
+        .max(today);
+    current + chrono::Duration::days(1)
+}
+
+/// How `action` changes `task`, or `None` if it leaves it as is.
+fn bulk_command(task: &TaskModel, action: &BulkTaskAction) -> Option<TaskCommand> {
+    let id = task.id;
+    let tag_names = || task.tags.iter().map(|t| t.name.clone()).collect::<Vec<_>>();
+    let input = match action {
+        BulkTaskAction::Complete | BulkTaskAction::Uncomplete => {
+            let completed = matches!(action, BulkTaskAction::Complete);
+            return (task.completed_at.is_some() != completed)
+                .then_some(TaskCommand::SetCompleted { id, completed });
+        }
+        BulkTaskAction::Delete => return Some(TaskCommand::Delete { id }),
+        BulkTaskAction::AddTags(names) => {
+            let before = tag_names();
+            let mut after = before.clone();
+            for name in names
+                .iter()
+                .filter_map(|n| north_dto::normalize_tag_name(n))
+            {
+                if !after.contains(&name) {
+                    after.push(name);
+                }
+            }
+            return (after != before).then_some(TaskCommand::SetTags { id, before, after });
+        }
+        BulkTaskAction::RemoveTags(names) => {
+            let names: Vec<String> = names
+                .iter()
+                .filter_map(|n| north_dto::normalize_tag_name(n))
+                .collect();
+            let before = tag_names();
+            let after: Vec<String> = before
+                .iter()
+                .filter(|n| !names.contains(n))
+                .cloned()
+                .collect();
+            return (after != before).then_some(TaskCommand::SetTags { id, before, after });
+        }
+        BulkTaskAction::SetProject(project_id) => UpdateTask {
+            project_id: Some(*project_id),
+            ..Default::default()
+        },
+        BulkTaskAction::SetStartAt(start_at) => UpdateTask {
+            start_at: Some(*start_at),
+            ..Default::default()
+        },
+        BulkTaskAction::SetDueDate(due_date) => UpdateTask {
+            due_date: Some(*due_date),
+            ..Default::default()
+        },
+        BulkTaskAction::MarkReviewed => UpdateTask {
+            reviewed_at: Some(Some(Utc::now().date_naive())),
+            ..Default::default()
+        },
+        BulkTaskAction::SetSomeday(someday) => UpdateTask {
+            someday: Some(*someday),
+            ..Default::default()
+        },
+        BulkTaskAction::SetPriority(priority) => UpdateTask {
+            priority: Some(*priority),
+            ..Default::default()
+        },
+    };
+    Some(TaskCommand::Update {
+        id,
+        before: Box::new(previous_values(task, &input)),
+        after: Box::new(input),
+    })
+}
+
+/// The task's current values for the fields `input` sets.
+fn previous_values(task: &TaskModel, input: &UpdateTask) -> UpdateTask {
+    let recurrence = task.recurrence.as_ref();
+    UpdateTask {
+        title: input.title.as_ref().map(|_| task.title.clone()),
+        body: input.body.as_ref().map(|_| task.body.clone()),
+        project_id: input.project_id.map(|_| task.project_id),
+        parent_id: input.parent_id.map(|_| task.parent_id),
+        sort_key: input.sort_key.as_ref().map(|_| task.sort_key.clone()),
+        sequential_limit: input.sequential_limit.map(|_| task.sequential_limit),
+        start_at: input.start_at.map(|_| task.start_at),
+        due_date: input.due_date.map(|_| task.due_date),
+        reviewed_at: input.reviewed_at.map(|_| task.reviewed_at),
+        recurrence_type: input
+            .recurrence_type
+            .map(|_| recurrence.map(|r| r.recurrence_type)),
+        recurrence_rule: input
+            .recurrence_rule
+            .as_ref()
+            .map(|_| recurrence.map(|r| r.rule_string())),
+        recurrence_exdates: input
+            .recurrence_exdates
+            .as_ref()
+            .map(|_| recurrence.map(|r| r.exdates.clone()).unwrap_or_default()),
+        someday: input.someday.map(|_| task.someday),
+        column_id: input.column_id.map(|_| task.column_id),
+        waiting_for: input.waiting_for.as_ref().map(|_| task.waiting_for.clone()),
+        follow_up_date: input.follow_up_date.map(|_| task.follow_up_date),
+        priority: input.priority.map(|_| task.priority),
+        estimate_minutes: input.estimate_minutes.map(|_| task.estimate_minutes),
+        section_id: input.section_id.map(|_| task.section_id),
+        ..Default::default()
+    }
+}
+
+/// Optimistically applies the plain fields of `input`; the rest arrive
+/// with the next refetch.
+fn apply_update(task: &mut TaskModel, input: &UpdateTask) {
+    if let Some(title) = &input.title {
+        task.title = title.clone();
+    }
+    if let Some(body) = &input.body {
+        task.body = body.clone();
+    }
+    if let Some(project_id) = input.project_id {
+        task.project_id = project_id;
+    }
+    if let Some(parent_id) = input.parent_id {
+        task.parent_id = parent_id;
+    }
+    if let Some(sort_key) = &input.sort_key {
+        task.sort_key = sort_key.clone();
+    }
+    if let Some(limit) = input.sequential_limit {
+        task.sequential_limit = limit;
+    }
+    if let Some(start_at) = input.start_at {
+        task.start_at = start_at;
+    }
+    if let Some(due_date) = input.due_date {
+        task.due_date = due_date;
+    }
+    if let Some(reviewed_at) = input.reviewed_at {
+        task.reviewed_at = reviewed_at;
+    }
+    if let Some(someday) = input.someday {
+        task.someday = someday;
+    }
+    if let Some(column_id) = input.column_id {
+        task.column_id = column_id;
+    }
+    if let Some(waiting_for) = &input.waiting_for {
+        task.waiting_for = waiting_for.clone();
+        if waiting_for.is_none() {
+            task.follow_up_date = None;
+        }
+    }
+    if let Some(follow_up_date) = input.follow_up_date {
+        if task.waiting_for.is_some() {
+            task.follow_up_date = follow_up_date;
+        }
+    }
+    if let Some(priority) = input.priority {
+        task.priority = priority;
+    }
+    if let Some(estimate_minutes) = input.estimate_minutes {
+        task.estimate_minutes = estimate_minutes;
+    }
+    if let Some(section_id) = input.section_id {
+        task.section_id = section_id;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> chrono::DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_default_postpone_date_uses_local_days() {
+        // 20:00 on the 10th in Honolulu is already the 11th in UTC.
+        let now = utc("2031-03-11T06:00:00Z");
+        let tz = "Pacific/Honolulu";
+
+        assert_eq!(
+            default_postpone_date(None, None, tz, now),
+            date("2031-03-11")
+        );
+        assert_eq!(
+            default_postpone_date(None, None, "UTC", now),
+            date("2031-03-12")
+        );
+        // A start late on a local day counts for that day.
+        let start = Some(utc("2031-03-15T08:00:00Z"));
+        assert_eq!(
+            default_postpone_date(start, None, tz, now),
+            date("2031-03-15")
+        );
+        assert_eq!(
+            default_postpone_date(None, Some(date("2031-03-20")), tz, now),
+            date("2031-03-21")
+        );
+        // Overdue tasks go to tomorrow, not the day after their old date.
+        assert_eq!(
+            default_postpone_date(None, Some(date("2031-03-01")), tz, now),
+            date("2031-03-11")
+        );
+        // Unknown zones fall back to UTC, as on the server.
+        assert_eq!(
+            default_postpone_date(None, None, "Mars/Olympus", now),
+            date("2031-03-12")
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use north_dto::UpdateTask;
+
+/// How many mutations Ctrl+Z can walk back.
+const MAX_UNDO_DEPTH: usize = 50;
+
+/// A task mutation that can be replayed in either direction.
+#[derive(Clone, Debug)]
+pub enum TaskCommand {
+    /// Field changes. `before` holds the values `after` overwrote.
+    Update {
+        id: i64,
+        before: Box<UpdateTask>,
+        after: Box<UpdateTask>,
+    },
+    SetCompleted {
+        id: i64,
+        completed: bool,
+    },
+    /// Reopens a task as the undo of completing it, also taking back the
+    /// next instance a recurring task spawned.
+    UndoComplete {
+        id: i64,
+    },
+    /// Moves the task (and its subtasks) to the trash.
+    Delete {
+        id: i64,
+    },
+    /// Brings a task back from the trash.
+    Restore {
+        id: i64,
+    },
+    SetTags {
+        id: i64,
+        before: Vec<String>,
+        after: Vec<String>,
+    },
+    /// Adds (`blocked`) or removes a "blocked by" link.
+    SetBlocker {
+        id: i64,
+        blocker_id: i64,
+        blocked: bool,
+    },
+    /// Several commands recorded as one step, e.g. a bulk action.
+    Batch(Vec<TaskCommand>),
+}
+
+impl TaskCommand {
+    pub fn inverse(&self) -> TaskCommand {
+        match self.clone() {
+            TaskCommand::Update { id, before, after } => TaskCommand::Update {
+                id,
+                before: after,
+                after: before,
+            },
+            TaskCommand::SetCompleted {
+                id,
+                completed: true,
+            } => TaskCommand::UndoComplete { id },
+            TaskCommand::SetCompleted {
+                id,
+                completed: false,
+            } => TaskCommand::SetCompleted {
+                id,
+                completed: true,
+            },
+            TaskCommand::UndoComplete { id } => TaskCommand::SetCompleted {
+                id,
+                completed: true,
+            },
+            TaskCommand::Delete { id } => TaskCommand::Restore { id },
+            TaskCommand::Restore { id } => TaskCommand::Delete { id },
+            TaskCommand::SetTags { id, before, after } => TaskCommand::SetTags {
+                id,
+                before: after,
+                after: before,
+            },
+            TaskCommand::SetBlocker {
+                id,
+                blocker_id,
+                blocked,
+            } => TaskCommand::SetBlocker {
+                id,
+                blocker_id,
+                blocked: !blocked,
+            },
+            TaskCommand::Batch(commands) => {
+                TaskCommand::Batch(commands.iter().rev().map(|c| c.inverse()).collect())
+            }
+        }
+    }
+
+    /// Folds a follow-up edit of the same fields into this one, so that
+    /// e.g. several Shift+Arrow presses undo as a single move.
+    fn absorb(&mut self, next: &TaskCommand) -> bool {
+        let (
+            TaskCommand::Update { id, before, after },
+            TaskCommand::Update {
+                id: next_id,
+                before: next_before,
+                after: next_after,
+            },
+        ) = (self, next)
+        else {
+            return false;
+        };
+        if id != next_id {
+            return false;
+        }
+        // Keep the oldest `before` value of each field and the newest `after`.
+        if next_after.sort_key.is_some() {
+            before.sort_key = before.sort_key.take().or(next_before.sort_key.clone());
+            after.sort_key = next_after.sort_key.clone();
+        }
+        if next_after.parent_id.is_some() {
+            before.parent_id = before.parent_id.or(next_before.parent_id);
+            after.parent_id = next_after.parent_id;
+        }
+        if next_after.column_id.is_some() {
+            before.column_id = before.column_id.or(next_before.column_id);
+            after.column_id = next_after.column_id;
+        }
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UndoEntry {
+    /// Shown in the status bar, e.g. "Task deleted".
+    pub label: String,
+    pub command: TaskCommand,
+}
+
+/// Undo and redo history for task mutations made in this session.
+#[derive(Clone, Debug, Default)]
+pub struct UndoStack {
+    undo: Vec<UndoEntry>,
+    redo: Vec<UndoEntry>,
+}
+
+impl UndoStack {
+    /// Records a new mutation and drops the redo history.
+    pub fn push(&mut self, label: impl Into<String>, command: TaskCommand) {
+        self.redo.clear();
+        self.undo.push(UndoEntry {
+            label: label.into(),
+            command,
+        });
+        if self.undo.len() > MAX_UNDO_DEPTH {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Like `push`, but merges into the last entry when it has the same
+    /// label and moves the same task.
+    pub fn push_or_merge(&mut self, label: impl Into<String>, command: TaskCommand) {
+        let label = label.into();
+        if let Some(last) = self.undo.last_mut() {
+            if last.label == label && last.command.absorb(&command) {
+                self.redo.clear();
+                return;
+            }
+        }
+        self.push(label, command);
+    }
+
+    /// Takes the latest mutation; the caller applies its inverse and
+    /// hands the entry back with `push_undone`, or with `push_done` if
+    /// that failed.
+    pub fn undo(&mut self) -> Option<UndoEntry> {
+        self.undo.pop()
+    }
+
+    /// Takes the latest undone mutation; the caller applies it again and
+    /// hands the entry back with `push_done`, or with `push_undone` if
+    /// that failed.
+    pub fn redo(&mut self) -> Option<UndoEntry> {
+        self.redo.pop()
+    }
+
+    /// Files an entry that is in effect, keeping the redo history.
+    pub fn push_done(&mut self, entry: UndoEntry) {
+        self.undo.push(entry);
+        if self.undo.len() > MAX_UNDO_DEPTH {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Files an entry that has been undone.
+    pub fn push_undone(&mut self, entry: UndoEntry) {
+        self.redo.push(entry);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reorder(id: i64, from: &str, to: &str) -> TaskCommand {
+        TaskCommand::Update {
+            id,
+            before: Box::new(UpdateTask {
+                sort_key: Some(from.into()),
+                ..Default::default()
+            }),
+            after: Box::new(UpdateTask {
+                sort_key: Some(to.into()),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn sort_keys(command: &TaskCommand) -> (Option<String>, Option<String>) {
+        match command {
+            TaskCommand::Update { before, after, .. } => {
+                (before.sort_key.clone(), after.sort_key.clone())
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_undo_redo_order() {
+        let mut stack = UndoStack::default();
+        stack.push("Task deleted", TaskCommand::Delete { id: 1 });
+        stack.push(
+            "Task completed",
+            TaskCommand::SetCompleted {
+                id: 2,
+                completed: true,
+            },
+        );
+
+        let completed = stack.undo().unwrap();
+        assert_eq!(completed.label, "Task completed");
+        stack.push_undone(completed);
+        let deleted = stack.undo().unwrap();
+        assert_eq!(deleted.label, "Task deleted");
+        stack.push_undone(deleted);
+        assert!(stack.undo().is_none());
+        let deleted = stack.redo().unwrap();
+        assert_eq!(deleted.label, "Task deleted");
+        stack.push_done(deleted);
+        assert!(stack.can_redo());
+        assert!(stack.can_undo());
+
+        // A new mutation forgets what was undone.
+        stack.push("Task deleted", TaskCommand::Delete { id: 3 });
+        assert!(!stack.can_redo());
+    }
+
+    #[test]
+    fn test_failed_undo_keeps_entry() {
+        let mut stack = UndoStack::default();
+        stack.push("Task deleted", TaskCommand::Delete { id: 1 });
+        stack.push("Task deleted", TaskCommand::Delete { id: 2 });
+
+        // While the server is asked, the entry is on neither side.
+        let entry = stack.undo().unwrap();
+        assert!(!stack.can_redo());
+        // It refused: the same entry is next to undo again.
+        stack.push_done(entry);
+        assert!(!stack.can_redo());
+        assert!(matches!(
+            stack.undo().unwrap().command,
+            TaskCommand::Delete { id: 2 }
+        ));
+
+        // A failed redo stays redoable.
+        let mut stack = UndoStack::default();
+        stack.push("Task deleted", TaskCommand::Delete { id: 3 });
+        let entry = stack.undo().unwrap();
+        stack.push_undone(entry);
+        let entry = stack.redo().unwrap();
+        stack.push_undone(entry);
+        assert!(!stack.can_undo());
+        assert!(stack.can_redo());
+    }
+
+    #[test]
+    fn test_push_or_merge_coalesces_moves() {
+        let mut stack = UndoStack::default();
+        stack.push_or_merge("Task moved", reorder(1, "a", "b"));
+        stack.push_or_merge("Task moved", reorder(1, "b", "c"));
+        stack.push_or_merge("Task moved", reorder(2, "x", "y"));
+
+        let entry = stack.undo().unwrap();
+        assert_eq!(
+            sort_keys(&entry.command),
+            (Some("x".into()), Some("y".into()))
+        );
+        let entry = stack.undo().unwrap();
+        assert_eq!(
+            sort_keys(&entry.command),
+            (Some("a".into()), Some("c".into()))
+        );
+        assert!(!stack.can_undo());
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert!(matches!(
+            TaskCommand::Delete { id: 4 }.inverse(),
+            TaskCommand::Restore { id: 4 }
+        ));
+        assert!(matches!(
+            TaskCommand::SetCompleted {
+                id: 4,
+                completed: true
+            }
+            .inverse(),
+            TaskCommand::UndoComplete { id: 4 }
+        ));
+        assert!(matches!(
+            TaskCommand::SetCompleted {
+                id: 4,
+                completed: false
+            }
+            .inverse(),
+            TaskCommand::SetCompleted {
+                id: 4,
+                completed: true
+            }
+        ));
+        assert!(matches!(
+            TaskCommand::SetBlocker {
+                id: 4,
+                blocker_id: 7,
+                blocked: true
+            }
+            .inverse(),
+            TaskCommand::SetBlocker {
+                id: 4,
+                blocker_id: 7,
+                blocked: false
+            }
+        ));
+        assert_eq!(
+            sort_keys(&reorder(4, "a", "b").inverse()),
+            (Some("b".into()), Some("a".into()))
+        );
+    }
+
+    #[test]
+    fn test_batch_inverse_reverses_order() {
+        let batch = TaskCommand::Batch(vec![
+            TaskCommand::Delete { id: 1 },
+            TaskCommand::Delete { id: 2 },
+        ]);
+        let TaskCommand::Batch(inverse) = batch.inverse() else {
+            panic!("expected a batch");
+        };
+        assert!(matches!(
+            inverse.as_slice(),
+            [
+                TaskCommand::Restore { id: 2 },
+                TaskCommand::Restore { id: 1 }
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_depth_is_capped() {
+        let mut stack = UndoStack::default();
+        for id in 0..(MAX_UNDO_DEPTH as i64 + 5) {
+            stack.push("Task deleted", TaskCommand::Delete { id });
+        }
+        let mut count = 0;
+        while stack.undo().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, MAX_UNDO_DEPTH);
+    }
+}
@@ -4,6 +4,12 @@ use leptos::prelude::*;
 pub struct SuggestionItem {
     pub name: String,
     pub color: String,
+    /// Heading shown above the first item of each run of equal groups.
+    pub group: Option<String>,
+    /// Passed to `on_select` instead of `name` when set.
+    pub value: Option<String>,
+    /// Dimmed text after the name.
+    pub detail: Option<String>,
 }
 
 #[component]
@@ -11,6 +17,10 @@ pub fn AutocompleteDropdown(
     items: Vec<SuggestionItem>,
     highlighted: ReadSignal<usize>,
     on_select: Callback<String>,
+    /// Replaces the default popup placement and size, e.g. to show the
+    /// list inline.
+    #[prop(optional)]
+    class: Option<&'static str>,
 ) -> impl IntoView {
     if items.is_empty() {
         return view! { <div class="hidden"/> }.into_any();
@@ -19,17 +29,31 @@ pub fn AutocompleteDropdown(
     view! {
         <div
             data-testid="autocomplete-dropdown"
-            class="absolute top-full z-50 mt-1 bg-bg-secondary border \
-                    border-border/60 rounded-xl shadow-lg p-1 w-[200px] \
-                    max-h-[200px] overflow-y-auto">
+            class=class.unwrap_or(
+                "absolute top-full z-50 mt-1 bg-bg-secondary border \
+                 border-border/60 rounded-xl shadow-lg p-1 w-[200px] \
+                 max-h-[200px] overflow-y-auto",
+            )>
             {items
-                .into_iter()
+                .iter()
                 .enumerate()
                 .map(|(i, item)| {
                     let name = item.name.clone();
                     let color = item.color.clone();
-                    let select_name = name.clone();
+                    let select_name = item.value.clone().unwrap_or_else(|| name.clone());
+                    let detail = item.detail.clone();
+                    let heading = item
+                        .group
+                        .clone()
+                        .filter(|g| i == 0 || items[i - 1].group.as_ref() != Some(g));
                     view! {
+                        {heading.map(|g| {
+                            view! {
+                                <div class="px-3 pt-1.5 pb-0.5 text-xs text-text-tertiary">
+                                    {g}
+                                </div>
+                            }
+                        })}
                         <button
                             data-testid="autocomplete-item"
                             class=move || {
@@ -51,7 +75,12 @@ pub fn AutocompleteDropdown(
                                 class="w-2.5 h-2.5 rounded-full flex-shrink-0"
                                 style=format!("background-color: {}", color)
                             />
-                            <span class="flex-1">{name}</span>
+                            <span class="flex-1 truncate">{name}</span>
+                            {detail.map(|d| {
+                                view! {
+                                    <span class="text-xs text-text-tertiary truncate">{d}</span>
+                                }
+                            })}
                         </button>
                     }
                 })
@@ -0,0 +1,3 @@
+-- EXDATE is its own iCalendar property rather than part of the RRULE, so
+-- exception dates get a column of their own.
+ALTER TABLE tasks ADD COLUMN recurrence_exdates DATE[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,5 @@
+ALTER TABLE tasks ADD COLUMN series_id BIGINT;
+
+UPDATE tasks SET series_id = id WHERE recurrence_rule IS NOT NULL;
+
+CREATE INDEX idx_tasks_series_id ON tasks(series_id);
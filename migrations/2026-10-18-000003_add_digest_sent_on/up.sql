@@ -0,0 +1,2 @@
+-- Local date of the last digest email, so each one is sent at most once.
+ALTER TABLE users ADD COLUMN digest_sent_on DATE;
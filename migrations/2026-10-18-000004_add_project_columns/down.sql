@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_tasks_column_id;
+ALTER TABLE tasks DROP COLUMN column_id;
+DROP TABLE IF EXISTS project_columns;
@@ -0,0 +1,14 @@
+CREATE TABLE project_columns (
+    id BIGSERIAL PRIMARY KEY,
+    project_id BIGINT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    position INT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_project_columns_project_id ON project_columns(project_id, position);
+
+ALTER TABLE tasks
+    ADD COLUMN column_id BIGINT REFERENCES project_columns(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_column_id ON tasks(column_id);
@@ -0,0 +1,16 @@
+CREATE TYPE task_event_kind AS ENUM (
+    'created', 'updated', 'completed', 'uncompleted', 'reviewed', 'moved', 'tags_changed'
+);
+
+CREATE TABLE task_events (
+    id BIGSERIAL PRIMARY KEY,
+    task_id BIGINT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind task_event_kind NOT NULL,
+    field TEXT,
+    old_value TEXT,
+    new_value TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_task_events_task_id ON task_events(task_id, created_at);
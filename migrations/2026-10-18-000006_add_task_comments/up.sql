@@ -0,0 +1,11 @@
+CREATE TABLE task_comments (
+    id BIGSERIAL PRIMARY KEY,
+    task_id BIGINT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    -- Set on user edits only, so background URL resolution doesn't mark a comment edited.
+    edited_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_task_comments_task_id ON task_comments(task_id, created_at);
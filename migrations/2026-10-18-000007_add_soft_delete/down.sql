@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_projects_deleted_at;
+DROP INDEX IF EXISTS idx_tasks_deleted_at;
+
+ALTER TABLE projects DROP COLUMN deleted_at;
+ALTER TABLE tasks DROP COLUMN deleted_at;
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_tasks_waiting;
+
+ALTER TABLE tasks DROP COLUMN follow_up_date;
+ALTER TABLE tasks DROP COLUMN waiting_for;
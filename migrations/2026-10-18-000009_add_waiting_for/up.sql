@@ -0,0 +1,5 @@
+-- A task is waiting while `waiting_for` (who it's delegated to) is set.
+ALTER TABLE tasks ADD COLUMN waiting_for TEXT;
+ALTER TABLE tasks ADD COLUMN follow_up_date DATE;
+
+CREATE INDEX idx_tasks_waiting ON tasks(user_id, follow_up_date) WHERE waiting_for IS NOT NULL;
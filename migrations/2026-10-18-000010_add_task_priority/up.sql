@@ -0,0 +1,2 @@
+-- 1 is the highest priority; NULL means none.
+ALTER TABLE tasks ADD COLUMN priority SMALLINT CHECK (priority BETWEEN 1 AND 4);
@@ -0,0 +1,21 @@
+-- Areas group projects in the sidebar. Archiving an area archives every
+-- project in it without touching the projects' own status.
+CREATE TABLE areas (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+    status project_status NOT NULL DEFAULT 'active',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_areas_user_id ON areas(user_id);
+
+CREATE TRIGGER areas_updated_at
+    BEFORE UPDATE ON areas
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+ALTER TABLE projects ADD COLUMN area_id BIGINT REFERENCES areas(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_projects_area_id ON projects(area_id);
@@ -0,0 +1,16 @@
+-- Sections split a project's list view into headed groups. Only top-level
+-- tasks carry a section; subtasks follow their root.
+CREATE TABLE project_sections (
+    id BIGSERIAL PRIMARY KEY,
+    project_id BIGINT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    sort_key VARCHAR NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_project_sections_project_id ON project_sections(project_id, sort_key);
+
+ALTER TABLE tasks
+    ADD COLUMN section_id BIGINT REFERENCES project_sections(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_section_id ON tasks(section_id);
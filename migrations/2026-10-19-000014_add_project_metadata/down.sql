@@ -0,0 +1,2 @@
+ALTER TABLE projects DROP COLUMN outcome;
+ALTER TABLE projects DROP COLUMN due_date;
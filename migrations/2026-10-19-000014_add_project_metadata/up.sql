@@ -0,0 +1,4 @@
+-- Planning fields: when the project should be done and what "done" looks
+-- like (the GTD desired outcome).
+ALTER TABLE projects ADD COLUMN due_date DATE;
+ALTER TABLE projects ADD COLUMN outcome TEXT;
@@ -0,0 +1,2 @@
+ALTER TABLE projects DROP COLUMN review_interval_days;
+ALTER TABLE projects DROP COLUMN reviewed_at;
@@ -0,0 +1,5 @@
+-- Projects are reviewed like tasks; a NULL interval falls back to the
+-- user's review_interval_days setting.
+ALTER TABLE projects ADD COLUMN reviewed_at DATE;
+ALTER TABLE projects ADD COLUMN review_interval_days SMALLINT
+    CHECK (review_interval_days > 0);
@@ -0,0 +1,20 @@
+-- Weekly reviews in progress and completed. `steps` is a JSON array of
+-- {kind, title, done} copied from the user's settings at the start.
+CREATE TABLE weekly_reviews (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    steps JSONB NOT NULL,
+    current_step INTEGER NOT NULL DEFAULT 0,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    completed_at TIMESTAMPTZ
+);
+
+-- At most one review in progress per user.
+CREATE UNIQUE INDEX idx_weekly_reviews_open ON weekly_reviews(user_id)
+    WHERE completed_at IS NULL;
+CREATE INDEX idx_weekly_reviews_user_completed ON weekly_reviews(user_id, completed_at);
+
+CREATE TRIGGER weekly_reviews_updated_at
+    BEFORE UPDATE ON weekly_reviews
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
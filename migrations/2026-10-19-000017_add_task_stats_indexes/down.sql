@@ -0,0 +1,2 @@
+DROP INDEX idx_tasks_user_completed;
+DROP INDEX idx_tasks_user_created;
@@ -0,0 +1,4 @@
+-- Date-bucketed aggregates on the stats page scan a user's tasks by when
+-- they were created and completed.
+CREATE INDEX idx_tasks_user_created ON tasks(user_id, created_at);
+CREATE INDEX idx_tasks_user_completed ON tasks(user_id, completed_at) WHERE completed_at IS NOT NULL;
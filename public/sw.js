@@ -0,0 +1,21 @@
+// Service worker for reminder push notifications.
+self.addEventListener('push', (event) => {
+  const data = event.data ? event.data.json() : {};
+  event.waitUntil(
+    self.registration.showNotification(data.title || 'North', {
+      body: data.body || '',
+      tag: data.task_id ? `task-${data.task_id}` : undefined,
+      icon: '/public/logo.png',
+    })
+  );
+});
+
+self.addEventListener('notificationclick', (event) => {
+  event.notification.close();
+  event.waitUntil(
+    self.clients.matchAll({ type: 'window' }).then((clients) => {
+      const open = clients.find((c) => 'focus' in c);
+      return open ? open.focus() : self.clients.openWindow('/');
+    })
+  );
+});